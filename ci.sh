@@ -4,6 +4,7 @@ set -ex
 
 THIS=$PWD
 cargo build --release
+cargo test --release
 
 cd $THIS/tests
 ./ci.sh
@@ -0,0 +1,102 @@
+use super::name::Name;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Field {
+    pub name: String,
+    pub typed: String,
+    pub array: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Symbol {
+    Function {
+        ret: String,
+        args: Vec<String>,
+        vararg: bool,
+    },
+    Closure {
+        ret: String,
+        args: Vec<String>,
+    },
+    Struct {
+        fields: Vec<Field>,
+        packed: bool,
+        union: bool,
+        tail: bool,
+    },
+    Enum {
+        values: Vec<(String, u64)>,
+    },
+}
+
+/// exported C symbols by name
+pub type Abi = BTreeMap<String, Symbol>;
+
+pub fn write(path: &Path, abi: &Abi) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect(&format!("cannot create {:?}", parent));
+    }
+    let s = serde_json::to_string_pretty(abi).unwrap();
+    fs::write(path, s).expect(&format!("cannot write {:?}", path));
+}
+
+pub fn read(path: &Path) -> Option<Abi> {
+    let s = fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&s) {
+        Ok(v) => Some(v),
+        Err(e) => {
+            error!("cannot read abi {:?}: {}", path, e);
+            super::exit(9);
+        }
+    }
+}
+
+/// merge the abi files of the modules in this build, so removed modules dont linger
+pub fn merge<'a>(stage: &str, modules: impl Iterator<Item = &'a Name>) {
+    let mut abi = Abi::new();
+    for name in modules {
+        let path = format!("target/{}/abi/{}.json", stage, name.0[1..].join("_"));
+        if let Some(m) = read(Path::new(&path)) {
+            abi.extend(m);
+        }
+    }
+    write(Path::new(&format!("target/{}/abi.json", stage)), &abi);
+}
+
+pub struct Diff {
+    pub removed: Vec<String>,
+    pub changed: Vec<(String, Symbol, Symbol)>,
+    pub added: Vec<String>,
+}
+
+impl Diff {
+    pub fn is_breaking(&self) -> bool {
+        self.removed.len() > 0 || self.changed.len() > 0
+    }
+}
+
+pub fn diff(baseline: &Abi, current: &Abi) -> Diff {
+    let mut d = Diff {
+        removed: Vec::new(),
+        changed: Vec::new(),
+        added: Vec::new(),
+    };
+    for (name, was) in baseline {
+        match current.get(name) {
+            None => d.removed.push(name.clone()),
+            Some(is) if is != was => d.changed.push((name.clone(), was.clone(), is.clone())),
+            Some(_) => (),
+        }
+    }
+    for name in current.keys() {
+        if !baseline.contains_key(name) {
+            d.added.push(name.clone());
+        }
+    }
+    d
+}
@@ -1,5 +1,6 @@
 /// make all names in a module absolute
 use super::ast;
+use super::generic;
 use super::loader;
 use super::makro;
 use super::name::Name;
@@ -10,6 +11,11 @@ use std::sync::{Arc, Mutex};
 
 static ABORT: AtomicBool = AtomicBool::new(false);
 
+/// clear errors left over from an earlier build in the same process
+pub fn reset() {
+    ABORT.store(false, Ordering::Relaxed);
+}
+
 #[derive(Clone)]
 pub struct Ext {
     pub ext: Arc<Mutex<HashMap<Name, ast::Local>>>,
@@ -21,6 +27,7 @@ impl Ext {
         ext.insert(
             Name::from("::ext::<stddef.h>"),
             ast::Local {
+                export_name: None,
                 doc: String::new(),
                 name: "::ext::<stddef.h>".to_string(),
                 vis: ast::Visibility::Object,
@@ -91,7 +98,7 @@ impl Scope {
                         (previous.loc.clone(), "also declared here"),
                     ],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         }
         trace!("  insert {} := {}", local, fqn);
@@ -120,10 +127,30 @@ impl Scope {
         }
     }
 
+    /// a tail bound to a constant refers to that constant
+    pub fn abs_tail(&self, t: &mut ast::Typed) {
+        if let ast::Tail::Bind(s, loc) = &t.tail {
+            if let Some(v) = self.get(s) {
+                if !v.is_module && v.name.is_absolute() {
+                    t.tail = ast::Tail::Bind(v.name.to_string(), loc.clone());
+                }
+            }
+        }
+    }
+
     pub fn abs(&self, t: &mut ast::Typed, inbody: bool) {
         for ptr in &mut t.ptr {
             self.tags(&mut ptr.tags);
         }
+        if let ast::Tail::Generic(args) = &mut t.tail {
+            for arg in args {
+                self.abs(arg, false);
+            }
+        }
+        if let ast::Type::Slice(mem) = &mut t.t {
+            self.abs(mem, inbody);
+            return;
+        }
 
         let name = match &mut t.t {
             ast::Type::Other(name) => name,
@@ -386,7 +413,7 @@ fn abs_import(
         format!("cannot find module '{}'", import.name),
         &[(import.loc.clone(), "imported here")],
     );
-    std::process::exit(9);
+    super::exit(9);
 }
 
 fn check_abs_available(
@@ -420,6 +447,11 @@ fn check_abs_available(
         return;
     }
 
+    // type parameter of a generic
+    if module_name.0[1] == generic::PARAMS {
+        return;
+    }
+
     if &module_name == selfname {
         return;
     }
@@ -449,7 +481,7 @@ fn check_abs_available(
                 ),
                 &[(loc.clone(), "expected to be in scope here")],
             );
-            std::process::exit(9);
+            super::exit(9);
         }
         Some(loader::Module::C(_)) => return,
         Some(loader::Module::ZZ(v)) => v,
@@ -605,7 +637,7 @@ fn abs_expr(
                 Err(e) => {
                     if scope.macros_available {
                         emit_error(e.message, &e.details);
-                        std::process::exit(9);
+                        super::exit(9);
                     } else {
                         scope.complete.replace(false);
                     }
@@ -616,6 +648,22 @@ fn abs_expr(
             abs_expr(lhs, scope, inbody, all_modules, self_md_name);
             abs_expr(rhs, scope, inbody, all_modules, self_md_name);
         }
+        ast::Expression::Slice { lhs, from, to, .. } => {
+            abs_expr(lhs, scope, inbody, all_modules, self_md_name);
+            for expr in from.iter_mut().chain(to.iter_mut()) {
+                abs_expr(expr, scope, inbody, all_modules, self_md_name);
+            }
+        }
+        ast::Expression::Ternary {
+            cond,
+            then,
+            otherwise,
+            ..
+        } => {
+            abs_expr(cond, scope, inbody, all_modules, self_md_name);
+            abs_expr(then, scope, inbody, all_modules, self_md_name);
+            abs_expr(otherwise, scope, inbody, all_modules, self_md_name);
+        }
         ast::Expression::Cpp{ expr, ..} => {
             abs_expr(expr, scope, inbody, all_modules, self_md_name);
         }
@@ -643,6 +691,9 @@ fn abs_statement(
         ast::Statement::Unsafe(b2) => {
             abs_block(b2, &scope, all_modules, self_md_name);
         }
+        ast::Statement::Defer { stm, .. } => {
+            abs_statement(stm, scope, inbody, all_modules, self_md_name);
+        }
         ast::Statement::For { e1, e2, e3, body } => {
             abs_block(body, &scope, all_modules, self_md_name);
             for s in e1 {
@@ -686,6 +737,7 @@ fn abs_statement(
                 }
             }
             scope.abs(typed, false);
+            scope.abs_tail(typed);
             if let ast::Type::Other(ref mut name) = &mut typed.t {
                 check_abs_available(
                     name,
@@ -806,7 +858,7 @@ fn expand_makro_statements(
                     Err(e) => {
                         if scope.macros_available {
                             emit_error(e.message, &e.details);
-                            std::process::exit(9);
+                            super::exit(9);
                         } else {
                             scope.complete.replace(false);
                             i += 1;
@@ -866,7 +918,7 @@ pub fn abs(
                             format!("path resolve error"),
                             &[(import.loc.clone(), format!("{} : {:?}", e, path))],
                         );
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                 };
                 expr = path.to_string_lossy().into();
@@ -935,6 +987,7 @@ pub fn abs(
                     let subname = format!("{}::{}", ast.name, name);
 
                     new_locals.push(ast::Local {
+                        export_name: None,
                         doc: String::new(),
                         name: subname.clone(),
                         loc: ast.loc.clone(),
@@ -958,6 +1011,15 @@ pub fn abs(
                 }
                 scope.insert(ast.name.clone(), ns, &ast.loc, false, true);
             }
+            ast::Def::Struct { variants, .. } if !variants.is_empty() => {
+                for (name, _) in variants.iter() {
+                    let subname = format!("{}::{}", ast.name, name);
+                    let mut ns = md.name.clone();
+                    ns.push(subname.clone());
+                    scope.insert(subname, ns, &ast.loc, false, false);
+                }
+                scope.insert(ast.name.clone(), ns, &ast.loc, false, true);
+            }
             _ => {
                 scope.insert(ast.name.clone(), ns, &ast.loc, false, false);
             }
@@ -969,9 +1031,15 @@ pub fn abs(
     // round two, make all dependencies absolute
     for ast in &mut md.locals {
         match &mut ast.def {
-            ast::Def::Static { typed, expr, .. } => {
+            ast::Def::Static {
+                typed, expr, array, ..
+            } => {
                 abs_expr(expr, &scope, false, all_modules, &md.name);
+                if let ast::Array::Sized(array) = array {
+                    abs_expr(array, &scope, false, all_modules, &md.name);
+                }
                 scope.abs(typed, false);
+                scope.abs_tail(typed);
                 if let ast::Type::Other(ref mut name) = &mut typed.t {
                     check_abs_available(name, &ast.vis, all_modules, &typed.loc, &md.name);
                 }
@@ -989,8 +1057,11 @@ pub fn abs(
                 ref mut body,
                 callassert,
                 calleffect,
+                generics,
                 ..
             } => {
+                scope.push();
+                generic_params(&mut scope, generics, &ast.loc);
 
                 for (_,expr, _) in &mut body.branches {
                     if let Some(expr) = expr {
@@ -1018,6 +1089,7 @@ pub fn abs(
                     abs_block(block, &scope, all_modules, &md.name);
                 }
                 scope.pop();
+                scope.pop();
             }
             ast::Def::Closure { ret, args, .. } => {
                 if let Some(ret) = ret {
@@ -1045,10 +1117,24 @@ pub fn abs(
                     }
                 }
             }
-            ast::Def::Struct { fields, .. } => {
+            ast::Def::Struct {
+                fields,
+                generics,
+                implements,
+                ..
+            } => {
+                scope.push();
+                generic_params(&mut scope, generics, &ast.loc);
+                for typed in implements.iter_mut() {
+                    scope.abs(typed, false);
+                    if let ast::Type::Other(ref mut name) = &mut typed.t {
+                        check_abs_available(name, &ast.vis, all_modules, &typed.loc, &md.name);
+                    }
+                }
                 let fieldslen = fields.len();
                 for (i, field) in fields.iter_mut().enumerate() {
                     scope.abs(&mut field.typed, false);
+                    scope.abs_tail(&mut field.typed);
                     if let ast::Type::Other(ref mut name) = &mut field.typed.t {
                         check_abs_available(
                             name,
@@ -1063,7 +1149,7 @@ pub fn abs(
                     }
 
                     match field.typed.tail {
-                        ast::Tail::None | ast::Tail::Static(_, _) => {}
+                        ast::Tail::None | ast::Tail::Static(_, _) | ast::Tail::Generic(_) => {}
                         ast::Tail::Bind(_, _) | ast::Tail::Dynamic(_) => {
                             if i != fieldslen - 1 {
                                 emit_error(
@@ -1076,11 +1162,12 @@ pub fn abs(
                                         ),
                                     )],
                                 );
-                                std::process::exit(9);
+                                super::exit(9);
                             }
                         }
                     }
                 }
+                scope.pop();
             }
             ast::Def::Symbol { .. } => {}
             ast::Def::Enum { .. } => {}
@@ -1088,10 +1175,40 @@ pub fn abs(
                 abs_block(body, &scope, all_modules, &md.name);
             }
             ast::Def::Testcase { fields, .. } => {
-                for (_, expr) in fields {
+                for (_, _, expr) in fields {
                     abs_expr(expr, &scope, false, all_modules, &md.name);
                 }
             }
+            ast::Def::StaticAssert { expr, .. } => {
+                abs_expr(expr, &scope, false, all_modules, &md.name);
+            }
+            ast::Def::Interface { methods } => {
+                for method in methods {
+                    if let ast::Def::Function {
+                        ret,
+                        args,
+                        callassert,
+                        calleffect,
+                        generics,
+                        ..
+                    } = &mut method.def
+                    {
+                        scope.push();
+                        generic_params(&mut scope, generics, &method.loc);
+                        if let Some(ret) = ret {
+                            scope.abs(&mut ret.typed, false);
+                            if let ast::Type::Other(ref mut name) = &mut ret.typed.t {
+                                check_abs_available(name, &ast.vis, all_modules, &ret.typed.loc, &md.name);
+                            }
+                        }
+                        abs_args(args, &mut scope, &ast.vis, all_modules, &md.name);
+                        for expr in calleffect.iter_mut().chain(callassert.iter_mut()) {
+                            abs_expr(expr, &scope, true, all_modules, &md.name);
+                        }
+                        scope.pop();
+                    }
+                }
+            }
             ast::Def::Include { needs, .. } => {
                 for (t, _) in needs {
                     scope.abs(t, false);
@@ -1100,6 +1217,10 @@ pub fn abs(
         }
     }
 
+    for typed in &mut md.instances {
+        scope.abs(typed, false);
+    }
+
     // round three, create ext types
     for import in &mut md.imports {
         for (name, _) in &mut import.needs {
@@ -1126,7 +1247,7 @@ pub fn abs(
                                 ),
                             ],
                         );
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                 }
             }
@@ -1141,14 +1262,25 @@ pub fn abs(
                             format!("'{}' is not a relative include", expr),
                         )],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
                 expr = (&expr[1..expr.len() - 1]).to_string();
             }
 
-            ext.ext.lock().unwrap().insert(
+            // modules are resolved in parallel. the first import in the source tree wins,
+            // so the emitted include doesn't depend on which module got here first
+            let mut ext = ext.ext.lock().unwrap();
+            if let Some(previous) = ext.get(&import.name) {
+                if !previous.loc.file.is_empty()
+                    && (&previous.loc.file, previous.loc.start) <= (&import.loc.file, import.loc.start)
+                {
+                    continue;
+                }
+            }
+            ext.insert(
                 import.name.clone(),
                 ast::Local {
+                    export_name: None,
                     doc: String::new(),
                     name: import.name.to_string(),
                     vis: ast::Visibility::Object,
@@ -1167,12 +1299,19 @@ pub fn abs(
 
     if ABORT.load(Ordering::Relaxed) {
         warn!("exit abs due to previous errors");
-        std::process::exit(9);
+        super::exit(9);
     }
 
     return scope.complete.into_inner();
 }
 
+/// type parameters resolve to placeholders, which the generic pass substitutes per instance
+fn generic_params(scope: &mut Scope, generics: &Vec<String>, loc: &ast::Location) {
+    for param in generics {
+        scope.insert(param.clone(), generic::param(param), loc, false, false);
+    }
+}
+
 fn abs_args(
     args: &mut Vec<ast::NamedArg>,
     scope: &mut Scope,
@@ -1190,7 +1329,7 @@ fn abs_args(
 
         args.push(arg.clone());
         match &arg.typed.tail {
-            ast::Tail::None => {}
+            ast::Tail::None | ast::Tail::Generic(_) => {}
             ast::Tail::Dynamic(_) => {
                 emit_error(
                     format!("missing tail binding "),
@@ -1199,7 +1338,7 @@ fn abs_args(
                         "+ without a name makes no sense in this context",
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
             ast::Tail::Static(_, _) => {
                 emit_error(
@@ -1209,7 +1348,7 @@ fn abs_args(
                         "+ with static size makes no sense in this context",
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
             ast::Tail::Bind(s, loc) => {
                 let mut tags = ast::Tags::new();
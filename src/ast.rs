@@ -78,6 +78,8 @@ pub enum Tail {
     Dynamic(Option<Box<Typed>> /*final*/),
     Static(u64, Location),
     Bind(String, Location),
+    /// type arguments of a generic. gone after the generic pass
+    Generic(Vec<Typed>),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -87,6 +89,51 @@ pub struct Derive {
     pub args: Vec<Box<Expression>>,
 }
 
+/// how a testcase field is compared against what the test produced
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TestOp {
+    Equals,
+    Nequals,
+    Lessthan,
+    Moreeq,
+    Morethan,
+    Lesseq,
+    Contains,
+    Matches,
+    Nmatches,
+}
+
+impl TestOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TestOp::Equals => "==",
+            TestOp::Nequals => "!=",
+            TestOp::Lessthan => "<",
+            TestOp::Moreeq => ">=",
+            TestOp::Morethan => ">",
+            TestOp::Lesseq => "<=",
+            TestOp::Contains => "contains",
+            TestOp::Matches => "~",
+            TestOp::Nmatches => "!~",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "==" | "=" => TestOp::Equals,
+            "!=" => TestOp::Nequals,
+            "<" => TestOp::Lessthan,
+            ">=" => TestOp::Moreeq,
+            ">" => TestOp::Morethan,
+            "<=" => TestOp::Lesseq,
+            "contains" => TestOp::Contains,
+            "~" => TestOp::Matches,
+            "!~" => TestOp::Nmatches,
+            _ => return None,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Array {
     None,
@@ -120,6 +167,9 @@ pub enum Def {
 
         // never checked, only asserted into smt
         callattests: Vec<Expression>,
+
+        /// type parameters. a generic fn is a template that is only emitted as its instances
+        generics: Vec<String>,
     },
     Theory {
         ret: Option<AnonArg>,
@@ -138,6 +188,12 @@ pub enum Def {
         tail: Tail,
         union: bool,
         impls: HashMap<String, (Name, Location)>,
+        /// tags of a tagged union. the first field is the tag, the rest are the variant payloads
+        variants: Vec<(String, u64)>,
+        /// type parameters, same as for fn
+        generics: Vec<String>,
+        /// interfaces this struct declares to implement
+        implements: Vec<Typed>,
     },
     Symbol {},
     Enum {
@@ -148,7 +204,16 @@ pub enum Def {
         body: Block,
     },
     Testcase {
-        fields: Vec<(String, Expression)>,
+        fields: Vec<(String, TestOp, Expression)>,
+    },
+    /// method signatures with their contracts. each method is a fn without body, generic over Self
+    Interface {
+        methods: Vec<Local>,
+    },
+    /// top level static_assert(expr, "message"), checked by expand
+    StaticAssert {
+        expr: Expression,
+        message: String,
     },
     Include {
         expr: String,
@@ -166,6 +231,9 @@ pub struct Local {
     pub loc: Location,
     pub def: Def,
     pub doc: String,
+    /// stable C symbol name from @export_name, independent of the module path
+    #[serde(default)]
+    pub export_name: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -213,6 +281,10 @@ pub enum Type {
     ILiteral,
 
     Other(Name),
+
+    /// T[], a pointer and its length. holds the type of the pointer,
+    /// whose tags say if the memory is mutable
+    Slice(Box<Typed>),
 }
 
 impl Type {
@@ -231,7 +303,8 @@ impl Type {
             | Type::F32
             | Type::F64
             | Type::ULiteral
-            | Type::Other(_) => false,
+            | Type::Other(_)
+            | Type::Slice(_) => false,
 
             Type::I8
             | Type::I16
@@ -253,6 +326,16 @@ pub struct Typed {
     pub tail: Tail,
 }
 
+impl Typed {
+    /// the outermost pointer may be null, written as T?*
+    pub fn nullable(&self) -> bool {
+        match self.ptr.last() {
+            Some(ptr) => ptr.tags.contains("nullable"),
+            None => false,
+        }
+    }
+}
+
 impl PartialEq for Typed {
     fn eq(&self, other: &Self) -> bool {
         self.t == other.t && self.ptr.len() == other.ptr.len() && self.tail == other.tail
@@ -283,9 +366,19 @@ impl std::fmt::Display for Typed {
             Type::ILiteral => write!(f, "iliteral"),
             Type::ULiteral => write!(f, "uliteral"),
             Type::Other(name) => write!(f, "{}", name),
+            Type::Slice(mem) => {
+                let mut elem = (**mem).clone();
+                match elem.ptr.pop() {
+                    Some(ptr) if ptr.tags.contains("mut") => write!(f, "{} mut[]", elem),
+                    _ => write!(f, "{}[]", elem),
+                }
+            }
         }?;
 
-        for _ in &self.ptr {
+        for ptr in &self.ptr {
+            if ptr.tags.contains("nullable") {
+                write!(f, "?")?;
+            }
             write!(f, "*")?;
         }
         match &self.tail {
@@ -293,6 +386,16 @@ impl std::fmt::Display for Typed {
             Tail::Dynamic(_) => write!(f, "+")?,
             Tail::Static(v, _) => write!(f, "+{}", v)?,
             Tail::Bind(v, _) => write!(f, "+{}", v)?,
+            Tail::Generic(args) => {
+                write!(f, "<")?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                write!(f, ">")?;
+            }
         }
         Ok(())
     }
@@ -305,6 +408,10 @@ pub struct Module {
     pub locals: Vec<Local>,
     pub imports: Vec<Import>,
     pub sources: HashSet<PathBuf>,
+    /// instances of generics requested with `export Name<T>;`
+    pub instances: Vec<Typed>,
+    /// locals added by the generic pass
+    pub instantiated: HashSet<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -351,6 +458,35 @@ pub enum InfixOperator {
     Bitor,
 }
 
+impl std::fmt::Display for InfixOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                InfixOperator::Equals => "==",
+                InfixOperator::Nequals => "!=",
+                InfixOperator::Add => "+",
+                InfixOperator::Subtract => "-",
+                InfixOperator::Multiply => "*",
+                InfixOperator::Divide => "/",
+                InfixOperator::Bitxor => "^",
+                InfixOperator::Booland => "&&",
+                InfixOperator::Boolor => "||",
+                InfixOperator::Moreeq => ">=",
+                InfixOperator::Lesseq => "<=",
+                InfixOperator::Lessthan => "<",
+                InfixOperator::Morethan => ">",
+                InfixOperator::Shiftleft => "<<",
+                InfixOperator::Shiftright => ">>",
+                InfixOperator::Modulo => "%",
+                InfixOperator::Bitand => "&",
+                InfixOperator::Bitor => "|",
+            }
+        )
+    }
+}
+
 impl InfixOperator {
     pub fn returns_boolean(&self) -> bool {
         match self {
@@ -442,11 +578,36 @@ pub enum PostfixOperator {
 pub enum AssignOperator {
     Bitor,
     Bitand,
+    Bitxor,
     Add,
     Sub,
+    Mul,
+    Div,
+    Mod,
+    Shiftleft,
+    Shiftright,
     Eq,
 }
 
+impl AssignOperator {
+    /// the infix operator a compound assignment applies
+    pub fn infix(&self) -> Option<InfixOperator> {
+        match self {
+            AssignOperator::Bitor => Some(InfixOperator::Bitor),
+            AssignOperator::Bitand => Some(InfixOperator::Bitand),
+            AssignOperator::Bitxor => Some(InfixOperator::Bitxor),
+            AssignOperator::Add => Some(InfixOperator::Add),
+            AssignOperator::Sub => Some(InfixOperator::Subtract),
+            AssignOperator::Mul => Some(InfixOperator::Multiply),
+            AssignOperator::Div => Some(InfixOperator::Divide),
+            AssignOperator::Mod => Some(InfixOperator::Modulo),
+            AssignOperator::Shiftleft => Some(InfixOperator::Shiftleft),
+            AssignOperator::Shiftright => Some(InfixOperator::Shiftright),
+            AssignOperator::Eq => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum EmitBehaviour {
     Default,
@@ -493,6 +654,20 @@ pub enum Expression {
         rhs: Box<Expression>,
         op: InfixOperator,
     },
+    /// lhs[from..to] of a slice, array or pointer
+    Slice {
+        loc: Location,
+        lhs: Box<Expression>,
+        from: Option<Box<Expression>>,
+        to: Option<Box<Expression>>,
+    },
+    /// cond ? then : otherwise
+    Ternary {
+        loc: Location,
+        cond: Box<Expression>,
+        then: Box<Expression>,
+        otherwise: Box<Expression>,
+    },
     Cast {
         loc: Location,
         into: Typed,
@@ -544,6 +719,8 @@ impl Expression {
             Expression::LiteralChar { loc, .. } => loc,
             Expression::Call { loc, .. } => loc,
             Expression::Infix { loc, .. } => loc,
+            Expression::Slice { loc, .. } => loc,
+            Expression::Ternary { loc, .. } => loc,
             Expression::Cast { loc, .. } => loc,
             Expression::UnaryPost { loc, .. } => loc,
             Expression::UnaryPre { loc, .. } => loc,
@@ -617,6 +794,11 @@ pub enum Statement {
     },
     Block(Box<Block>),
     Unsafe(Box<Block>),
+    /// runs at every exit of the enclosing block. expand copies it to each exit
+    Defer {
+        loc: Location,
+        stm: Box<Statement>,
+    },
     CBlock {
         loc: Location,
         lit: String,
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// timing of one bench fn. all durations are in nanoseconds
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub path: String,
+    pub iterations: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Stats {
+    pub fn from_samples(path: &str, samples: &[u64]) -> Self {
+        let n = samples.len();
+        let mut sorted: Vec<f64> = samples.iter().map(|s| *s as f64).collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mean = sorted.iter().sum::<f64>() / n.max(1) as f64;
+        let median = match n {
+            0 => 0.0,
+            n if n % 2 == 0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0,
+            n => sorted[n / 2],
+        };
+        let stddev = if n > 1 {
+            (sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        Stats {
+            path: path.to_string(),
+            iterations: n,
+            mean,
+            median,
+            stddev,
+            min: sorted.first().cloned().unwrap_or(0.0),
+            max: sorted.last().cloned().unwrap_or(0.0),
+        }
+    }
+}
+
+/// run all benches of a harness that match filter, in one process
+pub fn run(
+    exe: &Path,
+    filter: Option<&str>,
+    warmup: usize,
+    iterations: usize,
+) -> Result<Vec<Stats>, String> {
+    let mut cmd = Command::new(exe);
+    cmd.arg("--warmup")
+        .arg(warmup.to_string())
+        .arg("--iterations")
+        .arg(iterations.to_string());
+    if let Some(filter) = filter {
+        cmd.arg(filter);
+    }
+    let out = cmd
+        .output()
+        .map_err(|e| format!("failed to execute {:?}: {}", exe, e))?;
+    if !out.status.success() {
+        return Err(format!(
+            "{:?} failed with {}\n{}",
+            exe,
+            out.status,
+            String::from_utf8_lossy(&out.stderr)
+        ));
+    }
+
+    let mut r = Vec::new();
+    for line in String::from_utf8_lossy(&out.stdout).lines() {
+        let mut it = line.split_whitespace();
+        let path = match it.next() {
+            Some(v) => v,
+            None => continue,
+        };
+        let samples: Vec<u64> = it.filter_map(|v| v.parse().ok()).collect();
+        r.push(Stats::from_samples(path, &samples));
+    }
+    Ok(r)
+}
+
+fn baseline_path(root: &Path, name: &str) -> PathBuf {
+    root.join("target").join("bench").join(format!("{}.json", name))
+}
+
+pub fn save(root: &Path, name: &str, stats: &[Stats]) {
+    let p = baseline_path(root, name);
+    fs::create_dir_all(p.parent().unwrap()).expect(&format!("cannot create {:?}", p.parent()));
+    fs::write(&p, serde_json::to_string_pretty(stats).unwrap())
+        .expect(&format!("cannot write {:?}", p));
+}
+
+pub fn load(root: &Path, name: &str) -> Option<Vec<Stats>> {
+    let s = fs::read_to_string(baseline_path(root, name)).ok()?;
+    serde_json::from_str(&s).ok()
+}
+
+fn human(ns: f64) -> String {
+    if ns >= 1e9 {
+        format!("{:.2}s", ns / 1e9)
+    } else if ns >= 1e6 {
+        format!("{:.2}ms", ns / 1e6)
+    } else if ns >= 1e3 {
+        format!("{:.2}µs", ns / 1e3)
+    } else {
+        format!("{:.0}ns", ns)
+    }
+}
+
+/// print the results, compared against a baseline if there is one.
+/// returns false if any bench got slower than threshold percent
+pub fn report(stats: &[Stats], baseline: Option<&[Stats]>, threshold: f64) -> bool {
+    let mut ok = true;
+    println!(
+        "\n{:<40} {:>10} {:>10} {:>10} {:>8}",
+        "bench", "mean", "median", "stddev", "iters"
+    );
+    for s in stats {
+        let mut line = format!(
+            "{:<40} {:>10} {:>10} {:>10} {:>8}",
+            s.path,
+            human(s.mean),
+            human(s.median),
+            human(s.stddev),
+            s.iterations
+        );
+        if let Some(base) = baseline.and_then(|b| b.iter().find(|b| b.path == s.path)) {
+            let change = if base.mean > 0.0 {
+                (s.mean - base.mean) * 100.0 / base.mean
+            } else {
+                0.0
+            };
+            if change > threshold {
+                ok = false;
+                line.push_str(&format!("  regressed {:+.1}%", change));
+            } else if change < -threshold {
+                line.push_str(&format!("  improved {:+.1}%", change));
+            } else {
+                line.push_str(&format!("  {:+.1}%", change));
+            }
+        }
+        println!("{}", line);
+    }
+    ok
+}
@@ -0,0 +1,285 @@
+use super::ast::{self, Location};
+use super::emitter::LineMap;
+use super::parser::{self, JsonError};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+lazy_static::lazy_static! {
+    /// compiler steps run in parallel. each one reports as a whole
+    static ref OUTPUT: Mutex<()> = Mutex::new(());
+    /// structured diagnostics flags each compiler accepts, probed once
+    static ref FORMATS: Mutex<HashMap<String, Vec<String>>> = Mutex::new(HashMap::new());
+}
+
+/// flags that make cc print its diagnostics as sarif (clang) or json (gcc).
+/// compilers that support neither are left alone and their output is passed through
+pub fn format_flags(cc: &str) -> Vec<String> {
+    let mut formats = FORMATS.lock().unwrap();
+    if let Some(flags) = formats.get(cc) {
+        return flags.clone();
+    }
+    let candidates: [&[&str]; 2] = [
+        &["-fdiagnostics-format=sarif", "-Wno-sarif-format-unstable"],
+        &["-fdiagnostics-format=json"],
+    ];
+    let mut found = Vec::new();
+    for flags in candidates.iter() {
+        let ok = Command::new(cc)
+            .args(flags.iter())
+            .args(&["-fsyntax-only", "-x", "c", "-"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false);
+        if ok {
+            found = flags.iter().map(|s| s.to_string()).collect();
+            break;
+        }
+    }
+    debug!("diagnostics format for {}: {:?}", cc, found);
+    formats.insert(cc.to_string(), found.clone());
+    found
+}
+
+struct Diag {
+    file: String,
+    line: usize,
+    col: usize,
+    level: String,
+    message: String,
+    notes: Vec<Diag>,
+}
+
+impl Diag {
+    /// the way a compiler would have printed it
+    fn render(&self) -> String {
+        if self.file.is_empty() {
+            return format!("{}: {}", self.level, self.message);
+        }
+        format!(
+            "{}:{}:{}: {}: {}",
+            self.file, self.line, self.col, self.level, self.message
+        )
+    }
+}
+
+/// a location spanning the given line, starting at col if it is known
+fn location(file: &str, line: usize, col: Option<usize>) -> Option<Location> {
+    if !Path::new(file).is_file() {
+        return None;
+    }
+    let (src, _) = ast::read_source(file.to_string());
+    let mut offset = 0;
+    for (i, text) in src.split('\n').enumerate() {
+        if i + 1 == line {
+            let text = text.trim_end();
+            let indent = text.len() - text.trim_start().len();
+            let mut start = offset + indent;
+            if let Some(col) = col {
+                let c = offset + (col.max(1) - 1).min(text.len());
+                if src.is_char_boundary(c) {
+                    start = c;
+                }
+            }
+            let end = (offset + text.len()).max(start);
+            return Some(Location {
+                file: file.to_string(),
+                line,
+                start,
+                end,
+            });
+        }
+        offset += text.len() + 1;
+    }
+    None
+}
+
+fn usize_at(v: &Value, path: &[&str]) -> usize {
+    let mut v = v;
+    for p in path {
+        v = &v[*p];
+    }
+    v.as_u64().unwrap_or(0) as usize
+}
+
+/// gcc -fdiagnostics-format=json: an array of diagnostics with notes as children
+fn parse_gcc(v: &Value) -> Diag {
+    let caret = &v["locations"][0]["caret"];
+    Diag {
+        file: caret["file"].as_str().unwrap_or("").to_string(),
+        line: usize_at(caret, &["line"]),
+        col: usize_at(caret, &["column"]),
+        level: v["kind"].as_str().unwrap_or("error").to_string(),
+        message: v["message"].as_str().unwrap_or("").to_string(),
+        notes: v["children"]
+            .as_array()
+            .map(|c| c.iter().map(parse_gcc).collect())
+            .unwrap_or_default(),
+    }
+}
+
+/// sarif artifact uris are file:// urls, or paths relative to the working directory
+fn sarif_path(uri: &str) -> String {
+    match url::Url::parse(uri) {
+        Ok(u) if u.scheme() == "file" => u
+            .to_file_path()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or(uri.to_string()),
+        _ => uri.to_string(),
+    }
+}
+
+/// clang -fdiagnostics-format=sarif: notes are results of their own, following the one they belong to
+fn parse_sarif(v: &Value, diags: &mut Vec<Diag>) {
+    for run in v["runs"].as_array().into_iter().flatten() {
+        for result in run["results"].as_array().into_iter().flatten() {
+            let loc = &result["locations"][0]["physicalLocation"];
+            let d = Diag {
+                file: sarif_path(loc["artifactLocation"]["uri"].as_str().unwrap_or("")),
+                line: usize_at(loc, &["region", "startLine"]),
+                col: usize_at(loc, &["region", "startColumn"]),
+                level: result["level"].as_str().unwrap_or("error").to_string(),
+                message: result["message"]["text"]
+                    .as_str()
+                    .unwrap_or("")
+                    .to_string(),
+                notes: Vec::new(),
+            };
+            match (d.level.as_str(), diags.last_mut()) {
+                ("note", Some(prev)) => prev.notes.push(d),
+                _ => diags.push(d),
+            }
+        }
+    }
+}
+
+fn parse_structured(v: &Value, diags: &mut Vec<Diag>) {
+    match v {
+        Value::Array(a) => diags.extend(a.iter().map(parse_gcc)),
+        Value::Object(_) => parse_sarif(v, diags),
+        _ => (),
+    }
+}
+
+/// the structured documents in the compiler output, and whatever else it printed
+fn parse(stderr: &str) -> (Vec<Diag>, Vec<String>) {
+    let mut diags = Vec::new();
+    let mut raw = Vec::new();
+    if let Ok(v) = serde_json::from_str::<Value>(stderr.trim()) {
+        parse_structured(&v, &mut diags);
+        return (diags, raw);
+    }
+    // the driver and linker may still print plain text around the document
+    for line in stderr.lines() {
+        let t = line.trim();
+        match serde_json::from_str::<Value>(t) {
+            Ok(v) if t.starts_with('[') || t.starts_with('{') => {
+                parse_structured(&v, &mut diags)
+            }
+            _ => raw.push(line.to_string()),
+        }
+    }
+    (diags, raw)
+}
+
+struct Resolver {
+    maps: HashMap<String, Option<LineMap>>,
+}
+
+impl Resolver {
+    /// the zz location of a c diagnostic, and the raw c location if it was mapped through #line
+    fn resolve(&mut self, d: &Diag) -> (Option<Location>, Option<Location>) {
+        if d.file.ends_with(".zz") {
+            // the compiler already honored #line
+            return (location(&d.file, d.line, None), None);
+        }
+        let map = self
+            .maps
+            .entry(d.file.clone())
+            .or_insert_with(|| LineMap::load(Path::new(&d.file)));
+        let cloc = location(&d.file, d.line, Some(d.col));
+        match map.as_ref().and_then(|m| m.get(d.line)) {
+            Some((zfile, zline)) => (location(zfile, *zline, None), cloc),
+            None => (None, cloc),
+        }
+    }
+}
+
+/// re-emit what the c compiler printed as zz diagnostics.
+/// anything that can't be located is passed through as is
+pub fn report(stdout: &[u8], stderr: &[u8]) {
+    let stderr = String::from_utf8_lossy(stderr);
+    let (diags, raw) = parse(&stderr);
+    let json = parser::ERRORS_AS_JSON.load(Ordering::SeqCst);
+    let mut resolver = Resolver {
+        maps: HashMap::new(),
+    };
+
+    let _lock = OUTPUT.lock().unwrap();
+
+    // stdout carries the json diagnostics, so anything else goes to stderr
+    if json {
+        std::io::stderr().write_all(stdout).ok();
+    } else {
+        std::io::stdout().write_all(stdout).ok();
+    }
+
+    for d in &diags {
+        let (zloc, cloc) = resolver.resolve(d);
+
+        let mut labels: Vec<(Location, String)> = Vec::new();
+        match (&zloc, &cloc) {
+            (Some(zloc), Some(cloc)) => {
+                labels.push((zloc.clone(), d.message.clone()));
+                labels.push((cloc.clone(), "in the emitted c".to_string()));
+            }
+            (Some(loc), None) | (None, Some(loc)) => {
+                labels.push((loc.clone(), d.message.clone()));
+            }
+            (None, None) => {
+                if json {
+                    let j = JsonError {
+                        message: d.message.clone(),
+                        level: d.level.clone(),
+                        file_name: d.file.clone(),
+                        line_start: d.line,
+                        line_end: d.line,
+                        column_start: d.col,
+                        column_end: d.col,
+                    };
+                    parser::emit_json(&j);
+                } else {
+                    eprintln!("{}", d.render());
+                    for n in &d.notes {
+                        eprintln!("{}", n.render());
+                    }
+                }
+                continue;
+            }
+        }
+
+        for n in &d.notes {
+            let (nzloc, ncloc) = resolver.resolve(n);
+            if let Some(loc) = nzloc.or(ncloc) {
+                labels.push((loc, format!("note: {}", n.message)));
+            }
+        }
+
+        let message = format!("c compiler {}: {}", d.level, d.message);
+        match d.level.as_str() {
+            "warning" | "remark" => parser::emit_warn(message, &labels),
+            _ => parser::emit_error(message, &labels),
+        }
+    }
+
+    for line in &raw {
+        eprintln!("{}", line);
+    }
+}
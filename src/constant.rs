@@ -0,0 +1,441 @@
+/// compile time evaluation of integer constant expressions
+use super::ast;
+use super::name::Name;
+use super::parser;
+use super::Error;
+use std::collections::HashMap;
+
+/// a C integer type. int is 32 bits on every target zz supports,
+/// size_t and the other target dependent types are only known to be at least that wide
+#[derive(Clone, Copy, PartialEq)]
+enum CType {
+    Fixed(u32, bool),
+    Target(bool),
+}
+
+const INT: CType = CType::Fixed(32, true);
+
+impl CType {
+    fn of(t: &ast::Typed) -> Option<CType> {
+        if !is_integer(t) {
+            return None;
+        }
+        Some(match width(&t.t) {
+            Some((bits, signed)) => CType::Fixed(bits, signed),
+            None => match t.t {
+                ast::Type::Int | ast::Type::ILiteral => INT,
+                ast::Type::UInt | ast::Type::ULiteral => CType::Fixed(32, false),
+                ast::Type::ISize => CType::Target(true),
+                _ => CType::Target(false),
+            },
+        })
+    }
+
+    /// the integer promotions
+    fn promote(self) -> CType {
+        match self {
+            CType::Fixed(bits, _) if bits < 32 => INT,
+            t => t,
+        }
+    }
+
+    /// the usual arithmetic conversions. None if they depend on the width of the target
+    fn common(a: CType, b: CType) -> Option<CType> {
+        let (a, b) = (a.promote(), b.promote());
+        Some(match (a, b) {
+            (CType::Fixed(ab, asigned), CType::Fixed(bb, bsigned)) => {
+                if ab == bb {
+                    CType::Fixed(ab, asigned && bsigned)
+                } else if ab > bb {
+                    a
+                } else {
+                    b
+                }
+            }
+            (CType::Target(asigned), CType::Target(bsigned)) => CType::Target(asigned && bsigned),
+            (CType::Target(tsigned), CType::Fixed(bits, fsigned))
+            | (CType::Fixed(bits, fsigned), CType::Target(tsigned)) => {
+                if bits == 64 {
+                    if fsigned && !tsigned {
+                        return None;
+                    }
+                    CType::Fixed(64, fsigned)
+                } else {
+                    if tsigned && !fsigned {
+                        return None;
+                    }
+                    CType::Target(tsigned)
+                }
+            }
+        })
+    }
+
+    fn bits(self) -> u32 {
+        match self {
+            CType::Fixed(bits, _) => bits,
+            CType::Target(_) => 64,
+        }
+    }
+
+    /// v converted into this type, wrapping around like C does.
+    /// None if the result depends on the width of the target
+    fn wrap(self, v: i128) -> Option<i128> {
+        match self {
+            CType::Fixed(bits, signed) => {
+                let v = v & ((1i128 << bits) - 1);
+                if signed && v >= (1i128 << (bits - 1)) {
+                    Some(v - (1i128 << bits))
+                } else {
+                    Some(v)
+                }
+            }
+            CType::Target(true) if v >= i32::MIN as i128 && v <= i32::MAX as i128 => Some(v),
+            CType::Target(false) if v >= 0 && v <= u32::MAX as i128 => Some(v),
+            CType::Target(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Value {
+    v: i128,
+    t: CType,
+}
+
+/// evaluate an integer expression.
+/// None if it is not constant, like a call or a C macro
+pub fn eval(expr: &ast::Expression, defs: &HashMap<Name, ast::Def>) -> Result<Option<i128>, Error> {
+    Ok(eval_depth(expr, defs, 0)?.map(|v| v.v))
+}
+
+fn eval_depth(
+    expr: &ast::Expression,
+    defs: &HashMap<Name, ast::Def>,
+    depth: usize,
+) -> Result<Option<Value>, Error> {
+    // consts referring to each other in a cycle
+    if depth > 64 {
+        return Ok(None);
+    }
+    let v = match expr {
+        ast::Expression::Literal { v, .. } => literal(v),
+        ast::Expression::LiteralChar { v, .. } => Some(Value {
+            v: *v as i128,
+            t: INT,
+        }),
+        ast::Expression::Name(name) => match &name.t {
+            ast::Type::Other(n) => match defs.get(n) {
+                Some(ast::Def::Const { typed, expr }) => match eval_depth(expr, defs, depth + 1)? {
+                    Some(v) => match (initialize(v.v, typed), CType::of(typed)) {
+                        (Some(v), Some(t)) => Some(Value { v, t }),
+                        _ => None,
+                    },
+                    None => None,
+                },
+                _ => variant(n, defs).map(|v| Value { v, t: INT }),
+            },
+            _ => None,
+        },
+        ast::Expression::Cast { into, expr, .. } => match eval_depth(expr, defs, depth + 1)? {
+            Some(v) => CType::of(into).and_then(|t| t.wrap(v.v).map(|v| Value { v, t })),
+            None => None,
+        },
+        ast::Expression::UnaryPre { op, expr, .. } => match eval_depth(expr, defs, depth + 1)? {
+            None => None,
+            Some(v) => match op {
+                ast::PrefixOperator::Boolnot => Some(Value {
+                    v: (v.v == 0) as i128,
+                    t: INT,
+                }),
+                ast::PrefixOperator::Bitnot => {
+                    let t = v.t.promote();
+                    t.wrap(!v.v).map(|v| Value { v, t })
+                }
+                _ => None,
+            },
+        },
+        ast::Expression::Ternary {
+            cond,
+            then,
+            otherwise,
+            ..
+        } => match eval_depth(cond, defs, depth + 1)? {
+            Some(Value { v: 0, .. }) => eval_depth(otherwise, defs, depth + 1)?,
+            Some(_) => eval_depth(then, defs, depth + 1)?,
+            None => None,
+        },
+        ast::Expression::Infix { lhs, rhs, op, loc } => {
+            let lhs = match eval_depth(lhs, defs, depth + 1)? {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+            let rhs = match eval_depth(rhs, defs, depth + 1)? {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+            infix(lhs, rhs, op, loc)?
+        }
+        ast::Expression::Call { name, args, .. } => match name.as_ref() {
+            ast::Expression::Name(fname) if is_sizeof(fname) && args.len() == 1 => {
+                match args[0].as_ref() {
+                    ast::Expression::Name(arg) if arg.ptr.is_empty() => {
+                        size(&arg.t).map(|v| Value {
+                            v: v as i128,
+                            t: CType::Target(false),
+                        })
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+    Ok(v)
+}
+
+/// the value of an enum variant, counting up from the last explicit value
+fn variant(name: &Name, defs: &HashMap<Name, ast::Def>) -> Option<i128> {
+    let mut enumname = name.clone();
+    let variant = enumname.pop()?;
+    let names = match defs.get(&enumname) {
+        Some(ast::Def::Enum { names }) => names,
+        _ => return None,
+    };
+    let mut value = 0;
+    for (name, val) in names {
+        if let Some(val) = val {
+            value = *val;
+        }
+        if *name == variant {
+            return Some(value as i128);
+        }
+        value += 1;
+    }
+    None
+}
+
+/// evaluated in the type C would use, wrapping around where C does.
+/// signed overflow is undefined in C, so it is an error here
+fn infix(
+    lhs: Value,
+    rhs: Value,
+    op: &ast::InfixOperator,
+    loc: &ast::Location,
+) -> Result<Option<Value>, Error> {
+    let boolean = |b: bool| {
+        Ok(Some(Value {
+            v: b as i128,
+            t: INT,
+        }))
+    };
+    match op {
+        ast::InfixOperator::Booland => return boolean(lhs.v != 0 && rhs.v != 0),
+        ast::InfixOperator::Boolor => return boolean(lhs.v != 0 || rhs.v != 0),
+        ast::InfixOperator::Shiftleft | ast::InfixOperator::Shiftright => {
+            let t = lhs.t.promote();
+            if rhs.v < 0 || rhs.v >= t.bits() as i128 {
+                return Err(Error::new(
+                    format!("shift by {} in constant expression", rhs.v),
+                    vec![(
+                        loc.clone(),
+                        format!("shift must be less than {}", t.bits()),
+                    )],
+                ));
+            }
+            let v = if *op == ast::InfixOperator::Shiftleft {
+                lhs.v.wrapping_shl(rhs.v as u32)
+            } else {
+                lhs.v >> rhs.v
+            };
+            return Ok(t.wrap(v).map(|v| Value { v, t }));
+        }
+        _ => (),
+    }
+
+    let t = match CType::common(lhs.t, rhs.t) {
+        Some(t) => t,
+        None => return Ok(None),
+    };
+    let (l, r) = match (t.wrap(lhs.v), t.wrap(rhs.v)) {
+        (Some(l), Some(r)) => (l, r),
+        _ => return Ok(None),
+    };
+
+    let v = match op {
+        ast::InfixOperator::Add => l + r,
+        ast::InfixOperator::Subtract => l - r,
+        ast::InfixOperator::Multiply => l.wrapping_mul(r),
+        ast::InfixOperator::Divide | ast::InfixOperator::Modulo => {
+            if r == 0 {
+                return Err(Error::new(
+                    format!("division by zero in constant expression"),
+                    vec![(loc.clone(), format!("rhs is 0"))],
+                ));
+            }
+            if *op == ast::InfixOperator::Divide {
+                l / r
+            } else {
+                l % r
+            }
+        }
+        ast::InfixOperator::Bitand => l & r,
+        ast::InfixOperator::Bitor => l | r,
+        ast::InfixOperator::Bitxor => l ^ r,
+        ast::InfixOperator::Equals => return boolean(l == r),
+        ast::InfixOperator::Nequals => return boolean(l != r),
+        ast::InfixOperator::Lessthan => return boolean(l < r),
+        ast::InfixOperator::Lesseq => return boolean(l <= r),
+        ast::InfixOperator::Morethan => return boolean(l > r),
+        ast::InfixOperator::Moreeq => return boolean(l >= r),
+        _ => unreachable!(),
+    };
+
+    match t {
+        CType::Fixed(_, true) if t.wrap(v) != Some(v) => Err(Error::new(
+            format!("signed overflow in constant expression"),
+            vec![(loc.clone(), format!("{} does not fit into {} bits", v, t.bits()))],
+        )),
+        _ => Ok(t.wrap(v).map(|v| Value { v, t })),
+    }
+}
+
+/// typed like a C literal: the first of int, unsigned int (hex and binary only),
+/// and the 64 bit types it fits into
+fn literal(v: &str) -> Option<Value> {
+    let (v, prefixed) = match v {
+        "true" => return Some(Value { v: 1, t: INT }),
+        "false" => return Some(Value { v: 0, t: INT }),
+        _ => {
+            if let Some(b) = v.strip_prefix("0b") {
+                (i128::from_str_radix(b, 2).ok()?, true)
+            } else if let Some(n) = parser::parse_u64(v) {
+                (n as i128, v.starts_with("0x"))
+            } else {
+                (v.parse::<i128>().ok()?, false)
+            }
+        }
+    };
+    let candidates = [
+        INT,
+        CType::Fixed(32, false),
+        CType::Fixed(64, true),
+        CType::Fixed(64, false),
+    ];
+    candidates
+        .iter()
+        .filter(|t| prefixed || **t != CType::Fixed(32, false))
+        .find(|t| t.wrap(v) == Some(v))
+        .map(|t| Value { v, t: *t })
+}
+
+fn is_sizeof(name: &ast::Typed) -> bool {
+    match &name.t {
+        ast::Type::Other(n) => *n == Name::from("::ext::<stddef.h>::sizeof"),
+        _ => false,
+    }
+}
+
+/// size in bytes of a type that is the same on every target
+pub fn size(t: &ast::Type) -> Option<u64> {
+    match t {
+        ast::Type::U8 | ast::Type::I8 | ast::Type::Bool => Some(1),
+        ast::Type::U16 | ast::Type::I16 => Some(2),
+        ast::Type::U32 | ast::Type::I32 | ast::Type::F32 => Some(4),
+        ast::Type::U64 | ast::Type::I64 | ast::Type::F64 => Some(8),
+        ast::Type::U128 | ast::Type::I128 => Some(16),
+        _ => None,
+    }
+}
+
+/// bits and signedness of a fixed width integer
+fn width(t: &ast::Type) -> Option<(u32, bool)> {
+    match t {
+        ast::Type::U8 => Some((8, false)),
+        ast::Type::U16 => Some((16, false)),
+        ast::Type::U32 => Some((32, false)),
+        ast::Type::U64 => Some((64, false)),
+        ast::Type::I8 => Some((8, true)),
+        ast::Type::I16 => Some((16, true)),
+        ast::Type::I32 => Some((32, true)),
+        ast::Type::I64 => Some((64, true)),
+        _ => None,
+    }
+}
+
+/// true if t is an integer that a folded constant can be written as
+pub fn is_integer(t: &ast::Typed) -> bool {
+    if !t.ptr.is_empty() {
+        return false;
+    }
+    match t.t {
+        ast::Type::U8
+        | ast::Type::U16
+        | ast::Type::U32
+        | ast::Type::U64
+        | ast::Type::I8
+        | ast::Type::I16
+        | ast::Type::I32
+        | ast::Type::I64
+        | ast::Type::Int
+        | ast::Type::UInt
+        | ast::Type::ISize
+        | ast::Type::USize
+        | ast::Type::ULiteral
+        | ast::Type::ILiteral => true,
+        _ => false,
+    }
+}
+
+/// the value if it can be represented in t
+pub fn fits(v: i128, t: &ast::Typed) -> Option<i128> {
+    if !is_integer(t) {
+        return None;
+    }
+    match width(&t.t) {
+        Some((bits, true)) => {
+            let max = (1i128 << (bits - 1)) - 1;
+            if v >= -max - 1 && v <= max {
+                Some(v)
+            } else {
+                None
+            }
+        }
+        Some((bits, false)) => {
+            if v >= 0 && v < (1i128 << bits) {
+                Some(v)
+            } else {
+                None
+            }
+        }
+        // target dependent width. everything fits into 32 bits
+        None => match t.t {
+            ast::Type::Int | ast::Type::ISize | ast::Type::ILiteral => {
+                if v >= i32::MIN as i128 && v <= i32::MAX as i128 {
+                    Some(v)
+                } else {
+                    None
+                }
+            }
+            _ => {
+                if v >= 0 && v <= u32::MAX as i128 {
+                    Some(v)
+                } else {
+                    None
+                }
+            }
+        },
+    }
+}
+
+/// the value of a constant of type t initialized with v, if t can hold it.
+/// like in C, an unsigned type holds a negative value as its two's complement, so ~0 is all ones
+pub fn initialize(v: i128, t: &ast::Typed) -> Option<i128> {
+    if let Some(v) = fits(v, t) {
+        return Some(v);
+    }
+    match CType::of(t)? {
+        CType::Fixed(bits, false) if v < 0 && v >= -(1i128 << (bits - 1)) => Some(v + (1i128 << bits)),
+        _ => None,
+    }
+}
@@ -0,0 +1,271 @@
+use super::emitter::LineMap;
+use super::make::Stage;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// hit counts of one zz source file
+#[derive(Default)]
+struct FileCoverage {
+    lines: BTreeMap<usize, u64>,
+    /// (line, c symbol) -> hits
+    fns: BTreeMap<(usize, String), u64>,
+}
+
+pub fn dir(root: &Path, stage: &Stage) -> PathBuf {
+    root.join("target").join(stage.to_string()).join("coverage")
+}
+
+/// clear profiles of earlier runs. returns the LLVM_PROFILE_FILE to run tests with
+pub fn prepare(root: &Path, stage: &Stage) -> String {
+    let raw = dir(root, stage).join("profraw");
+    if raw.exists() {
+        fs::remove_dir_all(&raw).expect(&format!("cannot remove {:?}", raw));
+    }
+    fs::create_dir_all(&raw).expect(&format!("cannot create {:?}", raw));
+    raw.join("%p.profraw").to_string_lossy().to_string()
+}
+
+fn tool(env: &str, default: &str) -> String {
+    std::env::var(env).unwrap_or(default.to_string())
+}
+
+fn run(cmd: &mut Command) -> Result<Vec<u8>, String> {
+    debug!("{:?}", cmd);
+    let out = cmd
+        .output()
+        .map_err(|e| format!("failed to execute {:?}: {}", cmd, e))?;
+    if !out.status.success() {
+        return Err(format!(
+            "{:?} failed: {}",
+            cmd,
+            String::from_utf8_lossy(&out.stderr)
+        ));
+    }
+    Ok(out.stdout)
+}
+
+/// merge the profiles written by the test executables and write an lcov and html report per zz module
+pub fn report(root: &Path, stage: &Stage, exes: &[PathBuf]) -> Result<(), String> {
+    let dir = dir(root, stage);
+    let mut raws = Vec::new();
+    if let Ok(rd) = fs::read_dir(dir.join("profraw")) {
+        for entry in rd {
+            if let Ok(entry) = entry {
+                raws.push(entry.path());
+            }
+        }
+    }
+    if raws.is_empty() || exes.is_empty() {
+        return Err("no coverage profiles were written".to_string());
+    }
+
+    let profdata = dir.join("merged.profdata");
+    run(Command::new(tool("LLVM_PROFDATA", "llvm-profdata"))
+        .arg("merge")
+        .arg("-sparse")
+        .args(&raws)
+        .arg("-o")
+        .arg(&profdata))?;
+
+    let mut cmd = Command::new(tool("LLVM_COV", "llvm-cov"));
+    cmd.arg("export")
+        .arg("-format=lcov")
+        .arg(format!("-instr-profile={}", profdata.to_string_lossy()))
+        .arg(&exes[0]);
+    for exe in &exes[1..] {
+        cmd.arg("-object").arg(exe);
+    }
+    let lcov = run(&mut cmd)?;
+
+    let files = map_lcov(root, &String::from_utf8_lossy(&lcov));
+    write_reports(root, &dir, &files)?;
+    Ok(())
+}
+
+/// translate c level lcov records into zz source files
+fn map_lcov(root: &Path, lcov: &str) -> BTreeMap<String, FileCoverage> {
+    let mut maps: HashMap<String, Option<LineMap>> = HashMap::new();
+    let mut files: BTreeMap<String, FileCoverage> = BTreeMap::new();
+    let mut sf: Option<String> = None;
+    let mut fnlines: HashMap<String, usize> = HashMap::new();
+
+    for line in lcov.lines() {
+        if let Some(v) = line.strip_prefix("SF:") {
+            let path = root.join(v);
+            maps.entry(v.to_string())
+                .or_insert_with(|| LineMap::load(&path));
+            sf = Some(v.to_string());
+            fnlines.clear();
+            continue;
+        }
+        if line == "end_of_record" {
+            sf = None;
+            continue;
+        }
+        let map = match sf.as_ref().and_then(|sf| maps.get(sf)).and_then(|m| m.as_ref()) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        if let Some(v) = line.strip_prefix("DA:") {
+            let mut it = v.split(',');
+            let cline = it.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+            let hits: u64 = it.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+            if let Some((file, zline)) = map.get(cline) {
+                let e = files
+                    .entry(file.clone())
+                    .or_default()
+                    .lines
+                    .entry(*zline)
+                    .or_insert(0);
+                *e = (*e).max(hits);
+            }
+        } else if let Some(v) = line.strip_prefix("FN:") {
+            let mut it = v.splitn(2, ',');
+            let cline = it.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+            if let Some(name) = it.next() {
+                fnlines.insert(name.to_string(), cline);
+            }
+        } else if let Some(v) = line.strip_prefix("FNDA:") {
+            let mut it = v.splitn(2, ',');
+            let hits: u64 = it.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+            let name = match it.next() {
+                Some(v) => v,
+                None => continue,
+            };
+            let cline = fnlines.get(name).cloned().unwrap_or(0);
+            if let Some((file, zline)) = map.get(cline) {
+                let e = files
+                    .entry(file.clone())
+                    .or_default()
+                    .fns
+                    .entry((*zline, name.to_string()))
+                    .or_insert(0);
+                *e = (*e).max(hits);
+            }
+        }
+    }
+    files
+}
+
+/// a file name for the reports of a zz source
+fn module_label(root: &Path, file: &str) -> String {
+    let path = Path::new(file);
+    let rel = pathdiff::diff_paths(path, root).unwrap_or(path.to_path_buf());
+    let rel = rel.to_string_lossy();
+    rel.trim_end_matches(".zz")
+        .replace(|c: char| !c.is_alphanumeric() && c != '_' && c != '-', "_")
+        .trim_start_matches('_')
+        .to_string()
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn write_lcov(f: &mut String, file: &str, cov: &FileCoverage) {
+    f.push_str(&format!("SF:{}\n", file));
+    for ((line, name), _) in &cov.fns {
+        f.push_str(&format!("FN:{},{}\n", line, name));
+    }
+    for ((_, name), hits) in &cov.fns {
+        f.push_str(&format!("FNDA:{},{}\n", hits, name));
+    }
+    f.push_str(&format!("FNF:{}\n", cov.fns.len()));
+    f.push_str(&format!(
+        "FNH:{}\n",
+        cov.fns.values().filter(|h| **h > 0).count()
+    ));
+    for (line, hits) in &cov.lines {
+        f.push_str(&format!("DA:{},{}\n", line, hits));
+    }
+    f.push_str(&format!("LF:{}\n", cov.lines.len()));
+    f.push_str(&format!(
+        "LH:{}\n",
+        cov.lines.values().filter(|h| **h > 0).count()
+    ));
+    f.push_str("end_of_record\n");
+}
+
+const STYLE: &str = "body{font-family:sans-serif} pre{margin:0} td{padding:0 8px} \
+                     .hit{background:#dfd} .miss{background:#fdd} .n{color:#888;text-align:right}";
+
+fn write_reports(
+    root: &Path,
+    dir: &Path,
+    files: &BTreeMap<String, FileCoverage>,
+) -> Result<(), String> {
+    let mut all = String::new();
+    let mut index = format!(
+        "<html><head><meta charset=\"utf-8\"><title>coverage</title><style>{}</style></head><body>\n\
+         <h1>coverage</h1>\n<table>\n<tr><th>module</th><th>lines</th><th>%</th></tr>\n",
+        STYLE
+    );
+
+    println!("\ncoverage:");
+    for (file, cov) in files {
+        let label = module_label(root, file);
+        let total = cov.lines.len();
+        let hit = cov.lines.values().filter(|h| **h > 0).count();
+        let percent = if total > 0 {
+            hit as f64 * 100.0 / total as f64
+        } else {
+            100.0
+        };
+        println!("    {:>6.1}% {:>5}/{:<5} {}", percent, hit, total, file);
+
+        let mut lcov = String::new();
+        write_lcov(&mut lcov, file, cov);
+        all.push_str(&lcov);
+        fs::write(dir.join(format!("{}.info", label)), lcov)
+            .map_err(|e| format!("cannot write {}.info: {}", label, e))?;
+
+        let mut html = format!(
+            "<html><head><meta charset=\"utf-8\"><title>{f}</title><style>{s}</style></head><body>\n\
+             <h1>{f}</h1>\n<p>{h} of {t} lines ({p:.1}%)</p>\n<table>\n",
+            f = html_escape(file),
+            s = STYLE,
+            h = hit,
+            t = total,
+            p = percent
+        );
+        let source = fs::read_to_string(file).unwrap_or_default();
+        for (i, text) in source.lines().enumerate() {
+            let (class, count) = match cov.lines.get(&(i + 1)) {
+                Some(0) => ("miss", "0".to_string()),
+                Some(n) => ("hit", n.to_string()),
+                None => ("", String::new()),
+            };
+            html.push_str(&format!(
+                "<tr class=\"{}\"><td class=\"n\">{}</td><td class=\"n\">{}</td><td><pre>{}</pre></td></tr>\n",
+                class,
+                i + 1,
+                count,
+                html_escape(text)
+            ));
+        }
+        html.push_str("</table></body></html>\n");
+        fs::write(dir.join(format!("{}.html", label)), html)
+            .map_err(|e| format!("cannot write {}.html: {}", label, e))?;
+
+        index.push_str(&format!(
+            "<tr><td><a href=\"{}.html\">{}</a></td><td>{}/{}</td><td>{:.1}</td></tr>\n",
+            label,
+            html_escape(file),
+            hit,
+            total,
+            percent
+        ));
+    }
+    index.push_str("</table></body></html>\n");
+
+    fs::write(dir.join("lcov.info"), all).map_err(|e| format!("cannot write lcov.info: {}", e))?;
+    fs::write(dir.join("index.html"), index)
+        .map_err(|e| format!("cannot write index.html: {}", e))?;
+    println!("coverage report in {}", dir.join("index.html").to_string_lossy());
+    Ok(())
+}
@@ -1,11 +1,13 @@
 #![allow(unused)]
 
+use super::abi;
 use super::ast;
 use super::flatten;
 use super::make;
 use super::name::Name;
 use super::parser::{self, emit_error};
 use super::project::Project;
+use super::symbolic;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
@@ -19,6 +21,26 @@ pub struct CFile {
     pub sources: HashSet<PathBuf>,
     pub deps: HashSet<Name>,
     pub symbols: HashSet<Name>,
+    /// the public header, if this module exports anything
+    #[serde(default)]
+    pub header: Option<String>,
+    /// test fns defined in this module, as (path, c symbol)
+    #[serde(default)]
+    pub tests: Vec<(String, String)>,
+    /// bench fns defined in this module, as (path, c symbol)
+    #[serde(default)]
+    pub benches: Vec<(String, String)>,
+    /// c symbol of `export fn fuzz(u8* data, usize len)`, the libFuzzer entry point
+    #[serde(default)]
+    pub fuzz: Option<String>,
+}
+
+/// the C symbol of a zz name, honoring @export_name and the project symbol_prefix
+pub fn c_symbol_name(module: &flatten::Module, symbol_prefix: &str, s: &Name) -> String {
+    if let Some(export_name) = module.export_names.get(s) {
+        return export_name.clone();
+    }
+    format!("{}{}", symbol_prefix, s.0[1..].join("_"))
 }
 
 pub struct Emitter {
@@ -27,6 +49,10 @@ pub struct Emitter {
     f: fs::File,
     module: flatten::Module,
     header: bool,
+    headerpath: String,
+    abipath: String,
+    symbol_prefix: String,
+    libfuzzer: bool,
     inside_macro: bool,
     cur_loc: Option<ast::Location>,
     casedir: String,
@@ -73,6 +99,7 @@ impl Emitter {
         header: bool,
     ) -> Self {
         let (cxx, p) = outname(project, &stage, &module.name, header);
+        let (_, headerpath) = outname(project, &stage, &module.name, true);
         let mut f = fs::File::create(&p).expect(&format!("cannot create {}", p));
 
         let casedir = format!(
@@ -82,6 +109,11 @@ impl Emitter {
         );
         std::fs::remove_dir_all(&casedir).ok();
         std::fs::create_dir_all(&casedir).unwrap();
+        let abipath = format!(
+            "target/{}/abi/{}.json",
+            stage,
+            module.name.0[1..].join("_")
+        );
 
         write!(f, "#include <stdint.h>\n").unwrap();
         write!(f, "#include <stddef.h>\n").unwrap();
@@ -92,6 +124,10 @@ impl Emitter {
             p,
             f,
             header,
+            headerpath,
+            abipath,
+            symbol_prefix: project.symbol_prefix.clone().unwrap_or_default(),
+            libfuzzer: stage.libfuzzer,
             casedir,
             module,
             inside_macro: false,
@@ -147,13 +183,14 @@ impl Emitter {
             ast::Type::Other(ref n) => {
                 let mut s = self.to_local_name(&n);
                 match &name.tail {
-                    ast::Tail::Dynamic(_) | ast::Tail::None | ast::Tail::Bind(_, _) => {}
+                    ast::Tail::Dynamic(_) | ast::Tail::None | ast::Tail::Bind(_, _) | ast::Tail::Generic(_) => {}
                     ast::Tail::Static(v, _) => {
                         s = format!("{}_{}", s, v);
                     }
                 }
                 s
             }
+            ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
             ast::Type::ILiteral | ast::Type::ULiteral | ast::Type::Elided | ast::Type::New => {
                 parser::emit_error(
                     "ICE: untyped literal ended up in emitter",
@@ -162,7 +199,7 @@ impl Emitter {
                         format!("this should have been resolved earlier"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         }
     }
@@ -184,9 +221,11 @@ impl Emitter {
             return s.0.last().unwrap().clone();
         }
 
-        let mut s = s.clone();
-        s.0.remove(0);
-        return s.0.join("_");
+        self.to_c_name(s)
+    }
+
+    fn to_c_name(&self, s: &Name) -> String {
+        c_symbol_name(&self.module, &self.symbol_prefix, s)
     }
 
     fn to_local_name_mangle(&self, s: &Name) -> String {
@@ -238,6 +277,10 @@ impl Emitter {
         }
 
         for (d, complete) in &module.d {
+            match d.def {
+                ast::Def::StaticAssert { .. } | ast::Def::Interface { .. } => continue,
+                _ => (),
+            }
 
             if complete != &flatten::TypeComplete::Complete {
                 match d.def {
@@ -255,6 +298,13 @@ impl Emitter {
                     _ => (),
                 }
             }
+            let harness = harness_define(d);
+            if let Some(define) = harness {
+                if self.header {
+                    continue;
+                }
+                write!(self.f, "#if defined({})\n", define).unwrap();
+            }
 
 
             debug!("    emitting {}", d.name);
@@ -314,6 +364,9 @@ impl Emitter {
                 _ => (),
             }
             write!(self.f, "\n#endif\n").unwrap();
+            if harness.is_some() {
+                write!(self.f, "#endif\n").unwrap();
+            }
         }
 
         if !self.header {
@@ -330,6 +383,17 @@ impl Emitter {
                         if complete == &flatten::TypeComplete::Complete
                             && (mname == module.name || attr.contains_key("inline"))
                         {
+                            // test and bench fns only exist in their harness, which brings its own main. so does libFuzzer
+                            let guard = if let Some(define) = harness_define(d) {
+                                Some(format!("#if defined({})\n", define))
+                            } else if d.name.ends_with("::main") {
+                                Some("#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)\n".to_string())
+                            } else {
+                                None
+                            };
+                            if let Some(guard) = &guard {
+                                write!(self.f, "{}", guard).unwrap();
+                            }
                             write!(self.f, "#ifndef ZZ_IMPL_{tn}\n#define ZZ_IMPL_{tn}\n",
                                    tn = self.to_local_name_mangle(&Name::from(&d.name))
                             ).unwrap();
@@ -337,6 +401,13 @@ impl Emitter {
                             self.emit_def(&d);
 
                             write!(self.f, "\n#endif\n").unwrap();
+                            if guard.is_some() {
+                                write!(self.f, "#endif\n").unwrap();
+                            }
+
+                            if mname == module.name {
+                                self.emit_property_main(&d);
+                            }
                         }
 
 
@@ -346,15 +417,294 @@ impl Emitter {
             }
         }
 
+        let exports = module.d.iter().any(|(d, _)| {
+            let mut mname = Name::from(&d.name);
+            mname.pop();
+            mname == module.name && d.vis == ast::Visibility::Export
+        });
+
+        let mut tests = Vec::new();
+        let mut benches = Vec::new();
+        for (d, complete) in &module.d {
+            let name = Name::from(&d.name);
+            let mut mname = name.clone();
+            mname.pop();
+            if mname != module.name || complete != &flatten::TypeComplete::Complete {
+                continue;
+            }
+            match harness_define(d) {
+                Some("ZZ_TEST") => tests.push((name.0[1..].join("::"), self.to_c_name(&name))),
+                Some("ZZ_BENCH") => benches.push((name.0[1..].join("::"), self.to_c_name(&name))),
+                _ => (),
+            }
+        }
+
+        let fuzz = module.d.iter().find_map(|(d, complete)| {
+            let mut mname = Name::from(&d.name);
+            if mname.pop().as_deref() != Some("fuzz")
+                || mname != module.name
+                || d.vis != ast::Visibility::Export
+                || complete != &flatten::TypeComplete::Complete
+            {
+                return None;
+            }
+            match &d.def {
+                ast::Def::Function { args, ret: None, vararg: false, callassert, .. }
+                    if args.len() == 2
+                        && args[0].typed.t == ast::Type::U8
+                        && args[0].typed.ptr.len() == 1
+                        && args[1].typed.t == ast::Type::USize
+                        && args[1].typed.ptr.len() == 0 =>
+                {
+                    // libFuzzer only guarantees the length of its input
+                    for w in callassert {
+                        if self.libfuzzer && !fuzz_precondition(w, &args[0].name, &args[1].name) {
+                            parser::emit_error(
+                                "fuzz entry with a precondition the fuzzer cannot meet",
+                                &[(
+                                    w.loc().clone(),
+                                    format!(
+                                        "the only allowed where clause is len({}) >= {}",
+                                        args[0].name, args[1].name
+                                    ),
+                                )],
+                            );
+                            super::exit(9);
+                        }
+                    }
+                    Some(self.to_c_name(&Name::from(&d.name)))
+                }
+                _ => None,
+            }
+        });
+
+        if self.header {
+            self.emit_abi();
+        }
+
         CFile {
             name: module.name,
             filepath: self.p,
             sources: module.sources,
             deps: module.deps,
             symbols: self.symbols,
+            header: if exports { Some(self.headerpath) } else { None },
+            tests,
+            benches,
+            fuzz,
         }
     }
 
+    /// the main of a property harness, if d is the fn under property test.
+    /// it calls d with every generated input and checks its model clauses at runtime
+    fn emit_property_main(&mut self, d: &ast::Local) {
+        let name = Name::from(&d.name);
+        let inputs = match symbolic::PROPERTIES.lock().unwrap().as_ref() {
+            Some(req) if req.function == name => req.inputs.clone(),
+            _ => return,
+        };
+        let (args, ret, calleffect) = match &d.def {
+            ast::Def::Function {
+                args,
+                ret,
+                calleffect,
+                ..
+            } => (args, ret, calleffect),
+            _ => return,
+        };
+
+        let mut models = Vec::new();
+        for model in calleffect {
+            let mut model = model.clone();
+            if property_expr(&mut model) {
+                models.push(model);
+            } else {
+                parser::emit_warn(
+                    "model clause cannot be checked at runtime",
+                    &[(
+                        model.loc().clone(),
+                        "theories and calls only exist in the prover, this clause is skipped",
+                    )],
+                );
+            }
+        }
+
+        write!(self.f, "#if defined(ZZ_PROPERTY)\n#include <stdio.h>\n").unwrap();
+        for (i, arg) in args.iter().enumerate() {
+            let t = self.to_local_typed_name(&arg.typed);
+            let values: Vec<String> = inputs
+                .iter()
+                .map(|input| format!("({}){}ULL", t, input[i]))
+                .collect();
+            write!(
+                self.f,
+                "static const {} zz_property_{}[] = {{{}}};\n",
+                t,
+                arg.name,
+                values.join(", ")
+            )
+            .unwrap();
+        }
+
+        write!(self.f, "int main(int argc, char **argv) {{\n").unwrap();
+        write!(self.f, "    size_t zz_failed = 0;\n").unwrap();
+        write!(
+            self.f,
+            "    for (size_t zz_i = 0; zz_i < {}; zz_i++) {{\n",
+            inputs.len()
+        )
+        .unwrap();
+        let mut call = Vec::new();
+        for arg in args {
+            write!(
+                self.f,
+                "        {} {} = zz_property_{}[zz_i];\n",
+                self.to_local_typed_name(&arg.typed),
+                arg.name,
+                arg.name
+            )
+            .unwrap();
+            call.push(arg.name.clone());
+        }
+        match ret {
+            Some(ret) => write!(
+                self.f,
+                "        {} zz_return = ",
+                self.to_local_typed_name(&ret.typed)
+            )
+            .unwrap(),
+            None => write!(self.f, "        ").unwrap(),
+        }
+        write!(self.f, "{}({});\n", self.to_local_name(&name), call.join(", ")).unwrap();
+        write!(self.f, "        int zz_ok = 1;\n").unwrap();
+
+        for model in &models {
+            write!(self.f, "        if (!(").unwrap();
+            self.emit_expr(model);
+            write!(
+                self.f,
+                ")) {{\n            fprintf(stderr, \"model at {}:{} does not hold\\n\");\n            zz_ok = 0;\n        }}\n",
+                model.loc().file.replace("\\", "\\\\"),
+                model.loc().line
+            )
+            .unwrap();
+        }
+
+        write!(self.f, "        if (!zz_ok) {{\n").unwrap();
+        write!(
+            self.f,
+            "            fprintf(stderr, \"property {} FAILED with input\\n\");\n",
+            name
+        )
+        .unwrap();
+        for arg in args {
+            let (fmt, cast) = match arg.typed.t {
+                ast::Type::I8
+                | ast::Type::I16
+                | ast::Type::I32
+                | ast::Type::I64
+                | ast::Type::Int
+                | ast::Type::ISize => ("%lld", "long long"),
+                _ => ("%llu", "unsigned long long"),
+            };
+            write!(
+                self.f,
+                "            fprintf(stderr, \"    {n} = {f}\\n\", ({c}){n});\n",
+                n = arg.name,
+                f = fmt,
+                c = cast
+            )
+            .unwrap();
+        }
+        write!(self.f, "            zz_failed++;\n        }}\n    }}\n").unwrap();
+        write!(
+            self.f,
+            "    fprintf(stderr, \"property {}: %zu of {} inputs ok\\n\", (size_t){} - zz_failed);\n",
+            name,
+            inputs.len(),
+            inputs.len()
+        )
+        .unwrap();
+        write!(self.f, "    return zz_failed > 0;\n}}\n#endif\n").unwrap();
+    }
+
+    fn abi_type(&self, typed: &ast::Typed) -> String {
+        let mut s = self.to_local_typed_name(typed);
+        for ptr in &typed.ptr {
+            if !ptr.tags.contains_key("mut") {
+                s.push_str(" const");
+            }
+            s.push_str(" *");
+        }
+        s
+    }
+
+    /// write the exported symbols and struct layouts of this module for abi-diff
+    fn emit_abi(&self) {
+        let mut m = abi::Abi::new();
+        for (d, complete) in &self.module.d {
+            let mut mname = Name::from(&d.name);
+            mname.pop();
+            if mname != self.module.name || d.vis != ast::Visibility::Export {
+                continue;
+            }
+            if complete != &flatten::TypeComplete::Complete {
+                continue;
+            }
+            let sym = match &d.def {
+                ast::Def::Function { ret, args, vararg, .. } => abi::Symbol::Function {
+                    ret: ret.as_ref().map(|r| self.abi_type(&r.typed)).unwrap_or("void".into()),
+                    args: args.iter().map(|a| self.abi_type(&a.typed)).collect(),
+                    vararg: *vararg,
+                },
+                ast::Def::Closure { ret, args, .. } => abi::Symbol::Closure {
+                    ret: ret.as_ref().map(|r| self.abi_type(&r.typed)).unwrap_or("void".into()),
+                    args: args.iter().map(|a| self.abi_type(&a.typed)).collect(),
+                },
+                ast::Def::Struct { fields, packed, union, tail, .. } => abi::Symbol::Struct {
+                    fields: fields
+                        .iter()
+                        .map(|f| abi::Field {
+                            name: f.name.clone(),
+                            typed: self.abi_type(&f.typed),
+                            array: match &f.array {
+                                ast::Array::None => None,
+                                ast::Array::Unsized => Some(String::new()),
+                                ast::Array::Sized(ast::Expression::Literal { v, .. }) => {
+                                    Some(v.clone())
+                                }
+                                ast::Array::Sized(ast::Expression::Name(n)) => {
+                                    Some(self.to_local_typed_name(n))
+                                }
+                                ast::Array::Sized(_) => Some("?".into()),
+                            },
+                        })
+                        .collect(),
+                    packed: *packed,
+                    union: *union,
+                    tail: tail != &ast::Tail::None,
+                },
+                ast::Def::Enum { names } => {
+                    // implicit values count up from the last one, like in C
+                    let mut value = 0;
+                    let mut values = Vec::new();
+                    for (n, v) in names {
+                        if let Some(v) = v {
+                            value = *v;
+                        }
+                        values.push((n.clone(), value));
+                        value = value.wrapping_add(1);
+                    }
+                    abi::Symbol::Enum { values }
+                }
+                _ => continue,
+            };
+            m.insert(self.to_local_name(&Name::from(&d.name)), sym);
+        }
+
+        abi::write(std::path::Path::new(&self.abipath), &m);
+    }
+
     pub fn emit_include(&mut self, ast: &ast::Local) {
         self.emit_loc(&ast.loc);
         let (expr, loc, fqn, inline, _needs) = match &ast.def {
@@ -382,7 +732,7 @@ impl Emitter {
                         format!("cannot inline {:?}", expr),
                         &[(loc.clone(), format!("{}", e))],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
                 Ok(f) => f,
             };
@@ -563,7 +913,50 @@ impl Emitter {
         let dir = format!("{}/{}", self.casedir, testname);
         std::fs::remove_dir_all(&dir).ok();
         std::fs::create_dir_all(&dir).unwrap();
-        for (fname, expr) in fields {
+        for (fname, op, expr) in fields {
+            let valid = match (fname.as_str(), op) {
+                (_, ast::TestOp::Equals) => true,
+                ("exit", ast::TestOp::Contains)
+                | ("exit", ast::TestOp::Matches)
+                | ("exit", ast::TestOp::Nmatches) => false,
+                ("exit", _) => true,
+                ("stdout", ast::TestOp::Nequals)
+                | ("stdout", ast::TestOp::Contains)
+                | ("stdout", ast::TestOp::Matches)
+                | ("stdout", ast::TestOp::Nmatches) => true,
+                ("stderr", ast::TestOp::Nequals)
+                | ("stderr", ast::TestOp::Contains)
+                | ("stderr", ast::TestOp::Matches)
+                | ("stderr", ast::TestOp::Nmatches) => true,
+                _ => false,
+            };
+            if !valid {
+                parser::emit_error(
+                    format!("testcase field {} cannot be compared with {}", fname, op.as_str()),
+                    &[(expr.loc().clone(), "in this testcase field")],
+                );
+                super::exit(9);
+            }
+
+            if *op != ast::TestOp::Equals {
+                let p = format!("{}/{}.op", dir, fname);
+                fs::write(&p, op.as_str()).expect(&format!("cannot create {}", p));
+            }
+
+            match (op, expr) {
+                (ast::TestOp::Matches, ast::Expression::LiteralString { v, loc })
+                | (ast::TestOp::Nmatches, ast::Expression::LiteralString { v, loc }) => {
+                    if let Err(e) = regex::bytes::Regex::new(v) {
+                        parser::emit_error(
+                            "invalid regular expression",
+                            &[(loc.clone(), format!("{}", e))],
+                        );
+                        super::exit(9);
+                    }
+                }
+                _ => (),
+            }
+
             let p = format!("{}/{}", dir, fname);
             let mut f = fs::File::create(&p).expect(&format!("cannot create {}", p));
             match expr {
@@ -590,7 +983,7 @@ impl Emitter {
                                             "testcase field must be literal string or byte array",
                                             &[(loc.clone(), format!("this expression cannot be emitted as testcase file"))]
                                             );
-                                    std::process::exit(9);
+                                    super::exit(9);
                                 }
                             },
                             _ => {
@@ -603,7 +996,7 @@ impl Emitter {
                                         ),
                                     )],
                                 );
-                                std::process::exit(9);
+                                super::exit(9);
                             }
                         }
                     }
@@ -619,7 +1012,7 @@ impl Emitter {
                             format!("this expression cannot be emitted as testcase file"),
                         )],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             }
         }
@@ -675,17 +1068,37 @@ impl Emitter {
         isimpl: bool,
         tail_variant: Option<(u64, ast::Location)>,
     ) {
-        let (fields, packed, structtail, union) = match &ast.def {
+        let (fields, packed, structtail, union, variants) = match &ast.def {
             ast::Def::Struct {
                 fields,
                 packed,
                 tail,
                 union,
+                variants,
                 ..
-            } => (fields, packed, tail, union),
+            } => (fields, packed, tail, union, variants),
             _ => unreachable!(),
         };
 
+        // a tagged union is its tag followed by an anonymous union of the payloads.
+        // the tag is an unsigned int in zz, so the values are plain constants here
+        let tagged = !variants.is_empty();
+        if tagged {
+            self.emit_loc(&ast.loc);
+            write!(self.f, "enum {{\n").unwrap();
+            for (name, value) in variants {
+                write!(
+                    self.f,
+                    "    {}_{} = {},\n",
+                    self.to_local_name(&Name::from(&ast.name)),
+                    name,
+                    value
+                )
+                .unwrap();
+            }
+            write!(self.f, "}};\n").unwrap();
+        }
+
         self.emit_loc(&ast.loc);
         if *union {
             write!(self.f, "union ").unwrap();
@@ -698,6 +1111,10 @@ impl Emitter {
         let mut emitted_exact_tail = false;
         for i in 0..fields.len() {
             let field = &fields[i];
+            // the tag keeps the type the parser gave it, the payloads follow in the union
+            if tagged && i == 1 {
+                write!(self.f, "   union {{\n").unwrap();
+            }
             self.emit_loc(&field.loc);
             write!(self.f, "   {}", self.to_local_typed_name(&field.typed)).unwrap();
             self.emit_pointer(&field.typed.ptr);
@@ -718,7 +1135,7 @@ impl Emitter {
                                 format!("tail field would displace next field"),
                             )],
                         );
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                     if let Some((tt, _)) = &tail_variant {
                         emitted_exact_tail = true;
@@ -736,6 +1153,9 @@ impl Emitter {
 
             write!(self.f, " ;\n").unwrap();
         }
+        if tagged && fields.len() > 1 {
+            write!(self.f, "   }};\n").unwrap();
+        }
 
         if let Some((tt, loc)) = &tail_variant {
             if !emitted_exact_tail {
@@ -757,7 +1177,7 @@ impl Emitter {
                                 (loc.clone(), format!("when expanding type here")),
                             ],
                         );
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                 }
             }
@@ -859,7 +1279,7 @@ impl Emitter {
                         "ICE: unsupported attr",
                         &[(loc.clone(), format!("'{}' is not a valid c attribute", o))],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             }
         }
@@ -935,28 +1355,29 @@ impl Emitter {
         //    ast::Visibility::Export => write!(self.f, "__attribute__ ((visibility (\"default\"))) ").unwrap(),
         //}
 
-        let mut name = Name::from(&ast.name);
+        let name = Name::from(&ast.name);
+        let mut cname = self.to_c_name(&name);
         for (attr, loc) in attr {
             match attr.as_str() {
                 "extern" => {
                     self.emit_as_extern.insert(name.clone());
-                    name = Name::from(name.0.last().unwrap());
-                    name.0.insert(0, String::new());
+                    cname = name.0.last().unwrap().clone();
                 }
                 "inline" => {
                     write!(self.f, " static inline ").unwrap();
                 }
+                "test" | "bench" => {}
                 o => {
                     parser::emit_error(
                         "ICE: unsupported attr",
                         &[(loc.clone(), format!("'{}' is not a valid c attribute", o))],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             }
         }
 
-        write!(self.f, "{} (", name.0[1..].join("_")).unwrap();
+        write!(self.f, "{} (", cname).unwrap();
 
         self.function_args(args);
         if vararg {
@@ -971,7 +1392,7 @@ impl Emitter {
             return;
         }
 
-        if self.to_local_name(&Name::from(&ast.name)) == Name::from(&ast.name).0[1..].join("_") {
+        if self.to_local_name(&name) == cname {
             return;
         }
         if self.header {
@@ -1003,7 +1424,7 @@ impl Emitter {
             write!(self.f, "return ").unwrap();
         }
 
-        write!(self.f, "{}(", Name::from(&ast.name).0[1..].join("_")).unwrap();
+        write!(self.f, "{}(", cname).unwrap();
 
         let mut first = true;
         for arg in args {
@@ -1043,24 +1464,25 @@ impl Emitter {
         }
 
         let mut vis = ast.vis.clone();
-        let mut name = Name::from(&ast.name);
+        let name = Name::from(&ast.name);
+        let mut cname = self.to_c_name(&name);
         for (attr, loc) in attr {
             match attr.as_str() {
                 "extern" => {
                     self.emit_as_extern.insert(name.clone());
-                    name = Name::from(name.0.last().unwrap());
-                    name.0.insert(0, String::new());
+                    cname = name.0.last().unwrap().clone();
                 }
                 "inline" => {
                     write!(self.f, " static inline ").unwrap();
                     vis = ast::Visibility::Object;
                 }
+                "test" | "bench" => {}
                 o => {
                     parser::emit_error(
                         "ICE: unsupported attr",
                         &[(loc.clone(), format!("'{}' is not a valid c attribute", o))],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             }
         }
@@ -1085,7 +1507,7 @@ impl Emitter {
                     write!(self.f, "__attribute__ ((visibility (\"default\"))) ").unwrap()
                 }
             }
-            write!(self.f, "{} (", name.0[1..].join("_")).unwrap();
+            write!(self.f, "{} (", cname).unwrap();
         }
 
         self.function_args(args);
@@ -1143,6 +1565,9 @@ impl Emitter {
                 self.emit_zblock(b2, true);
                 false
             }
+            ast::Statement::Defer { .. } => {
+                panic!("ice: defer must be expanded before emitting");
+            }
             ast::Statement::CBlock { loc, lit } => {
                 self.emit_loc(&loc);
                 write!(self.f, "{}", lit).unwrap();
@@ -1234,8 +1659,14 @@ impl Emitter {
                     match op {
                         ast::AssignOperator::Bitor => "|=",
                         ast::AssignOperator::Bitand => "&=",
+                        ast::AssignOperator::Bitxor => "^=",
                         ast::AssignOperator::Add => "+=",
                         ast::AssignOperator::Sub => "-=",
+                        ast::AssignOperator::Mul => "*=",
+                        ast::AssignOperator::Div => "/=",
+                        ast::AssignOperator::Mod => "%=",
+                        ast::AssignOperator::Shiftleft => "<<=",
+                        ast::AssignOperator::Shiftright => ">>=",
                         ast::AssignOperator::Eq => "=",
                     }
                 )
@@ -1371,7 +1802,7 @@ impl Emitter {
             }
             _ =>  {
                 emit_error(format!("expression not usable (yet?) in cpp context"), &[(v.loc().clone(), "here")]);
-                std::process::exit(9);
+                super::exit(9);
             }
         }
     }
@@ -1384,6 +1815,16 @@ impl Emitter {
             ast::Expression::MacroCall { loc, .. } => {
                 write!(self.f, "{{INTERNAL_ERROR_MACRO_NOT_EXPANDED}}").unwrap();
             }
+            ast::Expression::Slice { loc, .. } => {
+                emit_error(
+                    "slice expression in unsafe code",
+                    &[(
+                        loc.clone(),
+                        format!("only checked code can slice. use .mem and .len in unsafe blocks"),
+                    )],
+                );
+                super::exit(9);
+            }
             ast::Expression::ArrayInit { fields, loc } => {
                 self.emit_loc(&loc);
                 write!(self.f, "{{").unwrap();
@@ -1486,7 +1927,7 @@ impl Emitter {
                     }
                     ast::EmitBehaviour::Error { loc, message } => {
                         emit_error(format!("{}", message), &[(loc.clone(), "here")]);
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                 };
 
@@ -1541,6 +1982,21 @@ impl Emitter {
                 self.emit_expr(rhs);
                 write!(self.f, "  )").unwrap();
             }
+            ast::Expression::Ternary {
+                loc,
+                cond,
+                then,
+                otherwise,
+            } => {
+                write!(self.f, "(").unwrap();
+                self.emit_expr(cond);
+                self.emit_loc(&loc);
+                write!(self.f, " ? ").unwrap();
+                self.emit_expr(then);
+                write!(self.f, " : ").unwrap();
+                self.emit_expr(otherwise);
+                write!(self.f, "  )").unwrap();
+            }
             ast::Expression::MemberAccess { loc, lhs, rhs, op } => {
                 self.emit_loc(&loc);
                 self.emit_expr(lhs);
@@ -1561,7 +2017,7 @@ impl Emitter {
                         format!("c preprocessor expression not possible in this location"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         }
     }
@@ -1601,6 +2057,95 @@ impl Emitter {
     }
 }
 
+/// prepare a model clause for the property harness, where the return value is a local.
+/// false if it needs something that only exists in the prover
+fn property_expr(expr: &mut ast::Expression) -> bool {
+    match expr {
+        ast::Expression::Name(name) => {
+            if let ast::Type::Other(n) = &name.t {
+                if n.0 == vec!["return".to_string()] {
+                    name.t = ast::Type::Other(Name::from("zz_return"));
+                }
+            }
+            true
+        }
+        ast::Expression::Literal { .. } | ast::Expression::LiteralChar { .. } => true,
+        ast::Expression::MemberAccess { lhs, .. } => property_expr(lhs),
+        ast::Expression::ArrayAccess { lhs, rhs, .. } | ast::Expression::Infix { lhs, rhs, .. } => {
+            property_expr(lhs) && property_expr(rhs)
+        }
+        ast::Expression::Ternary {
+            cond,
+            then,
+            otherwise,
+            ..
+        } => property_expr(cond) && property_expr(then) && property_expr(otherwise),
+        ast::Expression::Cast { expr, .. }
+        | ast::Expression::UnaryPost { expr, .. }
+        | ast::Expression::UnaryPre { expr, .. }
+        | ast::Expression::Unsafe { expr, .. } => property_expr(expr),
+        _ => false,
+    }
+}
+
+/// the define that compiles in a test or bench fn
+fn harness_define(d: &ast::Local) -> Option<&'static str> {
+    match &d.def {
+        ast::Def::Function { attr, .. } if attr.contains_key("test") => Some("ZZ_TEST"),
+        ast::Def::Function { attr, .. } if attr.contains_key("bench") => Some("ZZ_BENCH"),
+        _ => None,
+    }
+}
+
+/// maps lines of an emitted c file back to the zz source through its #line directives
+pub struct LineMap {
+    lines: Vec<Option<(String, usize)>>,
+}
+
+impl LineMap {
+    /// None if the file has no #line directives at all
+    pub fn load(cfile: &std::path::Path) -> Option<Self> {
+        let src = fs::read_to_string(cfile).ok()?;
+        let mut lines = Vec::new();
+        let mut cur: Option<(String, usize)> = None;
+        let mut any = false;
+        for line in src.lines() {
+            if let Some(directive) = line.strip_prefix("#line ") {
+                let mut it = directive.splitn(2, ' ');
+                let n = it.next().and_then(|n| n.parse::<usize>().ok());
+                let file = it
+                    .next()
+                    .map(|f| f.trim().trim_matches('"').replace("\\\\", "\\"));
+                if let (Some(n), Some(file)) = (n, file) {
+                    // the line following the directive is line n
+                    cur = Some((file, n.saturating_sub(1)));
+                    any = true;
+                }
+                lines.push(None);
+                continue;
+            }
+            if let Some((file, n)) = &mut cur {
+                *n += 1;
+                lines.push(Some((file.clone(), *n)));
+            } else {
+                lines.push(None);
+            }
+        }
+        if any {
+            Some(LineMap { lines })
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, line: usize) -> Option<&(String, usize)> {
+        if line == 0 {
+            return None;
+        }
+        self.lines.get(line - 1).and_then(|v| v.as_ref())
+    }
+}
+
 impl CFile {
     pub fn is_newer_than(&self, target: &str) -> bool {
         let itarget = match std::fs::metadata(&target) {
@@ -1646,10 +2191,12 @@ pub fn builtin(
     write!(f, "#include <stddef.h>\n").unwrap();
 
     let mut i = 1;
+    let symbol_prefix = project.symbol_prefix.clone().unwrap_or_default();
     for symbol in &symbols {
         write!(
             f,
-            "const __attribute__ ((unused)) size_t {} = {};\n",
+            "const __attribute__ ((unused)) size_t {}{} = {};\n",
+            symbol_prefix,
             symbol.0[1..].join("_"),
             i
         )
@@ -1681,5 +2228,345 @@ pub fn builtin(
         sources: HashSet::new(),
         deps: HashSet::new(),
         symbols: HashSet::new(),
+        header: None,
+        tests: Vec::new(),
+        benches: Vec::new(),
+        fuzz: None,
+    }
+}
+
+/// the main of a unit test harness. each test fn runs in a forked child,
+/// so a crash or failed assertion only fails that test.
+/// `--list` prints all paths, `--exact <path>` runs a single test, any other argument filters by substring
+pub fn unittest_main(
+    project: &Project,
+    stage: &make::Stage,
+    artifact: &super::project::Artifact,
+    tests: &[(String, String)],
+) -> CFile {
+    std::fs::create_dir_all(format!("target/{}/gen/", stage)).unwrap();
+    let p = format!(
+        "target/{}/gen/zz_unittests_{}_{}.c",
+        stage, project.name, artifact.name
+    );
+    let mut f = fs::File::create(&p).expect(&format!("cannot create {}", p));
+
+    write!(
+        f,
+        r#"#include <stdio.h>
+#include <string.h>
+#include <stdlib.h>
+#if !defined(_WIN32)
+#include <sys/types.h>
+#include <sys/wait.h>
+#include <unistd.h>
+#endif
+
+"#
+    )
+    .unwrap();
+
+    for (_, sym) in tests {
+        write!(f, "void {}(void);\n", sym).unwrap();
+    }
+
+    write!(
+        f,
+        "\nstatic const struct {{ const char *path; void (*fn)(void); }} zz_unittests[] = {{\n"
+    )
+    .unwrap();
+    for (path, sym) in tests {
+        write!(f, "    {{\"{}\", {}}},\n", path, sym).unwrap();
+    }
+    write!(f, "    {{0, 0}},\n}};\n").unwrap();
+
+    write!(
+        f,
+        r#"
+int main(int argc, char **argv) {{
+    int list = 0;
+    int exact = 0;
+    const char *filter = 0;
+    for (int i = 1; i < argc; i++) {{
+        if (strcmp(argv[i], "--list") == 0) {{
+            list = 1;
+        }} else if (strcmp(argv[i], "--exact") == 0) {{
+            exact = 1;
+        }} else {{
+            filter = argv[i];
+        }}
+    }}
+
+    size_t passed = 0;
+    size_t failed = 0;
+    for (size_t i = 0; zz_unittests[i].path; i++) {{
+        const char *path = zz_unittests[i].path;
+        if (filter && (exact ? strcmp(path, filter) != 0 : strstr(path, filter) == 0)) {{
+            continue;
+        }}
+        if (list) {{
+            printf("%s\n", path);
+            continue;
+        }}
+
+        int ok = 1;
+#if defined(_WIN32)
+        zz_unittests[i].fn();
+#else
+        fflush(stdout);
+        fflush(stderr);
+        pid_t pid = fork();
+        if (pid < 0) {{
+            perror("fork");
+            return 2;
+        }}
+        if (pid == 0) {{
+            zz_unittests[i].fn();
+            // not _exit, so coverage profiles get written
+            exit(0);
+        }}
+        int status = 0;
+        waitpid(pid, &status, 0);
+        if (WIFSIGNALED(status)) {{
+            ok = 0;
+            fprintf(stderr, "test %s died by signal %d\n", path, WTERMSIG(status));
+        }} else if (!WIFEXITED(status) || WEXITSTATUS(status) != 0) {{
+            ok = 0;
+            fprintf(stderr, "test %s exited with %d\n", path, WEXITSTATUS(status));
+        }}
+#endif
+        if (ok) {{
+            passed++;
+            fprintf(stderr, "test %s ... ok\n", path);
+        }} else {{
+            failed++;
+            fprintf(stderr, "test %s ... FAILED\n", path);
+        }}
+    }}
+
+    if (list) {{
+        return 0;
+    }}
+    if (exact && passed + failed == 0) {{
+        fprintf(stderr, "no test named %s\n", filter ? filter : "");
+        return 2;
+    }}
+    fprintf(stderr, "%zu passed; %zu failed\n", passed, failed);
+    return failed > 0 ? 1 : 0;
+}}
+"#
+    )
+    .unwrap();
+
+    CFile {
+        name: Name::from("__zz_unittests"),
+        filepath: p,
+        sources: HashSet::new(),
+        deps: HashSet::new(),
+        symbols: HashSet::new(),
+        header: None,
+        tests: Vec::new(),
+        benches: Vec::new(),
+        fuzz: None,
+    }
+}
+
+/// len(data) >= len, or the implicit safe(data)
+fn fuzz_precondition(e: &ast::Expression, data: &str, len: &str) -> bool {
+    let is_name = |e: &ast::Expression, s: &str| match e {
+        ast::Expression::Name(typed) => typed.t == ast::Type::Other(Name::from(s)),
+        _ => false,
+    };
+    let is_call = |e: &ast::Expression, f: &str| match e {
+        ast::Expression::Call { name, args, .. } => {
+            is_name(name, f) && args.len() == 1 && is_name(&args[0], data)
+        }
+        _ => false,
+    };
+    match e {
+        ast::Expression::Infix { lhs, rhs, op: ast::InfixOperator::Moreeq, .. } => {
+            is_call(lhs, "len") && is_name(rhs, len)
+        }
+        ast::Expression::Infix { lhs, rhs, op: ast::InfixOperator::Lesseq, .. } => {
+            is_name(lhs, len) && is_call(rhs, "len")
+        }
+        e => is_call(e, "safe"),
+    }
+}
+
+/// the libFuzzer callback, forwarding each input to the module's `fuzz` fn
+pub fn fuzz_main(
+    project: &Project,
+    stage: &make::Stage,
+    artifact: &super::project::Artifact,
+    fuzz: &str,
+) -> CFile {
+    std::fs::create_dir_all(format!("target/{}/gen/", stage)).unwrap();
+    let p = format!(
+        "target/{}/gen/zz_libfuzzer_{}_{}.c",
+        stage, project.name, artifact.name
+    );
+    let mut f = fs::File::create(&p).expect(&format!("cannot create {}", p));
+
+    write!(
+        f,
+        r#"#include <stddef.h>
+#include <stdint.h>
+
+void {fuzz}(uint8_t const * data, size_t len);
+
+int LLVMFuzzerTestOneInput(uint8_t const * data, size_t len) {{
+    {fuzz}(data, len);
+    return 0;
+}}
+"#,
+        fuzz = fuzz
+    )
+    .unwrap();
+
+    CFile {
+        name: Name::from("__zz_libfuzzer"),
+        filepath: p,
+        sources: HashSet::new(),
+        deps: HashSet::new(),
+        symbols: HashSet::new(),
+        header: None,
+        tests: Vec::new(),
+        benches: Vec::new(),
+        fuzz: None,
+    }
+}
+
+/// the main of a bench harness. every bench fn is warmed up, then timed per iteration.
+/// each selected bench prints one line to stdout: its path followed by every sample in nanoseconds
+pub fn bench_main(
+    project: &Project,
+    stage: &make::Stage,
+    artifact: &super::project::Artifact,
+    benches: &[(String, String)],
+) -> CFile {
+    std::fs::create_dir_all(format!("target/{}/gen/", stage)).unwrap();
+    let p = format!(
+        "target/{}/gen/zz_benches_{}_{}.c",
+        stage, project.name, artifact.name
+    );
+    let mut f = fs::File::create(&p).expect(&format!("cannot create {}", p));
+
+    write!(
+        f,
+        r#"#if !defined(_WIN32) && !defined(_POSIX_C_SOURCE)
+#define _POSIX_C_SOURCE 199309L
+#endif
+#include <stdio.h>
+#include <string.h>
+#include <stdlib.h>
+#if defined(_WIN32)
+#include <windows.h>
+static unsigned long long zz_bench_now(void) {{
+    LARGE_INTEGER freq, count;
+    QueryPerformanceFrequency(&freq);
+    QueryPerformanceCounter(&count);
+    return (unsigned long long)((double)count.QuadPart * 1e9 / (double)freq.QuadPart);
+}}
+#else
+#include <time.h>
+static unsigned long long zz_bench_now(void) {{
+    struct timespec ts;
+    clock_gettime(CLOCK_MONOTONIC, &ts);
+    return (unsigned long long)ts.tv_sec * 1000000000ull + (unsigned long long)ts.tv_nsec;
+}}
+#endif
+
+"#
+    )
+    .unwrap();
+
+    for (_, sym) in benches {
+        write!(f, "void {}(void);\n", sym).unwrap();
+    }
+
+    write!(
+        f,
+        "\nstatic const struct {{ const char *path; void (*fn)(void); }} zz_benches[] = {{\n"
+    )
+    .unwrap();
+    for (path, sym) in benches {
+        write!(f, "    {{\"{}\", {}}},\n", path, sym).unwrap();
+    }
+    write!(f, "    {{0, 0}},\n}};\n").unwrap();
+
+    write!(
+        f,
+        r#"
+int main(int argc, char **argv) {{
+    int list = 0;
+    unsigned long warmup = 10;
+    unsigned long iterations = 100;
+    const char *filter = 0;
+    for (int i = 1; i < argc; i++) {{
+        if (strcmp(argv[i], "--list") == 0) {{
+            list = 1;
+        }} else if (strcmp(argv[i], "--warmup") == 0 && i + 1 < argc) {{
+            warmup = strtoul(argv[++i], 0, 10);
+        }} else if (strcmp(argv[i], "--iterations") == 0 && i + 1 < argc) {{
+            iterations = strtoul(argv[++i], 0, 10);
+        }} else {{
+            filter = argv[i];
+        }}
+    }}
+    if (iterations == 0) {{
+        iterations = 1;
+    }}
+
+    unsigned long long *samples = malloc(sizeof(unsigned long long) * iterations);
+    if (!samples) {{
+        return 2;
+    }}
+
+    for (size_t i = 0; zz_benches[i].path; i++) {{
+        const char *path = zz_benches[i].path;
+        if (filter && strstr(path, filter) == 0) {{
+            continue;
+        }}
+        if (list) {{
+            printf("%s\n", path);
+            continue;
+        }}
+
+        fprintf(stderr, "bench %s ...\n", path);
+        for (unsigned long n = 0; n < warmup; n++) {{
+            zz_benches[i].fn();
+        }}
+        for (unsigned long n = 0; n < iterations; n++) {{
+            unsigned long long start = zz_bench_now();
+            zz_benches[i].fn();
+            samples[n] = zz_bench_now() - start;
+        }}
+
+        printf("%s", path);
+        for (unsigned long n = 0; n < iterations; n++) {{
+            printf(" %llu", samples[n]);
+        }}
+        printf("\n");
+        fflush(stdout);
+    }}
+
+    free(samples);
+    return 0;
+}}
+"#
+    )
+    .unwrap();
+
+    CFile {
+        name: Name::from("__zz_benches"),
+        filepath: p,
+        sources: HashSet::new(),
+        deps: HashSet::new(),
+        symbols: HashSet::new(),
+        header: None,
+        tests: Vec::new(),
+        benches: Vec::new(),
+        fuzz: None,
     }
 }
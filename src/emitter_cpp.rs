@@ -0,0 +1,479 @@
+use super::ast;
+use super::emitter;
+use super::flatten;
+use super::make;
+use super::name::Name;
+use super::parser;
+use super::project::Project;
+use std::collections::HashSet;
+use std::fs;
+use std::io::Write;
+
+pub struct Emitter {
+    p: String,
+    f: fs::File,
+    module: flatten::Module,
+    symbol_prefix: String,
+    cheader: String,
+}
+
+pub fn outname(_project: &Project, module: &Name) -> String {
+    format!("target/include/zz/{}.hpp", module.0[1..].join("_"))
+}
+
+const CXX_KEYWORDS: &[&str] = &[
+    "and", "asm", "bool", "catch", "class", "const_cast", "delete", "dynamic_cast", "explicit",
+    "export", "false", "friend", "mutable", "namespace", "new", "not", "operator", "or",
+    "private", "protected", "public", "reinterpret_cast", "static_cast", "template", "this",
+    "throw", "true", "try", "typeid", "typename", "using", "virtual", "xor",
+];
+
+fn cxx_ident(s: &str) -> String {
+    if CXX_KEYWORDS.contains(&s) {
+        format!("{}_", s)
+    } else {
+        s.to_string()
+    }
+}
+
+impl Emitter {
+    pub fn new(project: &Project, stage: make::Stage, module: flatten::Module) -> Self {
+        let p = outname(project, &module.name);
+        let f = fs::File::create(&p).expect(&format!("cannot create {}", p));
+        let (_, cheader) = emitter::outname(project, &stage, &module.name, true);
+        let cheader = std::path::Path::new(&cheader)
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+
+        Emitter {
+            p,
+            f,
+            module,
+            symbol_prefix: project.symbol_prefix.clone().unwrap_or_default(),
+            cheader,
+        }
+    }
+
+    fn to_local_name(&self, s: &Name) -> String {
+        if !s.is_absolute() {
+            return s.0.join("_");
+        }
+        if let Some(an) = self.module.aliases.get(&s) {
+            return an.clone();
+        }
+        if s.0[1] == "ext" {
+            return s.0.last().unwrap().clone();
+        }
+        format!(
+            "::{}",
+            emitter::c_symbol_name(&self.module, &self.symbol_prefix, s)
+        )
+    }
+
+    fn to_local_typed_name(&self, name: &ast::Typed) -> String {
+        let mut s = match name.t {
+            ast::Type::U8 => "uint8_t".to_string(),
+            ast::Type::U16 => "uint16_t".to_string(),
+            ast::Type::U32 => "uint32_t".to_string(),
+            ast::Type::U64 => "uint64_t".to_string(),
+            ast::Type::U128 => "uint128_t".to_string(),
+            ast::Type::I8 => "int8_t".to_string(),
+            ast::Type::I16 => "int16_t".to_string(),
+            ast::Type::I32 => "int32_t".to_string(),
+            ast::Type::I64 => "int64_t".to_string(),
+            ast::Type::I128 => "int128_t".to_string(),
+            ast::Type::Int => "int".to_string(),
+            ast::Type::UInt => "unsigned int".to_string(),
+            ast::Type::ISize => "intptr_t".to_string(),
+            ast::Type::USize => "uintptr_t".to_string(),
+            ast::Type::Bool => "bool".to_string(),
+            ast::Type::F32 => "float".to_string(),
+            ast::Type::F64 => "double".to_string(),
+            ast::Type::Other(ref n) => {
+                let mut s = self.to_local_name(&n);
+                if let ast::Tail::Static(v, _) = &name.tail {
+                    s = format!("{}_{}", s, v);
+                }
+                s
+            }
+            ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
+            ast::Type::ILiteral | ast::Type::ULiteral | ast::Type::Elided | ast::Type::New => {
+                parser::emit_error(
+                    "ICE: untyped literal ended up in emitter",
+                    &[(
+                        name.loc.clone(),
+                        format!("this should have been resolved earlier"),
+                    )],
+                );
+                super::exit(9);
+            }
+        };
+        for ptr in &name.ptr {
+            if !ptr.tags.contains_key("mut") {
+                s.push_str(" const");
+            }
+            s.push_str(" *");
+        }
+        s
+    }
+
+    /// the c++ spelling of a type in a wrapper signature.
+    /// enums of this module are exposed as their enum class.
+    fn to_cxx_typed_name(&self, typed: &ast::Typed) -> (String, bool) {
+        if typed.ptr.len() == 0 {
+            if let ast::Type::Other(n) = &typed.t {
+                if self.is_own_enum(n) {
+                    return (cxx_ident(n.0.last().unwrap()), true);
+                }
+            }
+        }
+        (self.to_local_typed_name(typed), false)
+    }
+
+    fn is_own_enum(&self, n: &Name) -> bool {
+        let mut mname = n.clone();
+        mname.pop();
+        if mname != self.module.name {
+            return false;
+        }
+        self.module.d.iter().any(|(d, _)| {
+            d.name == n.to_string()
+                && d.vis == ast::Visibility::Export
+                && match d.def {
+                    ast::Def::Enum { .. } => true,
+                    _ => false,
+                }
+        })
+    }
+
+    fn find_fn(&self, name: &Name) -> Option<ast::Local> {
+        self.module
+            .d
+            .iter()
+            .find(|(d, _)| d.name == name.to_string())
+            .map(|(d, _)| d.clone())
+    }
+
+    pub fn emit(mut self) {
+        let module = self.module.clone();
+        debug!("emitting cpp {}", module.name);
+
+        let guard = module.name.0[1..].join("_");
+        write!(
+            self.f,
+            "#ifndef ZZ_CXX_{g}\n#define ZZ_CXX_{g}\n\n#include <functional>\n\nextern \"C\" {{\n#include \"{h}\"\n}}\n\n",
+            g = guard,
+            h = self.cheader
+        )
+        .unwrap();
+
+        for ns in &module.name.0[1..] {
+            write!(self.f, "namespace {} {{\n", cxx_ident(ns)).unwrap();
+        }
+
+        let mut own = Vec::new();
+        for (d, complete) in &module.d {
+            let mut dmodname = Name::from(&d.name);
+            dmodname.pop();
+            if dmodname != module.name || d.vis != ast::Visibility::Export {
+                continue;
+            }
+            if complete != &flatten::TypeComplete::Complete {
+                continue;
+            }
+            own.push(d.clone());
+        }
+
+        for d in &own {
+            if let ast::Def::Enum { .. } = d.def {
+                self.emit_enum(d);
+            }
+        }
+        for d in &own {
+            if let ast::Def::Closure { .. } = d.def {
+                self.emit_closure(d);
+            }
+        }
+        for d in &own {
+            if let ast::Def::Struct { .. } = d.def {
+                self.emit_struct(d);
+            }
+        }
+        for d in &own {
+            if let ast::Def::Function { .. } = d.def {
+                if !d.name.ends_with("::main") {
+                    self.emit_fn(d);
+                }
+            }
+        }
+
+        for _ in &module.name.0[1..] {
+            write!(self.f, "}}\n").unwrap();
+        }
+        write!(self.f, "\n#endif\n").unwrap();
+        debug!("  written {}", self.p);
+    }
+
+    fn emit_enum(&mut self, ast: &ast::Local) {
+        let names = match &ast.def {
+            ast::Def::Enum { names } => names,
+            _ => unreachable!(),
+        };
+        let cname = self.to_local_name(&Name::from(&ast.name));
+        let shortname = Name::from(&ast.name).0.last().unwrap().clone();
+
+        write!(self.f, "enum class {} {{\n", cxx_ident(&shortname)).unwrap();
+        for (name, _) in names {
+            write!(self.f, "    {} = {}_{},\n", cxx_ident(name), cname, name).unwrap();
+        }
+        write!(self.f, "}};\n\n").unwrap();
+    }
+
+    fn emit_closure(&mut self, ast: &ast::Local) {
+        let (ret, args) = match &ast.def {
+            ast::Def::Closure { ret, args, .. } => (ret, args),
+            _ => unreachable!(),
+        };
+        let cname = self.to_local_name(&Name::from(&ast.name));
+        let shortname = cxx_ident(Name::from(&ast.name).0.last().unwrap());
+
+        let ret = match ret {
+            None => "void".to_string(),
+            Some(a) => self.to_local_typed_name(&a.typed),
+        };
+        let argtypes = args
+            .iter()
+            .map(|a| self.to_local_typed_name(&a.typed))
+            .collect::<Vec<String>>();
+        let argdecl = argtypes
+            .iter()
+            .enumerate()
+            .map(|(i, t)| format!("{} a{}, ", t, i))
+            .collect::<String>();
+        let argcall = (0..argtypes.len())
+            .map(|i| format!("a{}", i))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        // the std::function must outlive the c closure, it is only borrowed as ctx
+        write!(
+            self.f,
+            r#"using {n} = std::function<{ret}({args})>;
+inline {c} {n}_adapter({n} &f) {{
+    {c} c;
+    c.ctx = &f;
+    c.fn = []({argdecl}void * ctx) -> {ret} {{
+        return (*static_cast<{n}*>(ctx))({argcall});
+    }};
+    return c;
+}}
+
+"#,
+            n = shortname,
+            c = cname,
+            ret = ret,
+            args = argtypes.join(", "),
+            argdecl = argdecl,
+            argcall = argcall,
+        )
+        .unwrap();
+    }
+
+    fn emit_struct(&mut self, ast: &ast::Local) {
+        let (tail, impls) = match &ast.def {
+            ast::Def::Struct { tail, impls, .. } => (tail, impls),
+            _ => unreachable!(),
+        };
+        let cname = self.to_local_name(&Name::from(&ast.name));
+        let shortname = cxx_ident(Name::from(&ast.name).0.last().unwrap());
+
+        let mut methods = impls.iter().collect::<Vec<_>>();
+        methods.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut ctors = Vec::new();
+        let mut dtor = None;
+        let mut members = Vec::new();
+        for (field, (fnname, _)) in methods {
+            let d = match self.find_fn(fnname) {
+                Some(d) => d,
+                None => continue,
+            };
+            if d.vis != ast::Visibility::Export {
+                continue;
+            }
+            let (ret, args, vararg) = match &d.def {
+                ast::Def::Function {
+                    ret, args, vararg, ..
+                } => (ret.clone(), args.clone(), *vararg),
+                _ => continue,
+            };
+            if vararg || args.len() == 0 || args[0].typed.ptr.len() != 1 {
+                continue;
+            }
+            if args[0].typed.ptr[0].tags.contains_key("new") {
+                ctors.push((fnname.clone(), args));
+            } else if ret.is_none()
+                && args.len() == 1
+                && (field == "close" || field == "deinit" || field == "drop")
+            {
+                dtor = Some(fnname.clone());
+            } else {
+                members.push((field.clone(), fnname.clone(), ret, args));
+            }
+        }
+
+        // raii only for structs with a fixed size and a constructor to fill them
+        if ctors.len() == 0 || *tail != ast::Tail::None {
+            write!(self.f, "using {} = {};\n\n", shortname, cname).unwrap();
+            return;
+        }
+
+        write!(
+            self.f,
+            "class {n} {{\npublic:\n    {c} inner;\n\n",
+            n = shortname,
+            c = cname
+        )
+        .unwrap();
+
+        let mut signatures = HashSet::new();
+        for (fnname, args) in ctors {
+            let (decl, call) = self.wrapper_args(&args[1..]);
+            if !signatures.insert(decl.clone()) {
+                continue;
+            }
+            write!(
+                self.f,
+                "    {n}({decl}) {{\n        {f}(&inner{sep}{call});\n    }}\n",
+                n = shortname,
+                decl = decl,
+                f = self.to_local_name(&fnname),
+                sep = if call.is_empty() { "" } else { ", " },
+                call = call,
+            )
+            .unwrap();
+        }
+        if let Some(dtor) = dtor {
+            write!(
+                self.f,
+                "    ~{n}() {{\n        {f}(&inner);\n    }}\n",
+                n = shortname,
+                f = self.to_local_name(&dtor)
+            )
+            .unwrap();
+        }
+
+        write!(
+            self.f,
+            r#"    {n}(const {n} &) = delete;
+    {n} &operator=(const {n} &) = delete;
+
+    {c} *operator->() {{ return &inner; }}
+"#,
+            n = shortname,
+            c = cname
+        )
+        .unwrap();
+
+        for (field, fnname, ret, args) in members {
+            let (decl, call) = self.wrapper_args(&args[1..]);
+            let constness = if args[0].typed.ptr[0].tags.contains_key("mut") {
+                ""
+            } else {
+                " const"
+            };
+            let call = format!(
+                "{}(&inner{}{})",
+                self.to_local_name(&fnname),
+                if call.is_empty() { "" } else { ", " },
+                call
+            );
+            let (ret, body) = self.wrapper_return(&ret, call);
+            write!(
+                self.f,
+                "    {ret} {m}({decl}){cn} {{\n        {body};\n    }}\n",
+                ret = ret,
+                m = cxx_ident(&field),
+                decl = decl,
+                cn = constness,
+                body = body
+            )
+            .unwrap();
+        }
+
+        write!(self.f, "}};\n\n").unwrap();
+    }
+
+    fn emit_fn(&mut self, ast: &ast::Local) {
+        let (ret, args, vararg, attr) = match &ast.def {
+            ast::Def::Function {
+                ret,
+                args,
+                vararg,
+                attr,
+                ..
+            } => (ret, args, *vararg, attr),
+            _ => unreachable!(),
+        };
+        if vararg || attr.contains_key("inline") {
+            return;
+        }
+
+        let shortname = cxx_ident(Name::from(&ast.name).0.last().unwrap());
+        let (decl, call) = self.wrapper_args(args);
+        let call = format!(
+            "{}({})",
+            self.to_local_name(&Name::from(&ast.name)),
+            call
+        );
+        let (ret, body) = self.wrapper_return(ret, call);
+
+        write!(
+            self.f,
+            "inline {ret} {n}({decl}) {{\n    {body};\n}}\n\n",
+            ret = ret,
+            n = shortname,
+            decl = decl,
+            body = body
+        )
+        .unwrap();
+    }
+
+    /// declaration and forwarding call of wrapper arguments
+    fn wrapper_args(&self, args: &[ast::NamedArg]) -> (String, String) {
+        let mut decl = Vec::new();
+        let mut call = Vec::new();
+        for arg in args {
+            let name = cxx_ident(&arg.name);
+            let (t, is_enum) = self.to_cxx_typed_name(&arg.typed);
+            decl.push(format!("{} {}", t, name));
+            if is_enum {
+                call.push(format!(
+                    "static_cast<{}>({})",
+                    self.to_local_typed_name(&arg.typed),
+                    name
+                ));
+            } else {
+                call.push(name);
+            }
+        }
+        (decl.join(", "), call.join(", "))
+    }
+
+    fn wrapper_return(&self, ret: &Option<ast::AnonArg>, call: String) -> (String, String) {
+        match ret {
+            None => ("void".to_string(), call),
+            Some(a) => {
+                let (t, is_enum) = self.to_cxx_typed_name(&a.typed);
+                if is_enum {
+                    let body = format!("return static_cast<{}>({})", t, call);
+                    (t, body)
+                } else {
+                    (t, format!("return {}", call))
+                }
+            }
+        }
+    }
+}
@@ -68,8 +68,10 @@ impl Emitter {
             if complete != &flatten::TypeComplete::Complete {
                 continue;
             }
-            match d.def {
-                ast::Def::Function { .. } => {
+            match &d.def {
+                ast::Def::Function { attr, .. }
+                    if !attr.contains_key("test") && !attr.contains_key("bench") =>
+                {
                     self.emit_fndecl(&d);
                 }
                 _ => (),
@@ -111,6 +113,8 @@ impl Emitter {
                 }
                 ast::Def::Theory { .. } => {}
                 ast::Def::Testcase { .. } => {}
+                ast::Def::StaticAssert { .. } => {}
+                ast::Def::Interface { .. } => {}
                 ast::Def::Include { .. } => {}
             }
             write!(self.f, "\n").unwrap();
@@ -1,6 +1,7 @@
 #![allow(unused)]
 
 use super::ast;
+use super::emitter;
 use super::flatten;
 use super::make;
 use super::name::Name;
@@ -23,6 +24,7 @@ pub struct Emitter {
     project_name: String,
     f: fs::File,
     module: flatten::Module,
+    symbol_prefix: String,
     cur_loc: Option<ast::Location>,
     register_structs: Vec<String>,
     register_fns: Vec<(String, String)>,
@@ -223,6 +225,7 @@ impl Emitter {
             project_name: project.name.clone(),
             f,
             module,
+            symbol_prefix: project.symbol_prefix.clone().unwrap_or_default(),
             cur_loc: None,
             register_structs: Vec::new(),
             register_fns: Vec::new(),
@@ -261,13 +264,14 @@ impl Emitter {
             ast::Type::Other(ref n) => {
                 let mut s = self.to_local_name(&n);
                 match &name.tail {
-                    ast::Tail::Dynamic(_) | ast::Tail::None | ast::Tail::Bind(_, _) => {}
+                    ast::Tail::Dynamic(_) | ast::Tail::None | ast::Tail::Bind(_, _) | ast::Tail::Generic(_) => {}
                     ast::Tail::Static(v, _) => {
                         s = format!("{}_{}", s, v);
                     }
                 }
                 s
             }
+            ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
             ast::Type::ILiteral | ast::Type::ULiteral | ast::Type::Elided | ast::Type::New => {
                 parser::emit_error(
                     "ICE: untyped literal ended up in emitter",
@@ -276,7 +280,7 @@ impl Emitter {
                         format!("this should have been resolved earlier"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         }
     }
@@ -352,6 +356,7 @@ impl Emitter {
             ast::Type::Other(ref n) => {
                 //TODO
             }
+            ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
             ast::Type::ILiteral | ast::Type::ULiteral | ast::Type::Elided | ast::Type::New => {
                 parser::emit_error(
                     "ICE: untyped literal ended up in emitter",
@@ -360,7 +365,7 @@ impl Emitter {
                         format!("this should have been resolved earlier"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         }
     }
@@ -463,6 +468,7 @@ impl Emitter {
             ast::Type::Other(ref n) => {
                 //TODO
             }
+            ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
             ast::Type::ILiteral | ast::Type::ULiteral | ast::Type::Elided | ast::Type::New => {
                 parser::emit_error(
                     "ICE: untyped literal ended up in emitter",
@@ -471,7 +477,7 @@ impl Emitter {
                         format!("this should have been resolved earlier"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         }
     }
@@ -490,9 +496,7 @@ impl Emitter {
             return s.0.last().unwrap().clone();
         }
 
-        let mut s = s.clone();
-        s.0.remove(0);
-        return s.0.join("_");
+        emitter::c_symbol_name(&self.module, &self.symbol_prefix, s)
     }
 
     pub fn emit(mut self) {
@@ -516,6 +520,9 @@ impl Emitter {
         .unwrap();
 
         for (d, complete) in &module.d {
+            if module.hidden_instance(d) {
+                continue;
+            }
             match d.def {
                 ast::Def::Function { .. } => {
                     self.emit_fndecl(&d);
@@ -524,6 +531,9 @@ impl Emitter {
             }
         }
         for (d, complete) in &module.d {
+            if module.hidden_instance(d) {
+                continue;
+            }
             if complete != &flatten::TypeComplete::Complete {
                 continue;
             }
@@ -561,6 +571,8 @@ impl Emitter {
                 ast::Def::Symbol { .. } => {}
                 ast::Def::Theory { .. } => {}
                 ast::Def::Testcase { .. } => {}
+                ast::Def::StaticAssert { .. } => {}
+                ast::Def::Interface { .. } => {}
                 ast::Def::Include { .. } => {}
             }
             write!(self.f, "\n").unwrap();
@@ -1,6 +1,7 @@
 #![allow(unused)]
 
 use super::ast;
+use super::emitter;
 use super::flatten;
 use super::make;
 use super::name::Name;
@@ -16,6 +17,7 @@ pub struct Emitter {
     project_name: String,
     f: fs::File,
     module: flatten::Module,
+    symbol_prefix: String,
     cur_loc: Option<ast::Location>,
     register_structs: Vec<(String /* long */, String /*short*/)>,
     register_fns: Vec<(
@@ -134,6 +136,7 @@ impl Emitter {
             project_name: project.name.clone(),
             f,
             module,
+            symbol_prefix: project.symbol_prefix.clone().unwrap_or_default(),
             cur_loc: None,
             register_structs: Vec::new(),
             register_fns: Vec::new(),
@@ -175,13 +178,14 @@ impl Emitter {
             ast::Type::Other(ref n) => {
                 let mut s = self.to_local_name(&n);
                 match &name.tail {
-                    ast::Tail::Dynamic(_) | ast::Tail::None | ast::Tail::Bind(_, _) => {}
+                    ast::Tail::Dynamic(_) | ast::Tail::None | ast::Tail::Bind(_, _) | ast::Tail::Generic(_) => {}
                     ast::Tail::Static(v, _) => {
                         s = format!("{}_{}", s, v);
                     }
                 }
                 s
             }
+            ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
             ast::Type::ILiteral | ast::Type::ULiteral | ast::Type::Elided | ast::Type::New => {
                 parser::emit_error(
                     "ICE: untyped literal ended up in emitter",
@@ -190,7 +194,7 @@ impl Emitter {
                         format!("this should have been resolved earlier"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         }
     }
@@ -209,9 +213,7 @@ impl Emitter {
             return s.0.last().unwrap().clone();
         }
 
-        let mut s = s.clone();
-        s.0.remove(0);
-        return s.0.join("_");
+        emitter::c_symbol_name(&self.module, &self.symbol_prefix, s)
     }
 
     pub fn emit(mut self) {
@@ -253,6 +255,9 @@ static inline void * pyFATGetPtr(PyObject * obj , char * expected_type) {{
 
 
         for (d, complete) in &module.d {
+            if module.hidden_instance(d) {
+                continue;
+            }
             match d.def {
                 ast::Def::Function { .. } => {
                     self.emit_fndecl(&d);
@@ -266,6 +271,9 @@ static inline void * pyFATGetPtr(PyObject * obj , char * expected_type) {{
         }
 
         for (d, complete) in &module.d {
+            if module.hidden_instance(d) {
+                continue;
+            }
             if complete != &flatten::TypeComplete::Complete {
                 continue;
             }
@@ -295,12 +303,17 @@ static inline void * pyFATGetPtr(PyObject * obj , char * expected_type) {{
                 ast::Def::Symbol { .. } => {}
                 ast::Def::Theory { .. } => {}
                 ast::Def::Testcase { .. } => {}
+                ast::Def::StaticAssert { .. } => {}
+                ast::Def::Interface { .. } => {}
                 ast::Def::Include { .. } => {}
             }
             write!(self.f, "\n").unwrap();
         }
 
         for (d, complete) in &module.d {
+            if module.hidden_instance(d) {
+                continue;
+            }
             if complete != &flatten::TypeComplete::Complete {
                 continue;
             }
@@ -895,6 +908,7 @@ PyTypeObject py_Type_{ln}  = {{
                             unreachable!();
                         }
                     }
+                    ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
                     ast::Type::ILiteral
                     | ast::Type::ULiteral
                     | ast::Type::Elided
@@ -906,7 +920,7 @@ PyTypeObject py_Type_{ln}  = {{
                                 format!("this should have been resolved earlier"),
                             )],
                         );
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                 }
             } else {
@@ -1135,6 +1149,7 @@ static PyObject* py_{}(PyObject *pyself, PyObject *args) {{
                             unreachable!();
                         }
                     }
+                    ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
                     ast::Type::ILiteral
                     | ast::Type::ULiteral
                     | ast::Type::Elided
@@ -1146,7 +1161,7 @@ static PyObject* py_{}(PyObject *pyself, PyObject *args) {{
                                 format!("this should have been resolved earlier"),
                             )],
                         );
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                 }
             } else {
@@ -1281,6 +1296,7 @@ static PyObject* py_{}(PyObject *pyself, PyObject *args) {{
                         .unwrap();
                     }
                 }
+                ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
                 ast::Type::ILiteral | ast::Type::ULiteral | ast::Type::Elided | ast::Type::New => {
                     parser::emit_error(
                         "ICE: untyped literal ended up in emitter",
@@ -1289,7 +1305,7 @@ static PyObject* py_{}(PyObject *pyself, PyObject *args) {{
                             format!("this should have been resolved earlier"),
                         )],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             },
         }
@@ -1318,13 +1334,14 @@ static PyObject* py_{}(PyObject *pyself, PyObject *args) {{
             ast::Type::Other(ref n) => {
                 let mut s = self.to_local_name(&n);
                 match &name.tail {
-                    ast::Tail::Dynamic(_) | ast::Tail::None | ast::Tail::Bind(_, _) => {}
+                    ast::Tail::Dynamic(_) | ast::Tail::None | ast::Tail::Bind(_, _) | ast::Tail::Generic(_) => {}
                     ast::Tail::Static(v, _) => {
                         s = format!("{}_{}", s, v);
                     }
                 }
                 s
             }
+            ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
             ast::Type::ILiteral | ast::Type::ULiteral | ast::Type::Elided | ast::Type::New => {
                 parser::emit_error(
                     "ICE: untyped literal ended up in emitter",
@@ -1333,7 +1350,7 @@ static PyObject* py_{}(PyObject *pyself, PyObject *args) {{
                         format!("this should have been resolved earlier"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         }
     }
@@ -1,6 +1,7 @@
 #![allow(unused)]
 
 use super::ast;
+use super::emitter;
 use super::flatten;
 use super::make;
 use super::name::Name;
@@ -15,6 +16,7 @@ pub struct Emitter {
     p: String,
     f: fs::File,
     module: flatten::Module,
+    symbol_prefix: String,
     inside_macro: bool,
     cur_loc: Option<ast::Location>,
     closure_types:   HashSet<String>,
@@ -96,6 +98,7 @@ impl Emitter {
             p,
             f,
             module,
+            symbol_prefix: project.symbol_prefix.clone().unwrap_or_default(),
             inside_macro: false,
             cur_loc: None,
             closure_types: HashSet::new(),
@@ -161,6 +164,7 @@ impl Emitter {
                 s
             }
                 */
+            ast::Type::Slice(_) => panic!("ICE: slice type ended up in emitter"),
             ast::Type::ILiteral | ast::Type::ULiteral | ast::Type::Elided | ast::Type::New => {
                 parser::emit_error(
                     "ICE: untyped ended up in emitter",
@@ -169,7 +173,7 @@ impl Emitter {
                         format!("this should have been resolved earlier"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         })
     }
@@ -187,9 +191,7 @@ impl Emitter {
             return s.0.last().unwrap().clone();
         }
 
-        let mut s = s.clone();
-        s.0.remove(0);
-        return s.0.join("_");
+        emitter::c_symbol_name(&self.module, &self.symbol_prefix, s)
     }
 
     pub fn emit(mut self) {
@@ -204,6 +206,9 @@ impl Emitter {
         write!(self.f, "extern crate libc;\n").unwrap();
 
         for (d, complete) in &module.d {
+            if module.hidden_instance(d) {
+                continue;
+            }
             let mut dmodname = Name::from(&d.name);
             dmodname.pop();
             if dmodname != module.name {
@@ -235,6 +240,9 @@ impl Emitter {
 
         write!(self.f, "\npub mod heap {{\n").unwrap();
         for (d, complete) in &module.d {
+            if module.hidden_instance(d) {
+                continue;
+            }
             let mut dmodname = Name::from(&d.name);
             dmodname.pop();
             if dmodname != module.name {
@@ -262,6 +270,9 @@ impl Emitter {
         write!(self.f, "extern {{\n").unwrap();
 
         for (d, complete) in &module.d {
+            if module.hidden_instance(d) {
+                continue;
+            }
             let mut dmodname = Name::from(&d.name);
             dmodname.pop();
             if dmodname != module.name {
@@ -608,7 +619,7 @@ impl {name} {{
                                 format!("tail field would displace next field"),
                             )],
                         );
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                     if let Some(tt) = tail_variant {
                         write!(self.f, "    pub {} : [", field.name).unwrap();
@@ -758,10 +769,20 @@ impl {name} {{
                         format!("this should have been resolved earlier"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
             ast::Expression::ArrayInit { .. } => {}
             ast::Expression::StructInit { .. } => {}
+            ast::Expression::Slice { loc, .. } => {
+                parser::emit_error(
+                    "ICE: slice expression ended up in emitter",
+                    &[(
+                        loc.clone(),
+                        format!("this should have been resolved earlier"),
+                    )],
+                );
+                super::exit(9);
+            }
             ast::Expression::UnaryPost { expr, loc, op } => {
                 write!(self.f, "(").unwrap();
                 self.emit_loc(&loc);
@@ -838,7 +859,7 @@ impl {name} {{
                     }
                     ast::EmitBehaviour::Error { loc, message } => {
                         emit_error(format!("{}", message), &[(loc.clone(), "here")]);
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                 };
 
@@ -891,6 +912,21 @@ impl {name} {{
                 self.emit_expr(rhs);
                 write!(self.f, "  )").unwrap();
             }
+            ast::Expression::Ternary {
+                loc,
+                cond,
+                then,
+                otherwise,
+            } => {
+                write!(self.f, "(if ").unwrap();
+                self.emit_loc(&loc);
+                self.emit_expr(cond);
+                write!(self.f, " {{").unwrap();
+                self.emit_expr(then);
+                write!(self.f, " }} else {{").unwrap();
+                self.emit_expr(otherwise);
+                write!(self.f, " }})").unwrap();
+            }
             ast::Expression::MemberAccess { loc, lhs, rhs, op } => {
                 self.emit_loc(&loc);
                 self.emit_expr(lhs);
@@ -911,7 +947,7 @@ impl {name} {{
                         format!("c preprocessor expression not possible in this location"),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
         }
     }
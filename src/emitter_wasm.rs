@@ -0,0 +1,260 @@
+use super::ast;
+use super::emitter;
+use super::flatten;
+use super::make;
+use super::name::Name;
+use super::project::Project;
+use std::fs;
+use std::io::Write;
+
+pub struct Emitter {
+    p: String,
+    f: fs::File,
+    ts: fs::File,
+    module: flatten::Module,
+    symbol_prefix: String,
+}
+
+pub fn outname(_project: &Project, stage: &make::Stage, module: &flatten::Module) -> String {
+    format!("target/{}/wasm/{}.js", stage, module.name.0[1..].join("_"))
+}
+
+pub fn make_module(make: &super::make::Make) {
+    let pdir_ = format!("target/{}/wasm/", make.stage);
+    let pdir = std::path::Path::new(&pdir_);
+
+    let mut register_modules = Vec::new();
+    for step in &make.steps {
+        if step.source.parent().unwrap().file_name().unwrap() == "zz" {
+            register_modules.push(
+                step.source
+                    .file_stem()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string(),
+            );
+        }
+    }
+
+    let p = pdir.join(format!("{}.js", make.artifact.name));
+    let mut f = fs::File::create(&p).expect(&format!("cannot create {:?}", p));
+    write!(
+        f,
+        r#"'use strict';
+
+// instantiate {n}.wasm from a BufferSource or Response.
+// wasi builds need the wasi_snapshot_preview1 import passed in `imports`
+async function load(source, imports) {{
+    imports = imports || {{}};
+    const {{ instance }} = (typeof Response !== 'undefined' && source instanceof Response)
+        ? await WebAssembly.instantiateStreaming(source, imports)
+        : await WebAssembly.instantiate(source, imports);
+    const e = instance.exports;
+    if (e._initialize) {{
+        e._initialize();
+    }}
+    const m = {{ instance: instance, memory: e.memory }};
+"#,
+        n = make.artifact.name
+    )
+    .unwrap();
+    for module in &register_modules {
+        let frag = pdir.join(format!("{}.js", module));
+        if let Ok(frag) = fs::read_to_string(&frag) {
+            f.write_all(frag.as_bytes()).unwrap();
+        }
+    }
+    write!(f, "    return m;\n}}\n\nmodule.exports = {{ load }};\n").unwrap();
+
+    let p = pdir.join(format!("{}.d.ts", make.artifact.name));
+    let mut f = fs::File::create(&p).expect(&format!("cannot create {:?}", p));
+    write!(
+        f,
+        "export interface Module {{\n    instance: WebAssembly.Instance;\n    memory: WebAssembly.Memory;\n"
+    )
+    .unwrap();
+    for module in &register_modules {
+        let frag = pdir.join(format!("{}.d.ts", module));
+        if let Ok(frag) = fs::read_to_string(&frag) {
+            f.write_all(frag.as_bytes()).unwrap();
+        }
+    }
+    write!(
+        f,
+        "}}\n\nexport function load(source: BufferSource | Response, imports?: WebAssembly.Imports): Promise<Module>;\n"
+    )
+    .unwrap();
+}
+
+enum WasmType {
+    Number,
+    BigInt,
+    Bool,
+}
+
+impl Emitter {
+    pub fn new(project: &Project, stage: make::Stage, module: flatten::Module) -> Self {
+        std::fs::create_dir_all(format!("target/{}/wasm/", stage)).unwrap();
+        let p = outname(project, &stage, &module);
+        let f = fs::File::create(&p).expect(&format!("cannot create {}", p));
+        let pts = format!("target/{}/wasm/{}.d.ts", stage, module.name.0[1..].join("_"));
+        let ts = fs::File::create(&pts).expect(&format!("cannot create {}", pts));
+
+        Emitter {
+            p,
+            f,
+            ts,
+            module,
+            symbol_prefix: project.symbol_prefix.clone().unwrap_or_default(),
+        }
+    }
+
+    /// how a type crosses the wasm32 boundary, if it can be passed directly.
+    /// structs, closures and 128bit integers are passed indirectly by the c abi
+    fn wasm_type(&self, typed: &ast::Typed) -> Option<WasmType> {
+        if typed.ptr.len() > 0 {
+            return Some(WasmType::Number);
+        }
+        match &typed.t {
+            ast::Type::U8
+            | ast::Type::U16
+            | ast::Type::U32
+            | ast::Type::I8
+            | ast::Type::I16
+            | ast::Type::I32
+            | ast::Type::Int
+            | ast::Type::UInt
+            | ast::Type::ISize
+            | ast::Type::USize
+            | ast::Type::F32
+            | ast::Type::F64 => Some(WasmType::Number),
+            ast::Type::U64 | ast::Type::I64 => Some(WasmType::BigInt),
+            ast::Type::Bool => Some(WasmType::Bool),
+            ast::Type::Other(n) => {
+                let is_enum = self.module.d.iter().any(|(d, _)| {
+                    d.name == n.to_string()
+                        && match d.def {
+                            ast::Def::Enum { .. } => true,
+                            _ => false,
+                        }
+                });
+                if is_enum {
+                    Some(WasmType::Number)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    pub fn emit(mut self) {
+        let module = self.module.clone();
+        debug!("emitting wasm {}", module.name);
+
+        let mname = module.name.0[1..].join("_");
+        write!(self.f, "    m[\"{}\"] = {{\n", mname).unwrap();
+        write!(self.ts, "    \"{}\": {{\n", mname).unwrap();
+
+        for (d, complete) in &module.d {
+            let mut dmodname = Name::from(&d.name);
+            dmodname.pop();
+            if dmodname != module.name || d.vis != ast::Visibility::Export {
+                continue;
+            }
+            if complete != &flatten::TypeComplete::Complete {
+                continue;
+            }
+            if let ast::Def::Function { .. } = d.def {
+                if !d.name.ends_with("::main") {
+                    self.emit_fn(&d);
+                }
+            }
+        }
+
+        write!(self.f, "    }};\n").unwrap();
+        write!(self.ts, "    }};\n").unwrap();
+        debug!("  written {}", self.p);
+    }
+
+    fn emit_fn(&mut self, ast: &ast::Local) {
+        let (ret, args, vararg) = match &ast.def {
+            ast::Def::Function {
+                ret, args, vararg, ..
+            } => (ret, args, *vararg),
+            _ => unreachable!(),
+        };
+        if vararg {
+            return;
+        }
+
+        let shortname = Name::from(&ast.name).0.last().unwrap().clone();
+        let cname = emitter::c_symbol_name(&self.module, &self.symbol_prefix, &Name::from(&ast.name));
+
+        let mut jsargs = Vec::new();
+        let mut callargs = Vec::new();
+        let mut tsargs = Vec::new();
+        for arg in args {
+            let t = match self.wasm_type(&arg.typed) {
+                Some(t) => t,
+                None => {
+                    debug!(
+                        "{} is not callable from wasm: argument {} is passed indirectly",
+                        ast.name, arg.name
+                    );
+                    return;
+                }
+            };
+            let name = format!("a_{}", arg.name);
+            match t {
+                WasmType::Number => {
+                    callargs.push(name.clone());
+                    tsargs.push(format!("{}: number", name));
+                }
+                WasmType::BigInt => {
+                    callargs.push(format!("BigInt({})", name));
+                    tsargs.push(format!("{}: bigint", name));
+                }
+                WasmType::Bool => {
+                    callargs.push(format!("({} ? 1 : 0)", name));
+                    tsargs.push(format!("{}: boolean", name));
+                }
+            }
+            jsargs.push(name);
+        }
+
+        let call = format!("e.{}({})", cname, callargs.join(", "));
+        let (body, tsret) = match ret {
+            None => (call, "void"),
+            Some(a) => match self.wasm_type(&a.typed) {
+                Some(WasmType::Number) => (call, "number"),
+                Some(WasmType::BigInt) => (call, "bigint"),
+                Some(WasmType::Bool) => (format!("{} !== 0", call), "boolean"),
+                None => {
+                    debug!(
+                        "{} is not callable from wasm: return value is passed indirectly",
+                        ast.name
+                    );
+                    return;
+                }
+            },
+        };
+
+        write!(
+            self.f,
+            "        {}: ({}) => {},\n",
+            shortname,
+            jsargs.join(", "),
+            body
+        )
+        .unwrap();
+        write!(
+            self.ts,
+            "        {}({}): {};\n",
+            shortname,
+            tsargs.join(", "),
+            tsret
+        )
+        .unwrap();
+    }
+}
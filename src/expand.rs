@@ -1,9 +1,10 @@
+use super::constant;
 use super::flatten;
 use super::Error;
 use crate::ast;
 use crate::name::Name;
 use ast::Tags;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Clone)]
 struct Storage {
@@ -11,6 +12,8 @@ struct Storage {
     typed: ast::Typed,
     declared: ast::Location,
     complete: flatten::TypeComplete,
+    /// number of elements, if this is an array of known size
+    len: Option<u64>,
 }
 
 #[derive(Default)]
@@ -18,12 +21,18 @@ struct Scope {
     #[allow(unused)]
     name: String,
     storage: HashMap<Name, Storage>,
+    /// deferred statements, in the order they were declared
+    defers: Vec<ast::Statement>,
+    /// break and continue leave this scope
+    breakable: bool,
 }
 
 struct Stack {
     defs: HashMap<Name, ast::Def>,
     stack: Vec<Scope>,
-    moretypevariants: HashMap<Name, HashMap<u64, ast::Location>>,
+    moretypevariants: HashMap<Name, BTreeMap<u64, ast::Location>>,
+    /// return type of the fn being expanded
+    ret: Option<ast::Typed>,
 }
 
 impl Stack {
@@ -32,13 +41,14 @@ impl Stack {
             defs: HashMap::new(),
             stack: Vec::new(),
             moretypevariants: HashMap::new(),
+            ret: None,
         }
     }
     fn push(&mut self, name: String) {
         debug!("  scope {}", name);
         self.stack.push(Scope {
             name,
-            storage: HashMap::new(),
+            ..Default::default()
         });
     }
 
@@ -97,12 +107,75 @@ impl Stack {
                 name: name,
                 declared: loc.clone(),
                 complete: complete.clone(),
+                len: None,
             },
         );
 
         Ok(())
     }
 
+    fn get(&self, name: &Name) -> Option<&Storage> {
+        self.stack.iter().rev().find_map(|scope| scope.storage.get(name))
+    }
+
+    /// the number of elements of an array local, static or struct member, if its size is constant
+    fn array_len(&self, expr: &ast::Expression) -> Option<u64> {
+        match expr {
+            ast::Expression::Name(typed) => match &typed.t {
+                ast::Type::Other(n) => self.get(n)?.len,
+                _ => None,
+            },
+            ast::Expression::MemberAccess { lhs, rhs, .. } => {
+                let field = self.field(&self.type_of(lhs)?, rhs)?;
+                match &field.array {
+                    ast::Array::Sized(size) => match constant::eval(size, &self.defs) {
+                        Ok(Some(v)) if v > 0 => Some(v as u64),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// the type of a name or a member of it, if it is not an array
+    fn type_of(&self, expr: &ast::Expression) -> Option<ast::Typed> {
+        match expr {
+            ast::Expression::Name(typed) => match &typed.t {
+                ast::Type::Other(n) => {
+                    let storage = self.get(n)?;
+                    match storage.len {
+                        Some(_) => None,
+                        None => Some(storage.typed.clone()),
+                    }
+                }
+                _ => None,
+            },
+            ast::Expression::MemberAccess { lhs, rhs, .. } => {
+                let field = self.field(&self.type_of(lhs)?, rhs)?;
+                match field.array {
+                    ast::Array::None => Some(field.typed.clone()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn field(&self, typed: &ast::Typed, name: &str) -> Option<&ast::Field> {
+        if typed.ptr.len() > 1 {
+            return None;
+        }
+        match &typed.t {
+            ast::Type::Other(n) => match self.defs.get(n)? {
+                ast::Def::Struct { fields, .. } => fields.iter().find(|f| f.name == name),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     pub fn struct_final_tail_type(
         &self,
         fields: &Vec<ast::Field>,
@@ -161,7 +234,7 @@ impl Stack {
                         ));
                     }
                 }
-                ast::Tail::None => {
+                ast::Tail::None | ast::Tail::Generic(_) => {
                     if let ast::Array::Unsized = field.array {
                         return Ok(Some(Box::new(field.typed.clone())));
                     } else {
@@ -355,10 +428,82 @@ pub fn expand(module: &mut flatten::Module) -> Result<(), Error> {
                 )?;
             }
             ast::Def::Testcase { .. } => {}
+            ast::Def::StaticAssert { .. } => {}
+            ast::Def::Interface { .. } => {}
             ast::Def::Include { .. } => {}
         }
     }
 
+    // constant run
+    for (d, _) in &mut module.d {
+        match &mut d.def {
+            ast::Def::Const { typed, expr } => {
+                if let Some(v) = constant::eval(expr, &stack.defs)? {
+                    if constant::is_integer(typed) {
+                        let v = match constant::initialize(v, typed) {
+                            Some(v) => v,
+                            None => {
+                                return Err(Error::new(
+                                    format!("constant {} does not fit into {}", v, typed),
+                                    vec![(expr.loc().clone(), format!("evaluates to {}", v))],
+                                ));
+                            }
+                        };
+                        // C would read a decimal literal above the signed 64 bit range as unsigned with a warning
+                        let v = if v > i64::MAX as i128 {
+                            format!("0x{:x}", v)
+                        } else {
+                            format!("{}", v)
+                        };
+                        *expr = ast::Expression::Literal {
+                            loc: expr.loc().clone(),
+                            v,
+                        };
+                    }
+                }
+            }
+            ast::Def::Static { typed, array, .. } => {
+                if let ast::Array::Sized(expr) = array {
+                    fold_size(expr, &stack.defs)?;
+                    if let ast::Expression::Literal { v, .. } = expr {
+                        let len = v.parse().ok();
+                        if let Some(storage) = stack.cur().storage.get_mut(&Name::from(&d.name)) {
+                            storage.len = len;
+                        }
+                    }
+                }
+                stack.fold_tail(typed)?;
+            }
+            ast::Def::Struct { fields, .. } => {
+                for field in fields {
+                    if let ast::Array::Sized(expr) = &mut field.array {
+                        fold_size(expr, &stack.defs)?;
+                    }
+                    stack.fold_tail(&mut field.typed)?;
+                }
+            }
+            ast::Def::StaticAssert { expr, message } => match constant::eval(expr, &stack.defs)? {
+                None => {
+                    return Err(Error::new(
+                        format!("static_assert requires a constant expression"),
+                        vec![(
+                            expr.loc().clone(),
+                            format!("cannot be evaluated at compile time"),
+                        )],
+                    ));
+                }
+                Some(0) => {
+                    return Err(Error::new(
+                        message.clone(),
+                        vec![(d.loc.clone(), format!("static_assert failed"))],
+                    ));
+                }
+                Some(_) => (),
+            },
+            _ => (),
+        }
+    }
+
     // definition run
     for (d, complete) in &mut module.d {
         match &mut d.def {
@@ -383,12 +528,14 @@ pub fn expand(module: &mut flatten::Module) -> Result<(), Error> {
             }
             ast::Def::Function {
                 args,
+                ret,
                 body,
                 callassert,
                 callattests,
                 calleffect,
                 ..
             } => {
+                stack.ret = ret.as_ref().map(|r| r.typed.clone());
                 for farg in args.iter_mut() {
                     if farg.typed.ptr.len() > 0 {
                         if Name::from(&d.name).0.last() != Some(&"borrow".to_string()) {
@@ -448,8 +595,8 @@ pub fn expand(module: &mut flatten::Module) -> Result<(), Error> {
                             );
                         }
 
-                        // safe is implicit unless the arg is marked unsafe
-                        if !farg.tags.contains("unsafe") {
+                        // safe is implicit unless the arg is marked unsafe or nullable
+                        if !farg.tags.contains("unsafe") && !farg.typed.nullable() {
                             let loc = farg.typed.ptr[0].loc.clone();
                             let ast_safe = ast::Expression::Name(ast::Typed {
                                 t: ast::Type::Other(Name::from("safe")),
@@ -497,7 +644,9 @@ pub fn expand(module: &mut flatten::Module) -> Result<(), Error> {
                         }
 
                         stack.expand_scope(&mut body.statements)?;
-                        body.statements.extend(stack.drop_fn(&body.end)?);
+                        if !diverges(&body.statements) {
+                            body.statements.extend(stack.drop_fn(&body.end)?);
+                        }
 
                         stack.pop();
                     }
@@ -512,7 +661,7 @@ pub fn expand(module: &mut flatten::Module) -> Result<(), Error> {
             module
                 .typevariants
                 .entry(t.clone())
-                .or_insert(HashMap::new())
+                .or_insert(BTreeMap::new())
                 .insert(variant.0, variant.1);
         }
     }
@@ -529,7 +678,7 @@ impl Stack {
                 ..
             } => {
                 self.expand_expr(name)?;
-                for arg in args {
+                for arg in args.iter_mut() {
                     self.expand_expr(arg)?;
                 }
 
@@ -540,6 +689,18 @@ impl Stack {
                         }
                     }
                 }
+
+                // len of an array of constant size is a literal, so it can be emitted as is
+                if let ast::Expression::Name(ftyped) = name.as_ref() {
+                    if ftyped.t == ast::Type::Other(Name::from("len")) && args.len() == 1 {
+                        if let Some(len) = self.array_len(&args[0]) {
+                            *expr = ast::Expression::Literal {
+                                loc: expr.loc().clone(),
+                                v: format!("{}", len),
+                            };
+                        }
+                    }
+                }
             }
             ast::Expression::Name(_) => {}
             ast::Expression::MemberAccess { ref mut lhs, .. } => {
@@ -564,6 +725,27 @@ impl Stack {
                 self.expand_expr(lhs)?;
                 self.expand_expr(rhs)?;
             }
+            ast::Expression::Slice {
+                ref mut lhs,
+                ref mut from,
+                ref mut to,
+                ..
+            } => {
+                self.expand_expr(lhs)?;
+                for expr in from.iter_mut().chain(to.iter_mut()) {
+                    self.expand_expr(expr)?;
+                }
+            }
+            ast::Expression::Ternary {
+                ref mut cond,
+                ref mut then,
+                ref mut otherwise,
+                ..
+            } => {
+                self.expand_expr(cond)?;
+                self.expand_expr(then)?;
+                self.expand_expr(otherwise)?;
+            }
             ast::Expression::Cast { ref mut expr, .. } => {
                 self.expand_expr(expr)?;
             }
@@ -610,6 +792,11 @@ impl Stack {
                     assign,
                     ..
                 } => {
+                    if let Some(Some(expr)) = array {
+                        fold_size(expr, &self.defs)?;
+                    }
+                    self.fold_tail(typed)?;
+
                     if let ast::Type::New = typed.t {
                         if !tags.contains("mut") {
                             tags.insert("mut".to_string(), String::new(), loc.clone());
@@ -662,7 +849,7 @@ impl Stack {
                                             if let ast::Tail::Static(f, tvloc) = &tail {
                                                 self.moretypevariants
                                                     .entry(tn.clone())
-                                                    .or_insert(HashMap::new())
+                                                    .or_insert(BTreeMap::new())
                                                     .insert(*f, tvloc.clone());
                                             }
                                         }
@@ -730,6 +917,14 @@ impl Stack {
                             tags.clone(),
                             &flatten::TypeComplete::Complete,
                         )?;
+                        let len = match (&array, &assign) {
+                            (Some(Some(ast::Expression::Literal { v, .. })), _) => v.parse().ok(),
+                            (Some(None), Some(ast::Expression::ArrayInit { fields, .. })) => {
+                                Some(fields.len() as u64)
+                            }
+                            _ => None,
+                        };
+                        self.cur().storage.get_mut(&Name::from(name.as_str())).unwrap().len = len;
                         if let Some(expr) = assign {
                             self.expand_expr(expr)?;
                         }
@@ -739,7 +934,7 @@ impl Stack {
                     self.push("if".to_string());
                     for (_loc, _expr, block) in branches {
                         self.push("branch".to_string());
-                        self.expand_scope(&mut block.statements)?;
+                        self.expand_block(block)?;
                         self.pop();
                     }
                     self.pop();
@@ -753,18 +948,58 @@ impl Stack {
                     }
 
                     let r = self.drop_fn(&loc)?;
-                    for stm in r.into_iter().rev() {
-                        body.insert(i, stm);
-                        i += 1;
-                        len += 1;
+                    match (expr, &self.ret) {
+                        // the return value is computed before running the deferred statements
+                        (Some(expr), Some(ret)) if !r.is_empty() => {
+                            let loc = loc.clone();
+                            let retname = ast::Expression::Name(ast::Typed {
+                                t: ast::Type::Other(Name::from("__deferred_return")),
+                                ptr: Vec::new(),
+                                loc: loc.clone(),
+                                tail: ast::Tail::None,
+                            });
+                            let mut statements = vec![Box::new(ast::Statement::Var {
+                                loc: loc.clone(),
+                                typed: ast::Typed {
+                                    loc: loc.clone(),
+                                    ..ret.clone()
+                                },
+                                tags: Tags::new(),
+                                name: "__deferred_return".to_string(),
+                                array: None,
+                                assign: Some(expr.clone()),
+                            })];
+                            statements.extend(r);
+                            statements.push(Box::new(ast::Statement::Return {
+                                loc: loc.clone(),
+                                expr: Some(retname),
+                            }));
+                            body[i] = Box::new(ast::Statement::Block(Box::new(ast::Block {
+                                end: loc,
+                                statements,
+                                expanded: false,
+                            })));
+                        }
+                        _ => {
+                            for stm in r.into_iter().rev() {
+                                body.insert(i, stm);
+                                i += 1;
+                                len += 1;
+                            }
+                        }
                     }
                 }
                 ast::Statement::Label { .. } => {}
                 ast::Statement::Mark { .. } => {}
-                ast::Statement::Switch { cases, .. } => {
+                ast::Statement::Switch { cases, default, .. } => {
                     for (_, block) in cases {
                         self.push("case".to_string());
-                        self.expand_scope(&mut block.statements)?;
+                        self.expand_block(block)?;
+                        self.pop();
+                    }
+                    if let Some(block) = default {
+                        self.push("case".to_string());
+                        self.expand_block(block)?;
                         self.pop();
                     }
                 }
@@ -773,9 +1008,8 @@ impl Stack {
                     self.expand_expr(rhs)?;
                 }
 
-                ast::Statement::Continue { .. } => {}
-                ast::Statement::Break { loc } => {
-                    let r = self.drop(&loc)?;
+                ast::Statement::Continue { loc } | ast::Statement::Break { loc } => {
+                    let r = self.drop_loop(&loc)?;
                     for stm in r.into_iter().rev() {
                         body.insert(i, stm);
                         i += 1;
@@ -784,8 +1018,7 @@ impl Stack {
                 }
                 ast::Statement::Unsafe(block) | ast::Statement::Block(block) => {
                     self.push("block".to_string());
-                    self.expand_scope(&mut block.statements)?;
-                    block.statements.extend(self.drop(&block.end)?);
+                    self.expand_block(block)?;
                     self.pop();
                 }
                 ast::Statement::For {
@@ -797,17 +1030,37 @@ impl Stack {
                         self.expand_expr(expr)?;
                     }
                     self.expand_scope(e3)?;
-                    self.expand_scope(&mut body.statements)?;
-                    body.statements.extend(self.drop(&body.end)?);
+                    self.cur().breakable = true;
+                    self.expand_block(body)?;
                     self.pop();
                 }
                 ast::Statement::While { body, expr, .. } => {
                     self.push("while loop".to_string());
-                    self.expand_scope(&mut body.statements)?;
-                    body.statements.extend(self.drop(&body.end)?);
+                    self.cur().breakable = true;
+                    self.expand_block(body)?;
                     self.pop();
                     self.expand_expr(expr)?;
                 }
+                ast::Statement::Defer { loc, stm } => {
+                    if let Some(rloc) = returns(stm) {
+                        return Err(Error::new(
+                            format!("cannot return from a deferred statement"),
+                            vec![
+                                (rloc, "return here".to_string()),
+                                (loc.clone(), "in this defer".to_string()),
+                            ],
+                        ));
+                    }
+                    let mut deferred = vec![stm.clone()];
+                    self.push("defer".to_string());
+                    self.expand_scope(&mut deferred)?;
+                    self.pop();
+
+                    self.cur().defers.extend(deferred.into_iter().map(|stm| *stm));
+                    body.remove(i);
+                    len -= 1;
+                    continue;
+                }
                 ast::Statement::CBlock { .. } => {}
                 ast::Statement::MacroCall {  .. } => {}
             }
@@ -817,6 +1070,63 @@ impl Stack {
         Ok(())
     }
 
+    /// expand a block that is its own scope, running the scope exit at its end
+    fn expand_block(&mut self, block: &mut ast::Block) -> Result<(), Error> {
+        self.expand_scope(&mut block.statements)?;
+        if !diverges(&block.statements) {
+            block.statements.extend(self.drop(&block.end)?);
+        }
+        Ok(())
+    }
+
+    /// a tail sized by a constant is static
+    fn fold_tail(&mut self, typed: &mut ast::Typed) -> Result<(), Error> {
+        let (name, loc) = match &typed.tail {
+            ast::Tail::Bind(s, loc) => (Name::from(s.as_str()), loc.clone()),
+            _ => return Ok(()),
+        };
+        if !name.is_absolute() {
+            return Ok(());
+        }
+        let v = constant::eval(
+            &ast::Expression::Name(ast::Typed {
+                t: ast::Type::Other(name),
+                ptr: Vec::new(),
+                loc: loc.clone(),
+                tail: ast::Tail::None,
+            }),
+            &self.defs,
+        )?;
+        if let Some(v) = v {
+            if v < 0 {
+                return Err(Error::new(
+                    format!("tail size cannot be negative"),
+                    vec![(loc, format!("evaluates to {}", v))],
+                ));
+            }
+            if let ast::Type::Other(tn) = &typed.t {
+                self.moretypevariants
+                    .entry(tn.clone())
+                    .or_insert(BTreeMap::new())
+                    .insert(v as u64, loc.clone());
+            }
+            typed.tail = ast::Tail::Static(v as u64, loc);
+        }
+        Ok(())
+    }
+
+    /// exits all scopes up to the innermost loop
+    fn drop_loop(&mut self, loc: &ast::Location) -> Result<Vec<Box<ast::Statement>>, Error> {
+        let mut r = Vec::new();
+        for i in (1..self.stack.len()).rev() {
+            r.extend(self.drop_frame(loc, i)?);
+            if self.stack[i].breakable {
+                break;
+            }
+        }
+        Ok(r)
+    }
+
     fn drop_fn(&mut self, loc: &ast::Location) -> Result<Vec<Box<ast::Statement>>, Error> {
         let mut r = Vec::new();
         for i in (1..self.stack.len()).rev() {
@@ -833,7 +1143,7 @@ impl Stack {
         loc: &ast::Location,
         frame: usize,
     ) -> Result<Vec<Box<ast::Statement>>, Error> {
-        let r = Vec::new();
+        let mut r = Vec::new();
         for (name, storage) in &self.stack[frame].storage {
             //TODO also drop owned pointers some day
 
@@ -853,10 +1163,61 @@ impl Stack {
             };
             //r.extend(self.drop_local(loc, &storage.typed, accesslocal, format!("(&{})", name))?);
         }
+        for stm in self.stack[frame].defers.iter().rev() {
+            r.push(Box::new(stm.clone()));
+        }
         Ok(r)
     }
 }
 
+/// replace an array size with its value if it is constant
+fn fold_size(expr: &mut ast::Expression, defs: &HashMap<Name, ast::Def>) -> Result<(), Error> {
+    if let Some(v) = constant::eval(expr, defs)? {
+        if v < 0 {
+            return Err(Error::new(
+                format!("array size cannot be negative"),
+                vec![(expr.loc().clone(), format!("evaluates to {}", v))],
+            ));
+        }
+        *expr = ast::Expression::Literal {
+            loc: expr.loc().clone(),
+            v: format!("{}", v),
+        };
+    }
+    Ok(())
+}
+
+/// the block never reaches its end
+fn diverges(statements: &Vec<Box<ast::Statement>>) -> bool {
+    match statements.last().map(|s| s.as_ref()) {
+        Some(ast::Statement::Return { .. })
+        | Some(ast::Statement::Break { .. })
+        | Some(ast::Statement::Continue { .. }) => true,
+        _ => false,
+    }
+}
+
+fn returns(stm: &ast::Statement) -> Option<ast::Location> {
+    match stm {
+        ast::Statement::Return { loc, .. } => Some(loc.clone()),
+        ast::Statement::Block(block) | ast::Statement::Unsafe(block) => {
+            block.statements.iter().find_map(|s| returns(s))
+        }
+        ast::Statement::If { branches } => branches
+            .iter()
+            .find_map(|(_, _, block)| block.statements.iter().find_map(|s| returns(s))),
+        ast::Statement::While { body, .. } | ast::Statement::For { body, .. } => {
+            body.statements.iter().find_map(|s| returns(s))
+        }
+        ast::Statement::Switch { cases, default, .. } => cases
+            .iter()
+            .map(|(_, block)| block)
+            .chain(default.iter())
+            .find_map(|block| block.statements.iter().find_map(|s| returns(s))),
+        _ => None,
+    }
+}
+
 fn replace_named(expr: &mut ast::Expression, replacefrom: &ast::Type, replacewith: &ast::Type) {
     match expr {
         ast::Expression::Name(ref mut t) => {
@@ -901,6 +1262,27 @@ fn replace_named(expr: &mut ast::Expression, replacefrom: &ast::Type, replacewit
             replace_named(lhs, replacefrom, replacewith);
             replace_named(rhs, replacefrom, replacewith);
         }
+        ast::Expression::Slice {
+            ref mut lhs,
+            ref mut from,
+            ref mut to,
+            ..
+        } => {
+            replace_named(lhs, replacefrom, replacewith);
+            for expr in from.iter_mut().chain(to.iter_mut()) {
+                replace_named(expr, replacefrom, replacewith);
+            }
+        }
+        ast::Expression::Ternary {
+            ref mut cond,
+            ref mut then,
+            ref mut otherwise,
+            ..
+        } => {
+            replace_named(cond, replacefrom, replacewith);
+            replace_named(then, replacefrom, replacewith);
+            replace_named(otherwise, replacefrom, replacewith);
+        }
         ast::Expression::Cast { ref mut expr, .. } => {
             replace_named(expr, replacefrom, replacewith);
         }
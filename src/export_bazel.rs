@@ -0,0 +1,101 @@
+use super::make::Make;
+use std::fs;
+use std::io::Write;
+
+// bazel labels cannot leave their package, so this is written for use as the build_file
+// of a new_local_repository pointing at the project root. all paths are relative to that.
+pub fn export(make: Make) {
+    let pdir_ = format!("target/bazel/{}/", make.artifact.name);
+    let pdir = std::path::Path::new(&pdir_);
+    std::fs::create_dir_all(&pdir).unwrap();
+
+    let p = pdir.join("BUILD.bazel");
+    let mut f = fs::File::create(&p).expect(&format!("cannot create {:?}", p));
+
+    // pkg-config would give paths on this machine, which cannot be checked in
+    if make.pkgconfig.len() > 0 {
+        warn!(
+            "pkgconfig dependencies are not exported to bazel, the consumer must provide {}",
+            make.pkgconfig.join(", ")
+        );
+        write!(
+            f,
+            "# needs these pkg-config packages, which the consumer must provide: {}\n",
+            make.pkgconfig.join(" ")
+        )
+        .unwrap();
+    }
+
+    write!(
+        f,
+        r#"
+cc_library(
+    name = "{}",
+    visibility = ["//visibility:public"],
+    srcs = [
+"#,
+        make.artifact.name
+    )
+    .unwrap();
+
+    for step in &make.steps {
+        if step.source.is_absolute() {
+            warn!(
+                "{:?} is outside of the project and cannot be exported to bazel",
+                step.source
+            );
+            continue;
+        }
+        write!(f, "        \"{}\",\n", step.source.to_string_lossy()).unwrap();
+    }
+    write!(f, "    ],\n").unwrap();
+
+    write!(f, "    hdrs = glob([\n").unwrap();
+    write!(f, "        \"target/include/**/*.h\",\n").unwrap();
+    write!(f, "        \"src/**/*.h\",\n").unwrap();
+    for cinc in &make.cincludes {
+        if !std::path::Path::new(cinc).is_absolute() {
+            write!(f, "        \"{}/**/*.h\",\n", cinc.trim_end_matches('/')).unwrap();
+        }
+    }
+    write!(f, "    ], allow_empty = True),\n").unwrap();
+
+    write!(f, "    includes = [\n").unwrap();
+    write!(f, "        \"target/include\",\n").unwrap();
+    write!(f, "        \".\",\n").unwrap();
+    for cinc in &make.cincludes {
+        if !std::path::Path::new(cinc).is_absolute() {
+            write!(f, "        \"{}\",\n", cinc).unwrap();
+        }
+    }
+    write!(f, "    ],\n").unwrap();
+
+    let mut copts = Vec::new();
+    let mut linkopts = Vec::new();
+    for cinc in &make.cincludes {
+        if std::path::Path::new(cinc).is_absolute() {
+            copts.push(format!("-I{}", cinc));
+        }
+    }
+    copts.extend(make.user_cflags.clone());
+    linkopts.extend(make.user_lflags.clone());
+
+    write!(f, "    copts = [\n").unwrap();
+    for flag in &copts {
+        write!(f, "        \"{}\",\n", escape(flag)).unwrap();
+    }
+    write!(f, "    ],\n").unwrap();
+
+    write!(f, "    linkopts = [\n").unwrap();
+    for flag in &linkopts {
+        write!(f, "        \"{}\",\n", escape(flag)).unwrap();
+    }
+    write!(f, "    ],\n").unwrap();
+
+    write!(f, ")\n").unwrap();
+}
+
+/// starlark string literals use backslash escapes
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
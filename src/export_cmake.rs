@@ -10,24 +10,87 @@ pub fn export(make: Make) {
     let p = pdir.join("CMakeLists.txt");
     let mut f = fs::File::create(&p).expect(&format!("cannot create {:?}", p));
 
+    let an = &make.artifact.name;
+
     write!(
         f,
         r#"
 include_directories("${{CMAKE_CURRENT_LIST_DIR}}/../../{}/")
 set({}_SOURCES
 "#,
-        make.stage, make.artifact.name
+        make.stage, an
     )
     .unwrap();
 
     for step in &make.steps {
+        if step.source.is_absolute() {
+            write!(f, "     {}\n", step.source.to_string_lossy()).unwrap();
+        } else {
+            write!(
+                f,
+                "     ${{CMAKE_CURRENT_LIST_DIR}}/../../../{}\n",
+                step.source.to_string_lossy()
+            )
+            .unwrap();
+        }
+    }
+
+    write!(f, ")\n\n").unwrap();
+
+    write!(f, "add_library({an} ${{{an}_SOURCES}})\n", an = an).unwrap();
+
+    write!(f, "target_include_directories({} PUBLIC\n", an).unwrap();
+    write!(f, "     ${{CMAKE_CURRENT_LIST_DIR}}/../../include/\n").unwrap();
+    write!(f, "     ${{CMAKE_CURRENT_LIST_DIR}}/../../../\n").unwrap();
+    for cinc in &make.cincludes {
+        if std::path::Path::new(cinc).is_absolute() {
+            write!(f, "     {}\n", cinc).unwrap();
+        } else {
+            write!(f, "     ${{CMAKE_CURRENT_LIST_DIR}}/../../../{}\n", cinc).unwrap();
+        }
+    }
+    write!(f, ")\n").unwrap();
+
+    if make.pkgconfig.len() > 0 {
+        write!(
+            f,
+            "find_package(PkgConfig REQUIRED)\npkg_check_modules({}_PKGCONFIG REQUIRED IMPORTED_TARGET",
+            an
+        )
+        .unwrap();
+        for pkg in &make.pkgconfig {
+            write!(f, " {}", pkg).unwrap();
+        }
+        write!(f, ")\n").unwrap();
         write!(
             f,
-            "     ${{CMAKE_CURRENT_LIST_DIR}}/../../../{}\n",
-            step.source.to_string_lossy()
+            "target_link_libraries({an} PUBLIC PkgConfig::{an}_PKGCONFIG)\n",
+            an = an
         )
         .unwrap();
     }
 
-    write!(f, ")\n").unwrap();
+    if make.user_cflags.len() > 0 {
+        write!(f, "target_compile_options({} PRIVATE\n", an).unwrap();
+        for cflag in &make.user_cflags {
+            write!(f, "     \"{}\"\n", escape(cflag)).unwrap();
+        }
+        write!(f, ")\n").unwrap();
+    }
+
+    if make.user_lflags.len() > 0 {
+        write!(f, "target_link_options({} PUBLIC\n", an).unwrap();
+        for lflag in &make.user_lflags {
+            write!(f, "     \"{}\"\n", escape(lflag)).unwrap();
+        }
+        write!(f, ")\n").unwrap();
+    }
+}
+
+/// one flag as a quoted cmake argument. a ; would split it into a list
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace(';', "\\;")
 }
@@ -0,0 +1,84 @@
+use super::make::Make;
+use std::fs;
+use std::io::Write;
+
+pub fn export(make: Make) {
+    let pdir_ = format!("target/meson/{}/", make.artifact.name);
+    let pdir = std::path::Path::new(&pdir_);
+    std::fs::create_dir_all(&pdir).unwrap();
+
+    let p = pdir.join("meson.build");
+    let mut f = fs::File::create(&p).expect(&format!("cannot create {:?}", p));
+
+    // meson identifiers cannot contain dashes
+    let an = make
+        .artifact
+        .name
+        .replace(|c: char| !c.is_alphanumeric(), "_");
+
+    write!(f, "{}_sources = files(\n", an).unwrap();
+    for step in &make.steps {
+        if step.source.is_absolute() {
+            write!(f, "    '{}',\n", step.source.to_string_lossy()).unwrap();
+        } else {
+            write!(f, "    '../../../{}',\n", step.source.to_string_lossy()).unwrap();
+        }
+    }
+    write!(f, ")\n\n").unwrap();
+
+    write!(f, "{}_inc = include_directories(\n", an).unwrap();
+    write!(f, "    '../../include',\n").unwrap();
+    write!(f, "    '../../..',\n").unwrap();
+    for cinc in &make.cincludes {
+        if std::path::Path::new(cinc).is_absolute() {
+            write!(f, "    '{}',\n", cinc).unwrap();
+        } else {
+            write!(f, "    '../../../{}',\n", cinc).unwrap();
+        }
+    }
+    write!(f, ")\n\n").unwrap();
+
+    write!(f, "{}_deps = [\n", an).unwrap();
+    for pkg in &make.pkgconfig {
+        write!(f, "    dependency('{}'),\n", pkg).unwrap();
+    }
+    write!(f, "]\n\n").unwrap();
+
+    write!(
+        f,
+        r#"{an}_lib = library('{name}',
+    {an}_sources,
+    include_directories: {an}_inc,
+    dependencies: {an}_deps,
+    c_args: [
+"#,
+        an = an,
+        name = make.artifact.name
+    )
+    .unwrap();
+    for cflag in &make.user_cflags {
+        write!(f, "        '{}',\n", escape(cflag)).unwrap();
+    }
+    write!(f, "    ],\n    link_args: [\n").unwrap();
+    for lflag in &make.user_lflags {
+        write!(f, "        '{}',\n", escape(lflag)).unwrap();
+    }
+    write!(f, "    ],\n)\n\n").unwrap();
+
+    write!(
+        f,
+        r#"{an}_dep = declare_dependency(
+    link_with: {an}_lib,
+    include_directories: {an}_inc,
+    dependencies: {an}_deps,
+)
+"#,
+        an = an
+    )
+    .unwrap();
+}
+
+/// meson string literals are single quoted and use backslash escapes
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\'', "\\'")
+}
@@ -1,9 +1,10 @@
 use super::abs::Ext;
 use super::ast;
+use super::generic;
 use super::loader;
 use super::name::Name;
 use super::parser::emit_error;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::collections::HashSet;
 use std::path::PathBuf;
 
@@ -35,9 +36,20 @@ pub struct Module {
     pub d: Vec<(ast::Local, TypeComplete)>,
 
     pub aliases: HashMap<Name, String>,
+    pub export_names: HashMap<Name, String>,
     pub deps: HashSet<Name>,
 
-    pub typevariants: HashMap<Name, HashMap<u64, ast::Location>>,
+    pub typevariants: HashMap<Name, BTreeMap<u64, ast::Location>>,
+
+    /// locals of this module that are instances of a generic
+    pub instances: HashSet<Name>,
+}
+
+impl Module {
+    /// instances are only exported to other languages when requested with `export Name<T>;`
+    pub fn hidden_instance(&self, d: &ast::Local) -> bool {
+        d.vis != ast::Visibility::Export && self.instances.contains(&Name::from(&d.name))
+    }
 }
 
 #[derive(Clone)]
@@ -51,7 +63,7 @@ struct Local {
 
 #[derive(Default)]
 struct Collector {
-    typevariants: HashMap<Name, HashMap<u64, ast::Location>>,
+    typevariants: HashMap<Name, BTreeMap<u64, ast::Location>>,
 }
 
 #[derive(Default)]
@@ -81,6 +93,9 @@ fn type_deps(cr: &mut Collector, typed: &ast::Typed) -> Vec<(Name, TypeComplete,
         };
         r.push((name.clone(), cc, typed.loc.clone()));
     }
+    if let ast::Type::Slice(mem) = &typed.t {
+        r.extend(type_deps(cr, mem));
+    }
     for ptr in &typed.ptr {
         r.extend(tag_deps(cr, &ptr.tags));
     }
@@ -102,6 +117,7 @@ fn stm_deps(cr: &mut Collector, stm: &ast::Statement) -> Vec<(Name, TypeComplete
         ast::Statement::Mark { .. } | ast::Statement::Label { .. } => Vec::new(),
         ast::Statement::Block(b2) => block_deps(cr, b2),
         ast::Statement::Unsafe(b2) => block_deps(cr, b2),
+        ast::Statement::Defer { stm, .. } => stm_deps(cr, stm),
         ast::Statement::Switch {
             expr,
             cases,
@@ -299,6 +315,26 @@ fn expr_deps(
             v.extend(expr_deps(cr, rhs));
             v
         }
+        ast::Expression::Slice { lhs, from, to, .. } => {
+            let mut v = Vec::new();
+            v.extend(expr_deps(cr, lhs));
+            for expr in from.iter().chain(to.iter()) {
+                v.extend(expr_deps(cr, expr));
+            }
+            v
+        }
+        ast::Expression::Ternary {
+            cond,
+            then,
+            otherwise,
+            ..
+        } => {
+            let mut v = Vec::new();
+            v.extend(expr_deps(cr, cond));
+            v.extend(expr_deps(cr, then));
+            v.extend(expr_deps(cr, otherwise));
+            v
+        }
         ast::Expression::Cpp {expr, .. } => {
             expr_deps(cr, expr)
         },
@@ -327,9 +363,15 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
     let cr = &mut collector;
 
     for local in &md.locals {
+        if generic::is_template(local) {
+            continue;
+        }
         let mut ns = md.name.clone();
         ns.push(local.name.clone());
         debug!("  local from abs.md: {}", local.name);
+        if md.instantiated.contains(&local.name) {
+            flat.instances.insert(ns.clone());
+        }
         thisobject.insert(ns.clone(), TypeComplete::Complete);
         incomming.push((ns, local.loc.clone()));
     }
@@ -356,7 +398,7 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
                         &format!("type '{}' unavailable in this scope", name),
                     )],
                 );
-                std::process::exit(9);
+                super::exit(9);
             }
 
             let mut module_name = name.clone();
@@ -377,7 +419,7 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
                             &format!("type '{}' unavailable in this scope", name),
                         )],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             } else {
                 let module = loop {
@@ -406,7 +448,7 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
                                         &format!("type '{}' unavailable in this scope", name),
                                     )],
                                 );
-                                std::process::exit(9);
+                                super::exit(9);
                             }
                             Some(loader::Module::C(_)) => panic!("not implemented"),
                             Some(loader::Module::ZZ(ast)) => ast,
@@ -417,6 +459,9 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
 
                 // find the local we're looking for
                 for local2 in &module.locals {
+                    if generic::is_template(local2) {
+                        continue;
+                    }
                     if local2.name == local_name {
                         local = Some(local2.clone());
                     } else {
@@ -445,7 +490,7 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
                             &format!("type '{}' unavailable in this scope", name),
                         )],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             };
 
@@ -514,7 +559,7 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
                                     format!("self arg must be first"),
                                     &[(arg.loc.clone(), "self argument in wrong position")],
                                 );
-                                std::process::exit(9);
+                                super::exit(9);
                             }
                             if let ast::Type::Other(name) = &arg.typed.t {
                                 let mut ns = module_name.clone();
@@ -568,7 +613,39 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
                         decl_deps.extend(tag_deps(cr, &arg.tags));
                     }
                 }
-                ast::Def::Struct { fields, .. } => {
+                ast::Def::Struct {
+                    fields,
+                    variants,
+                    implements,
+                    ..
+                } => {
+                    // the implementation is checked against the interface wherever the struct is complete
+                    for typed in implements {
+                        impl_deps.extend(type_deps(cr, typed));
+                    }
+
+                    // tags of a tagged union are resolved as sub names of the struct
+                    if !variants.is_empty() {
+                        let mut ns = module_name.clone();
+                        ns.push(ast_name.clone());
+
+                        expecting_sub_type = false;
+                        for (subname, _) in variants {
+                            let mut name = ns.clone();
+                            name.push(subname.clone());
+                            collected.0.insert(
+                                name,
+                                Local {
+                                    impl_deps: Vec::new(),
+                                    decl_deps: vec![(ns.clone(), TypeComplete::Complete, loc.clone())],
+                                    use_deps: Vec::new(),
+                                    ast: None,
+                                    in_scope_here: loc.clone(),
+                                },
+                            );
+                        }
+                    }
+
                     for field in fields {
                         impl_deps.extend(type_deps(cr, &field.typed));
 
@@ -585,11 +662,42 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
                     forceinline.insert(name.clone());
                 }
                 ast::Def::Testcase { fields, .. } => {
-                    for (_, expr) in fields {
+                    for (_, _, expr) in fields {
                         decl_deps.extend(expr_deps(cr, expr));
                     }
                     forceinline.insert(name.clone());
                 }
+                ast::Def::StaticAssert { expr, .. } => {
+                    decl_deps.extend(expr_deps(cr, expr));
+                    forceinline.insert(name.clone());
+                }
+                ast::Def::Interface { methods } => {
+                    for method in methods {
+                        if let ast::Def::Function {
+                            ret,
+                            args,
+                            callassert,
+                            calleffect,
+                            ..
+                        } = &method.def
+                        {
+                            let mut deps = Vec::new();
+                            if let Some(ret) = ret {
+                                deps.extend(type_deps(cr, &ret.typed));
+                            }
+                            for arg in args {
+                                deps.extend(type_deps(cr, &arg.typed));
+                                deps.extend(tag_deps(cr, &arg.tags));
+                            }
+                            for expr in callassert.iter().chain(calleffect.iter()) {
+                                deps.extend(expr_deps(cr, expr));
+                            }
+                            // Self is whatever implements it
+                            decl_deps.extend(deps.into_iter().filter(|(n, _, _)| n.0[1] != generic::PARAMS));
+                        }
+                    }
+                    forceinline.insert(name.clone());
+                }
                 ast::Def::Include {
                     needs,
                     expr,
@@ -712,6 +820,10 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
                     } else {
                         for (local, import_as) in &import.local {
                             debug!("      < {}", local);
+                            // only instances of a generic are ever localized
+                            if ast.locals.iter().any(|l| &l.name == local && generic::is_template(l)) {
+                                continue;
+                            }
                             let mut nn = import.name.clone();
                             nn.push(local.clone());
                             incomming.push((nn.clone(), import.loc.clone()));
@@ -781,12 +893,14 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
         );
     }
 
-    // sort dependencies
+    // sort dependencies, starting in name order so the emitted c is the same on every run
 
     let mut sorted = Vec::new();
     let mut sorted_mark = HashMap::new();
     let mut more = HashSet::new();
-    for (name, complete) in &thisobject.0 {
+    let mut roots: Vec<(&Name, &TypeComplete)> = thisobject.0.iter().collect();
+    roots.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, complete) in roots {
         sort_visit(
             &mut sorted,
             &mut sorted_mark,
@@ -799,7 +913,11 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
             &mut more,
         );
     }
-    for name in std::mem::replace(&mut more, HashSet::new()) {
+    let mut discovered: Vec<Name> = std::mem::replace(&mut more, HashSet::new())
+        .into_iter()
+        .collect();
+    discovered.sort();
+    for name in discovered {
         sort_visit(
             &mut sorted,
             &mut sorted_mark,
@@ -843,6 +961,42 @@ pub fn flatten(md: &ast::Module, all_modules: &HashMap<Name, loader::Module>, ex
     }
 
     flat.typevariants = collector.typevariants;
+
+    // stable C names, including everything that is derived from them
+    for (ast, _) in &flat.d {
+        if let Some(export_name) = &ast.export_name {
+            let name = Name::from(&ast.name);
+            match &ast.def {
+                ast::Def::Enum { names } => {
+                    for (subname, _) in names {
+                        let mut nn = name.clone();
+                        nn.push(subname.clone());
+                        flat.export_names
+                            .insert(nn, format!("{}_{}", export_name, subname));
+                    }
+                }
+                ast::Def::Struct { variants, .. } => {
+                    for (subname, _) in variants {
+                        let mut nn = name.clone();
+                        nn.push(subname.clone());
+                        flat.export_names
+                            .insert(nn, format!("{}_{}", export_name, subname));
+                    }
+                    if let Some(vs) = flat.typevariants.get(&name) {
+                        for (v, _) in vs {
+                            flat.export_names.insert(
+                                Name::from(&format!("{}_{}", ast.name, v)),
+                                format!("{}_{}", export_name, v),
+                            );
+                        }
+                    }
+                }
+                _ => (),
+            }
+            flat.export_names.insert(name, export_name.clone());
+        }
+    }
+
     flat
 }
 
@@ -909,7 +1063,7 @@ fn sort_visit(
                 for (name, _) in unsorted {
                     debug!("  {}", name);
                 }
-                std::process::exit(10);
+                super::exit(10);
             }
         },
     };
@@ -0,0 +1,526 @@
+/// monomorphize generic fn and struct definitions.
+/// every use of a generic with concrete type arguments becomes a local of the module
+/// the generic is declared in, named after the generic and its arguments.
+use super::ast;
+use super::loader;
+use super::name::Name;
+use super::parser::emit_error;
+use std::collections::{HashMap, HashSet};
+
+/// type parameters resolve into this module during abs
+pub const PARAMS: &str = "<generic>";
+
+/// how many instances may be created from within each other. deeper nesting means a generic
+/// instantiates itself with an ever growing argument, which never ends
+const MAX_DEPTH: usize = 64;
+
+pub fn param(name: &str) -> Name {
+    Name(vec![String::new(), PARAMS.to_string(), name.to_string()])
+}
+
+pub fn generics(def: &ast::Def) -> &[String] {
+    match def {
+        ast::Def::Function { generics, .. } | ast::Def::Struct { generics, .. } => generics,
+        _ => &[],
+    }
+}
+
+/// a generic is never emitted itself, only its instances are
+pub fn is_template(local: &ast::Local) -> bool {
+    !generics(&local.def).is_empty()
+}
+
+/// the local name of an instance. stable across builds, so it can be linked against
+pub fn mangle(name: &str, args: &[ast::Typed]) -> String {
+    let mut r = name.to_string();
+    for arg in args {
+        r.push_str("__");
+        r.push_str(&mangle_arg(arg));
+    }
+    r
+}
+
+fn mangle_arg(t: &ast::Typed) -> String {
+    let mut r = match &t.t {
+        ast::Type::Other(name) if name.0.get(1).map(|s| s.as_str()) == Some("ext") => {
+            name.0.last().unwrap().clone()
+        }
+        ast::Type::Other(name) => name.0[1..].join("_"),
+        ast::Type::Slice(mem) => super::slice::name(mem),
+        _ => format!(
+            "{}",
+            ast::Typed {
+                ptr: Vec::new(),
+                tail: ast::Tail::None,
+                ..t.clone()
+            }
+        ),
+    };
+    for ptr in &t.ptr {
+        r.push('_');
+        if ptr.tags.contains("mut") {
+            r.push('m');
+        }
+        if ptr.tags.contains("nullable") {
+            r.push('n');
+        }
+        r.push('p');
+    }
+    r
+}
+
+struct Instance {
+    template: Name,
+    args: Vec<ast::Typed>,
+    export: bool,
+    /// the instances this one was created from, outermost first, ending with itself
+    chain: Vec<(ast::Location, String)>,
+}
+
+/// instantiate every generic used in the ready modules.
+/// returns the modules that own instances. what they contain depends on other modules.
+pub fn generic(
+    modules: &mut HashMap<Name, loader::Module>,
+    ready: &HashSet<Name>,
+) -> HashSet<Name> {
+    let mut templates = HashMap::new();
+    for (name, module) in modules.iter() {
+        if !ready.contains(name) {
+            continue;
+        }
+        if let loader::Module::ZZ(md) = module {
+            for local in &md.locals {
+                if is_template(local) {
+                    let mut ns = md.name.clone();
+                    ns.push(local.name.clone());
+                    templates.insert(ns, local.clone());
+                }
+            }
+        }
+    }
+
+    let mut queue = Vec::new();
+    for (name, module) in modules.iter_mut() {
+        if !ready.contains(name) {
+            continue;
+        }
+        if let loader::Module::ZZ(md) = module {
+            for local in &mut md.locals {
+                if !is_template(local) {
+                    walk_local(local, &mut |t| resolve(t, &templates, &mut queue, false, &[]));
+                }
+            }
+            for typed in &mut md.instances {
+                resolve(typed, &templates, &mut queue, true, &[]);
+            }
+        }
+    }
+
+    let mut owners = HashSet::new();
+    while let Some(instance) = queue.pop() {
+        let mut owner = instance.template.clone();
+        let name = owner.pop().unwrap();
+        let name = mangle(&name, &instance.args);
+
+        let md = match modules.get_mut(&owner) {
+            Some(loader::Module::ZZ(md)) => md,
+            _ => unreachable!("ice: generic {} without module", instance.template),
+        };
+        owners.insert(owner);
+
+        if let Some(existing) = md.locals.iter_mut().find(|l| l.name == name) {
+            if instance.export {
+                existing.vis = ast::Visibility::Export;
+            }
+            continue;
+        }
+
+        if instance.chain.len() > MAX_DEPTH {
+            emit_error(
+                format!(
+                    "generic instantiation of '{}' is nested more than {} deep",
+                    instance.template, MAX_DEPTH
+                ),
+                &instance.chain[..3]
+                    .iter()
+                    .chain(instance.chain[instance.chain.len() - 3..].iter())
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            );
+            super::exit(9);
+        }
+
+        let template = &templates[&instance.template];
+        let substitutions: HashMap<Name, ast::Typed> = generics(&template.def)
+            .iter()
+            .map(|p| param(p))
+            .zip(instance.args.into_iter())
+            .collect();
+
+        let mut local = template.clone();
+        local.name = name;
+        local.export_name = None;
+        local.vis = if instance.export {
+            ast::Visibility::Export
+        } else {
+            ast::Visibility::Shared
+        };
+        match &mut local.def {
+            ast::Def::Function { generics, .. } | ast::Def::Struct { generics, .. } => {
+                generics.clear();
+            }
+            _ => (),
+        }
+
+        walk_local(&mut local, &mut |t| substitute(t, &substitutions));
+        let chain = instance.chain;
+        walk_local(&mut local, &mut |t| resolve(t, &templates, &mut queue, false, &chain));
+
+        md.instantiated.insert(local.name.clone());
+        md.locals.push(local);
+    }
+
+    owners
+}
+
+pub(crate) fn substitute(t: &mut ast::Typed, substitutions: &HashMap<Name, ast::Typed>) {
+    if let ast::Tail::Generic(args) = &mut t.tail {
+        for arg in args {
+            substitute(arg, substitutions);
+        }
+    }
+    if let ast::Type::Slice(mem) = &mut t.t {
+        substitute(mem, substitutions);
+        return;
+    }
+    let arg = match &t.t {
+        ast::Type::Other(name) => match substitutions.get(name) {
+            Some(v) => v,
+            None => return,
+        },
+        _ => return,
+    };
+
+    let mut ptr = arg.ptr.clone();
+    ptr.extend(std::mem::replace(&mut t.ptr, Vec::new()));
+    t.t = arg.t.clone();
+    t.ptr = ptr;
+    if let ast::Tail::None = t.tail {
+        t.tail = arg.tail.clone();
+    }
+}
+
+fn resolve(
+    t: &mut ast::Typed,
+    templates: &HashMap<Name, ast::Local>,
+    queue: &mut Vec<Instance>,
+    export: bool,
+    chain: &[(ast::Location, String)],
+) {
+    if let ast::Tail::Generic(args) = &mut t.tail {
+        for arg in args {
+            resolve(arg, templates, queue, false, chain);
+        }
+    }
+    if let ast::Type::Slice(mem) = &mut t.t {
+        resolve(mem, templates, queue, false, chain);
+        return;
+    }
+
+    let template = match &t.t {
+        ast::Type::Other(name) => templates.get(name).map(|v| (name.clone(), v)),
+        _ => None,
+    };
+
+    let (name, template) = match (template, &t.tail) {
+        (None, ast::Tail::Generic(_)) => {
+            emit_error(
+                format!("'{}' is not generic", t),
+                &[(t.loc.clone(), "type arguments given here")],
+            );
+            super::exit(9);
+        }
+        (None, _) => return,
+        (Some((name, template)), ast::Tail::Generic(_)) => (name, template),
+        (Some((name, template)), _) => {
+            emit_error(
+                format!("generic '{}' requires type arguments", name),
+                &[
+                    (t.loc.clone(), "used without type arguments"),
+                    (declared(template), "declared here"),
+                ],
+            );
+            super::exit(9);
+        }
+    };
+
+    let args = match std::mem::replace(&mut t.tail, ast::Tail::None) {
+        ast::Tail::Generic(args) => args,
+        _ => unreachable!(),
+    };
+
+    let params = generics(&template.def);
+    if params.len() != args.len() {
+        emit_error(
+            format!(
+                "generic '{}' takes {} type arguments but {} were given",
+                name,
+                params.len(),
+                args.len()
+            ),
+            &[
+                (t.loc.clone(), "used here"),
+                (declared(template), "declared here"),
+            ],
+        );
+        super::exit(9);
+    }
+
+    let mut instance = name.clone();
+    let last = instance.pop().unwrap();
+    instance.push(mangle(&last, &args));
+
+    let mut chain = chain.to_vec();
+    chain.push((
+        t.loc.clone(),
+        format!("instantiates {}, {} deep", name, chain.len() + 1),
+    ));
+    t.t = ast::Type::Other(instance);
+
+    queue.push(Instance {
+        template: name,
+        args,
+        export,
+        chain,
+    });
+}
+
+fn declared(local: &ast::Local) -> ast::Location {
+    match &local.def {
+        ast::Def::Function { nameloc, .. } => nameloc.clone(),
+        _ => local.loc.clone(),
+    }
+}
+
+pub(crate) fn walk_local(local: &mut ast::Local, f: &mut dyn FnMut(&mut ast::Typed)) {
+    match &mut local.def {
+        ast::Def::Static { typed, expr, .. } | ast::Def::Const { typed, expr } => {
+            f(typed);
+            walk_expr(expr, f);
+        }
+        ast::Def::Function {
+            ret,
+            args,
+            body,
+            callassert,
+            calleffect,
+            callattests,
+            ..
+        } => {
+            if let Some(ret) = ret {
+                f(&mut ret.typed);
+            }
+            for arg in args {
+                f(&mut arg.typed);
+            }
+            for expr in callassert
+                .iter_mut()
+                .chain(calleffect.iter_mut())
+                .chain(callattests.iter_mut())
+            {
+                walk_expr(expr, f);
+            }
+            for (_, expr, block) in &mut body.branches {
+                if let Some(expr) = expr {
+                    walk_expr(expr, f);
+                }
+                walk_block(block, f);
+            }
+        }
+        ast::Def::Theory { ret, args, .. } | ast::Def::Closure { ret, args, .. } => {
+            if let Some(ret) = ret {
+                f(&mut ret.typed);
+            }
+            for arg in args {
+                f(&mut arg.typed);
+            }
+        }
+        ast::Def::Struct {
+            fields, implements, ..
+        } => {
+            for field in fields {
+                f(&mut field.typed);
+                if let ast::Array::Sized(expr) = &mut field.array {
+                    walk_expr(expr, f);
+                }
+            }
+            for typed in implements {
+                f(typed);
+            }
+        }
+        ast::Def::Macro { body, .. } => {
+            walk_block(body, f);
+        }
+        ast::Def::Testcase { fields } => {
+            for (_, _, expr) in fields {
+                walk_expr(expr, f);
+            }
+        }
+        ast::Def::StaticAssert { expr, .. } => walk_expr(expr, f),
+        ast::Def::Interface { methods } => {
+            for method in methods {
+                walk_local(method, f);
+            }
+        }
+        ast::Def::Include { needs, .. } => {
+            for (typed, _) in needs {
+                f(typed);
+            }
+        }
+        ast::Def::Symbol {} | ast::Def::Enum { .. } => (),
+    }
+}
+
+fn walk_block(block: &mut ast::Block, f: &mut dyn FnMut(&mut ast::Typed)) {
+    for stm in &mut block.statements {
+        walk_statement(stm, f);
+    }
+}
+
+fn walk_statement(stm: &mut ast::Statement, f: &mut dyn FnMut(&mut ast::Typed)) {
+    match stm {
+        ast::Statement::Mark { lhs, .. } => walk_expr(lhs, f),
+        ast::Statement::Assign { lhs, rhs, .. } => {
+            walk_expr(lhs, f);
+            walk_expr(rhs, f);
+        }
+        ast::Statement::Expr { expr, .. } => walk_expr(expr, f),
+        ast::Statement::Switch {
+            expr,
+            cases,
+            default,
+            ..
+        } => {
+            walk_expr(expr, f);
+            for (conds, block) in cases {
+                for cond in conds {
+                    walk_expr(cond, f);
+                }
+                walk_block(block, f);
+            }
+            if let Some(default) = default {
+                walk_block(default, f);
+            }
+        }
+        ast::Statement::Return { expr, .. } => {
+            if let Some(expr) = expr {
+                walk_expr(expr, f);
+            }
+        }
+        ast::Statement::Var {
+            typed,
+            array,
+            assign,
+            ..
+        } => {
+            f(typed);
+            if let Some(Some(expr)) = array {
+                walk_expr(expr, f);
+            }
+            if let Some(expr) = assign {
+                walk_expr(expr, f);
+            }
+        }
+        ast::Statement::While { expr, body } => {
+            walk_expr(expr, f);
+            walk_block(body, f);
+        }
+        ast::Statement::For { e1, e2, e3, body } => {
+            for stm in e1.iter_mut().chain(e3.iter_mut()) {
+                walk_statement(stm, f);
+            }
+            if let Some(expr) = e2 {
+                walk_expr(expr, f);
+            }
+            walk_block(body, f);
+        }
+        ast::Statement::If { branches } => {
+            for (_, expr, block) in branches {
+                if let Some(expr) = expr {
+                    walk_expr(expr, f);
+                }
+                walk_block(block, f);
+            }
+        }
+        ast::Statement::Block(block) | ast::Statement::Unsafe(block) => walk_block(block, f),
+        ast::Statement::Defer { stm, .. } => walk_statement(stm, f),
+        ast::Statement::MacroCall { args, .. } => {
+            for arg in args {
+                walk_expr(arg, f);
+            }
+        }
+        ast::Statement::Label { .. }
+        | ast::Statement::Continue { .. }
+        | ast::Statement::Break { .. }
+        | ast::Statement::CBlock { .. } => (),
+    }
+}
+
+fn walk_expr(expr: &mut ast::Expression, f: &mut dyn FnMut(&mut ast::Typed)) {
+    match expr {
+        ast::Expression::Name(typed) => f(typed),
+        ast::Expression::MemberAccess { lhs, .. } => walk_expr(lhs, f),
+        ast::Expression::ArrayAccess { lhs, rhs, .. } | ast::Expression::Infix { lhs, rhs, .. } => {
+            walk_expr(lhs, f);
+            walk_expr(rhs, f);
+        }
+        ast::Expression::Slice { lhs, from, to, .. } => {
+            walk_expr(lhs, f);
+            for expr in from.iter_mut().chain(to.iter_mut()) {
+                walk_expr(expr, f);
+            }
+        }
+        ast::Expression::Ternary {
+            cond,
+            then,
+            otherwise,
+            ..
+        } => {
+            walk_expr(cond, f);
+            walk_expr(then, f);
+            walk_expr(otherwise, f);
+        }
+        ast::Expression::Call { name, args, .. } => {
+            walk_expr(name, f);
+            for arg in args {
+                walk_expr(arg, f);
+            }
+        }
+        ast::Expression::Cast { into, expr, .. } | ast::Expression::Unsafe { into, expr, .. } => {
+            f(into);
+            walk_expr(expr, f);
+        }
+        ast::Expression::UnaryPost { expr, .. }
+        | ast::Expression::UnaryPre { expr, .. }
+        | ast::Expression::Cpp { expr, .. } => walk_expr(expr, f),
+        ast::Expression::StructInit { typed, fields, .. } => {
+            f(typed);
+            for (_, expr) in fields {
+                walk_expr(expr, f);
+            }
+        }
+        ast::Expression::ArrayInit { fields, .. } => {
+            for expr in fields {
+                walk_expr(expr, f);
+            }
+        }
+        ast::Expression::MacroCall { args, .. } => {
+            for arg in args {
+                walk_expr(arg, f);
+            }
+        }
+        ast::Expression::LiteralString { .. }
+        | ast::Expression::LiteralChar { .. }
+        | ast::Expression::Literal { .. } => (),
+    }
+}
@@ -0,0 +1,80 @@
+use super::make::Make;
+use super::project::ArtifactType;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+pub fn install(make: &Make, prefix: &Path) {
+    let an = &make.artifact.name;
+
+    let libdir = prefix.join("lib");
+    let incdir = prefix.join("include").join(an).join("zz");
+    let pcdir = libdir.join("pkgconfig");
+    fs::create_dir_all(&libdir).expect(&format!("cannot create {:?}", libdir));
+    fs::create_dir_all(&incdir).expect(&format!("cannot create {:?}", incdir));
+    fs::create_dir_all(&pcdir).expect(&format!("cannot create {:?}", pcdir));
+
+    let libname = match make.artifact.typ {
+        ArtifactType::Staticlib => format!("lib{}.a", an),
+        _ => format!("lib{}.so", an),
+    };
+    let from = format!("./target/{}/lib/{}", make.stage, libname);
+    fs::copy(&from, libdir.join(&libname))
+        .expect(&format!("cannot install {} into {:?}", from, libdir));
+
+    for header in &make.headers {
+        let header = Path::new(header);
+        fs::copy(&header, incdir.join(header.file_name().unwrap()))
+            .expect(&format!("cannot install {:?} into {:?}", header, incdir));
+        let cxxheader = header.with_extension("hpp");
+        if cxxheader.exists() {
+            fs::copy(&cxxheader, incdir.join(cxxheader.file_name().unwrap()))
+                .expect(&format!("cannot install {:?} into {:?}", cxxheader, incdir));
+        }
+    }
+
+    let p = pcdir.join(format!("{}.pc", an));
+    let mut f = fs::File::create(&p).expect(&format!("cannot create {:?}", p));
+
+    write!(
+        f,
+        r#"prefix={prefix}
+libdir=${{prefix}}/lib
+includedir=${{prefix}}/include
+
+Name: {an}
+Description: {an}
+Version: {version}
+"#,
+        prefix = prefix.to_string_lossy(),
+        an = an,
+        version = make.version,
+    )
+    .unwrap();
+
+    // a static library always needs its dependencies, a shared one only when the consumer links with --static
+    if make.pkgconfig.len() > 0 {
+        match make.artifact.typ {
+            ArtifactType::Staticlib => write!(f, "Requires:").unwrap(),
+            _ => write!(f, "Requires.private:").unwrap(),
+        }
+        for pkg in &make.pkgconfig {
+            write!(f, " {}", pkg).unwrap();
+        }
+        write!(f, "\n").unwrap();
+    }
+
+    write!(f, "Cflags: -I${{includedir}}/{}", an).unwrap();
+    for cflag in &make.user_cflags {
+        write!(f, " {}", cflag).unwrap();
+    }
+    write!(f, "\n").unwrap();
+
+    write!(f, "Libs: -L${{libdir}} -l{}", an).unwrap();
+    for lflag in &make.user_lflags {
+        write!(f, " {}", lflag).unwrap();
+    }
+    write!(f, "\n").unwrap();
+
+    println!("installed {} into {}", an, prefix.to_string_lossy());
+}
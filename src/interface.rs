@@ -0,0 +1,259 @@
+/// interfaces: method signatures with contracts, implemented by fns taking a struct as self.
+/// each method of each implementation is checked by proving a fn that has the contract of the interface
+/// and calls the implementation. so the implementation cannot need more than the interface guarantees,
+/// and must promise at least what the interface promises. these checks are never emitted.
+use super::ast;
+use super::flatten;
+use super::generic;
+use super::name::Name;
+use super::Error;
+use std::collections::HashMap;
+
+/// attr marking a check, so it can be removed before emitting
+const CHECK: &str = "interface";
+
+/// add a check for every method of every interface implemented by a struct declared in this module
+pub fn implement(module: &mut flatten::Module) -> Result<(), Error> {
+    let mut checks = Vec::new();
+    for (local, complete) in &module.d {
+        let (implements, impls) = match &local.def {
+            ast::Def::Struct {
+                implements, impls, ..
+            } => (implements, impls),
+            _ => continue,
+        };
+
+        // checked once, in the module declaring the struct
+        let mut owner = Name::from(&local.name);
+        owner.pop();
+        if owner != module.name || complete != &flatten::TypeComplete::Complete {
+            continue;
+        }
+
+        for iface in implements {
+            let methods = match module.d.iter().find(|(l, _)| match &iface.t {
+                ast::Type::Other(name) => &Name::from(&l.name) == name,
+                _ => false,
+            }) {
+                Some((
+                    ast::Local {
+                        def: ast::Def::Interface { methods },
+                        ..
+                    },
+                    _,
+                )) => methods,
+                _ => {
+                    return Err(Error::new(
+                        format!("{} is not an interface", iface),
+                        vec![(iface.loc.clone(), format!("cannot implement {}", iface))],
+                    ));
+                }
+            };
+            for method in methods {
+                checks.push(check(local, impls, iface, method, &module.d)?);
+            }
+        }
+    }
+
+    module
+        .d
+        .extend(checks.into_iter().map(|l| (l, flatten::TypeComplete::Complete)));
+    Ok(())
+}
+
+/// remove the checks, once they are proven
+pub fn strip(module: &mut flatten::Module) {
+    module.d.retain(|(local, _)| match &local.def {
+        ast::Def::Function { attr, .. } => !attr.contains_key(CHECK),
+        _ => true,
+    });
+}
+
+fn check(
+    strct: &ast::Local,
+    impls: &HashMap<String, (Name, ast::Location)>,
+    iface: &ast::Typed,
+    method: &ast::Local,
+    d: &Vec<(ast::Local, flatten::TypeComplete)>,
+) -> Result<ast::Local, Error> {
+    let selftype = ast::Typed {
+        t: ast::Type::Other(Name::from(&strct.name)),
+        loc: strct.loc.clone(),
+        ptr: Vec::new(),
+        tail: ast::Tail::None,
+    };
+    let mut substitutions = HashMap::new();
+    substitutions.insert(generic::param("Self"), selftype.clone());
+
+    let mut method = method.clone();
+    generic::walk_local(&mut method, &mut |t| generic::substitute(t, &substitutions));
+
+    // errors use the names as written
+    let strctname = Name::from(&strct.name).0.last().unwrap().clone();
+    let ifacename = match &iface.t {
+        ast::Type::Other(name) => name.0.last().unwrap().clone(),
+        _ => unreachable!(),
+    };
+
+    let fnname = match impls.get(&method.name) {
+        Some((fnname, _)) => fnname,
+        None => {
+            return Err(Error::new(
+                format!("{} does not implement {}::{}", strctname, ifacename, method.name),
+                vec![
+                    (iface.loc.clone(), format!("missing fn {}", method.name)),
+                    (method.loc.clone(), format!("declared here")),
+                ],
+            ));
+        }
+    };
+    let (nameloc, args, ret) = match d.iter().find(|(l, _)| &Name::from(&l.name) == fnname) {
+        Some((
+            ast::Local {
+                def: ast::Def::Function {
+                    nameloc, args, ret, ..
+                },
+                ..
+            },
+            _,
+        )) => (nameloc, args, ret),
+        _ => panic!("ice: impl {} of {} is not a fn in scope", fnname, selftype),
+    };
+
+    let (margs, mret) = match &mut method.def {
+        ast::Def::Function {
+            args,
+            ret,
+            attr,
+            generics,
+            ..
+        } => {
+            generics.clear();
+            attr.insert(CHECK.to_string(), iface.loc.clone());
+            (args.clone(), ret.clone())
+        }
+        _ => unreachable!(),
+    };
+
+    // the same signature, except for Self
+    let mismatch = |detail: String| {
+        Error::new(
+            format!("{} does not match {}::{}", method.name, ifacename, method.name),
+            vec![
+                (nameloc.clone(), detail),
+                (method.loc.clone(), format!("declared here")),
+            ],
+        )
+    };
+    if args.len() != margs.len() {
+        return Err(mismatch(format!(
+            "takes {} arguments, but the interface has {}",
+            args.len(),
+            margs.len()
+        )));
+    }
+    for (arg, marg) in args.iter().zip(margs.iter()) {
+        if !same_type(&arg.typed, &marg.typed) {
+            return Err(mismatch(format!(
+                "{} is {}, but the interface has {}",
+                arg.name,
+                signature(&arg.typed),
+                signature(&marg.typed)
+            )));
+        }
+    }
+    match (ret, &mret) {
+        (None, None) => (),
+        (Some(ret), Some(mret)) if same_type(&ret.typed, &mret.typed) => (),
+        _ => {
+            return Err(mismatch(format!(
+                "returns {}, but the interface returns {}",
+                ret.as_ref().map(|r| signature(&r.typed)).unwrap_or("nothing".to_string()),
+                mret.as_ref().map(|r| signature(&r.typed)).unwrap_or("nothing".to_string()),
+            )));
+        }
+    }
+
+    let loc = iface.loc.clone();
+    let call = ast::Expression::Call {
+        loc: loc.clone(),
+        name: Box::new(ast::Expression::Name(ast::Typed {
+            t: ast::Type::Other(fnname.clone()),
+            loc: loc.clone(),
+            ptr: Vec::new(),
+            tail: ast::Tail::None,
+        })),
+        args: margs
+            .iter()
+            .map(|arg| {
+                Box::new(ast::Expression::Name(ast::Typed {
+                    t: ast::Type::Other(Name::from(&arg.name)),
+                    loc: loc.clone(),
+                    ptr: Vec::new(),
+                    tail: ast::Tail::None,
+                }))
+            })
+            .collect(),
+        expanded: false,
+        emit: ast::EmitBehaviour::Default,
+    };
+    let stm = match mret {
+        Some(_) => ast::Statement::Return {
+            loc: method.loc.clone(),
+            expr: Some(call),
+        },
+        None => ast::Statement::Expr {
+            loc: loc.clone(),
+            expr: call,
+        },
+    };
+    if let ast::Def::Function { body, .. } = &mut method.def {
+        body.branches = vec![(
+            loc.clone(),
+            None,
+            ast::Block {
+                end: method.loc.clone(),
+                statements: vec![Box::new(stm)],
+                expanded: false,
+            },
+        )];
+    }
+
+    let mut name = Name::from(&strct.name);
+    name.pop();
+    name.push(format!("{}_{}_{}", strctname, ifacename, method.name));
+    method.name = name.to_string();
+    method.vis = ast::Visibility::Object;
+    Ok(method)
+}
+
+/// Typed equality ignores pointer tags, but an implementation must agree on which pointers are mut or nullable
+fn same_type(a: &ast::Typed, b: &ast::Typed) -> bool {
+    a == b
+        && a.ptr.iter().zip(b.ptr.iter()).all(|(a, b)| {
+            ["mut", "nullable"]
+                .iter()
+                .all(|tag| a.tags.contains(tag) == b.tags.contains(tag))
+        })
+}
+
+/// a type as it is written in a signature, with its pointer tags
+fn signature(t: &ast::Typed) -> String {
+    let mut s = format!(
+        "{}",
+        ast::Typed {
+            ptr: Vec::new(),
+            ..t.clone()
+        }
+    );
+    for ptr in &t.ptr {
+        if ptr.tags.contains("mut") {
+            s.push_str(" mut");
+        }
+        if ptr.tags.contains("nullable") {
+            s.push('?');
+        }
+        s.push('*');
+    }
+    s
+}
@@ -7,19 +7,32 @@ extern crate askama;
 extern crate pbr;
 extern crate rayon;
 
+pub mod abi;
 pub mod abs;
 pub mod ast;
+pub mod bench;
+pub mod cdiag;
+pub mod constant;
+pub mod coverage;
 pub mod emitter;
+pub mod emitter_cpp;
 pub mod emitter_docs;
 pub mod emitter_js;
 pub mod emitter_py;
 pub mod emitter_rs;
 pub mod emitter_go;
+pub mod emitter_wasm;
 pub mod expand;
 pub mod export_make;
 pub mod export_cmake;
 pub mod export_esp;
+pub mod export_meson;
+pub mod export_bazel;
 pub mod flatten;
+pub mod generic;
+pub mod install;
+pub mod interface;
+pub mod libfuzzer;
 pub mod loader;
 pub mod make;
 pub mod makro;
@@ -28,8 +41,11 @@ pub mod parser;
 pub mod pipeline;
 pub mod project;
 pub mod repos;
+pub mod slice;
 pub mod smt;
 pub mod symbolic;
+pub mod testcase;
+pub mod testrunner;
 
 use name::Name;
 use std::collections::HashMap;
@@ -46,11 +62,27 @@ impl Error {
     }
 }
 
+/// the code a build exited with while diagnostics were captured. see parser::capture
+pub struct Exit(pub i32);
+
+/// end the process, or unwind back to the caller if the compiler runs in-process
+pub fn exit(code: i32) -> ! {
+    if parser::capturing() {
+        std::panic::panic_any(Exit(code));
+    }
+    std::process::exit(code);
+}
+
 #[derive(PartialEq)]
 pub enum BuildSet {
     Tests,
+    Benches,
+    /// the harnesses of the fn under property test
+    Properties,
     Run,
     Check,
+    /// like Check, but stop at the emitted c without running the c compiler
+    Emit,
     All,
     Export,
     Named(String),
@@ -187,7 +219,7 @@ fn getdep(
                 "dependency \"{}\" not found in any of {:#?}",
                 name, searchpaths
             );
-            std::process::exit(9);
+            exit(9);
         }
     };
 
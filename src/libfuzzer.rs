@@ -0,0 +1,171 @@
+use super::make::Stage;
+use super::project::Artifact;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// fuzzing state of a test, kept across runs in target/fuzz/<test>/
+pub struct Dirs {
+    pub corpus: PathBuf,
+    pub crashes: PathBuf,
+}
+
+impl Dirs {
+    pub fn new(root: &Path, artifact: &Artifact) -> Self {
+        let base = root.join("target").join("fuzz").join(&artifact.name);
+        let dirs = Dirs {
+            corpus: base.join("corpus"),
+            crashes: base.join("crashes"),
+        };
+        fs::create_dir_all(&dirs.corpus).expect(&format!("cannot create {:?}", dirs.corpus));
+        fs::create_dir_all(&dirs.crashes).expect(&format!("cannot create {:?}", dirs.crashes));
+        dirs
+    }
+}
+
+fn exe(root: &Path, stage: &Stage, artifact: &Artifact) -> PathBuf {
+    root.join("target")
+        .join(stage.to_string())
+        .join("bin")
+        .join(&artifact.name)
+}
+
+fn files(dir: &Path) -> Vec<PathBuf> {
+    let mut r = Vec::new();
+    if let Ok(dir) = fs::read_dir(dir) {
+        for entry in dir {
+            if let Ok(entry) = entry {
+                if entry.path().is_file() {
+                    r.push(entry.path());
+                }
+            }
+        }
+    }
+    r.sort();
+    r
+}
+
+fn status(cmd: &mut Command) -> i32 {
+    debug!("{:?}", cmd);
+    let status = cmd.status().expect("failed to execute fuzzer");
+    status.code().unwrap_or(1)
+}
+
+/// an empty corpus is seeded with the stdin of the test's testcases
+fn seed(root: &Path, stage: &Stage, artifact: &Artifact, dirs: &Dirs) {
+    if !files(&dirs.corpus).is_empty() {
+        return;
+    }
+    let casedir = root
+        .join("target")
+        .join(stage.to_string())
+        .join("testcases")
+        .join(artifact.main.replace("::", "_"));
+    if let Ok(dir) = fs::read_dir(casedir) {
+        for entry in dir {
+            let path = match entry {
+                Ok(v) => v.path(),
+                Err(_) => continue,
+            };
+            let stdin = path.join("stdin");
+            if stdin.exists() {
+                fs::copy(&stdin, dirs.corpus.join(path.file_name().unwrap())).unwrap();
+            }
+        }
+    }
+}
+
+/// fuzz until a crash is found, or for max_time seconds
+pub fn run(root: &Path, stage: &Stage, artifact: &Artifact, max_time: Option<u64>) -> i32 {
+    let dirs = Dirs::new(root, artifact);
+    seed(root, stage, artifact, &dirs);
+
+    println!("corpus in {}", dirs.corpus.to_string_lossy());
+    let mut cmd = Command::new(exe(root, stage, artifact));
+    cmd.arg(&dirs.corpus)
+        .arg(format!("-artifact_prefix={}/", dirs.crashes.to_string_lossy()));
+    if let Some(t) = max_time {
+        cmd.arg(format!("-max_total_time={}", t));
+    }
+    let r = status(&mut cmd);
+    println!("\n\ncrashes in {}", dirs.crashes.to_string_lossy());
+    r
+}
+
+/// shrink the corpus to the inputs that still add coverage
+pub fn minimize_corpus(root: &Path, stage: &Stage, artifact: &Artifact) -> i32 {
+    let dirs = Dirs::new(root, artifact);
+    let min = dirs.corpus.with_extension("min");
+    if min.exists() {
+        fs::remove_dir_all(&min).expect(&format!("cannot remove {:?}", min));
+    }
+    fs::create_dir_all(&min).expect(&format!("cannot create {:?}", min));
+
+    let before = files(&dirs.corpus).len();
+    let r = status(
+        Command::new(exe(root, stage, artifact))
+            .arg("-merge=1")
+            .arg(&min)
+            .arg(&dirs.corpus),
+    );
+    if r != 0 {
+        return r;
+    }
+
+    fs::remove_dir_all(&dirs.corpus).expect(&format!("cannot remove {:?}", dirs.corpus));
+    fs::rename(&min, &dirs.corpus).expect(&format!("cannot move {:?}", min));
+    println!(
+        "corpus minimized from {} to {} inputs",
+        before,
+        files(&dirs.corpus).len()
+    );
+    0
+}
+
+/// find a smaller input that still crashes the same way. it is written next to the crash as <crash>.min
+pub fn minimize_crash(root: &Path, stage: &Stage, artifact: &Artifact, crash: &Path) -> i32 {
+    let min = crash.with_extension("min");
+    let r = status(
+        Command::new(exe(root, stage, artifact))
+            .arg("-minimize_crash=1")
+            .arg("-runs=100000")
+            .arg(format!("-exact_artifact_path={}", min.to_string_lossy()))
+            .arg(crash),
+    );
+    if min.exists() {
+        println!("minimized crash in {}", min.to_string_lossy());
+    }
+    r
+}
+
+/// run the given inputs once each, or all recorded crashes if none are given.
+/// returns how many of them still crash
+pub fn reproduce(root: &Path, stage: &Stage, artifact: &Artifact, inputs: Vec<PathBuf>) -> usize {
+    let dirs = Dirs::new(root, artifact);
+    let inputs = if inputs.is_empty() {
+        files(&dirs.crashes)
+    } else {
+        inputs
+    };
+    if inputs.is_empty() {
+        println!("no crashes recorded in {}", dirs.crashes.to_string_lossy());
+        return 0;
+    }
+
+    let mut crashed = 0;
+    for input in &inputs {
+        let r = status(Command::new(exe(root, stage, artifact)).arg(input));
+        if r == 0 {
+            info!("FIXED {}", input.to_string_lossy());
+        } else {
+            error!("CRASH {}", input.to_string_lossy());
+            crashed += 1;
+        }
+    }
+    println!(
+        "\n{} of {} inputs still crash",
+        crashed,
+        inputs.len()
+    );
+    crashed
+}
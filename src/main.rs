@@ -7,10 +7,9 @@ extern crate rayon;
 extern crate tempdir;
 
 use clap::{App, Arg, SubCommand};
-use std::io::{Read, Write};
 use std::process::Command;
 use std::sync::atomic::Ordering;
-use std::time::{Instant};
+use std::time::Duration;
 use zz;
 
 fn main() {
@@ -91,6 +90,14 @@ fn main() {
                         .required(false)
                         .long("debug"),
                 )
+                .arg(
+                    Arg::with_name("stage")
+                        .help("a builtin stage, or one from the [stages] section of zz.toml")
+                        .takes_value(true)
+                        .required(false)
+                        .conflicts_with_all(&["release", "debug"])
+                        .long("stage"),
+                )
                 .arg(
                     Arg::with_name("artifact")
                         .takes_value(true)
@@ -104,12 +111,100 @@ fn main() {
                         .long("export"),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("install")
+                .about("build in release mode and install libraries, headers and pkg-config files")
+                .arg(
+                    Arg::with_name("prefix")
+                        .takes_value(true)
+                        .required(true)
+                        .long("prefix"),
+                )
+                .arg(
+                    Arg::with_name("variant")
+                        .takes_value(true)
+                        .required(false)
+                        .long("variant")
+                        .short("s"),
+                )
+                .arg(
+                    Arg::with_name("artifact")
+                        .takes_value(true)
+                        .required(false)
+                        .index(1),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("abi-diff")
+                .about("compare exported symbols and struct layouts against a saved baseline")
+                .arg(
+                    Arg::with_name("save")
+                        .takes_value(false)
+                        .required(false)
+                        .long("save"),
+                )
+                .arg(
+                    Arg::with_name("stage")
+                        .help("a builtin stage, or one from the [stages] section of zz.toml")
+                        .takes_value(true)
+                        .required(false)
+                        .long("stage"),
+                )
+                .arg(
+                    Arg::with_name("variant")
+                        .takes_value(true)
+                        .required(false)
+                        .long("variant")
+                        .short("s"),
+                )
+                .arg(
+                    Arg::with_name("baseline")
+                        .takes_value(true)
+                        .required(false)
+                        .index(1),
+                ),
+        )
         .subcommand(SubCommand::with_name("clean").about("remove the target directory"))
         .subcommand(
             SubCommand::with_name("bench")
-                .about("benchmark tests/*.zz")
+                .about("run bench fns")
+                .arg(
+                    Arg::with_name("warmup")
+                        .help("untimed runs of each bench before measuring")
+                        .takes_value(true)
+                        .default_value("10")
+                        .long("warmup"),
+                )
+                .arg(
+                    Arg::with_name("iterations")
+                        .help("timed runs of each bench")
+                        .takes_value(true)
+                        .default_value("100")
+                        .long("iterations")
+                        .short("n"),
+                )
+                .arg(
+                    Arg::with_name("save-baseline")
+                        .help("also save the results in target/bench/ under this name")
+                        .takes_value(true)
+                        .long("save-baseline"),
+                )
+                .arg(
+                    Arg::with_name("baseline")
+                        .help("compare against results saved with --save-baseline")
+                        .takes_value(true)
+                        .long("baseline"),
+                )
+                .arg(
+                    Arg::with_name("threshold")
+                        .help("percent a mean may grow over the baseline before it counts as a regression")
+                        .takes_value(true)
+                        .default_value("5")
+                        .long("threshold"),
+                )
                 .arg(
                     Arg::with_name("testname")
+                        .help("part of a bench fn path")
                         .takes_value(true)
                         .required(false)
                         .index(1),
@@ -117,9 +212,62 @@ fn main() {
         )
         .subcommand(
             SubCommand::with_name("test")
-                .about("execute tests/*.zz")
+                .about("execute tests/*.zz and test fns")
+                .arg(
+                    Arg::with_name("jobs")
+                        .help("how many cases to run at once, defaults to one per cpu")
+                        .takes_value(true)
+                        .required(false)
+                        .long("jobs")
+                        .short("j"),
+                )
+                .arg(
+                    Arg::with_name("timeout")
+                        .help("seconds before a case is killed, unless the case sets its own timeout. 0 disables it")
+                        .takes_value(true)
+                        .required(false)
+                        .default_value("60")
+                        .long("timeout"),
+                )
+                .arg(
+                    Arg::with_name("coverage")
+                        .help("build with source based coverage and write an lcov and html report per module")
+                        .long("coverage"),
+                )
+                .arg(
+                    Arg::with_name("stage")
+                        .help("a builtin stage, or one from the [stages] section of zz.toml")
+                        .takes_value(true)
+                        .required(false)
+                        .conflicts_with_all(&["coverage"])
+                        .long("stage"),
+                )
+                .arg(
+                    Arg::with_name("properties")
+                        .help("call this fn with inputs generated from its where clauses and check its model at runtime")
+                        .takes_value(true)
+                        .required(false)
+                        .conflicts_with_all(&["coverage", "stage"])
+                        .long("properties"),
+                )
+                .arg(
+                    Arg::with_name("count")
+                        .help("how many inputs to generate for --properties")
+                        .takes_value(true)
+                        .required(false)
+                        .default_value("100")
+                        .long("count"),
+                )
+                .arg(
+                    Arg::with_name("seed")
+                        .help("seed for --properties, to reproduce a previous run")
+                        .takes_value(true)
+                        .required(false)
+                        .long("seed"),
+                )
                 .arg(
                     Arg::with_name("testname")
+                        .help("a tests/*.zz name, or part of a test fn path")
                         .takes_value(true)
                         .required(false)
                         .index(1),
@@ -141,6 +289,14 @@ fn main() {
                         .required(false)
                         .long("debug"),
                 )
+                .arg(
+                    Arg::with_name("stage")
+                        .help("a builtin stage, or one from the [stages] section of zz.toml")
+                        .takes_value(true)
+                        .required(false)
+                        .conflicts_with_all(&["release", "debug"])
+                        .long("stage"),
+                )
                 .arg(
                     Arg::with_name("variant")
                         .takes_value(true)
@@ -158,12 +314,57 @@ fn main() {
         )
         .subcommand(
             SubCommand::with_name("fuzz")
-                .about("execute tests/*.zz with afl fuzzer")
+                .about("execute tests/*.zz with afl fuzzer, or libFuzzer")
+                .arg(
+                    Arg::with_name("libfuzzer")
+                        .help("link against libFuzzer and call the test's `export fn fuzz(u8* data, usize len)`")
+                        .long("libfuzzer"),
+                )
+                .arg(
+                    Arg::with_name("max-time")
+                        .help("stop libFuzzer after this many seconds")
+                        .takes_value(true)
+                        .required(false)
+                        .long("max-time"),
+                )
                 .arg(
                     Arg::with_name("testname")
                         .takes_value(true)
                         .required(false)
                         .index(1),
+                )
+                .subcommand(
+                    SubCommand::with_name("minimize")
+                        .about("shrink the libFuzzer corpus, or a single crash")
+                        .arg(
+                            Arg::with_name("testname")
+                                .takes_value(true)
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("crash")
+                                .takes_value(true)
+                                .required(false)
+                                .index(2),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("reproduce")
+                        .about("run crashing inputs against the libFuzzer build. defaults to all recorded crashes")
+                        .arg(
+                            Arg::with_name("testname")
+                                .takes_value(true)
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("crash")
+                                .takes_value(true)
+                                .required(false)
+                                .multiple(true)
+                                .index(2),
+                        ),
                 ),
         )
         .get_matches();
@@ -182,14 +383,33 @@ fn main() {
                 std::fs::remove_dir_all(root.join("target")).unwrap();
             }
         }
-        ("test", Some(submatches)) | ("bench", Some(submatches)) => {
-            let bench = matches.subcommand().0 == "bench";
-
+        ("test", Some(submatches)) => {
             let variant = submatches.value_of("variant").unwrap_or("default");
-            let stage = zz::make::Stage::test();
+            if let Some(function) = submatches.value_of("properties") {
+                let count = submatches
+                    .value_of("count")
+                    .unwrap()
+                    .parse()
+                    .expect("--count must be a number");
+                let seed = match submatches.value_of("seed") {
+                    Some(v) => v.parse().expect("--seed must be a number"),
+                    None => std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap()
+                        .as_nanos() as u64,
+                };
+                std::process::exit(run_properties(variant, function, count, seed));
+            }
+            let coverage = submatches.is_present("coverage");
+            let stage = if coverage {
+                zz::make::Stage::coverage()
+            } else {
+                select_stage(submatches, zz::make::Stage::test())
+            };
             zz::build(zz::BuildSet::Tests, variant, stage.clone(), false);
             let (root, mut project) = zz::project::load_cwd();
 
+            let mut jobs = Vec::new();
             for artifact in std::mem::replace(&mut project.artifacts, None).expect("no artifacts") {
                 if let zz::project::ArtifactType::Test = artifact.typ {
                     if let Some(testname) = submatches.value_of("testname") {
@@ -205,150 +425,154 @@ fn main() {
                         .join(stage.to_string())
                         .join("testcases")
                         .join(format!("{}", artifact.main.replace("::", "_")));
-                    let mut cases = Vec::new();
-                    match std::fs::read_dir(casedir) {
-                        Err(_) => (),
-                        Ok(dir) => {
-                            for entry in dir {
-                                let entry = match entry {
-                                    Ok(v) => v,
-                                    Err(_) => continue,
-                                };
-                                let path = entry.path();
-                                let mut stdin = None;
-                                let mut stdout = None;
-                                let mut exit = 0;
-                                match std::fs::File::open(path.join("stdin")) {
-                                    Ok(mut f) => {
-                                        let mut v = Vec::new();
-                                        f.read_to_end(&mut v).unwrap();
-                                        stdin = Some(v);
-                                    }
-                                    Err(_) => {
-                                        eprintln!(
-                                            "stdin testfile not found {}",
-                                            path.to_string_lossy()
-                                        );
-                                    }
-                                }
-                                match std::fs::File::open(path.join("stdout")) {
-                                    Ok(mut f) => {
-                                        let mut v = Vec::new();
-                                        f.read_to_end(&mut v).unwrap();
-                                        stdout = Some(v);
-                                    }
-                                    Err(_) => {
-                                        eprintln!(
-                                            "stdout testfile not found {}",
-                                            path.to_string_lossy()
-                                        );
-                                    }
-                                }
+                    let exe = root
+                        .join("target")
+                        .join(stage.to_string())
+                        .join("bin")
+                        .join(&artifact.name);
+                    for case in zz::testcase::load_dir(&casedir) {
+                        jobs.push(zz::testrunner::Job {
+                            artifact: artifact.name.clone(),
+                            exe: exe.clone(),
+                            case,
+                        });
+                    }
+                }
+                if let zz::project::ArtifactType::UnitTest = artifact.typ {
+                    let exe = root
+                        .join("target")
+                        .join(stage.to_string())
+                        .join("bin")
+                        .join(&artifact.name);
+                    let list = std::process::Command::new(&exe)
+                        .arg("--list")
+                        .output()
+                        .expect(&format!("failed to execute {:?}", exe));
 
-                                match std::fs::File::open(path.join("exit")) {
-                                    Ok(mut f) => {
-                                        let mut v = String::new();
-                                        f.read_to_string(&mut v).unwrap();
-                                        exit = v.parse().unwrap_or(0);
-                                    }
-                                    Err(_) => {}
-                                }
-                                cases.push((
-                                    entry.file_name().to_string_lossy().to_string(),
-                                    stdin,
-                                    stdout,
-                                    exit,
-                                ));
+                    // unit tests are selected by function path, each running in its own process
+                    for path in String::from_utf8_lossy(&list.stdout).lines() {
+                        if let Some(testname) = submatches.value_of("testname") {
+                            if !path.contains(testname) {
+                                continue;
                             }
                         }
+                        jobs.push(zz::testrunner::Job {
+                            artifact: artifact.name.clone(),
+                            exe: exe.clone(),
+                            case: zz::testcase::Case {
+                                name: path.to_string(),
+                                args: vec!["--exact".to_string(), path.to_string()],
+                                ..Default::default()
+                            },
+                        });
                     }
+                }
+            }
 
-                    if cases.is_empty() {
-                        cases.push(("default".to_string(), None, None, 0));
+            let parallel = match submatches.value_of("jobs") {
+                Some(v) => v.parse().expect("--jobs must be a number"),
+                None => 0,
+            };
+            let timeout = match submatches.value_of("timeout") {
+                Some("0") | None => None,
+                Some(v) => Some(Duration::from_secs(
+                    v.parse().expect("--timeout must be a number of seconds"),
+                )),
+            };
+
+            let mut exes: Vec<std::path::PathBuf> = Vec::new();
+            if coverage {
+                let profile = zz::coverage::prepare(&root, &stage);
+                for job in &mut jobs {
+                    job.case
+                        .env
+                        .push(("LLVM_PROFILE_FILE".to_string(), profile.clone()));
+                    if !exes.contains(&job.exe) {
+                        exes.push(job.exe.clone());
                     }
+                }
+            }
 
-                    for case in &cases {
-                        let running = root
-                            .join("target")
-                            .join(stage.to_string())
-                            .join("bin")
-                            .join(&artifact.name);
-                        println!("running \"{}\"\n", running.to_string_lossy());
-                        let start = Instant::now();
-                        let mut average = 0;
-                        loop {
-                            let istart = Instant::now();
-                            let mut child = Command::new(&running)
-                                .stdin(std::process::Stdio::piped())
-                                .stdout(std::process::Stdio::piped())
-                                .spawn()
-                                .expect("failed to execute process");
-
-                            if let Some(i) = &case.1 {
-                                let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-                                stdin.write_all(&i).unwrap();
-                            }
-                            let output = child.wait_with_output().expect("Failed to read stdout");
-                            average = (average + istart.elapsed().as_millis()) / 2;
-
-                            match output.status.code() {
-                                Some(c) => {
-                                    if c != case.3 {
-                                        error!(
-                                            "FAIL {}::{} exit: {} instead of: {}",
-                                            artifact.name, case.0, c, case.3
-                                        );
-                                        std::process::exit(10);
-                                    }
-                                }
-                                _ => {
-                                    #[cfg(unix)]
-                                    {
-                                        use std::os::unix::process::ExitStatusExt;
-                                        error!(
-                                            "FAIL {}::{} died by signal {}",
-                                            artifact.name,
-                                            case.0,
-                                            output.status.signal().unwrap()
-                                        );
-                                    }
-                                    #[cfg(not(unix))]
-                                    {
-                                        error!("FAIL {}::{} died by signal", artifact.name, case.0);
-                                    }
-                                    std::process::exit(10);
-                                }
-                            }
-                            if let Some(expect_stdout) = &case.2 {
-                                let mut output_stdout = output.stdout;
-                                output_stdout.retain(|&i| i != b'\r');
-                                if &output_stdout != expect_stdout {
-                                    error!("FAIL {} {} \nstdout expected:\n<{}>({})\nbut got:\n<{}>({})\n",
-                                           artifact.name,
-                                           case.0,
-                                           String::from_utf8_lossy(&expect_stdout),
-                                           expect_stdout.len(),
-                                           String::from_utf8_lossy(&output_stdout),
-                                           output_stdout.len()
-                                          );
-                                    std::process::exit(10);
-                                }
-                            }
-                            if bench {
-                                if start.elapsed().as_secs() > 0 {
-                                    info!("PASS {} {} {}ms/iter", artifact.name, case.0, average);
-                                    break;
-                                } else {
-                                    continue;
+            let outcomes = zz::testrunner::run(jobs, parallel, timeout);
+
+            let reportdir = root.join("target").join(stage.to_string()).join("report");
+            zz::testrunner::write_junit(&reportdir.join("junit.xml"), &outcomes);
+            zz::testrunner::write_json(&reportdir.join("report.json"), &outcomes);
+
+            let passed = zz::testrunner::summary(&outcomes);
+            if coverage {
+                if let Err(e) = zz::coverage::report(&root, &stage, &exes) {
+                    error!("coverage: {}", e);
+                    std::process::exit(9);
+                }
+            }
+            if !passed {
+                std::process::exit(10);
+            }
+        }
+        ("bench", Some(submatches)) => {
+            let variant = submatches.value_of("variant").unwrap_or("default");
+            let stage = zz::make::Stage::release();
+            zz::build(zz::BuildSet::Benches, variant, stage.clone(), false);
+            let (root, mut project) = zz::project::load_cwd();
+
+            let warmup = submatches
+                .value_of("warmup")
+                .unwrap()
+                .parse()
+                .expect("--warmup must be a number");
+            let iterations = submatches
+                .value_of("iterations")
+                .unwrap()
+                .parse()
+                .expect("--iterations must be a number");
+            let threshold: f64 = submatches
+                .value_of("threshold")
+                .unwrap()
+                .parse()
+                .expect("--threshold must be a number");
+
+            let mut stats: Vec<zz::bench::Stats> = Vec::new();
+            for artifact in std::mem::replace(&mut project.artifacts, None).expect("no artifacts") {
+                if let zz::project::ArtifactType::Bench = artifact.typ {
+                    let exe = root
+                        .join("target")
+                        .join(stage.to_string())
+                        .join("bin")
+                        .join(&artifact.name);
+                    let r = zz::bench::run(&exe, submatches.value_of("testname"), warmup, iterations);
+                    match r {
+                        Ok(v) => {
+                            for s in v {
+                                // an exe and a lib can link the same bench fns
+                                if !stats.iter().any(|o| o.path == s.path) {
+                                    stats.push(s);
                                 }
-                            } else {
-                                info!("PASS {} {} in {}ms", artifact.name, case.0, average);
-                                break;
                             }
                         }
+                        Err(e) => {
+                            error!("{}", e);
+                            std::process::exit(10);
+                        }
                     }
                 }
             }
+
+            let baseline = submatches.value_of("baseline").map(|name| {
+                zz::bench::load(&root, name).unwrap_or_else(|| {
+                    error!("no saved bench baseline named {}", name);
+                    std::process::exit(1);
+                })
+            });
+
+            let ok = zz::bench::report(&stats, baseline.as_deref(), threshold);
+            zz::bench::save(&root, "latest", &stats);
+            if let Some(name) = submatches.value_of("save-baseline") {
+                zz::bench::save(&root, name, &stats);
+            }
+            if !ok {
+                std::process::exit(10);
+            }
         }
         ("run", Some(submatches)) => {
             let stage = if submatches.is_present("release") {
@@ -356,7 +580,7 @@ fn main() {
             } else if submatches.is_present("debug") {
                 zz::make::Stage::debug()
             } else {
-                zz::make::Stage::test()
+                select_stage(submatches, zz::make::Stage::test())
             };
             let variant = submatches.value_of("variant").unwrap_or("default");
             zz::build(zz::BuildSet::Run, variant, stage.clone(), false);
@@ -391,41 +615,50 @@ fn main() {
         }
         ("fuzz", Some(submatches)) => {
             let variant = submatches.value_of("variant").unwrap_or("default");
-            let stage = zz::make::Stage::fuzz();
-            zz::build(zz::BuildSet::Tests, variant, stage.clone(), false);
-            let (root, mut project) = zz::project::load_cwd();
 
-            let mut exes = Vec::new();
-            for artifact in std::mem::replace(&mut project.artifacts, None).expect("no artifacts") {
-                if let zz::project::ArtifactType::Test = artifact.typ {
-                    match submatches.value_of("testname") {
-                        Some(v) if v == artifact.name => {
-                            exes.push((artifact.name, artifact.main));
-                        }
-                        Some(_) => {}
-                        None => {
-                            exes.push((artifact.name, artifact.main));
-                        }
+            match submatches.subcommand() {
+                ("minimize", Some(m)) => {
+                    let (root, artifact, stage) = build_libfuzzer(variant, m.value_of("testname"));
+                    let r = match m.value_of("crash") {
+                        Some(crash) => zz::libfuzzer::minimize_crash(
+                            &root,
+                            &stage,
+                            &artifact,
+                            std::path::Path::new(crash),
+                        ),
+                        None => zz::libfuzzer::minimize_corpus(&root, &stage, &artifact),
+                    };
+                    std::process::exit(r);
+                }
+                ("reproduce", Some(m)) => {
+                    let (root, artifact, stage) = build_libfuzzer(variant, m.value_of("testname"));
+                    let inputs = m
+                        .values_of("crash")
+                        .map(|v| v.map(std::path::PathBuf::from).collect())
+                        .unwrap_or_default();
+                    if zz::libfuzzer::reproduce(&root, &stage, &artifact, inputs) > 0 {
+                        std::process::exit(10);
                     }
+                    return;
                 }
+                _ => (),
             }
 
-            if exes.len() == 0 {
-                if let Some(_) = submatches.value_of("testname") {
-                    eprintln!("no such test name");
-                } else {
-                    eprintln!("no tests");
-                }
-                std::process::exit(1);
+            if submatches.is_present("libfuzzer") {
+                let (root, artifact, stage) =
+                    build_libfuzzer(variant, submatches.value_of("testname"));
+                let max_time = submatches
+                    .value_of("max-time")
+                    .map(|v| v.parse().expect("--max-time must be a number of seconds"));
+                let r = zz::libfuzzer::run(&root, &stage, &artifact, max_time);
+                std::process::exit(r);
             }
 
-            if exes.len() > 1 {
-                eprintln!("specify which test to run:");
-                for (exe, _) in exes {
-                    eprintln!(" - {}", exe);
-                }
-                std::process::exit(1);
-            }
+            let stage = zz::make::Stage::fuzz();
+            zz::build(zz::BuildSet::Tests, variant, stage.clone(), false);
+            let (root, mut project) = zz::project::load_cwd();
+            let artifact = select_fuzz_test(&mut project, submatches.value_of("testname"));
+            let exes = vec![(artifact.name, artifact.main)];
 
             let indir = tempdir::TempDir::new("zzfuzz").unwrap();
             let casedir = root
@@ -497,7 +730,7 @@ fn main() {
             } else if submatches.is_present("debug") {
                 zz::make::Stage::debug()
             } else {
-                zz::make::Stage::test()
+                select_stage(submatches, zz::make::Stage::test())
             };
 
             let set = if submatches.is_present("export") {
@@ -515,9 +748,218 @@ fn main() {
                 submatches.is_present("slow"),
             )
         }
+        ("abi-diff", Some(submatches)) => {
+            let baseline = std::env::current_dir()
+                .unwrap()
+                .join(submatches.value_of("baseline").unwrap_or("abi.json"));
+
+            let stage = select_stage(submatches, zz::make::Stage::test());
+            let current = format!("target/{}/abi.json", stage);
+            zz::build(
+                zz::BuildSet::Check,
+                submatches.value_of("variant").unwrap_or("default"),
+                stage,
+                false,
+            );
+            let (root, _) = zz::project::load_cwd();
+            let current = zz::abi::read(&root.join(current)).unwrap_or_default();
+
+            if submatches.is_present("save") {
+                zz::abi::write(&baseline, &current);
+                info!("saved {} symbols to {}", current.len(), baseline.to_string_lossy());
+                return;
+            }
+
+            let baseline = match zz::abi::read(&baseline) {
+                Some(v) => v,
+                None => {
+                    error!(
+                        "no abi baseline at {}, create one with abi-diff --save",
+                        baseline.to_string_lossy()
+                    );
+                    std::process::exit(9);
+                }
+            };
+
+            let diff = zz::abi::diff(&baseline, &current);
+            for name in &diff.removed {
+                error!("removed {}", name);
+            }
+            for (name, was, is) in &diff.changed {
+                error!("changed {}\n  was: {:?}\n  now: {:?}", name, was, is);
+            }
+            for name in &diff.added {
+                info!("added {}", name);
+            }
+            if diff.is_breaking() {
+                std::process::exit(10);
+            }
+            info!("abi compatible with baseline");
+        }
+        ("install", Some(submatches)) => {
+            // build() changes into the project root, so relative prefixes must be resolved here
+            *zz::make::INSTALL_PREFIX.lock().unwrap() = Some(
+                std::env::current_dir()
+                    .unwrap()
+                    .join(submatches.value_of("prefix").unwrap()),
+            );
+
+            let set = if let Some(v) = submatches.value_of("artifact") {
+                zz::BuildSet::Named(v.to_string())
+            } else {
+                zz::BuildSet::All
+            };
+
+            zz::build(
+                set,
+                submatches.value_of("variant").unwrap_or("default"),
+                zz::make::Stage::release(),
+                false,
+            )
+        }
         ("", None) => {
             zz::build(zz::BuildSet::All, "default", zz::make::Stage::test(), false);
         }
         _ => unreachable!(),
     }
 }
+
+/// build the property harnesses that reach the fn and run them. returns the exit code
+fn run_properties(variant: &str, function: &str, count: usize, seed: u64) -> i32 {
+    let (root, mut project) = zz::project::load_cwd();
+    let mut name = zz::name::Name::from(function);
+    if !name.is_absolute() {
+        name.0.insert(0, project.project.name.clone());
+        name.0.insert(0, String::new());
+    }
+
+    let stage = zz::make::Stage::test();
+    let artifacts: Vec<zz::project::Artifact> = std::mem::replace(&mut project.artifacts, None)
+        .expect("no artifacts")
+        .into_iter()
+        .filter(|a| a.typ == zz::project::ArtifactType::Property)
+        .collect();
+    let exe = |a: &zz::project::Artifact| {
+        root.join("target")
+            .join(stage.to_string())
+            .join("bin")
+            .join(&a.name)
+    };
+    // only harnesses that reach the fn get linked, don't run stale ones
+    for artifact in &artifacts {
+        std::fs::remove_file(exe(artifact)).ok();
+    }
+
+    println!("generating {} inputs for {} with seed {}", count, name, seed);
+    *zz::symbolic::PROPERTIES.lock().unwrap() = Some(zz::symbolic::Properties {
+        function: name.clone(),
+        count,
+        seed,
+        inputs: Vec::new(),
+    });
+    zz::build(zz::BuildSet::Properties, variant, stage.clone(), false);
+
+    if zz::symbolic::PROPERTIES
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|r| r.inputs.is_empty())
+        .unwrap_or(true)
+    {
+        error!("no fn named {} was proven", name);
+        return 1;
+    }
+
+    let mut ran = false;
+    let mut failed = false;
+    for artifact in &artifacts {
+        let exe = exe(artifact);
+        if !exe.exists() {
+            continue;
+        }
+        ran = true;
+        let status = Command::new(&exe)
+            .status()
+            .expect(&format!("failed to execute {:?}", exe));
+        if !status.success() {
+            failed = true;
+        }
+    }
+    if !ran {
+        error!("{} is not reachable from any artifact", name);
+        return 1;
+    }
+    if failed {
+        error!("the compiled code of {} does not behave like its model. reproduce with --seed {}", name, seed);
+        return 1;
+    }
+    0
+}
+
+/// the stage named by --stage, or the default of the subcommand
+fn select_stage(submatches: &clap::ArgMatches, default: zz::make::Stage) -> zz::make::Stage {
+    match submatches.value_of("stage") {
+        Some(name) => {
+            let (_, project) = zz::project::load_cwd();
+            zz::make::Stage::named(&project, name)
+        }
+        None => default,
+    }
+}
+
+/// the one test artifact to fuzz
+fn select_fuzz_test(
+    project: &mut zz::project::Config,
+    testname: Option<&str>,
+) -> zz::project::Artifact {
+    let mut exes = Vec::new();
+    for artifact in std::mem::replace(&mut project.artifacts, None).expect("no artifacts") {
+        if let zz::project::ArtifactType::Test = artifact.typ {
+            match testname {
+                Some(v) if v == artifact.name || format!("tests_{}", v) == artifact.name => {
+                    exes.push(artifact);
+                }
+                Some(_) => {}
+                None => {
+                    exes.push(artifact);
+                }
+            }
+        }
+    }
+
+    if exes.len() == 0 {
+        if let Some(_) = testname {
+            eprintln!("no such test name");
+        } else {
+            eprintln!("no tests");
+        }
+        std::process::exit(1);
+    }
+
+    if exes.len() > 1 {
+        eprintln!("specify which test to run:");
+        for exe in exes {
+            eprintln!(" - {}", exe.name);
+        }
+        std::process::exit(1);
+    }
+
+    exes.remove(0)
+}
+
+/// build only the selected test, linked against libFuzzer
+fn build_libfuzzer(
+    variant: &str,
+    testname: Option<&str>,
+) -> (std::path::PathBuf, zz::project::Artifact, zz::make::Stage) {
+    let (root, mut project) = zz::project::load_cwd();
+    let artifact = select_fuzz_test(&mut project, testname);
+    let stage = zz::make::Stage::libfuzzer();
+    zz::build(
+        zz::BuildSet::Named(artifact.name.clone()),
+        variant,
+        stage.clone(),
+        false,
+    );
+    (root, artifact, stage)
+}
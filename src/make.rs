@@ -1,11 +1,16 @@
-use super::project::{Artifact, Config};
+use super::project::{Artifact, ArtifactType, Config};
+use crate::cdiag;
 use crate::emitter_js;
 use crate::emitter_py;
 use crate::emitter_go;
 use crate::emitter_rs;
+use crate::emitter_wasm;
 use crate::export_make;
 use crate::export_cmake;
 use crate::export_esp;
+use crate::export_meson;
+use crate::export_bazel;
+use crate::install;
 use metrohash::MetroHash128;
 use pbr;
 use std::collections::HashSet;
@@ -14,10 +19,16 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 
 static ABORT: AtomicBool = AtomicBool::new(false);
 pub static BUILD_RS: AtomicBool = AtomicBool::new(false);
 
+lazy_static::lazy_static! {
+    /// when set, lib and staticlib artifacts are installed into this prefix after linking
+    pub static ref INSTALL_PREFIX: Mutex<Option<PathBuf>> = Mutex::new(None);
+}
+
 #[cfg(windows)]
 pub static EXE_EXT: &'static str = ".exe";
 #[cfg(not(windows))]
@@ -29,11 +40,30 @@ pub struct Stage {
     pub debug: bool,
     pub optimize: Option<String>,
     pub lto: bool,
-    pub asan: bool,
+    /// clang -fsanitize names, like address or undefined
+    pub sanitizers: Vec<String>,
     pub fuzz: bool,
+    /// build against libFuzzer instead of afl
+    pub libfuzzer: bool,
+    /// clang source based coverage instrumentation
+    pub coverage: bool,
     pub pic: bool,
+    /// extra flags of user defined stages
+    pub cflags: Vec<String>,
+    pub lflags: Vec<String>,
 }
 
+pub const SANITIZERS: &[&str] = &["address", "undefined", "memory", "thread", "leak"];
+
+/// pairs of sanitizers that clang refuses to combine
+const SANITIZER_CONFLICTS: &[(&str, &str)] = &[
+    ("address", "memory"),
+    ("address", "thread"),
+    ("memory", "thread"),
+    ("memory", "leak"),
+    ("thread", "leak"),
+];
+
 impl Stage {
     pub fn release() -> Self {
         Stage {
@@ -41,9 +71,13 @@ impl Stage {
             debug: false,
             optimize: Some("03".to_string()),
             lto: true,
-            asan: false,
+            sanitizers: Vec::new(),
             fuzz: false,
+            libfuzzer: false,
+            coverage: false,
             pic: !cfg!(windows),
+            cflags: Vec::new(),
+            lflags: Vec::new(),
         }
     }
     pub fn test() -> Self {
@@ -52,9 +86,13 @@ impl Stage {
             debug: true,
             optimize: None,
             lto: false,
-            asan: true,
+            sanitizers: vec!["address".to_string(), "undefined".to_string()],
             fuzz: false,
+            libfuzzer: false,
+            coverage: false,
             pic: !cfg!(windows),
+            cflags: Vec::new(),
+            lflags: Vec::new(),
         }
     }
     pub fn debug() -> Self {
@@ -63,9 +101,13 @@ impl Stage {
             debug: true,
             optimize: Some("03".to_string()),
             lto: false,
-            asan: false,
+            sanitizers: Vec::new(),
             fuzz: false,
+            libfuzzer: false,
+            coverage: false,
             pic: !cfg!(windows),
+            cflags: Vec::new(),
+            lflags: Vec::new(),
         }
     }
     pub fn fuzz() -> Self {
@@ -74,9 +116,136 @@ impl Stage {
             debug: true,
             optimize: None,
             lto: false,
-            asan: true,
+            sanitizers: vec!["address".to_string(), "undefined".to_string()],
             fuzz: true,
+            libfuzzer: false,
+            coverage: false,
+            pic: !cfg!(windows),
+            cflags: Vec::new(),
+            lflags: Vec::new(),
+        }
+    }
+    pub fn coverage() -> Self {
+        Stage {
+            name: "coverage".to_string(),
+            debug: true,
+            optimize: None,
+            lto: false,
+            sanitizers: vec!["address".to_string(), "undefined".to_string()],
+            fuzz: false,
+            libfuzzer: false,
+            coverage: true,
+            pic: !cfg!(windows),
+            cflags: Vec::new(),
+            lflags: Vec::new(),
+        }
+    }
+    pub fn libfuzzer() -> Self {
+        Stage {
+            name: "libfuzzer".to_string(),
+            debug: true,
+            optimize: Some("1".to_string()),
+            lto: false,
+            sanitizers: vec!["address".to_string(), "undefined".to_string()],
+            fuzz: false,
+            libfuzzer: true,
+            coverage: false,
             pic: !cfg!(windows),
+            cflags: Vec::new(),
+            lflags: Vec::new(),
+        }
+    }
+}
+
+impl Stage {
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "release" => Some(Stage::release()),
+            "test" => Some(Stage::test()),
+            "debug" => Some(Stage::debug()),
+            "fuzz" => Some(Stage::fuzz()),
+            "coverage" => Some(Stage::coverage()),
+            "libfuzzer" => Some(Stage::libfuzzer()),
+            _ => None,
+        }
+    }
+
+    /// a builtin stage, or one declared in the [stages] section of zz.toml
+    pub fn named(config: &Config, name: &str) -> Self {
+        Stage::resolve(config, name, &mut Vec::new())
+    }
+
+    fn resolve(config: &Config, name: &str, visiting: &mut Vec<String>) -> Self {
+        let sc = match config.stages.get(name) {
+            Some(v) => v,
+            None => match Stage::builtin(name) {
+                Some(v) => return v,
+                None => {
+                    error!("no stage named \"{}\" in zz.toml", name);
+                    super::exit(9);
+                }
+            },
+        };
+        if Stage::builtin(name).is_some() {
+            error!("stage \"{}\" in zz.toml shadows a builtin stage", name);
+            super::exit(9);
+        }
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            error!("invalid stage name \"{}\". only letters, digits, _ and - are allowed", name);
+            super::exit(9);
+        }
+        if visiting.iter().any(|v| v == name) {
+            error!("stage \"{}\" inherits from itself", name);
+            super::exit(9);
+        }
+        visiting.push(name.to_string());
+
+        let mut stage = Stage::resolve(config, sc.inherits.as_deref().unwrap_or("release"), visiting);
+        stage.name = name.to_string();
+        if let Some(v) = sc.debug {
+            stage.debug = v;
+        }
+        if let Some(v) = &sc.optimize {
+            stage.optimize = if v.is_empty() { None } else { Some(v.clone()) };
+        }
+        if let Some(v) = sc.lto {
+            stage.lto = v;
+        }
+        if let Some(v) = sc.pic {
+            stage.pic = v;
+        }
+        if let Some(v) = &sc.sanitizers {
+            stage.sanitizers = v.clone();
+        }
+        stage.cflags.extend(sc.cflags.iter().cloned());
+        stage.lflags.extend(sc.lflags.iter().cloned());
+
+        for san in &stage.sanitizers {
+            if !SANITIZERS.contains(&san.as_str()) {
+                error!(
+                    "stage \"{}\": unknown sanitizer \"{}\". expected one of {}",
+                    name,
+                    san,
+                    SANITIZERS.join(", ")
+                );
+                super::exit(9);
+            }
+        }
+        for (a, b) in SANITIZER_CONFLICTS {
+            if stage.sanitizers.iter().any(|s| s == a) && stage.sanitizers.iter().any(|s| s == b) {
+                error!("stage \"{}\": the {} and {} sanitizers cannot be combined", name, a, b);
+                super::exit(9);
+            }
+        }
+        stage
+    }
+
+    /// the -fsanitize flag for both compiling and linking
+    pub fn sanitize_flag(&self) -> Option<String> {
+        if self.sanitizers.is_empty() {
+            None
+        } else {
+            Some(format!("-fsanitize={}", self.sanitizers.join(",")))
         }
     }
 }
@@ -109,12 +278,34 @@ pub struct Make {
     pub lobjs: Vec<String>,
     pub variant: String,
     pub stage: Stage,
+
+    // unexpanded project inputs, for exporters to other build systems
+    pub cincludes: Vec<String>,
+    pub pkgconfig: Vec<String>,
+    pub cobjects: Vec<String>,
+    pub user_cflags: Vec<String>,
+    pub user_lflags: Vec<String>,
+
+    pub version: String,
+    project_name: String,
+    /// public headers of this project's modules with exported symbols
+    pub headers: Vec<String>,
 }
 
 impl Make {
     pub fn new(mut config: Config, variant: &str, stage: Stage, artifact: Artifact) -> Self {
         let features = config.features(variant);
 
+        let mut stage = stage;
+        if artifact.typ == ArtifactType::Wasm {
+            // there is no sanitizer or profile runtime for wasm32
+            stage.sanitizers.clear();
+            stage.coverage = false;
+            stage.fuzz = false;
+            stage.libfuzzer = false;
+            stage.pic = false;
+        }
+
         let mut cflags: Vec<String> =
             match std::env::var("TARGET_CFLAGS").or(std::env::var("CFLAGS")) {
                 Err(_) => Vec::new(),
@@ -145,6 +336,48 @@ impl Make {
         if let Some(std) = config.project.std {
             cflags.push(format!("-std={}", std));
         }
+
+        if artifact.typ == ArtifactType::Wasm {
+            match &artifact.wasi_sysroot {
+                Some(sysroot) => {
+                    cflags.push("--target=wasm32-wasi".to_string());
+                    cflags.push(format!("--sysroot={}", sysroot));
+                    lflags.push("--target=wasm32-wasi".to_string());
+                    lflags.push(format!("--sysroot={}", sysroot));
+                    lflags.push("-mexec-model=reactor".to_string());
+                }
+                None => {
+                    cflags.push("--target=wasm32".to_string());
+                    cflags.push("-ffreestanding".to_string());
+                    lflags.push("--target=wasm32".to_string());
+                    lflags.push("-nostdlib".to_string());
+                    lflags.push("-Wl,--no-entry".to_string());
+                }
+            }
+            // export fns have default visibility, everything else is hidden
+            lflags.push("-Wl,--export-dynamic".to_string());
+        }
+        if stage.libfuzzer {
+            // libFuzzer brings its own main, so the zz one is left out
+            cflags.push("-fsanitize=fuzzer,address".to_string());
+            cflags.push("-DZZ_FUZZ".to_string());
+            lflags.push("-fsanitize=fuzzer,address".to_string());
+        }
+        if stage.coverage {
+            cflags.push("-fprofile-instr-generate".to_string());
+            cflags.push("-fcoverage-mapping".to_string());
+            lflags.push("-fprofile-instr-generate".to_string());
+        }
+        if artifact.typ == ArtifactType::UnitTest {
+            // compiles in test fns and leaves out the zz main
+            cflags.push("-DZZ_TEST".to_string());
+        }
+        if artifact.typ == ArtifactType::Bench {
+            cflags.push("-DZZ_BENCH".to_string());
+        }
+        if artifact.typ == ArtifactType::Property {
+            cflags.push("-DZZ_PROPERTY".to_string());
+        }
         let ar = std::env::var("TARGET_AR")
             .or(std::env::var("AR"))
             .unwrap_or("ar".to_string());
@@ -171,43 +404,22 @@ impl Make {
             user_lflags.extend(feature.lflags.clone());
         }
 
-        for cinc in cincludes {
+        for cinc in &cincludes {
             cflags.push("-I".into());
-            cflags.push(cinc);
+            cflags.push(cinc.clone());
         }
 
         for pkg in &pkgconfig {
-            let flags = Command::new("pkg-config")
-                .arg("--cflags")
-                .arg(pkg)
-                .output()
-                .expect(&format!("failed to execute pkg-config --cflags {}", pkg));
-
-            let flags = String::from_utf8_lossy(&flags.stdout);
-            let flags = flags.split_whitespace();
-            for flag in flags {
-                cflags.push(flag.to_string());
-            }
-
-            let flags = Command::new("pkg-config")
-                .arg("--libs")
-                .arg(pkg)
-                .output()
-                .expect(&format!("failed to execute pkg-config --lflags {}", pkg));
-
-            let flags = String::from_utf8_lossy(&flags.stdout);
-            let flags = flags.split_whitespace();
-            for flag in flags {
-                lflags.push(flag.to_string());
-            }
+            cflags.extend(pkgconfig_flags(pkg, "--cflags"));
+            lflags.extend(pkgconfig_flags(pkg, "--libs"));
         }
         cflags.push("-I".into());
         cflags.push(".".into());
         cflags.push("-I".into());
         cflags.push("-fvisibility=hidden".to_string());
 
-        cflags.extend(user_cflags);
-        lflags.extend(user_lflags);
+        cflags.extend(user_cflags.clone());
+        lflags.extend(user_lflags.clone());
 
         let mut stage = stage.clone();
         if let Ok(_) = std::env::var("ZZ_BUILD_NO_PIC") {
@@ -228,6 +440,14 @@ impl Make {
             host_cc,
             cxx,
             host_cxx,
+            cincludes,
+            pkgconfig,
+            cobjects: cobjects.clone(),
+            user_cflags,
+            user_lflags,
+            version: config.project.version.clone(),
+            project_name: config.project.name.clone(),
+            headers: Vec::new(),
         };
 
         for c in cobjects {
@@ -237,8 +457,9 @@ impl Make {
         m
     }
 
-    pub fn cobject(&mut self, inp: &Path) {
-        let mut args = self.cflags.clone();
+    /// code generation flags of the stage, for zz and c sources alike
+    fn stage_cflags(&self) -> Vec<String> {
+        let mut args = Vec::new();
 
         if self.stage.pic {
             args.push("-fPIC".into());
@@ -259,18 +480,27 @@ impl Make {
 
         if self.stage.debug {
             args.push("-g".into());
-            args.push("-fstack-protector-strong".into());
+            if self.artifact.typ != ArtifactType::Wasm {
+                args.push("-fstack-protector-strong".into());
+            }
         }
 
-        if self.stage.asan {
-            args.push("-fsanitize=address".into());
-            args.push("-fsanitize=undefined".into());
+        if let Some(flag) = self.stage.sanitize_flag() {
+            args.push(flag);
         }
 
         if self.stage.fuzz {
             args.push("-m32".into());
         }
 
+        args.extend(self.stage.cflags.iter().cloned());
+        args
+    }
+
+    pub fn cobject(&mut self, inp: &Path) {
+        let mut args = self.cflags.clone();
+        args.extend(self.stage_cflags());
+
         args.push("-c".to_string());
         args.push(inp.to_string_lossy().to_string());
         args.push("-o".to_string());
@@ -332,9 +562,11 @@ impl Make {
 
         args.push("-Werror=pointer-sign".to_string());
         args.push("-Werror=int-to-pointer-cast".to_string());
-        if self.stage.pic {
-            args.push("-fPIC".into());
+        if self.cc.contains("clang") {
+            // report raw c locations, cdiag maps them back through the #line directives itself
+            args.push("-fno-diagnostics-use-presumed-location".to_string());
         }
+        args.extend(self.stage_cflags());
         args.push("-c".to_string());
         args.push(cf.filepath.clone());
         args.push("-o".to_string());
@@ -355,6 +587,13 @@ impl Make {
         );
         args.push(outp.clone());
 
+        // dependencies install their own headers
+        if let Some(header) = &cf.header {
+            if cf.name.0.get(1) == Some(&self.project_name) {
+                self.headers.push(header.clone());
+            }
+        }
+
         self.steps.push(Step {
             cxx: false,
             source: Path::new(&cf.filepath).into(),
@@ -365,6 +604,50 @@ impl Make {
         self.lobjs.push(outp);
     }
 
+    /// run one compile step. diagnostics are captured and reported as zz diagnostics,
+    /// and kept next to the object so that check can report them again while it is clean
+    fn compile(&self, cmd: &str, step: &Step) -> bool {
+        debug!("{} {:?}", cmd, step.args);
+        let output = Command::new(cmd)
+            .env("AFL_USE_ASAN", "1")
+            .args(&step.args)
+            .args(cdiag::format_flags(cmd))
+            .output()
+            .expect("failed to execute cc");
+        cdiag::report(&output.stdout, &output.stderr);
+        let diag = format!("{}.diag", step.outp);
+        if output.stderr.is_empty() {
+            std::fs::remove_file(&diag).ok();
+        } else {
+            std::fs::write(&diag, &output.stderr).ok();
+        }
+        if !output.status.success() {
+            debug!("cc: [{}] args: [{}]", cmd, step.args.join(" "));
+            return false;
+        }
+        true
+    }
+
+    /// compile without linking, so that c compiler diagnostics show up in zz check
+    pub fn check(self) {
+        use rayon::prelude::*;
+
+        let failed = AtomicBool::new(false);
+        self.steps.par_iter().for_each(|step| {
+            let cmd = if step.cxx { &self.cxx } else { &self.cc };
+            if !step.is_dirty() {
+                if let Ok(diag) = std::fs::read(format!("{}.diag", step.outp)) {
+                    cdiag::report(&[], &diag);
+                }
+            } else if !self.compile(cmd, step) {
+                failed.store(true, Ordering::Relaxed);
+            }
+        });
+        if failed.load(Ordering::Relaxed) {
+            super::exit(11);
+        }
+    }
+
     pub fn link(self) {
         use rayon::prelude::*;
         use std::sync::{Arc, Mutex};
@@ -372,15 +655,20 @@ impl Make {
         let needs_objects = match self.artifact.typ {
             super::project::ArtifactType::Exe
             | super::project::ArtifactType::Test
+            | super::project::ArtifactType::UnitTest
+            | super::project::ArtifactType::Bench
+            | super::project::ArtifactType::Property
             | super::project::ArtifactType::Macro
             | super::project::ArtifactType::Lib => true,
             | super::project::ArtifactType::Staticlib => true,
+            | super::project::ArtifactType::Wasm => true,
             _ => false,
         };
 
         let has_used_cxx = AtomicBool::new(false);
         let pb = Arc::new(Mutex::new(pbr::ProgressBar::new(self.steps.len() as u64)));
         pb.lock().unwrap().show_speed = false;
+        ABORT.store(false, Ordering::Relaxed);
 
         if needs_objects {
             self.steps.par_iter().for_each(|step| {
@@ -396,17 +684,8 @@ impl Make {
                     .unwrap()
                     .message(&format!("{} {:?} ", self.cc, step.source));
 
-                if step.is_dirty() {
-                    debug!("{} {:?}", cmd, step.args);
-                    let status = Command::new(&cmd)
-                        .env("AFL_USE_ASAN", "1")
-                        .args(&step.args)
-                        .status()
-                        .expect("failed to execute cc");
-                    if !status.success() {
-                        error!("cc: [{}] args: [{}]", cmd, step.args.join(" "));
-                        ABORT.store(true, Ordering::Relaxed);
-                    }
+                if step.is_dirty() && !self.compile(&cmd, step) {
+                    ABORT.store(true, Ordering::Relaxed);
                 }
                 pb.lock().unwrap().inc();
             });
@@ -416,7 +695,7 @@ impl Make {
                     "failed [{:?}] {}",
                     self.artifact.typ, self.artifact.name
                 ));
-                std::process::exit(11);
+                super::exit(11);
             }
         }
 
@@ -429,11 +708,12 @@ impl Make {
 
         if self.stage.debug {
             args.push("-g".into());
-            args.push("-fstack-protector-strong".into());
+            if self.artifact.typ != ArtifactType::Wasm {
+                args.push("-fstack-protector-strong".into());
+            }
         }
-        if self.stage.asan {
-            args.push("-fsanitize=address".into());
-            args.push("-fsanitize=undefined".into());
+        if let Some(flag) = self.stage.sanitize_flag() {
+            args.push(flag);
         }
         if self.stage.fuzz {
             args.push("-m32".into());
@@ -441,6 +721,7 @@ impl Make {
         if self.stage.lto {
             args.push("-flto".into());
         }
+        args.extend(self.stage.lflags.iter().cloned());
 
         match self.artifact.typ {
             super::project::ArtifactType::Python => {
@@ -471,6 +752,14 @@ impl Make {
                 export_esp::export(self);
                 return;
             }
+            super::project::ArtifactType::Meson => {
+                export_meson::export(self);
+                return;
+            }
+            super::project::ArtifactType::Bazel => {
+                export_bazel::export(self);
+                return;
+            }
             super::project::ArtifactType::Staticlib => {
                 std::fs::create_dir_all(format!("./target/{}/lib/", self.stage))
                     .expect("create target dir");
@@ -517,9 +806,8 @@ impl Make {
                 std::fs::create_dir_all(format!("./target/macro/")).expect("create target dir");
                 cmd = self.host_cc.clone();
                 args.extend_from_slice(&self.lobjs);
-                if self.stage.asan {
-                    args.push("-fsanitize=address".into());
-                    args.push("-fsanitize=undefined".into());
+                if let Some(flag) = self.stage.sanitize_flag() {
+                    args.push(flag);
                 }
                 args.push("-o".into());
                 args.push(format!("./target/macro/{}{}", self.artifact.name, EXE_EXT));
@@ -546,7 +834,21 @@ impl Make {
                     self.stage, self.artifact.name, EXE_EXT
                 ));
             }
-            super::project::ArtifactType::Test => {
+            super::project::ArtifactType::Wasm => {
+                std::fs::create_dir_all(format!("./target/{}/wasm/", self.stage))
+                    .expect("create target dir");
+                args.extend_from_slice(&self.lobjs);
+                args.extend_from_slice(&self.lflags);
+                args.push("-o".into());
+                args.push(format!(
+                    "./target/{}/wasm/{}.wasm",
+                    self.stage, self.artifact.name
+                ));
+            }
+            super::project::ArtifactType::Test
+            | super::project::ArtifactType::UnitTest
+            | super::project::ArtifactType::Bench
+            | super::project::ArtifactType::Property => {
                 if self.stage.pic {
                     args.push("-fPIC".into());
                 }
@@ -570,13 +872,14 @@ impl Make {
 
         debug!("{:?}", args);
 
-        let status = Command::new(&cmd)
+        let output = Command::new(&cmd)
             .env("AFL_USE_ASAN", "1")
             .args(&args)
-            .status()
+            .output()
             .expect("failed to execute linker");
-        if !status.success() {
-            std::process::exit(status.code().unwrap_or(3));
+        cdiag::report(&output.stdout, &output.stderr);
+        if !output.status.success() {
+            super::exit(output.status.code().unwrap_or(3));
         }
 
         pb.lock().unwrap().finish_print(&format!(
@@ -584,9 +887,32 @@ impl Make {
             self.artifact.typ, self.artifact.name
         ));
         println!("");
+
+        match self.artifact.typ {
+            super::project::ArtifactType::Lib | super::project::ArtifactType::Staticlib => {
+                if let Some(prefix) = INSTALL_PREFIX.lock().unwrap().clone() {
+                    install::install(&self, &prefix);
+                }
+            }
+            super::project::ArtifactType::Wasm => {
+                emitter_wasm::make_module(&self);
+            }
+            _ => (),
+        }
     }
 }
 
+fn pkgconfig_flags(pkg: &str, arg: &str) -> Vec<String> {
+    let flags = Command::new("pkg-config")
+        .arg(arg)
+        .arg(pkg)
+        .output()
+        .expect(&format!("failed to execute pkg-config {} {}", arg, pkg));
+
+    let flags = String::from_utf8_lossy(&flags.stdout);
+    flags.split_whitespace().map(|s| s.to_string()).collect()
+}
+
 impl Step {
     fn is_dirty(&self) -> bool {
         let itarget = match std::fs::metadata(&self.outp) {
@@ -48,7 +48,7 @@ pub fn expr(
 
     if !cmd.wait().unwrap().success() {
         eprintln!("failed to execute macro {}", name);
-        std::process::exit(9);
+        super::exit(9);
     }
 
     let mut n = String::new();
@@ -114,7 +114,7 @@ pub fn stm(
 
     if !cmd.wait().unwrap().success() {
         eprintln!("failed to execute macro {}", name);
-        std::process::exit(9);
+        super::exit(9);
     }
 
     let mut n = String::new();
@@ -179,6 +179,7 @@ pub fn sieve(md: &ast::Module) -> Vec<ast::Module> {
                     callassert: Vec::new(),
                     calleffect: Vec::new(),
                     callattests: Vec::new(),
+                    generics: Vec::new(),
                 };
                 nl.name = "main".to_string();
 
@@ -1376,7 +1376,7 @@ pub fn check(md: &mut flatten::Module) {
         match &mut local.def {
 
             ast::Def::Testcase {fields, ..} => {
-                for (name, expr) in fields {
+                for (name, _, expr) in fields {
                     match stack.check_expr(expr) {
                         Ok(v) => {
                             match &stack.storage[v].value {
@@ -1535,7 +1535,7 @@ pub fn check(md: &mut flatten::Module) {
         }
     }
     if ABORT.load(Ordering::Relaxed) {
-        std::process::exit(9);
+        super::exit(9);
     }
 }
 
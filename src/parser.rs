@@ -6,6 +6,7 @@ use pest::Parser;
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 
 #[derive(Parser)]
 #[grammar = "zz.pest"]
@@ -13,6 +14,10 @@ pub struct ZZParser;
 
 pub static ERRORS_AS_JSON: AtomicBool = AtomicBool::new(false);
 
+lazy_static::lazy_static! {
+    static ref CAPTURED: Mutex<Option<Vec<JsonError>>> = Mutex::new(None);
+}
+
 pub fn parse(n: &Path, features: &HashMap<String, bool>, stage: &Stage) -> Module {
     match p(&n, features, stage) {
         Err(e) => {
@@ -37,11 +42,11 @@ pub fn parse(n: &Path, features: &HashMap<String, bool>, stage: &Stage) -> Modul
                         j.column_end = c1;
                     }
                 };
-                println!("{}", serde_json::to_string(&j).unwrap());
+                emit_json(&j);
             } else {
                 error!("syntax error\n{}", e);
             }
-            std::process::exit(9);
+            super::exit(9);
         }
         Ok(md) => md,
     }
@@ -116,6 +121,7 @@ fn p(
                         body: body.unwrap(),
                     },
                     doc: std::mem::replace(&mut doccomments, String::new()),
+                    export_name: None,
                 });
             }
             Rule::function | Rule::closure | Rule::theory => {
@@ -135,19 +141,33 @@ fn p(
                 let mut calleffect = Vec::new();
                 let mut vis = Visibility::Object;
                 let mut derives = Vec::new();
+                let mut export_name = None;
+                let mut generics = Vec::new();
 
                 for part in decl {
                     match part.as_rule() {
+                        Rule::export_name => {
+                            export_name = Some(parse_export_name(n, part));
+                        }
                         Rule::key_shared => {
                             vis = Visibility::Shared;
                         }
                         Rule::exported => {
                             vis = Visibility::Export;
                         }
+                        Rule::key_test | Rule::key_bench => {
+                            // the generated test or bench main links against it
+                            let loc = Location::from_span(n.into(), &part.as_span());
+                            attr.insert(part.as_str().into(), loc);
+                            vis = Visibility::Shared;
+                        }
                         Rule::ident => {
                             nameloc = Location::from_span(n.into(), &part.as_span());
                             name = part.as_str().into();
                         }
+                        Rule::generics => {
+                            generics = parse_generics(part);
+                        }
                         Rule::ret_arg => {
                             let part = part.into_inner().next().unwrap();
                             ret = Some(AnonArg {
@@ -168,7 +188,7 @@ fn p(
                                             "vararg not possible here",
                                             &[(argloc, "nope")],
                                         );
-                                        std::process::exit(9);
+                                        super::exit(9);
                                     }
                                     vararg = true;
                                 } else {
@@ -228,9 +248,20 @@ fn p(
                     }
                 }
 
+                if (attr.contains_key("test") || attr.contains_key("bench"))
+                    && (args.len() > 0 || ret.is_some() || vararg)
+                {
+                    emit_error(
+                        "test and bench fns cannot take arguments or return a value",
+                        &[(nameloc.clone(), "declared here")],
+                    );
+                    super::exit(9);
+                }
+
                 match declrule {
                     Rule::function => {
                         module.locals.push(Local {
+                            export_name,
                             doc: std::mem::replace(&mut doccomments, String::new()),
                             name,
                             vis,
@@ -246,11 +277,13 @@ fn p(
                                 callassert,
                                 calleffect,
                                 callattests: Vec::new(),
+                                generics,
                             },
                         });
                     }
                     Rule::theory => {
                         module.locals.push(Local {
+                            export_name: None,
                             doc: std::mem::replace(&mut doccomments, String::new()),
                             name,
                             vis,
@@ -260,6 +293,7 @@ fn p(
                     }
                     Rule::closure => {
                         module.locals.push(Local {
+                            export_name,
                             doc: std::mem::replace(&mut doccomments, String::new()),
                             name,
                             vis,
@@ -298,6 +332,7 @@ fn p(
                 }
 
                 module.locals.push(Local {
+                    export_name: None,
                     doc: std::mem::replace(&mut doccomments, String::new()),
                     name: name.unwrap(),
                     vis,
@@ -311,10 +346,15 @@ fn p(
                 let mut vis = Visibility::Object;
                 let mut name = None;
                 let mut names = Vec::new();
+                let mut payloads = Vec::new();
                 let mut loc = None;
+                let mut export_name = None;
 
                 for part in decl {
                     match part.as_rule() {
+                        Rule::export_name => {
+                            export_name = Some(parse_export_name(n, part));
+                        }
                         Rule::key_shared => {
                             vis = Visibility::Shared;
                         }
@@ -327,9 +367,20 @@ fn p(
                         }
                         Rule::enum_i => {
                             let mut part = part.into_inner();
-                            let name = part.next().unwrap().as_str().to_string();
+                            let ident = part.next().unwrap();
+                            let name = ident.as_str().to_string();
                             let mut literal = None;
                             if let Some(part) = part.next() {
+                                if part.as_rule() == Rule::enum_v {
+                                    let loc = Location::from_span(n.into(), &ident.as_span());
+                                    let fields: Vec<Field> =
+                                        part.into_inner().map(|f| parse_struct_field(n, f)).collect();
+                                    if !fields.is_empty() {
+                                        payloads.push((name.clone(), loc, fields));
+                                    }
+                                    names.push((name, None));
+                                    continue;
+                                }
                                 literal = Some(match part.as_str().to_string().parse() {
                                     Err(e) => {
                                         let loc = Location::from_span(n.into(), &part.as_span());
@@ -337,7 +388,7 @@ fn p(
                                             "enums must be positive integer literals",
                                             &[(loc, format!("{}", e))],
                                         );
-                                        std::process::exit(9);
+                                        super::exit(9);
                                     }
                                     Ok(v) => v,
                                 });
@@ -349,12 +400,94 @@ fn p(
                     }
                 }
 
+                let name: String = name.unwrap();
+                let loc = loc.unwrap();
+
+                if payloads.is_empty() {
+                    module.locals.push(Local {
+                        export_name,
+                        doc: std::mem::replace(&mut doccomments, String::new()),
+                        name,
+                        vis,
+                        loc,
+                        def: Def::Enum { names },
+                    });
+                    continue;
+                }
+
+                // a tagged union is a struct of the tag and an anonymous union of one struct per payload
+                let mut variants = Vec::new();
+                let mut value = 0;
+                for (vname, literal) in names {
+                    if let Some(literal) = literal {
+                        value = literal;
+                    }
+                    variants.push((vname, value));
+                    value += 1;
+                }
+
+                let mut fields = vec![Field {
+                    typed: Typed {
+                        t: Type::UInt,
+                        ptr: Vec::new(),
+                        loc: loc.clone(),
+                        tail: Tail::None,
+                    },
+                    name: "tag".to_string(),
+                    array: Array::None,
+                    tags: Tags::new(),
+                    loc: loc.clone(),
+                }];
+
+                for (vname, vloc, vfields) in payloads {
+                    let body = format!("{}_{}_Body", name, vname);
+                    module.locals.push(Local {
+                        export_name: export_name.as_ref().map(|e| format!("{}_{}_Body", e, vname)),
+                        doc: String::new(),
+                        name: body.clone(),
+                        vis: vis.clone(),
+                        loc: vloc.clone(),
+                        def: Def::Struct {
+                            fields: vfields,
+                            packed: false,
+                            tail: Tail::None,
+                            union: false,
+                            impls: HashMap::new(),
+                            variants: Vec::new(),
+                            generics: Vec::new(),
+                            implements: Vec::new(),
+                        },
+                    });
+                    fields.push(Field {
+                        typed: Typed {
+                            t: Type::Other(Name::from(&body)),
+                            ptr: Vec::new(),
+                            loc: vloc.clone(),
+                            tail: Tail::None,
+                        },
+                        name: vname,
+                        array: Array::None,
+                        tags: Tags::new(),
+                        loc: vloc,
+                    });
+                }
+
                 module.locals.push(Local {
+                    export_name,
                     doc: std::mem::replace(&mut doccomments, String::new()),
-                    name: name.unwrap(),
+                    name,
                     vis,
-                    loc: loc.unwrap(),
-                    def: Def::Enum { names },
+                    loc,
+                    def: Def::Struct {
+                        fields,
+                        packed: false,
+                        tail: Tail::None,
+                        union: false,
+                        impls: HashMap::new(),
+                        variants,
+                        generics: Vec::new(),
+                        implements: Vec::new(),
+                    },
                 });
             }
             Rule::testcase => {
@@ -372,14 +505,15 @@ fn p(
                         Rule::testfield => {
                             let mut part = part.into_inner();
                             let fname = part.next().unwrap().as_str().to_string();
-                            let _op = part.next().unwrap().as_str().to_string();
+                            let op = TestOp::parse(part.next().unwrap().as_str()).unwrap();
                             let expr = parse_expr(n, part.next().unwrap());
-                            fields.push((fname, expr));
+                            fields.push((fname, op, expr));
                         }
                         e => panic!("unexpected rule {:?} in testcase", e),
                     }
                 }
                 module.locals.push(Local {
+                    export_name: None,
                     doc: std::mem::replace(&mut doccomments, String::new()),
                     name: name.unwrap_or(format!("anonymous_test_case_{}", loc.line)),
                     vis: Visibility::Object,
@@ -398,15 +532,27 @@ fn p(
                 let mut packed = false;
                 let mut tail = Tail::None;
                 let mut union = false;
+                let mut export_name = None;
+                let mut generics = Vec::new();
+                let mut implements = Vec::new();
 
                 for part in decl {
                     match part.as_rule() {
+                        Rule::export_name => {
+                            export_name = Some(parse_export_name(n, part));
+                        }
                         Rule::doc_comment => {
                             //TODO
                         }
+                        Rule::implements => {
+                            implements = part.into_inner().map(|p| parse_anon_type(n, p)).collect();
+                        }
                         Rule::tail => {
                             tail = Tail::Dynamic(None);
                         }
+                        Rule::generics => {
+                            generics = parse_generics(part);
+                        }
                         Rule::key_packed => {
                             packed = true;
                         }
@@ -427,34 +573,14 @@ fn p(
                             name = Some(part.as_str().into());
                         }
                         Rule::struct_f => {
-                            let loc = Location::from_span(n.into(), &part.as_span());
-
-                            let mut part = part.into_inner();
-
-                            let TypedName { typed, name, tags } =
-                                parse_named_type(n, part.next().unwrap());
-
-                            let array = match part.next() {
-                                None => Array::None,
-                                Some(array) => match array.into_inner().next() {
-                                    Some(expr) => Array::Sized(parse_expr(n, expr)),
-                                    None => Array::Unsized,
-                                },
-                            };
-
-                            fields.push(Field {
-                                typed,
-                                array,
-                                tags,
-                                name,
-                                loc,
-                            });
+                            fields.push(parse_struct_field(n, part));
                         }
                         e => panic!("unexpected rule {:?} in struct ", e),
                     }
                 }
 
                 module.locals.push(Local {
+                    export_name,
                     doc: std::mem::replace(&mut doccomments, String::new()),
                     name: name.unwrap(),
                     vis,
@@ -465,9 +591,90 @@ fn p(
                         tail,
                         union,
                         impls: HashMap::new(),
+                        variants: Vec::new(),
+                        generics,
+                        implements,
                     },
                 });
             }
+            Rule::interface => {
+                let decl = decl.into_inner();
+
+                let mut vis = Visibility::Object;
+                let mut name = None;
+                let mut loc = None;
+                let mut methods = Vec::new();
+                let mut methoddoc = String::new();
+
+                for part in decl {
+                    match part.as_rule() {
+                        Rule::key_shared => {
+                            vis = Visibility::Shared;
+                        }
+                        Rule::exported => {
+                            vis = Visibility::Export;
+                        }
+                        Rule::key_interface => {}
+                        Rule::ident => {
+                            loc = Some(Location::from_span(n.into(), &part.as_span()));
+                            name = Some(part.as_str().into());
+                        }
+                        Rule::doc_comment => {
+                            let mut s = part.as_str().to_string();
+                            s.remove(0);
+                            s.remove(0);
+                            s.remove(0);
+                            methoddoc.push_str(&s);
+                        }
+                        Rule::method => {
+                            let mut method = parse_method(n, part);
+                            method.doc = std::mem::replace(&mut methoddoc, String::new());
+                            methods.push(method);
+                        }
+                        e => panic!("unexpected rule {:?} in interface", e),
+                    }
+                }
+
+                module.locals.push(Local {
+                    export_name: None,
+                    doc: std::mem::replace(&mut doccomments, String::new()),
+                    name: name.unwrap(),
+                    vis,
+                    loc: loc.unwrap(),
+                    def: Def::Interface { methods },
+                });
+            }
+            Rule::static_assert_d => {
+                let loc = Location::from_span(n.into(), &decl.as_span());
+                let mut decl = decl.into_inner();
+                decl.next();
+                let expr = parse_expr(n, decl.next().unwrap());
+                let message = match parse_expr_inner(n, decl.next().unwrap()) {
+                    Expression::LiteralString { v, .. } => v,
+                    _ => unreachable!(),
+                };
+                module.locals.push(Local {
+                    export_name: None,
+                    doc: String::new(),
+                    name: format!("static_assert_{}", loc.start),
+                    vis: Visibility::Object,
+                    loc,
+                    def: Def::StaticAssert { expr, message },
+                });
+            }
+            Rule::instance => {
+                let part = decl.into_inner().nth(1).unwrap();
+                let typed = parse_anon_type(n, part);
+                if let Tail::Generic(_) = typed.tail {
+                } else {
+                    emit_error(
+                        "syntax error",
+                        &[(typed.loc.clone(), "expected an instance of a generic, like Name<u8>")],
+                    );
+                    super::exit(9);
+                }
+                module.instances.push(typed);
+            }
             Rule::import => {
                 let loc = Location::from_span(n.into(), &decl.as_span());
                 let mut vis = Visibility::Object;
@@ -552,7 +759,7 @@ fn p(
                                     part.as_span(),
                                 );
                                 error!("{} : {}", n, e);
-                                std::process::exit(9);
+                                super::exit(9);
                             } else {
                                 vis = Visibility::Shared;
                             }
@@ -568,7 +775,7 @@ fn p(
                                     part.as_span(),
                                 );
                                 error!("{} : {}", n, e);
-                                std::process::exit(9);
+                                super::exit(9);
                             } else {
                                 vis = Visibility::Export;
                             }
@@ -601,10 +808,11 @@ fn p(
                                     "anonymous type cannot have storage tags (yet)",
                                 )],
                             );
-                            std::process::exit(9);
+                            super::exit(9);
                         }
 
                         module.locals.push(Local {
+                            export_name: None,
                             doc: std::mem::replace(&mut doccomments, String::new()),
                             name: name,
                             loc,
@@ -617,6 +825,7 @@ fn p(
                     }
                     Rule::istatic => {
                         module.locals.push(Local {
+                            export_name: None,
                             doc: std::mem::replace(&mut doccomments, String::new()),
                             name: name,
                             loc,
@@ -640,6 +849,50 @@ fn p(
     Ok(module)
 }
 
+fn parse_export_name(n: &str, decl: pest::iterators::Pair<'static, Rule>) -> String {
+    let loc = Location::from_span(n.into(), &decl.as_span());
+    let v = decl.into_inner().next().unwrap().as_str();
+    let v = &v[1..v.len() - 1];
+
+    let valid = v.chars().enumerate().all(|(i, c)| match c {
+        'a'..='z' | 'A'..='Z' | '_' => true,
+        '0'..='9' => i > 0,
+        _ => false,
+    });
+    if v.is_empty() || !valid {
+        emit_error(
+            "invalid export name",
+            &[(loc, format!("'{}' is not a valid C identifier", v))],
+        );
+        super::exit(9);
+    }
+    v.to_string()
+}
+
+fn parse_struct_field(n: &str, decl: pest::iterators::Pair<'static, Rule>) -> Field {
+    let loc = Location::from_span(n.into(), &decl.as_span());
+
+    let mut part = decl.into_inner();
+
+    let TypedName { typed, name, tags } = parse_named_type(n, part.next().unwrap());
+
+    let array = match part.next() {
+        None => Array::None,
+        Some(array) => match array.into_inner().next() {
+            Some(expr) => Array::Sized(parse_expr(n, expr)),
+            None => Array::Unsized,
+        },
+    };
+
+    Field {
+        typed,
+        array,
+        tags,
+        name,
+        loc,
+    }
+}
+
 pub(crate) fn parse_derive(n: &str, decl: pest::iterators::Pair<'static, Rule>) -> Derive {
     match decl.as_rule() {
         Rule::macrocall => {}
@@ -724,7 +977,7 @@ pub(crate) fn parse_expr(n: &str, decl: pest::iterators::Pair<'static, Rule>) ->
                 format!("ICE: unexpected rhs {:?}", rhs),
                 &[(loc.clone(), "in this memberaccess ")],
             );
-            std::process::exit(9);
+            super::exit(9);
         } else if op.as_rule() == Rule::ptraccess {
             if let Expression::Name(typed) = &rhs {
                 if let Type::Other(n) = &typed.t {
@@ -740,7 +993,7 @@ pub(crate) fn parse_expr(n: &str, decl: pest::iterators::Pair<'static, Rule>) ->
                 format!("ICE: unexpected rhs {:?}", rhs),
                 &[(loc.clone(), "in this ptraccess ")],
             );
-            std::process::exit(9);
+            super::exit(9);
         } else if op.as_rule() == Rule::callstart {
             if let Expression::Call { loc, args, .. } = &rhs {
                 return Expression::Call {
@@ -755,8 +1008,16 @@ pub(crate) fn parse_expr(n: &str, decl: pest::iterators::Pair<'static, Rule>) ->
                 format!("ICE: unexpected rhs {:?}", rhs),
                 &[(loc.clone(), "in this call ")],
             );
-            std::process::exit(9);
+            super::exit(9);
         } else if op.as_rule() == Rule::arraystart {
+            if let Expression::Slice { from, to, .. } = rhs {
+                return Expression::Slice {
+                    loc: loc.clone(),
+                    lhs: Box::new(lhs),
+                    from,
+                    to,
+                };
+            }
             return Expression::ArrayAccess {
                 loc: loc.clone(),
                 lhs: Box::new(lhs),
@@ -792,12 +1053,35 @@ pub(crate) fn parse_expr(n: &str, decl: pest::iterators::Pair<'static, Rule>) ->
                         format!("ICE: unexpected operator {}", op),
                         &[(loc.clone(), "in this infix")],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             },
         }
     };
-    climber.climb(decl.into_inner(), |pair| parse_expr_inner(n, pair), reduce)
+
+    let mut pairs: Vec<pest::iterators::Pair<'static, Rule>> = decl.into_inner().collect();
+    let ternary = match pairs.last() {
+        Some(p) if p.as_rule() == Rule::ternary => pairs.pop(),
+        _ => None,
+    };
+
+    let cond = climber.climb(pairs.into_iter(), |pair| parse_expr_inner(n, pair), reduce);
+
+    match ternary {
+        None => cond,
+        Some(ternary) => {
+            let loc = Location::from_span(n.into(), &ternary.as_span());
+            let mut ternary = ternary.into_inner();
+            let then = parse_expr(n, ternary.next().unwrap());
+            let otherwise = parse_expr(n, ternary.next().unwrap());
+            Expression::Ternary {
+                loc,
+                cond: Box::new(cond),
+                then: Box::new(then),
+                otherwise: Box::new(otherwise),
+            }
+        }
+    }
 }
 
 pub(crate) fn parse_expr_inner(n: &str, expr: pest::iterators::Pair<'static, Rule>) -> Expression {
@@ -815,7 +1099,7 @@ pub(crate) fn parse_expr_inner(n: &str, expr: pest::iterators::Pair<'static, Rul
                 Rule::decrement => crate::ast::PrefixOperator::Decrement,
                 _ => {
                     emit_error("ICE: unexpected operator", &[(loc.clone(), "in this expr")]);
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             };
             let part = expr.next().unwrap();
@@ -863,7 +1147,7 @@ pub(crate) fn parse_expr_inner(n: &str, expr: pest::iterators::Pair<'static, Rul
                 Rule::decrement => crate::ast::PostfixOperator::Decrement,
                 _ => {
                     emit_error("ICE: unexpected operator", &[(loc.clone(), "in this expr")]);
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             };
 
@@ -915,6 +1199,23 @@ pub(crate) fn parse_expr_inner(n: &str, expr: pest::iterators::Pair<'static, Rul
                 tail: Tail::None,
             })
         }
+        Rule::generic_name => {
+            let mut name = Name::default();
+            let mut tail = Tail::None;
+            for part in expr.into_inner() {
+                match part.as_rule() {
+                    Rule::ident => name.0.push(part.as_str().to_string()),
+                    Rule::generic_args => tail = parse_generic_args(n, part),
+                    e => panic!("unexpected rule {:?} in generic name", e),
+                }
+            }
+            Expression::Name(Typed {
+                t: Type::Other(name),
+                ptr: Vec::new(),
+                loc,
+                tail,
+            })
+        }
         Rule::string_literal => {
             let mut val = expr.as_str().to_string();
             let v = if val.starts_with("r#") {
@@ -977,6 +1278,30 @@ pub(crate) fn parse_expr_inner(n: &str, expr: pest::iterators::Pair<'static, Rul
             }
         }
         Rule::call => parse_call(n, expr),
+        Rule::slicerange => {
+            let mut from = None;
+            let mut to = None;
+            for part in expr.into_inner() {
+                match part.as_rule() {
+                    Rule::slicefrom => {
+                        from = Some(Box::new(parse_expr(n, part.into_inner().next().unwrap())))
+                    }
+                    Rule::sliceto => {
+                        to = Some(Box::new(parse_expr(n, part.into_inner().next().unwrap())))
+                    }
+                    e => panic!("unexpected rule {:?} in slice range", e),
+                }
+            }
+            Expression::Slice {
+                lhs: Box::new(Expression::Literal {
+                    v: "#error ICE this was supposed to be removed by pre climber pass".to_string(),
+                    loc: loc.clone(),
+                }),
+                loc,
+                from,
+                to,
+            }
+        }
         Rule::macrocall => {
             let mut expr = expr.into_inner();
             let mut name = expr.next().unwrap().into_inner();
@@ -1144,7 +1469,7 @@ pub(crate) fn parse_statement(
                             "this else branch does not follow an if condition",
                         )],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
                 Some(c) => {
                     if let Statement::If { ref mut branches } = *into[c] {
@@ -1166,7 +1491,7 @@ pub(crate) fn parse_statement(
                             "this else branch does not follow an if condition",
                         )],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
                 Some(c) => {
                     if let Statement::If { ref mut branches } = *into[c] {
@@ -1216,6 +1541,124 @@ pub(crate) fn parse_statement(
                 body: block.unwrap(),
             }));
         }
+        Rule::for_in_stm => {
+            let mut stm = stm.into_inner();
+            let TypedName { typed, name, tags } = parse_named_type(n, stm.next().unwrap());
+            let range = stm.nth(1).unwrap();
+            let mut body = parse_block(n, features, stage, stm.next().unwrap());
+
+            // lowered to a plain for loop over a hidden index.
+            // the loop variable is a copy, so the body cannot change how often the loop runs
+            let local = |name: &str| {
+                Expression::Name(Typed {
+                    t: Type::Other(Name::from(name)),
+                    ptr: Vec::new(),
+                    loc: loc.clone(),
+                    tail: Tail::None,
+                })
+            };
+            let var = |typed: &Typed, name: &str, tags: Tags, assign: Expression| {
+                Box::new(Statement::Var {
+                    loc: loc.clone(),
+                    typed: typed.clone(),
+                    name: name.to_string(),
+                    tags,
+                    array: None,
+                    assign: Some(assign),
+                })
+            };
+            let mut mutable = Tags::new();
+            mutable.insert("mut".to_string(), String::new(), loc.clone());
+            let next = Box::new(Statement::Expr {
+                loc: loc.clone(),
+                expr: Expression::UnaryPost {
+                    loc: loc.clone(),
+                    op: PostfixOperator::Increment,
+                    expr: Box::new(local("__for_index")),
+                },
+            });
+
+            match range.as_rule() {
+                Rule::forrange => {
+                    let mut range = range.into_inner();
+                    let from = parse_expr(n, range.next().unwrap());
+                    let to = parse_expr(n, range.next().unwrap());
+
+                    body.statements
+                        .insert(0, var(&typed, &name, tags, local("__for_index")));
+                    let lp = Statement::For {
+                        e1: vec![var(&typed, "__for_index", mutable, from)],
+                        e2: Some(Expression::Infix {
+                            loc: loc.clone(),
+                            lhs: Box::new(local("__for_index")),
+                            rhs: Box::new(local("__for_end")),
+                            op: InfixOperator::Lessthan,
+                        }),
+                        e3: vec![next],
+                        body,
+                    };
+                    // the end is evaluated once, before the loop
+                    into.push(Box::new(Statement::Block(Box::new(Block {
+                        end: loc.clone(),
+                        statements: vec![
+                            var(&typed, "__for_end", Tags::new(), to),
+                            Box::new(lp),
+                        ],
+                        expanded: false,
+                    }))));
+                }
+                _ => {
+                    let over = parse_expr(n, range);
+                    if !super::slice::pure(&over) {
+                        emit_error(
+                            "for loop over an expression with side effects",
+                            &[(
+                                over.loc().clone(),
+                                "this is evaluated on every iteration. assign it to a local first",
+                            )],
+                        );
+                        super::exit(9);
+                    }
+                    let usize = Typed {
+                        t: Type::USize,
+                        ptr: Vec::new(),
+                        loc: loc.clone(),
+                        tail: Tail::None,
+                    };
+                    let element = Expression::UnaryPre {
+                        loc: loc.clone(),
+                        op: PrefixOperator::AddressOf,
+                        expr: Box::new(Expression::ArrayAccess {
+                            loc: loc.clone(),
+                            lhs: Box::new(over.clone()),
+                            rhs: Box::new(local("__for_index")),
+                        }),
+                    };
+                    body.statements.insert(0, var(&typed, &name, tags, element));
+                    let zero = Expression::Literal {
+                        loc: loc.clone(),
+                        v: "0".to_string(),
+                    };
+                    into.push(Box::new(Statement::For {
+                        e1: vec![var(&usize, "__for_index", mutable, zero)],
+                        e2: Some(Expression::Infix {
+                            loc: loc.clone(),
+                            lhs: Box::new(local("__for_index")),
+                            rhs: Box::new(Expression::Call {
+                                loc: loc.clone(),
+                                name: Box::new(local("len")),
+                                args: vec![Box::new(over)],
+                                expanded: false,
+                                emit: EmitBehaviour::Default,
+                            }),
+                            op: InfixOperator::Lessthan,
+                        }),
+                        e3: vec![next],
+                        body,
+                    }));
+                }
+            }
+        }
         Rule::vardecl => {
             let stm = stm.into_inner();
             let mut typed = None;
@@ -1267,15 +1710,21 @@ pub(crate) fn parse_statement(
                         op = Some(match part.into_inner().next().unwrap().as_rule() {
                             Rule::assignbitor => AssignOperator::Bitor,
                             Rule::assignbitand => AssignOperator::Bitand,
+                            Rule::assignbitxor => AssignOperator::Bitxor,
                             Rule::assignadd => AssignOperator::Add,
                             Rule::assignsub => AssignOperator::Sub,
+                            Rule::assignmul => AssignOperator::Mul,
+                            Rule::assigndiv => AssignOperator::Div,
+                            Rule::assignmod => AssignOperator::Mod,
+                            Rule::assignshl => AssignOperator::Shiftleft,
+                            Rule::assignshr => AssignOperator::Shiftright,
                             Rule::assigneq => AssignOperator::Eq,
                             _ => {
                                 emit_error(
                                     "ICE: unexpected operator",
                                     &[(loc.clone(), "in this assign expr")],
                                 );
-                                std::process::exit(9);
+                                super::exit(9);
                             }
                         });
                     }
@@ -1306,10 +1755,58 @@ pub(crate) fn parse_statement(
                 if ppart.as_rule() == Rule::key_default {
                     if default.is_some() {
                         emit_error("multiple default cases", &[(loc.clone(), "in this switch")]);
-                        std::process::exit(9);
+                        super::exit(9);
                     } else {
                         default = Some(parse_block(n, features, stage, part.next().unwrap()));
                     }
+                } else if ppart.as_rule() == Rule::case_bind {
+                    // Shape::Circle c => { .. } copies the payload into c before the case body.
+                    // the switch itself is turned into a switch over the tag once its type is known
+                    let mut bind = ppart.into_inner();
+                    let variant = parse_expr_inner(n, bind.next().unwrap());
+                    let ident = bind.next().unwrap();
+                    let bloc = Location::from_span(n.into(), &ident.as_span());
+
+                    // the payload is read from the switch expression again inside the case
+                    if !super::slice::pure(&expr) {
+                        emit_error(
+                            "cannot destructure an expression with side effects",
+                            &[(bloc, "assign it to a local and switch over that")],
+                        );
+                        super::exit(9);
+                    }
+
+                    let field = match &variant {
+                        Expression::Name(typed) => match &typed.t {
+                            Type::Other(name) => name.0.last().cloned().unwrap_or_default(),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+
+                    let mut block = parse_block(n, features, stage, part.next().unwrap());
+                    block.statements.insert(
+                        0,
+                        Box::new(Statement::Var {
+                            loc: bloc.clone(),
+                            typed: Typed {
+                                t: Type::Elided,
+                                ptr: Vec::new(),
+                                loc: bloc.clone(),
+                                tail: Tail::None,
+                            },
+                            tags: Tags::new(),
+                            name: ident.as_str().to_string(),
+                            array: None,
+                            assign: Some(Expression::MemberAccess {
+                                loc: bloc,
+                                lhs: Box::new(expr.clone()),
+                                op: ".".to_string(),
+                                rhs: field,
+                            }),
+                        }),
+                    );
+                    cases.push((vec![variant], block));
                 } else {
                     let mut case_cond = Vec::new();
                     for case in ppart.into_inner() {
@@ -1336,6 +1833,15 @@ pub(crate) fn parse_statement(
                 stm.into_inner().next().unwrap(),
             )))));
         }
+        Rule::defer_stm => {
+            let part = stm.into_inner().nth(1).unwrap();
+            let mut deferred = Vec::new();
+            parse_statement(n, features, stage, part, &mut deferred, &mut None);
+            into.push(Box::new(Statement::Defer {
+                loc,
+                stm: deferred.pop().unwrap(),
+            }));
+        }
         Rule::cblock => {
             let stm = stm.into_inner().next().unwrap();
             let loc = Location::from_span(n.into(), &stm.as_span());
@@ -1406,7 +1912,7 @@ pub(crate) struct TypedName {
 
 pub(crate) fn parse_named_type(n: &str, decl: pest::iterators::Pair<'static, Rule>) -> TypedName {
     match decl.as_rule() {
-        Rule::named_type => {}
+        Rule::named_type | Rule::for_in_type => {}
         _ => {
             panic!("parse_named_type called with {:?}", decl);
         }
@@ -1436,6 +1942,9 @@ pub(crate) fn parse_named_type(n: &str, decl: pest::iterators::Pair<'static, Rul
                     tail = Tail::Dynamic(None)
                 }
             }
+            Rule::generic_args => {
+                tail = parse_generic_args(n, lhs);
+            }
             e => panic!("unexpected rule {:?} in named_type lhs", e),
         }
     }
@@ -1449,19 +1958,20 @@ pub(crate) fn parse_named_type(n: &str, decl: pest::iterators::Pair<'static, Rul
             if name == "return" {
                 let loc = Location::from_span(n.into(), &name_part.as_span());
                 emit_error("syntax error", &[(loc, "llegal use of keyword 'return'")]);
-                std::process::exit(9);
+                super::exit(9);
             }
             name
         }
         _ => {
             let loc = Location::from_span(n.into(), &name_part.as_span());
             emit_error("syntax error", &[(loc.clone(), "expected a name")]);
-            std::process::exit(9);
+            super::exit(9);
         }
     };
 
     let mut tags = Tags::new();
     let mut ptr = Vec::new();
+    let mut slices = Vec::new();
 
     for part in decl {
         let loc = Location::from_span(n.into(), &part.as_span());
@@ -1472,6 +1982,16 @@ pub(crate) fn parse_named_type(n: &str, decl: pest::iterators::Pair<'static, Rul
                     loc,
                 });
             }
+            Rule::slice => {
+                ptr.push(Pointer {
+                    tags: std::mem::replace(&mut tags, Tags::new()),
+                    loc,
+                });
+                slices.push(std::mem::replace(&mut ptr, Vec::new()));
+            }
+            Rule::nullable => {
+                tags.insert("nullable".to_string(), String::new(), loc);
+            }
             Rule::tag_name => {
                 let mut part = part.into_inner();
                 let mut name = part.next().unwrap().as_str().into();
@@ -1491,16 +2011,126 @@ pub(crate) fn parse_named_type(n: &str, decl: pest::iterators::Pair<'static, Rul
 
     TypedName {
         name,
-        typed: Typed {
-            t: Type::Other(typename),
-            loc: loc.clone(),
+        typed: slice_typed(
+            Typed {
+                t: Type::Other(typename),
+                loc: loc.clone(),
+                ptr: Vec::new(),
+                tail,
+            },
+            slices,
             ptr,
-            tail,
-        },
+        ),
         tags,
     }
 }
 
+/// wrap the element type into one slice for every [], left to right.
+/// each [] holds the pointers that came before it
+fn slice_typed(mut typed: Typed, slices: Vec<Vec<Pointer>>, ptr: Vec<Pointer>) -> Typed {
+    for mem in slices {
+        typed.ptr = mem;
+        typed = Typed {
+            loc: typed.loc.clone(),
+            t: Type::Slice(Box::new(typed)),
+            ptr: Vec::new(),
+            tail: Tail::None,
+        };
+    }
+    typed.ptr = ptr;
+    typed
+}
+
+/// a fn signature in an interface. Self is the type implementing it
+fn parse_method(n: &str, decl: pest::iterators::Pair<'static, Rule>) -> Local {
+    let loc = Location::from_span(n.into(), &decl.as_span());
+    let mut nameloc = loc.clone();
+    let mut name = String::new();
+    let mut args = Vec::new();
+    let mut ret = None;
+    let mut callassert = Vec::new();
+    let mut calleffect = Vec::new();
+
+    for part in decl.into_inner() {
+        match part.as_rule() {
+            Rule::ident => {
+                nameloc = Location::from_span(n.into(), &part.as_span());
+                name = part.as_str().into();
+            }
+            Rule::ret_arg => {
+                let part = part.into_inner().next().unwrap();
+                ret = Some(AnonArg {
+                    typed: parse_anon_type(n, part),
+                });
+            }
+            Rule::fn_args => {
+                for arg in part.into_inner() {
+                    let argloc = Location::from_span(n.into(), &arg.as_span());
+                    if arg.as_rule() == Rule::vararg {
+                        emit_error("vararg not possible here", &[(argloc, "nope")]);
+                        super::exit(9);
+                    }
+                    let TypedName { typed, name, tags } = parse_named_type(n, arg);
+                    args.push(NamedArg {
+                        name,
+                        typed,
+                        tags,
+                        loc: argloc,
+                    });
+                }
+            }
+            Rule::call_assert => {
+                let part = part.into_inner().next().unwrap();
+                callassert.push(parse_expr(n, part));
+            }
+            Rule::call_effect => {
+                let part = part.into_inner().next().unwrap();
+                calleffect.push(parse_expr(n, part));
+            }
+            e => panic!("unexpected rule {:?} in method", e),
+        }
+    }
+
+    if args.first().map(|a| a.name != "self").unwrap_or(true) {
+        emit_error(
+            "interface method without self",
+            &[(nameloc, "the first argument must be self, of type Self")],
+        );
+        super::exit(9);
+    }
+
+    Local {
+        export_name: None,
+        doc: String::new(),
+        name,
+        vis: Visibility::Object,
+        loc,
+        def: Def::Function {
+            nameloc,
+            ret,
+            attr: HashMap::new(),
+            derives: Vec::new(),
+            args,
+            body: ConditionalBlock {
+                branches: Vec::new(),
+            },
+            vararg: false,
+            callassert,
+            calleffect,
+            callattests: Vec::new(),
+            generics: vec!["Self".to_string()],
+        },
+    }
+}
+
+pub(crate) fn parse_generics(decl: pest::iterators::Pair<'static, Rule>) -> Vec<String> {
+    decl.into_inner().map(|p| p.as_str().to_string()).collect()
+}
+
+pub(crate) fn parse_generic_args(n: &str, decl: pest::iterators::Pair<'static, Rule>) -> Tail {
+    Tail::Generic(decl.into_inner().map(|p| parse_anon_type(n, p)).collect())
+}
+
 pub(crate) fn parse_anon_type(n: &str, decl: pest::iterators::Pair<'static, Rule>) -> Typed {
     match decl.as_rule() {
         Rule::anon_type => {}
@@ -1515,6 +2145,7 @@ pub(crate) fn parse_anon_type(n: &str, decl: pest::iterators::Pair<'static, Rule
 
     let mut tags = Tags::new();
     let mut ptr = Vec::new();
+    let mut slices = Vec::new();
     let mut tail = Tail::None;
 
     for part in decl {
@@ -1526,6 +2157,16 @@ pub(crate) fn parse_anon_type(n: &str, decl: pest::iterators::Pair<'static, Rule
                     loc,
                 });
             }
+            Rule::slice => {
+                ptr.push(Pointer {
+                    tags: std::mem::replace(&mut tags, Tags::new()),
+                    loc,
+                });
+                slices.push(std::mem::replace(&mut ptr, Vec::new()));
+            }
+            Rule::nullable => {
+                tags.insert("nullable".to_string(), String::new(), loc);
+            }
             Rule::tag_name => {
                 let mut part = part.into_inner();
                 let mut name = part.next().unwrap().as_str().into();
@@ -1553,6 +2194,9 @@ pub(crate) fn parse_anon_type(n: &str, decl: pest::iterators::Pair<'static, Rule
                     tail = Tail::Dynamic(None)
                 }
             }
+            Rule::generic_args => {
+                tail = parse_generic_args(n, part);
+            }
             e => panic!("unexpected rule {:?} in anon_type", e),
         }
     }
@@ -1565,15 +2209,19 @@ pub(crate) fn parse_anon_type(n: &str, decl: pest::iterators::Pair<'static, Rule
                 "anonymous type cannot have storage tags (yet)",
             )],
         );
-        std::process::exit(9);
+        super::exit(9);
     }
 
-    Typed {
-        t: Type::Other(name),
-        loc,
+    slice_typed(
+        Typed {
+            t: Type::Other(name),
+            loc,
+            ptr: Vec::new(),
+            tail,
+        },
+        slices,
         ptr,
-        tail,
-    }
+    )
 }
 
 pub(crate) fn parse_importname(
@@ -1661,9 +2309,9 @@ fn parse_call(n: &str, expr: pest::iterators::Pair<'static, Rule>) -> Expression
     }
 }
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct JsonError {
     pub message: String,
     pub level: String,
@@ -1674,6 +2322,29 @@ pub struct JsonError {
     pub column_end: usize,
 }
 
+/// collect json diagnostics instead of printing them, and turn exits into panics.
+/// this is for running the compiler in-process, see super::exit
+pub fn capture() {
+    *CAPTURED.lock().unwrap() = Some(Vec::new());
+}
+
+/// stop capturing and return everything emitted since capture()
+pub fn captured() -> Vec<JsonError> {
+    CAPTURED.lock().unwrap().take().unwrap_or_default()
+}
+
+pub fn capturing() -> bool {
+    CAPTURED.lock().unwrap().is_some()
+}
+
+pub fn emit_json(j: &JsonError) {
+    if let Some(c) = CAPTURED.lock().unwrap().as_mut() {
+        c.push(j.clone());
+        return;
+    }
+    println!("{}", serde_json::to_string(j).unwrap());
+}
+
 pub fn emit_error<'a, S1, S2, I>(message: S1, v: I)
 where
     S1: std::string::ToString,
@@ -1696,13 +2367,13 @@ where
             j.column_end = span.end_pos().line_col().1;
 
             if first {
-                println!("{}", serde_json::to_string(&j).unwrap());
+                emit_json(&j);
                 first = false;
             }
 
             j.level = "W".to_string();
             j.message = message.to_string();
-            println!("{}", serde_json::to_string(&j).unwrap());
+            emit_json(&j);
         }
 
         return;
@@ -1745,7 +2416,7 @@ where
             j.column_end = span.end_pos().line_col().1;
         }
 
-        println!("{}", serde_json::to_string(&j).unwrap());
+        emit_json(&j);
         return;
     }
 
@@ -1807,7 +2478,7 @@ fn unescape(s: &str, loc: &Location) -> Vec<u8> {
                             "octal value too big for char",
                             &[(loc.clone(), "in this literal string")],
                         );
-                        std::process::exit(9);
+                        super::exit(9);
                     }
                     value as u8
                 }
@@ -1827,7 +2498,7 @@ fn unescape(s: &str, loc: &Location) -> Vec<u8> {
                         "unsupported escape character",
                         &[(loc.clone(), "in this literal string")],
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             }
         })
@@ -3,6 +3,7 @@ use super::ast;
 use super::emitter;
 use super::expand;
 use super::flatten;
+use super::generic;
 use super::loader;
 use super::make;
 use super::makro;
@@ -29,6 +30,7 @@ pub struct Pipeline {
 
     ext: abs::Ext,
     completed_abs: HashSet<Name>,
+    instantiated: HashSet<Name>,
     macros_available: bool,
     working_on_these: Arc<Mutex<HashSet<String>>>,
 }
@@ -44,6 +46,8 @@ impl Pipeline {
         pb.lock().unwrap().show_speed = false;
 
         let silent = parser::ERRORS_AS_JSON.load(Ordering::SeqCst);
+        ABORT.store(false, Ordering::Relaxed);
+        abs::reset();
 
         Self {
             variant,
@@ -54,6 +58,7 @@ impl Pipeline {
             modules,
             ext: abs::Ext::new(),
             completed_abs: HashSet::new(),
+            instantiated: HashSet::new(),
             macros_available: false,
             working_on_these: Arc::new(Mutex::new(HashSet::new())),
         }
@@ -71,12 +76,22 @@ impl Pipeline {
                 (project::ArtifactType::NodeModule, super::BuildSet::Export) => (),
                 (project::ArtifactType::CMake, super::BuildSet::Export) => (),
                 (project::ArtifactType::Esp32, super::BuildSet::Export) => (),
+                (project::ArtifactType::Meson, super::BuildSet::Export) => (),
+                (project::ArtifactType::Bazel, super::BuildSet::Export) => (),
                 (_, super::BuildSet::Export) => continue,
 
                 (_, super::BuildSet::Named(name)) if &artifact.name == name => (),
                 (_, super::BuildSet::Named(_)) => continue,
                 (project::ArtifactType::Test, super::BuildSet::Tests) => (),
                 (project::ArtifactType::Test, _) => continue,
+                (project::ArtifactType::UnitTest, super::BuildSet::Tests) => (),
+                (project::ArtifactType::UnitTest, _) => continue,
+                (project::ArtifactType::Bench, super::BuildSet::Benches) => (),
+                (project::ArtifactType::Bench, _) => continue,
+                (_, super::BuildSet::Benches) => continue,
+                (project::ArtifactType::Property, super::BuildSet::Properties) => (),
+                (project::ArtifactType::Property, _) => continue,
+                (_, super::BuildSet::Properties) => continue,
                 (project::ArtifactType::Exe, _) => (),
                 (_, super::BuildSet::Run) => continue,
                 (_, _) => (),
@@ -146,19 +161,40 @@ impl Pipeline {
             self.modules.insert(name.clone(), md);
             self.pb_done("abs", hn);
         }
+
+        let owners = generic::generic(&mut self.modules, &self.completed_abs);
+        self.instantiated.extend(owners);
     }
 
     fn do_emit(&self, ast: &mut ast::Module) -> Result<emitter::CFile, Option<super::Error>> {
-        if let Some(v) = self.from_buildcache(&ast.name) {
-            return Ok(v);
+        // inputs of a property test are generated while proving, so that module always runs
+        let property = symbolic::property_module().as_ref() == Some(&ast.name);
+        // instances are added by the modules using them, which the cache doesnt know about
+        let instantiated = self.instantiated.contains(&ast.name);
+        if !property && !instantiated {
+            if let Some(v) = self.from_buildcache(&ast.name) {
+                return Ok(v);
+            }
         }
 
         let mut module = flatten::flatten(ast, &self.modules, self.ext.clone());
+        super::interface::implement(&mut module).map_err(|e| Some(e))?;
         expand::expand(&mut module).map_err(|e| Some(e))?;
         let (ok, complete) = symbolic::execute(&mut module, false /*TODO*/);
         if !ok {
             return Err(None);
         }
+        super::interface::strip(&mut module);
+
+        // docs show slices as written
+        let docs = super::emitter_docs::Emitter::new(
+            &self.project.project,
+            self.stage.clone(),
+            module.clone(),
+        );
+        docs.emit();
+
+        super::slice::lower(&mut module);
 
         let header = super::emitter::Emitter::new(
             &self.project.project,
@@ -168,6 +204,15 @@ impl Pipeline {
         );
         header.emit();
 
+        if self.project.project.cxx_headers {
+            let cxxheader = super::emitter_cpp::Emitter::new(
+                &self.project.project,
+                self.stage.clone(),
+                module.clone(),
+            );
+            cxxheader.emit();
+        }
+
         let rsbridge = super::emitter_rs::Emitter::new(
             &self.project.project,
             self.stage.clone(),
@@ -189,18 +234,18 @@ impl Pipeline {
         );
         pybridge.emit();
 
-        let docs = super::emitter_docs::Emitter::new(
+        let wasmbridge = super::emitter_wasm::Emitter::new(
             &self.project.project,
             self.stage.clone(),
             module.clone(),
         );
-        docs.emit();
+        wasmbridge.emit();
 
         let em =
             super::emitter::Emitter::new(&self.project.project, self.stage.clone(), module, false);
         let cf = em.emit();
 
-        if complete {
+        if complete && !property {
             self.to_buildcache(&cf);
         }
 
@@ -232,6 +277,10 @@ impl Pipeline {
                                 sources: HashSet::new(),
                                 deps: HashSet::new(),
                                 symbols: HashSet::new(),
+                                header: None,
+                                tests: Vec::new(),
+                                benches: Vec::new(),
+                                fuzz: None,
                             },
                         ))
                     }
@@ -257,16 +306,20 @@ impl Pipeline {
             .collect::<HashMap<Name, emitter::CFile>>();
 
         if ABORT.load(Ordering::Relaxed) {
-            std::process::exit(1);
+            super::exit(1);
         }
 
+        super::abi::merge(&self.stage.name, cfiles.keys());
+
         let mut main = Name::from(&artifact.main);
         if !main.is_absolute() {
             main.0.insert(0, String::new());
         }
-        let mut need = vec![main];
+        let mut need = vec![main.clone()];
         let mut used: HashSet<Name> = HashSet::new();
         let mut symbols: HashSet<Name> = HashSet::new();
+        let mut tests: Vec<(String, String)> = Vec::new();
+        let mut benches: Vec<(String, String)> = Vec::new();
 
         let mut make = make::Make::new(
             self.project.clone(),
@@ -275,6 +328,25 @@ impl Pipeline {
             artifact.clone(),
         );
 
+        if self.stage.libfuzzer && artifact.typ == project::ArtifactType::Test {
+            let fuzz = cfiles.get(&main).and_then(|cf| cf.fuzz.clone());
+            match fuzz {
+                Some(fuzz) => make.build(&emitter::fuzz_main(
+                    &self.project.project,
+                    &self.stage,
+                    &artifact,
+                    &fuzz,
+                )),
+                None => {
+                    error!(
+                        "{} has no entry point for libFuzzer. it needs an `export fn fuzz(u8* data, usize len) where len(data) >= len`",
+                        main
+                    );
+                    super::exit(9);
+                }
+            }
+        }
+
         while need.len() > 0 {
             for n in std::mem::replace(&mut need, Vec::new()) {
                 if !used.insert(n.clone()) {
@@ -287,10 +359,21 @@ impl Pipeline {
                     need.push(d.clone());
                 }
                 symbols.extend(n.symbols.clone());
+                if n.name.0.get(1) == Some(&self.project.project.name) {
+                    tests.extend(n.tests.clone());
+                    benches.extend(n.benches.clone());
+                }
                 make.build(n);
             }
         }
 
+        if artifact.typ == project::ArtifactType::Property {
+            match symbolic::property_module() {
+                Some(m) if used.contains(&m) => (),
+                _ => return,
+            }
+        }
+
         for entry in std::fs::read_dir("./src").unwrap() {
             let entry = entry.unwrap();
             let path = entry.path();
@@ -310,6 +393,26 @@ impl Pipeline {
             }
         }
 
+        if artifact.typ == project::ArtifactType::UnitTest {
+            tests.sort();
+            make.build(&emitter::unittest_main(
+                &self.project.project,
+                &self.stage,
+                &artifact,
+                &tests,
+            ));
+        }
+
+        if artifact.typ == project::ArtifactType::Bench {
+            benches.sort();
+            make.build(&emitter::bench_main(
+                &self.project.project,
+                &self.stage,
+                &artifact,
+                &benches,
+            ));
+        }
+
         make.build(&emitter::builtin(
             &self.project.project,
             &self.stage,
@@ -317,8 +420,10 @@ impl Pipeline {
             symbols,
         ));
 
-        if buildset != &super::BuildSet::Check {
-            make.link();
+        match buildset {
+            super::BuildSet::Check => make.check(),
+            super::BuildSet::Emit => (),
+            _ => make.link(),
         }
     }
 
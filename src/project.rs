@@ -17,6 +17,15 @@ pub enum ArtifactType {
     Exe,
     #[serde(rename = "test")]
     Test,
+    /// the `test fn` items reachable from an artifact, linked against a generated test main
+    #[serde(rename = "unittest")]
+    UnitTest,
+    /// the `bench fn` items reachable from an artifact, linked against a generated bench main
+    #[serde(rename = "bench")]
+    Bench,
+    /// calls one fn with inputs generated from its where clauses and checks its model, see zz test --properties
+    #[serde(rename = "property")]
+    Property,
 
     #[serde(rename = "rust")]
     Rust,
@@ -32,6 +41,12 @@ pub enum ArtifactType {
     Go,
     #[serde(rename = "make")]
     Make,
+    #[serde(rename = "meson")]
+    Meson,
+    #[serde(rename = "bazel")]
+    Bazel,
+    #[serde(rename = "wasm")]
+    Wasm,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -43,6 +58,8 @@ pub struct Artifact {
     pub indexjs: Option<String>,
     #[serde(default)]
     pub requires: Vec<String>,
+    /// wasi-libc sysroot for wasm artifacts. without one they are built freestanding
+    pub wasi_sysroot: Option<String>,
 }
 
 impl Default for Artifact {
@@ -53,6 +70,7 @@ impl Default for Artifact {
             typ: ArtifactType::Lib,
             indexjs: None,
             requires: Vec::new(),
+            wasi_sysroot: None,
         }
     }
 }
@@ -76,6 +94,11 @@ pub struct Project {
     pub version: String,
     pub name: String,
     pub std: Option<String>,
+    /// prepended to every C symbol emitted for zz code
+    pub symbol_prefix: Option<String>,
+    /// also emit a c++ header next to each c header
+    #[serde(default)]
+    pub cxx_headers: bool,
 
     #[serde(default)]
     pub cincludes: Vec<String>,
@@ -89,6 +112,25 @@ pub struct Project {
     pub lflags: Vec<String>,
 }
 
+/// a user defined stage. unset fields are inherited
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct StageConfig {
+    /// release, debug, test or another stage in zz.toml. defaults to release
+    pub inherits: Option<String>,
+    pub debug: Option<bool>,
+    /// the -O level, like "2" or "s". an empty string leaves it to the compiler
+    pub optimize: Option<String>,
+    pub lto: Option<bool>,
+    pub pic: Option<bool>,
+    /// replaces the inherited sanitizers. any of address, undefined, memory, thread, leak
+    pub sanitizers: Option<Vec<String>>,
+    /// added to the inherited flags
+    #[serde(default)]
+    pub cflags: Vec<String>,
+    #[serde(default)]
+    pub lflags: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub enum Dependency {
     V(String),
@@ -107,6 +149,9 @@ pub struct Config {
 
     #[serde(default)]
     pub repos: HashMap<String, String>,
+
+    #[serde(default)]
+    pub stages: HashMap<String, StageConfig>,
 }
 
 pub fn init() {
@@ -130,6 +175,7 @@ pub fn init() {
         features: None,
         variants: HashMap::new(),
         repos: HashMap::new(),
+        stages: HashMap::new(),
     };
     c.variants.insert("default".to_string(), Vec::new());
 
@@ -188,7 +234,7 @@ pub fn load_cwd() -> (PathBuf, Config) {
                         "error: could not find \"zz.toml\" in {:?} or any parent directory",
                         std::env::current_dir().unwrap()
                     );
-                    std::process::exit(9);
+                    super::exit(9);
                 }
             }
         } else {
@@ -289,6 +335,34 @@ pub fn load(search: &std::path::Path) -> (PathBuf, Config) {
         }
     }
 
+    // every binary gets a unit test, bench and property harness for the fns it links
+    if let Some(artifacts) = c.artifacts.as_mut() {
+        let mut harnesses: Vec<Artifact> = Vec::new();
+        for artifact in artifacts.iter() {
+            match artifact.typ {
+                ArtifactType::Exe | ArtifactType::Lib | ArtifactType::Staticlib => (),
+                _ => continue,
+            }
+            for (prefix, typ) in &[
+                ("unittests", ArtifactType::UnitTest),
+                ("benches", ArtifactType::Bench),
+                ("properties", ArtifactType::Property),
+            ] {
+                let name = format!("{}_{}", prefix, artifact.main.replace("::", "_"));
+                if harnesses.iter().any(|a| a.name == name) || artifacts.iter().any(|a| a.name == name) {
+                    continue;
+                }
+                harnesses.push(Artifact {
+                    name,
+                    main: artifact.main.clone(),
+                    typ: typ.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+        artifacts.extend(harnesses);
+    }
+
     (search.into(), c)
 }
 
@@ -297,7 +371,7 @@ impl Config {
         match self.variants.get(variant) {
             None => {
                 error!("variant {} not defined", variant);
-                std::process::exit(9);
+                super::exit(9);
             }
             Some(v) => {
                 let mut r = HashMap::new();
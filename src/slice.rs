@@ -0,0 +1,152 @@
+/// built in T[] slices, a pointer and the number of elements it points to.
+/// symbolic execution proves bounds over the slice type itself.
+/// before emitting, every slice type is lowered into a struct of its two members.
+use super::ast;
+use super::flatten;
+use super::generic;
+use super::name::Name;
+use std::collections::HashMap;
+
+/// the pointer type of a slice. None if t is not a slice, or a pointer to one
+pub fn mem(t: &ast::Typed) -> Option<&ast::Typed> {
+    match &t.t {
+        ast::Type::Slice(mem) if t.ptr.is_empty() => Some(mem),
+        _ => None,
+    }
+}
+
+/// the element type of a slice
+pub fn element(mem: &ast::Typed) -> ast::Typed {
+    let mut elem = mem.clone();
+    elem.ptr.pop();
+    elem
+}
+
+/// true if the elements can be written through the slice
+pub fn mutable(mem: &ast::Typed) -> bool {
+    mem.ptr.last().map(|ptr| ptr.tags.contains("mut")).unwrap_or(false)
+}
+
+/// the local name of the struct a slice is lowered into.
+/// a T[] and a T mut[] are different structs, so that C rejects writes to immutable elements
+pub fn name(mem: &ast::Typed) -> String {
+    let prefix = if mutable(mem) { "mslice" } else { "slice" };
+    generic::mangle(prefix, &[element(mem)])
+}
+
+/// the members of a slice
+pub fn fields(mem: &ast::Typed) -> Vec<ast::Field> {
+    vec![
+        ast::Field {
+            typed: mem.clone(),
+            name: "mem".to_string(),
+            array: ast::Array::None,
+            tags: ast::Tags::new(),
+            loc: mem.loc.clone(),
+        },
+        ast::Field {
+            typed: ast::Typed {
+                t: ast::Type::USize,
+                loc: mem.loc.clone(),
+                ptr: Vec::new(),
+                tail: ast::Tail::None,
+            },
+            name: "len".to_string(),
+            array: ast::Array::None,
+            tags: ast::Tags::new(),
+            loc: mem.loc.clone(),
+        },
+    ]
+}
+
+/// replace every slice type in the module with its struct.
+/// the struct is declared right before its first use and has the same C name in every module
+pub fn lower(module: &mut flatten::Module) {
+    let mut structs = HashMap::new();
+    let mut d = Vec::new();
+    for (mut local, complete) in std::mem::replace(&mut module.d, Vec::new()) {
+        let mut used = Vec::new();
+        generic::walk_local(&mut local, &mut |t| {
+            lower_type(t, &module.name, &mut structs, &mut used)
+        });
+        for name in used {
+            // declared once, before the first local using it
+            if let Some(Some(local)) = structs.get_mut(&name).map(Option::take) {
+                if let Some(export_name) = &local.export_name {
+                    module.export_names.insert(name, export_name.clone());
+                }
+                d.push((local, flatten::TypeComplete::Complete));
+            }
+        }
+        d.push((local, complete));
+    }
+    module.d = d;
+}
+
+fn lower_type(
+    t: &mut ast::Typed,
+    module: &Name,
+    structs: &mut HashMap<Name, Option<ast::Local>>,
+    used: &mut Vec<Name>,
+) {
+    let mut mem = match &t.t {
+        ast::Type::Slice(mem) => (**mem).clone(),
+        _ => return,
+    };
+    let local = self::name(&mem);
+    let mut name = module.clone();
+    name.push(local.clone());
+
+    // a slice of slices needs the inner struct first
+    lower_type(&mut mem, module, structs, used);
+
+    if !structs.contains_key(&name) {
+        structs.insert(
+            name.clone(),
+            Some(ast::Local {
+                name: name.to_string(),
+                vis: ast::Visibility::Shared,
+                loc: mem.loc.clone(),
+                def: ast::Def::Struct {
+                    fields: fields(&mem),
+                    packed: false,
+                    tail: ast::Tail::None,
+                    union: false,
+                    impls: HashMap::new(),
+                    variants: Vec::new(),
+                    generics: Vec::new(),
+                    implements: Vec::new(),
+                },
+                doc: String::new(),
+                export_name: Some(format!("zz_{}", local)),
+            }),
+        );
+    }
+    used.push(name.clone());
+    t.t = ast::Type::Other(name);
+}
+
+/// true if evaluating expr twice is the same as evaluating it once.
+/// slice expressions and conversions are emitted as literals that may repeat their operands
+pub fn pure(expr: &ast::Expression) -> bool {
+    match expr {
+        ast::Expression::Name(_)
+        | ast::Expression::Literal { .. }
+        | ast::Expression::LiteralChar { .. }
+        | ast::Expression::LiteralString { .. } => true,
+        ast::Expression::MemberAccess { lhs, .. } => pure(lhs),
+        ast::Expression::ArrayAccess { lhs, rhs, .. } | ast::Expression::Infix { lhs, rhs, .. } => {
+            pure(lhs) && pure(rhs)
+        }
+        ast::Expression::Cast { expr, .. } => pure(expr),
+        ast::Expression::Slice { lhs, from, to, .. } => {
+            pure(lhs) && from.iter().chain(to.iter()).all(|expr| pure(expr))
+        }
+        ast::Expression::StructInit { fields, .. } => fields.iter().all(|(_, expr)| pure(expr)),
+        ast::Expression::UnaryPre { op, expr, .. } => match op {
+            ast::PrefixOperator::Increment | ast::PrefixOperator::Decrement => false,
+            _ => pure(expr),
+        },
+        _ => false,
+    }
+}
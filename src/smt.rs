@@ -277,6 +277,30 @@ impl Solver {
         self.checkpoint();
     }
 
+    pub fn ite(
+        &mut self,
+        lhs: TemporalSymbol,
+        cond: TemporalSymbol,
+        rhs_if: TemporalSymbol,
+        rhs_else: TemporalSymbol,
+        t: Type,
+    ) {
+        let smt_lhs = self.var_as(&lhs, t.clone());
+        let smt_cond = self.var(&cond);
+        let smt_if = self.var_as(&rhs_if, t.clone());
+        let smt_else = self.var_as(&rhs_else, t.clone());
+
+        self.solver
+            .borrow_mut()
+            .assert(&format!(
+                "(= {}  (ite {} {} {})  )",
+                smt_lhs, smt_cond, smt_if, smt_else,
+            ))
+            .unwrap();
+
+        self.checkpoint();
+    }
+
     pub fn assign(&mut self, lhs: TemporalSymbol, rhs: TemporalSymbol, t: Type) {
         let smt_lhs = self.var_as(&lhs, t.clone());
         let smt_rhs = self.var_as(&rhs, t.clone());
@@ -445,7 +469,7 @@ impl Solver {
                 self.solver
                     .borrow_mut()
                     .assert(&format!(
-                        "(=  {} (bvxnor {} {}))",
+                        "(=  {} (bvxor {} {}))",
                         smt_tmp, smt_lhs, smt_rhs
                     ))
                     .unwrap();
@@ -539,13 +563,24 @@ impl Solver {
             }
             crate::ast::InfixOperator::Modulo => {
                 assert!(t != Type::Bool);
-                self.solver
-                    .borrow_mut()
-                    .assert(&format!(
-                        "(=  {} (bvsmod {} {}))",
-                        smt_tmp, smt_lhs, smt_rhs
-                    ))
-                    .unwrap();
+                // c rounds towards zero, so the remainder has the sign of lhs
+                if signed {
+                    self.solver
+                        .borrow_mut()
+                        .assert(&format!(
+                            "(=  {} (bvsrem {} {}))",
+                            smt_tmp, smt_lhs, smt_rhs
+                        ))
+                        .unwrap();
+                } else {
+                    self.solver
+                        .borrow_mut()
+                        .assert(&format!(
+                            "(=  {} (bvurem {} {}))",
+                            smt_tmp, smt_lhs, smt_rhs
+                        ))
+                        .unwrap();
+                }
             }
             crate::ast::InfixOperator::Bitand => {
                 assert!(t != Type::Bool);
@@ -691,20 +726,108 @@ impl Solver {
         };
 
         debug!("extracted: {}", value);
-        if value == "false" {
-            return Some(0);
-        } else if value == "true" {
-            return Some(1);
-        } else if value.starts_with("#x") {
-            if let Ok(v) = u64::from_str_radix(&value[2..], 16) {
-                return Some(v);
+        parse_value(&value)
+    }
+
+    /// up to count distinct solutions for syms under the current assertions.
+    /// each one is pushed towards random pivots, so they spread over the domain
+    /// instead of clustering around the first solution the solver finds
+    pub fn models(
+        &self,
+        syms: &[TemporalSymbol],
+        count: usize,
+        seed: u64,
+    ) -> Result<Vec<Vec<u64>>, String> {
+        // declare outside of the scope below, so the declarations survive it
+        let vars: Vec<(String, Type)> = syms
+            .iter()
+            .map(|sym| {
+                let name = self.var(sym);
+                (name, self.vars.borrow()[&sym.0].typ.clone())
+            })
+            .collect();
+        let names: Vec<String> = vars.iter().map(|(n, _)| n.clone()).collect();
+
+        let mut rng = seed | 1;
+        let mut random = move || {
+            rng ^= rng << 13;
+            rng ^= rng >> 7;
+            rng ^= rng << 17;
+            rng
+        };
+
+        let mut r = Vec::new();
+        write!(self.solver.borrow_mut(), "; property inputs\n").unwrap();
+        self.solver.borrow_mut().push(1).unwrap();
+        while r.len() < count {
+            let mut pivots = Vec::new();
+            for (name, typ) in &vars {
+                let (ge, le, size) = match typ {
+                    Type::Bool => {
+                        pivots.push(format!("(= {} {})", name, random() % 2 == 0));
+                        continue;
+                    }
+                    Type::Signed(size) => ("bvsge", "bvsle", *size),
+                    Type::Unsigned(size) => ("bvuge", "bvule", *size),
+                };
+                let pivot = if size < 64 {
+                    random() & ((1u64 << size) - 1)
+                } else {
+                    random()
+                };
+                pivots.push(format!(
+                    "({} {} (_ bv{} {}))",
+                    if random() % 2 == 0 { ge } else { le },
+                    name,
+                    pivot,
+                    size
+                ));
             }
-        } else if value.starts_with("#b") {
-            if let Ok(v) = u64::from_str_radix(&value[2..], 2) {
-                return Some(v);
+
+            self.solver.borrow_mut().push(1).unwrap();
+            if !pivots.is_empty() {
+                self.solver
+                    .borrow_mut()
+                    .assert(&format!("(and {})", pivots.join(" ")))
+                    .unwrap();
+            }
+            if !self.solve() {
+                self.solver.borrow_mut().pop(1).unwrap();
+                self.solver.borrow_mut().push(1).unwrap();
+                if !self.solve() {
+                    // no solutions left
+                    self.solver.borrow_mut().pop(1).unwrap();
+                    break;
+                }
+            }
+            let values = match self.solver.borrow_mut().get_values(&names) {
+                Ok(v) => v,
+                Err(e) => {
+                    self.solver.borrow_mut().pop(2).unwrap();
+                    return Err(format!("{}", e));
+                }
+            };
+            self.solver.borrow_mut().pop(1).unwrap();
+
+            let parsed: Option<Vec<u64>> = values.iter().map(|(_, v)| parse_value(v)).collect();
+            match parsed {
+                Some(v) => r.push(v),
+                None => break,
             }
+
+            // never the same solution twice
+            let same: Vec<String> = names
+                .iter()
+                .zip(values.iter())
+                .map(|(n, (_, v))| format!("(= {} {})", n, v))
+                .collect();
+            self.solver
+                .borrow_mut()
+                .assert(&format!("(not (and {}))", same.join(" ")))
+                .unwrap();
         }
-        None
+        self.solver.borrow_mut().pop(1).unwrap();
+        Ok(r)
     }
 
     // asserts are false if
@@ -985,6 +1108,24 @@ impl Solver {
     }
 }
 
+/// a model value as printed by the solver
+fn parse_value(value: &str) -> Option<u64> {
+    if value == "false" {
+        return Some(0);
+    } else if value == "true" {
+        return Some(1);
+    } else if value.starts_with("#x") {
+        if let Ok(v) = u64::from_str_radix(&value[2..], 16) {
+            return Some(v);
+        }
+    } else if value.starts_with("#b") {
+        if let Ok(v) = u64::from_str_radix(&value[2..], 2) {
+            return Some(v);
+        }
+    }
+    None
+}
+
 use rsmt2::{parse::ExprParser, parse::ValueParser, SmtRes};
 
 #[derive(Clone, Copy)]
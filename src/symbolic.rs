@@ -11,6 +11,49 @@ use std::collections::HashMap;
 pub type Symbol = usize;
 pub type TemporalSymbol = (Symbol, u64);
 
+/// a `zz test --properties` request for one fn.
+/// executing that fn fills in inputs that satisfy its where clauses
+pub struct Properties {
+    pub function: Name,
+    pub count: usize,
+    pub seed: u64,
+    pub inputs: Vec<Vec<u64>>,
+}
+
+lazy_static::lazy_static! {
+    pub static ref PROPERTIES: std::sync::Mutex<Option<Properties>> = std::sync::Mutex::new(None);
+}
+
+/// the module that defines the fn under property test
+pub fn property_module() -> Option<Name> {
+    let mut n = PROPERTIES.lock().unwrap().as_ref()?.function.clone();
+    n.pop();
+    Some(n)
+}
+
+/// types the solver can generate property inputs for
+pub fn property_type(typed: &ast::Typed) -> bool {
+    if !typed.ptr.is_empty() || typed.tail != ast::Tail::None {
+        return false;
+    }
+    match typed.t {
+        ast::Type::U8
+        | ast::Type::U16
+        | ast::Type::U32
+        | ast::Type::U64
+        | ast::Type::I8
+        | ast::Type::I16
+        | ast::Type::I32
+        | ast::Type::I64
+        | ast::Type::Int
+        | ast::Type::UInt
+        | ast::Type::ISize
+        | ast::Type::USize
+        | ast::Type::Bool => true,
+        _ => false,
+    }
+}
+
 #[derive(Clone, Debug)]
 enum Value {
     Void,
@@ -497,7 +540,7 @@ impl Symbolic {
                         Ok(v) => v,
                     };
                 }
-                ast::Def::Struct { .. } => {
+                ast::Def::Struct { variants, .. } => {
                     let sym = self.alloc(
                         Name::from(&d.name),
                         ast::Typed {
@@ -513,6 +556,22 @@ impl Symbolic {
                         Err(_) => continue,
                         Ok(v) => v,
                     };
+
+                    for (name, value) in variants {
+                        let mut localname = Name::from(&d.name);
+                        localname.push(name.clone());
+
+                        let t = ast::Typed {
+                            t: ast::Type::ULiteral,
+                            loc: d.loc.clone(),
+                            ptr: Vec::new(),
+                            tail: ast::Tail::None,
+                        };
+                        let sym = self.alloc(localname, t, d.loc.clone(), ast::Tags::new())?;
+                        self.memory[sym].value = Value::Integer(*value);
+
+                        self.ssa.literal(sym, *value, self.memory[sym].t.clone());
+                    }
                 }
                 ast::Def::Symbol {} => {
                     let sym = self.alloc(
@@ -598,6 +657,8 @@ impl Symbolic {
                     self.ssa_mark_safe(sym, &d.loc)?;
                 }
                 ast::Def::Testcase { .. } => {}
+                ast::Def::StaticAssert { .. } => {}
+                ast::Def::Interface { .. } => {}
                 ast::Def::Include { .. } => {}
             }
         }
@@ -665,6 +726,7 @@ impl Symbolic {
         self.current_function_model = calleffect.clone();
 
         let mut prev: Option<Symbol> = None;
+        let mut argsyms = Vec::new();
         for i in 0..args.len() {
             let argname = Name::from(&args[i].name);
             let sym = self.alloc(
@@ -675,6 +737,9 @@ impl Symbolic {
             )?;
             self.memory[sym].value =
                 Value::Unconstrained(format!("passed by value as {}", argname));
+            if self.memory[sym].typed.nullable() {
+                self.ssa_mark_nullable(sym, &args[i].loc)?;
+            }
 
             if args[i].tags.contains("tail") {
                 let prev = match prev {
@@ -782,6 +847,7 @@ impl Symbolic {
             }
 
             prev = Some(sym);
+            argsyms.push(sym);
         }
 
         for callassert in callassert.iter_mut().chain(callattests.iter_mut()) {
@@ -806,6 +872,8 @@ impl Symbolic {
             }
         }
 
+        self.property_inputs(name, args, &argsyms, &body.end)?;
+
         if let Some(ret) = ret {
             self.current_function_ret = Some(self.alloc(
                 Name::from("return"),
@@ -827,6 +895,61 @@ impl Symbolic {
         Ok(())
     }
 
+    /// if this fn is under property test, ask the solver for inputs that satisfy its where clauses
+    fn property_inputs(
+        &mut self,
+        name: &str,
+        args: &Vec<ast::NamedArg>,
+        syms: &[Symbol],
+        end: &ast::Location,
+    ) -> Result<(), Error> {
+        let (count, seed) = match PROPERTIES.lock().unwrap().as_ref() {
+            Some(req) if req.function == Name::from(name) => (req.count, req.seed),
+            _ => return Ok(()),
+        };
+
+        for arg in args {
+            if !property_type(&arg.typed) {
+                return Err(self.trace(
+                    format!("cannot generate inputs for {}", arg.name),
+                    vec![(
+                        arg.loc.clone(),
+                        format!("only integer and bool arguments can be property tested"),
+                    )],
+                ));
+            }
+        }
+
+        let inputs = if syms.is_empty() {
+            vec![Vec::new()]
+        } else {
+            let syms: Vec<TemporalSymbol> = syms
+                .iter()
+                .map(|sym| (*sym, self.memory[*sym].temporal))
+                .collect();
+            match self.ssa.models(&syms, count, seed) {
+                Ok(v) => v,
+                Err(e) => {
+                    return Err(self.trace(
+                        format!("cannot generate inputs for {}", name),
+                        vec![(end.clone(), format!("solver failed: {}", e))],
+                    ))
+                }
+            }
+        };
+        if inputs.is_empty() {
+            return Err(self.trace(
+                format!("no inputs satisfy the where clauses"),
+                vec![(end.clone(), format!("of this function"))],
+            ));
+        }
+
+        if let Some(req) = PROPERTIES.lock().unwrap().as_mut() {
+            req.inputs = inputs;
+        }
+        Ok(())
+    }
+
     fn check_function_model(&mut self, end: &ast::Location) -> Result<(), Error> {
         if self.current_function_model.len() < 1 {
             return Ok(());
@@ -894,6 +1017,15 @@ impl Symbolic {
     ) -> Result<Symbol, Error> {
         let mut symbol = self.execute_expr(expr)?;
 
+        // arrays, structs with a tail and mut slices can be used where a slice is expected
+        if let Some(mem) = crate::slice::mem(expected_type) {
+            if let Some(init) = self.slice_from(expr, symbol, mem)? {
+                *expr = init;
+                symbol = self.execute_expr(expr)?;
+            }
+            return Ok(symbol);
+        }
+
         if let ast::Type::Other(o) = &self.memory[symbol].typed.t {
             if let Some(has_d) = self.defs.get(o) {
                 if let ast::Type::Other(o2) = &expected_type.t {
@@ -1072,8 +1204,8 @@ impl Symbolic {
             return Ok((self.memory[a].typed.clone(), a, b));
         }
 
-        // if one is an unsigned literal, cast it into the other type
-        if self.memory[a].typed.t == ast::Type::ULiteral {
+        // if one is a literal, cast it into the other type
+        if let ast::Type::ULiteral | ast::Type::ILiteral = self.memory[a].typed.t {
             let tmp = self.temporary(
                 format!("implicit coercion of {}", self.memory[a].name),
                 self.memory[b].typed.clone(),
@@ -1091,7 +1223,7 @@ impl Symbolic {
             return Ok((self.memory[b].typed.clone(), tmp, b));
         }
 
-        if self.memory[b].typed.t == ast::Type::ULiteral {
+        if let ast::Type::ULiteral | ast::Type::ILiteral = self.memory[b].typed.t {
             let tmp = self.temporary(
                 format!("implicit coercion of {}", self.memory[b].name),
                 self.memory[a].typed.clone(),
@@ -1224,7 +1356,11 @@ impl Symbolic {
                     self.tail_into_ssa(sym, loc)?;
 
                     if let Some(assign) = assign {
-                        let sym2 = self.execute_expr(assign)?;
+                        let sym2 = if crate::slice::mem(typed_o).is_some() {
+                            self.autocast(assign, &typed_o.clone())?
+                        } else {
+                            self.execute_expr(assign)?
+                        };
                         self.copy(sym, sym2, loc)?;
                     };
                     if typed_o.t == ast::Type::Elided {
@@ -1341,7 +1477,13 @@ impl Symbolic {
                 }
                 ast::Statement::Return { loc, expr } => {
                     if let Some(expr) = expr {
-                        let e = self.execute_expr(expr)?;
+                        let e = match self.current_function_ret {
+                            Some(retsym) if crate::slice::mem(&self.memory[retsym].typed).is_some() => {
+                                let typed = self.memory[retsym].typed.clone();
+                                self.autocast(expr, &typed)?
+                            }
+                            _ => self.execute_expr(expr)?,
+                        };
                         if let Some(retsym) = self.current_function_ret {
                             self.copy(retsym, e, expr.loc())?;
                         }
@@ -1353,12 +1495,76 @@ impl Symbolic {
                 ast::Statement::Label { .. } => {}
                 ast::Statement::Mark { .. } => {}
                 ast::Statement::Switch {
+                    loc,
                     expr,
                     cases,
                     default,
-                    ..
                 } => {
-                    let switchsym = self.execute_expr(expr)?;
+                    let mut switchsym = self.execute_expr(expr)?;
+
+                    // tags a switch must cover, if it is over an enum or a tagged union
+                    let mut tags = None;
+                    match &self.memory[switchsym].typed.t {
+                        ast::Type::Other(n) if self.memory[switchsym].typed.ptr.is_empty() => match self.defs.get(n) {
+                            Some(ast::Def::Enum { names }) => {
+                                tags = Some((n.clone(), names.iter().map(|(v, _)| v.clone()).collect()));
+                            }
+                            Some(ast::Def::Struct { variants, .. }) if !variants.is_empty() => {
+                                tags = Some((n.clone(), variants.iter().map(|(v, _)| v.clone()).collect()));
+                            }
+                            _ => (),
+                        },
+                        _ => (),
+                    }
+
+                    if let Some((n, names)) = tags {
+                        let names: Vec<String> = names;
+                        if let Some(ast::Def::Struct { .. }) = self.defs.get(&n) {
+                            // the emitter switches over the tag
+                            switchsym = self.member_access(switchsym, "tag", loc)?;
+                            *expr = ast::Expression::MemberAccess {
+                                loc: expr.loc().clone(),
+                                lhs: Box::new(expr.clone()),
+                                op: ".".to_string(),
+                                rhs: "tag".to_string(),
+                            };
+                        }
+
+                        if default.is_none() {
+                            let mut missing = names.clone();
+                            for (conds, _) in cases.iter() {
+                                for cond in conds {
+                                    if let ast::Expression::Name(ast::Typed {
+                                        t: ast::Type::Other(cn),
+                                        ..
+                                    }) = cond
+                                    {
+                                        let mut parent = cn.clone();
+                                        let last = parent.pop();
+                                        if parent == n {
+                                            missing.retain(|v| Some(v) != last.as_ref());
+                                        }
+                                    }
+                                }
+                            }
+                            if !missing.is_empty() {
+                                return Err(self.trace(
+                                    format!("switch over {} is not exhaustive", n),
+                                    vec![(
+                                        loc.clone(),
+                                        format!(
+                                            "missing {}. add them or a default case",
+                                            missing
+                                                .iter()
+                                                .map(|v| format!("{}::{}", n.0.last().unwrap(), v))
+                                                .collect::<Vec<String>>()
+                                                .join(", ")
+                                        ),
+                                    )],
+                                ));
+                            }
+                        }
+                    }
 
                     for (conds, body) in cases {
                         for expr2 in conds {
@@ -1426,6 +1632,7 @@ impl Symbolic {
                     }
                 }
                 ast::Statement::Assign { loc, lhs, op, rhs } => {
+                    self.slice_member_write(lhs)?;
                     let lhs = self.execute_expr(lhs)?;
                     let rhs = self.autocast(rhs, &self.memory[lhs].typed.clone())?;
 
@@ -1439,13 +1646,6 @@ impl Symbolic {
                             self.copy(lhs, rhs, loc)?;
                         }
                     } else {
-                        let tmp = self.temporary(
-                            "assign inter".to_string(),
-                            newtype.clone(),
-                            loc.clone(),
-                            self.memory[lhs].tags.clone(),
-                        )?;
-
                         if newtype.ptr.len() > 0 {
                             return Err(self.trace(
                                 format!("assign arithmetic is not yet implemented"),
@@ -1453,30 +1653,9 @@ impl Symbolic {
                             ));
                         }
 
-                        let infix = match op {
-                            ast::AssignOperator::Bitor => ast::InfixOperator::Bitor,
-                            ast::AssignOperator::Bitand => ast::InfixOperator::Bitand,
-                            ast::AssignOperator::Add => ast::InfixOperator::Add,
-                            ast::AssignOperator::Sub => ast::InfixOperator::Subtract,
-                            ast::AssignOperator::Eq => {
-                                unreachable!();
-                            }
-                        };
-                        let value = Value::InfixOp {
-                            lhs: (lhs, self.memory[lhs].temporal),
-                            rhs: (rhs, self.memory[rhs].temporal),
-                            op: infix.clone(),
-                        };
-                        self.memory[tmp].value = value;
-
-                        self.ssa.infix_op(
-                            tmp,
-                            (lhs, self.memory[lhs].temporal),
-                            (rhs, self.memory[rhs].temporal),
-                            infix,
-                            self.memory[tmp].t.clone(),
-                            newtype.t.signed(),
-                        );
+                        // same checks as the infix form
+                        let infix = op.infix().expect("ice: assign without operator");
+                        let tmp = self.infix(lhs, rhs, &infix, loc)?;
 
                         if self.in_loop {
                             self.memory[lhs].temporal += 1;
@@ -1576,6 +1755,9 @@ impl Symbolic {
                     self.pop();
                 }
                 ast::Statement::Unsafe { .. } => {}
+                ast::Statement::Defer { .. } => {
+                    panic!("ice: defer must be expanded before symbolic execution");
+                }
                 ast::Statement::CBlock { .. } => {}
                 ast::Statement::MacroCall { .. } => {
                     self.incomplete = true;
@@ -1659,7 +1841,7 @@ impl Symbolic {
                         }));
                         called.push(genarg);
                     }
-                    ast::Tail::Dynamic(_) | ast::Tail::None => {
+                    ast::Tail::Dynamic(_) | ast::Tail::None | ast::Tail::Generic(_) => {
                         return Err(self.trace(
                             format!("tail size of {} not bound", self.memory[callptr].name),
                             vec![
@@ -1847,6 +2029,9 @@ impl Symbolic {
                 struct_def = Some((fields.clone(), tail));
             }
         };
+        if let Some(mem) = crate::slice::mem(&self.memory[lhs_sym].typed) {
+            struct_def = Some((crate::slice::fields(mem), &ast::Tail::None));
+        }
 
         let struct_def = match struct_def {
             Some(v) => v,
@@ -1905,6 +2090,14 @@ impl Symbolic {
                 self.memory[lhs_sym].value = Value::Struct {
                     members: HashMap::new(),
                 };
+
+                // a slice from elsewhere was proven to fit into its memory when it was made
+                if crate::slice::mem(&self.memory[lhs_sym].typed).is_some() {
+                    let mem = self.member_access(lhs_sym, "mem", loc)?;
+                    let len = self.member_access(lhs_sym, "len", loc)?;
+                    self.slice_bound(mem, len, loc, true)?;
+                    return self.member_access(lhs_sym, rhs, loc);
+                }
             }
             o => {
                 return Err(self.trace(
@@ -1974,11 +2167,17 @@ impl Symbolic {
             _ => {}
         }
 
+        // members of mutable memory are mutable
+        let mut tags = field.1.tags.clone();
+        if self.memory[lhs_sym].tags.contains("mut") {
+            tags.insert("mut".to_string(), String::new(), loc.clone());
+        }
+
         let tmp = self.temporary(
             format!("{}.{}", self.memory[lhs_sym].name, rhs),
             fieldtyped,
             loc.clone(),
-            field.1.tags.clone(),
+            tags,
         )?;
 
         match (&fieldvalue, &field.1.array) {
@@ -2003,6 +2202,335 @@ impl Symbolic {
         Ok(tmp)
     }
 
+    /// lhs op rhs into a new temporary
+    fn infix(
+        &mut self,
+        lhs_sym: Symbol,
+        rhs_sym: Symbol,
+        op: &ast::InfixOperator,
+        loc: &ast::Location,
+    ) -> Result<Symbol, Error> {
+        let (mut newtype, lhs_sym, rhs_sym) = self.type_coersion(lhs_sym, rhs_sym, loc)?;
+
+        if !op.takes_boolean() && newtype.t == ast::Type::Bool {
+            return Err(self.trace(
+                format!("invalid types for integer operator"),
+                vec![(loc.clone(), format!("not defined for type {}", newtype))],
+            ));
+        } else if !op.takes_integer() && newtype.t != ast::Type::Bool {
+            return Err(self.trace(
+                format!("invalid types for boolean operator"),
+                vec![(loc.clone(), format!("not defined for type {}", newtype))],
+            ));
+        }
+
+        /* TODO too noisy
+        if let ast::Type::Other(o) = &newtype.t {
+            match format!("{}", o).as_str() {
+                "::ext::<stddef.h>::char" => (),
+                _ => if newtype.ptr.len() == 0 {
+                    return Err(self.trace(format!("unprovable types for expression"), vec![
+                        (loc.clone(), format!("not defined for type {}. consider casting to a builtin type", newtype))
+                    ]))
+                }
+            }
+        }
+        */
+        let signed = newtype.t.signed();
+        if op.returns_boolean() {
+            newtype = ast::Typed {
+                t: ast::Type::Bool,
+                ptr: Vec::new(),
+                loc: loc.clone(),
+                tail: ast::Tail::None,
+            };
+        };
+
+        if newtype.t.signed() && *op == crate::ast::InfixOperator::Shiftright {
+            return Err(self.trace(format!("shift right of signed value is unprovable"), vec![
+                (loc.clone(), format!("compiler specific behaviour is not allowed because it is not provable"))
+            ]));
+        }
+
+        let tmp = self.temporary(
+            format!("infix expression"),
+            newtype.clone(),
+            loc.clone(),
+            Tags::new(),
+        )?;
+
+        // pointer arithmetic
+        if newtype.ptr.len() > 0 {
+            self.ssa.debug("begin pointer arithmetic");
+            let len_of_lhs = self.temporary(
+                format!("len({})", self.memory[lhs_sym].name),
+                ast::Typed {
+                    t: ast::Type::USize,
+                    ptr: Vec::new(),
+                    loc: loc.clone(),
+                    tail: ast::Tail::None,
+                },
+                loc.clone(),
+                Tags::new(),
+            )?;
+            let lensym = self
+                .builtin
+                .get("len")
+                .expect("ICE: len theory not built in");
+            self.ssa.invocation(
+                *lensym,
+                vec![(lhs_sym, self.memory[lhs_sym].temporal)],
+                (len_of_lhs, 0),
+            );
+
+            let len_assert = self.temporary(
+                format!(
+                    "{} < len({})",
+                    self.memory[rhs_sym].name, self.memory[lhs_sym].name
+                ),
+                ast::Typed {
+                    t: ast::Type::Bool,
+                    ptr: Vec::new(),
+                    loc: loc.clone(),
+                    tail: ast::Tail::None,
+                },
+                loc.clone(),
+                Tags::new(),
+            )?;
+            self.memory[len_assert].value = Value::InfixOp {
+                lhs: (rhs_sym, self.memory[rhs_sym].temporal),
+                rhs: (len_of_lhs, self.memory[len_of_lhs].temporal),
+                op: ast::InfixOperator::Lessthan,
+            };
+            self.ssa.infix_op(
+                len_assert,
+                (rhs_sym, self.memory[rhs_sym].temporal),
+                (len_of_lhs, 0),
+                ast::InfixOperator::Lessthan,
+                smt::Type::Bool,
+                false,
+            );
+            self.ssa.debug("assert that length less than index is true");
+            self.ssa.assert(
+                vec![(len_assert, self.memory[len_assert].temporal)],
+                |a, model| match a {
+                    false => {
+                        let mut estack = Vec::new();
+                        estack.extend(self.demonstrate(
+                            model.as_ref().unwrap(),
+                            (len_assert, self.memory[len_assert].temporal),
+                            0,
+                        ));
+                        Err(self.trace(
+                            format!("possible out of bounds pointer arithmetic"),
+                            estack,
+                        ))
+                    }
+                    true => Ok(()),
+                },
+            )?;
+            self.ssa_mark_safe(tmp, loc)?;
+
+            let len_of_opresult = self.temporary(
+                format!("len({})", self.memory[lhs_sym].name),
+                ast::Typed {
+                    t: ast::Type::USize,
+                    ptr: Vec::new(),
+                    loc: loc.clone(),
+                    tail: ast::Tail::None,
+                },
+                loc.clone(),
+                Tags::new(),
+            )?;
+            let lensym = self
+                .builtin
+                .get("len")
+                .expect("ICE: len theory not built in");
+            self.ssa.invocation(
+                *lensym,
+                vec![(tmp, self.memory[tmp].temporal)],
+                (len_of_opresult, 0),
+            );
+
+            let opposite_op = match op {
+                ast::InfixOperator::Add => ast::InfixOperator::Subtract,
+
+                //TODO need to check for wrap
+                //ast::InfixOperator::Subtract => ast::InfixOperator::Add,
+                _ => {
+                    return Err(self.trace(
+                        format!("unprovable pointer arithmetic"),
+                        vec![(loc.clone(), format!("only + is possible"))],
+                    ));
+                }
+            };
+
+            self.ssa.infix_op(
+                len_of_opresult,
+                (len_of_lhs, self.memory[len_of_lhs].temporal),
+                (rhs_sym, self.memory[rhs_sym].temporal),
+                opposite_op,
+                self.memory[tmp].t.clone(),
+                signed,
+            );
+
+            let mut value = Value::Unconstrained("pointer arithmetic".into());
+            if let Value::Array { .. } = &self.memory[lhs_sym].value {
+                if let Some(nuval) = self.ssa.value(
+                    (len_of_opresult, self.memory[len_of_opresult].temporal),
+                    |a, _| match a {
+                        smt::Assertion::Constrained(val) => {
+                            let nuarray = HashMap::new();
+                            Some(Value::Array {
+                                len: val as usize,
+                                array: nuarray,
+                            })
+                        }
+                        _ => None,
+                    },
+                ) {
+                    value = nuval;
+                }
+            };
+            self.memory[tmp].value = value;
+
+            return Ok(tmp);
+        }
+
+        self.infix_undefined(lhs_sym, rhs_sym, op, loc)?;
+
+        let value = Value::InfixOp {
+            lhs: (lhs_sym, self.memory[lhs_sym].temporal),
+            rhs: (rhs_sym, self.memory[rhs_sym].temporal),
+            op: op.clone(),
+        };
+        self.memory[tmp].value = value;
+
+        self.ssa.infix_op(
+            tmp,
+            (lhs_sym, self.memory[lhs_sym].temporal),
+            (rhs_sym, self.memory[rhs_sym].temporal),
+            op.clone(),
+            self.memory[tmp].t.clone(),
+            signed,
+        );
+        Ok(tmp)
+    }
+
+    /// prove that an integer infix op is defined in C:
+    /// no division by zero, no INT_MIN / -1 and no shift by the bit width or more
+    fn infix_undefined(
+        &mut self,
+        lhs_sym: Symbol,
+        rhs_sym: Symbol,
+        op: &ast::InfixOperator,
+        loc: &ast::Location,
+    ) -> Result<(), Error> {
+        let (signed, size) = match self.memory[lhs_sym].t {
+            smt::Type::Signed(size) => (true, size),
+            smt::Type::Unsigned(size) => (false, size),
+            smt::Type::Bool => return Ok(()),
+        };
+
+        match op {
+            ast::InfixOperator::Divide | ast::InfixOperator::Modulo => {
+                let what = if *op == ast::InfixOperator::Divide {
+                    "division"
+                } else {
+                    "modulo"
+                };
+                let nonzero =
+                    self.infix_literal(rhs_sym, 0, ast::InfixOperator::Nequals, loc)?;
+                self.assert_defined(nonzero, format!("possible {} by zero", what))?;
+
+                if signed {
+                    let min = 1u64 << (size - 1);
+                    let minus_one = if size == 64 {
+                        u64::MAX
+                    } else {
+                        (1u64 << size) - 1
+                    };
+                    let notmin =
+                        self.infix_literal(lhs_sym, min, ast::InfixOperator::Nequals, loc)?;
+                    let notminus =
+                        self.infix_literal(rhs_sym, minus_one, ast::InfixOperator::Nequals, loc)?;
+                    let defined = self.infix_bool(notmin, notminus, ast::InfixOperator::Boolor, loc)?;
+                    self.assert_defined(
+                        defined,
+                        format!("possible signed overflow in {} of the smallest value by -1", what),
+                    )?;
+                }
+            }
+            ast::InfixOperator::Shiftleft | ast::InfixOperator::Shiftright => {
+                let inrange =
+                    self.infix_literal(rhs_sym, size as u64, ast::InfixOperator::Lessthan, loc)?;
+                self.assert_defined(
+                    inrange,
+                    format!("possible shift by {} bits or more", size),
+                )?;
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// a boolean temporary for sym op literal, compared unsigned
+    fn infix_literal(
+        &mut self,
+        sym: Symbol,
+        v: u64,
+        op: ast::InfixOperator,
+        loc: &ast::Location,
+    ) -> Result<Symbol, Error> {
+        let lit = self.literal(loc, Value::Integer(v), self.memory[sym].typed.clone())?;
+        self.infix_bool(sym, lit, op, loc)
+    }
+
+    fn infix_bool(
+        &mut self,
+        lhs: Symbol,
+        rhs: Symbol,
+        op: ast::InfixOperator,
+        loc: &ast::Location,
+    ) -> Result<Symbol, Error> {
+        let tmp = self.temporary(
+            format!("{} {} {}", self.memory[lhs].name, op, self.memory[rhs].name),
+            ast::Typed {
+                t: ast::Type::Bool,
+                ptr: Vec::new(),
+                loc: loc.clone(),
+                tail: ast::Tail::None,
+            },
+            loc.clone(),
+            Tags::new(),
+        )?;
+        self.memory[tmp].value = Value::InfixOp {
+            lhs: (lhs, self.memory[lhs].temporal),
+            rhs: (rhs, self.memory[rhs].temporal),
+            op: op.clone(),
+        };
+        self.ssa.infix_op(
+            tmp,
+            (lhs, self.memory[lhs].temporal),
+            (rhs, self.memory[rhs].temporal),
+            op,
+            smt::Type::Bool,
+            false,
+        );
+        Ok(tmp)
+    }
+
+    fn assert_defined(&mut self, sym: Symbol, message: String) -> Result<(), Error> {
+        let sym = (sym, self.memory[sym].temporal);
+        self.ssa.assert(vec![sym], |a, model| match a {
+            false => {
+                let estack = self.demonstrate(model.as_ref().unwrap(), sym, 0);
+                Err(self.trace(message.clone(), estack))
+            }
+            true => Ok(()),
+        })
+    }
+
     fn execute_expr(&mut self, expr: &mut ast::Expression) -> Result<Symbol, Error> {
         let exprloc = expr.loc().clone();
         self.ssa.debug_loc(expr.loc());
@@ -2056,6 +2584,8 @@ impl Symbolic {
                     lhs_sym = self.deref(lhs_sym, loc)?;
                 }
 
+                self.assert_variant(lhs_sym, rhs, loc)?;
+
                 match self.member_access(lhs_sym, rhs, loc) {
                     Ok(v) => Ok(v),
                     Err(e) => {
@@ -2107,7 +2637,19 @@ impl Symbolic {
                 }
             }
             ast::Expression::ArrayAccess { lhs, rhs, loc } => {
-                let lhs_sym = self.execute_expr(lhs)?;
+                let mut lhs_sym = self.execute_expr(lhs)?;
+
+                // s[i] of a slice is s.mem[i]
+                if crate::slice::mem(&self.memory[lhs_sym].typed).is_some() {
+                    lhs_sym = self.member_access(lhs_sym, "mem", loc)?;
+                    *lhs = Box::new(ast::Expression::MemberAccess {
+                        loc: loc.clone(),
+                        lhs: lhs.clone(),
+                        op: ".".to_string(),
+                        rhs: "mem".to_string(),
+                    });
+                }
+
                 let rhs_sym = self.execute_expr(rhs)?;
 
                 if self.memory[rhs_sym].typed.t.signed() {
@@ -2319,6 +2861,15 @@ impl Symbolic {
                         tail: ast::Tail::None,
                     };
                     self.literal(loc, Value::Integer(v), t)
+                } else if let Ok(v) = v.parse::<i64>() {
+                    // a negative literal, like a folded constant. coercion truncates the two's complement
+                    let t = ast::Typed {
+                        t: ast::Type::ILiteral,
+                        loc: loc.clone(),
+                        ptr: Vec::new(),
+                        tail: ast::Tail::None,
+                    };
+                    self.literal(loc, Value::Integer(v as u64), t)
                 } else {
                     let t = ast::Typed {
                         t: ast::Type::ULiteral,
@@ -2333,208 +2884,170 @@ impl Symbolic {
             ast::Expression::Infix { lhs, rhs, loc, op } => {
                 let lhs_sym = self.execute_expr(lhs)?;
                 let rhs_sym = self.execute_expr(rhs)?;
+                self.infix(lhs_sym, rhs_sym, op, loc)
+            }
+            ast::Expression::Slice { loc, lhs, from, to } => {
+                for expr in std::iter::once(&**lhs).chain(from.iter().chain(to.iter()).map(|e| &**e)) {
+                    if !crate::slice::pure(expr) {
+                        return Err(self.trace(
+                            format!("slice range with side effects"),
+                            vec![(
+                                expr.loc().clone(),
+                                format!("this may be evaluated more than once. assign it to a local first"),
+                            )],
+                        ));
+                    }
+                }
 
-                let (mut newtype, lhs_sym, rhs_sym) = self.type_coersion(lhs_sym, rhs_sym, loc)?;
-
-                if !op.takes_boolean() && newtype.t == ast::Type::Bool {
-                    return Err(self.trace(
-                        format!("invalid types for integer operator"),
-                        vec![(loc.clone(), format!("not defined for type {}", newtype))],
-                    ));
-                } else if !op.takes_integer() && newtype.t != ast::Type::Bool {
+                let lhs_sym = self.execute_expr(lhs)?;
+                let typed = self.memory[lhs_sym].typed.clone();
+
+                // the memory of the new slice and the len of what is sliced, if it is known
+                let (mem, mem_expr, len_expr) = if let Some(mem) = crate::slice::mem(&typed) {
+                    let member = |rhs: &str| ast::Expression::MemberAccess {
+                        loc: loc.clone(),
+                        lhs: lhs.clone(),
+                        op: ".".to_string(),
+                        rhs: rhs.to_string(),
+                    };
+                    (mem.clone(), member("mem"), Some(member("len")))
+                } else if !typed.ptr.is_empty() {
+                    let mut mem = typed.clone();
+                    mem.tail = ast::Tail::None;
+                    if self.mutable_mem(lhs_sym) {
+                        if let Some(ptr) = mem.ptr.last_mut() {
+                            ptr.tags.insert("mut".to_string(), String::new(), loc.clone());
+                        }
+                    }
+                    let len = match self.memory[lhs_sym].value {
+                        Value::Array { len, .. } if len > 0 => Some(ast::Expression::Literal {
+                            loc: loc.clone(),
+                            v: format!("{}", len),
+                        }),
+                        _ => None,
+                    };
+                    (mem, (**lhs).clone(), len)
+                } else {
                     return Err(self.trace(
-                        format!("invalid types for boolean operator"),
-                        vec![(loc.clone(), format!("not defined for type {}", newtype))],
+                        format!("cannot slice {}", typed),
+                        vec![(lhs.loc().clone(), format!("expected a slice, array or pointer"))],
                     ));
+                };
+
+                let from_sym = match from {
+                    Some(from) => Some(self.slice_index(from)?),
+                    None => None,
+                };
+                let to_sym = match to {
+                    Some(to) => Some(self.slice_index(to)?),
+                    None => None,
+                };
+                if let (Some(from_sym), Some(to_sym)) = (from_sym, to_sym) {
+                    let cond = self.infix(from_sym, to_sym, &ast::InfixOperator::Lesseq, loc)?;
+                    self.assert_slice(cond, "slice start may be behind its end", loc)?;
                 }
 
-                /* TODO too noisy
-                if let ast::Type::Other(o) = &newtype.t {
-                    match format!("{}", o).as_str() {
-                        "::ext::<stddef.h>::char" => (),
-                        _ => if newtype.ptr.len() == 0 {
-                            return Err(self.trace(format!("unprovable types for expression"), vec![
-                                (loc.clone(), format!("not defined for type {}. consider casting to a builtin type", newtype))
-                            ]))
-                        }
+                // a sub slice stays within the len of its slice, even if the memory is longer
+                if crate::slice::mem(&typed).is_some() {
+                    if let Some(end) = to_sym.or(from_sym) {
+                        let len_sym = self.member_access(lhs_sym, "len", loc)?;
+                        let cond = self.infix(end, len_sym, &ast::InfixOperator::Lesseq, loc)?;
+                        self.assert_slice(cond, "slice range may be out of bounds", loc)?;
                     }
                 }
-                */
-                let signed = newtype.t.signed();
-                if op.returns_boolean() {
-                    newtype = ast::Typed {
-                        t: ast::Type::Bool,
-                        ptr: Vec::new(),
+
+                let end = match (to, len_expr) {
+                    (Some(to), _) => (**to).clone(),
+                    (None, Some(len)) => len,
+                    (None, None) => {
+                        return Err(self.trace(
+                            format!("slice of a pointer needs an end"),
+                            vec![(loc.clone(), format!("the len of {} is unknown here. use [..n]", typed))],
+                        ));
+                    }
+                };
+                let fields = match from {
+                    Some(from) => vec![
+                        ("mem".to_string(), Box::new(ast::Expression::Infix {
+                            loc: loc.clone(),
+                            lhs: Box::new(mem_expr),
+                            rhs: from.clone(),
+                            op: ast::InfixOperator::Add,
+                        })),
+                        ("len".to_string(), Box::new(ast::Expression::Infix {
+                            loc: loc.clone(),
+                            lhs: Box::new(end),
+                            rhs: from.clone(),
+                            op: ast::InfixOperator::Subtract,
+                        })),
+                    ],
+                    None => vec![
+                        ("mem".to_string(), Box::new(mem_expr)),
+                        ("len".to_string(), Box::new(end)),
+                    ],
+                };
+
+                *expr = ast::Expression::StructInit {
+                    loc: loc.clone(),
+                    typed: ast::Typed {
+                        t: ast::Type::Slice(Box::new(mem)),
                         loc: loc.clone(),
+                        ptr: Vec::new(),
                         tail: ast::Tail::None,
-                    };
+                    },
+                    fields,
                 };
-
-                if newtype.t.signed() && *op == crate::ast::InfixOperator::Shiftright {
-                    return Err(self.trace(format!("shift right of signed value is unprovable"), vec![
-                        (loc.clone(), format!("compiler specific behaviour is not allowed because it is not provable"))
-                    ]));
+                self.execute_expr(expr)
+            }
+            ast::Expression::Ternary {
+                loc,
+                cond,
+                then,
+                otherwise,
+            } => {
+                let cond_sym = self.execute_expr(cond)?;
+                if self.memory[cond_sym].typed.t != ast::Type::Bool {
+                    return Err(self.trace(
+                        format!("expected boolean, got {}", self.memory[cond_sym].typed),
+                        vec![(
+                            cond.loc().clone(),
+                            format!("coercion to boolean is difficult to prove"),
+                        )],
+                    ));
                 }
+                let cond_sym = (cond_sym, self.memory[cond_sym].temporal);
+                self.cur().trace.push((cond_sym, cond.loc().clone(), false));
+
+                // each side only executes under its path condition
+                self.push("ternary then".to_string());
+                self.ssa.branch();
+                self.ssa.constrain_branch(cond_sym, true);
+                let then_sym = self.execute_expr(then)?;
+                self.ssa.unbranch(false);
+                self.pop();
+
+                self.push("ternary else".to_string());
+                self.ssa.branch();
+                self.ssa.constrain_branch(cond_sym, false);
+                let otherwise_sym = self.execute_expr(otherwise)?;
+                self.ssa.unbranch(false);
+                self.pop();
+
+                let (newtype, then_sym, otherwise_sym) =
+                    self.type_coersion(then_sym, otherwise_sym, loc)?;
 
                 let tmp = self.temporary(
-                    format!("infix expression"),
-                    newtype.clone(),
+                    format!("ternary expression"),
+                    newtype,
                     loc.clone(),
                     Tags::new(),
                 )?;
-
-                // pointer arithmetic
-                if newtype.ptr.len() > 0 {
-                    self.ssa.debug("begin pointer arithmetic");
-                    let len_of_lhs = self.temporary(
-                        format!("len({})", self.memory[lhs_sym].name),
-                        ast::Typed {
-                            t: ast::Type::USize,
-                            ptr: Vec::new(),
-                            loc: loc.clone(),
-                            tail: ast::Tail::None,
-                        },
-                        loc.clone(),
-                        Tags::new(),
-                    )?;
-                    let lensym = self
-                        .builtin
-                        .get("len")
-                        .expect("ICE: len theory not built in");
-                    self.ssa.invocation(
-                        *lensym,
-                        vec![(lhs_sym, self.memory[lhs_sym].temporal)],
-                        (len_of_lhs, 0),
-                    );
-
-                    let len_assert = self.temporary(
-                        format!(
-                            "{} < len({})",
-                            self.memory[rhs_sym].name, self.memory[lhs_sym].name
-                        ),
-                        ast::Typed {
-                            t: ast::Type::Bool,
-                            ptr: Vec::new(),
-                            loc: loc.clone(),
-                            tail: ast::Tail::None,
-                        },
-                        loc.clone(),
-                        Tags::new(),
-                    )?;
-                    self.memory[len_assert].value = Value::InfixOp {
-                        lhs: (rhs_sym, self.memory[rhs_sym].temporal),
-                        rhs: (len_of_lhs, self.memory[len_of_lhs].temporal),
-                        op: ast::InfixOperator::Lessthan,
-                    };
-                    self.ssa.infix_op(
-                        len_assert,
-                        (rhs_sym, self.memory[rhs_sym].temporal),
-                        (len_of_lhs, 0),
-                        ast::InfixOperator::Lessthan,
-                        smt::Type::Bool,
-                        false,
-                    );
-                    self.ssa.debug("assert that length less than index is true");
-                    self.ssa.assert(
-                        vec![(len_assert, self.memory[len_assert].temporal)],
-                        |a, model| match a {
-                            false => {
-                                let mut estack = Vec::new();
-                                estack.extend(self.demonstrate(
-                                    model.as_ref().unwrap(),
-                                    (len_assert, self.memory[len_assert].temporal),
-                                    0,
-                                ));
-                                Err(self.trace(
-                                    format!("possible out of bounds pointer arithmetic"),
-                                    estack,
-                                ))
-                            }
-                            true => Ok(()),
-                        },
-                    )?;
-                    self.ssa_mark_safe(tmp, loc)?;
-
-                    let len_of_opresult = self.temporary(
-                        format!("len({})", self.memory[lhs_sym].name),
-                        ast::Typed {
-                            t: ast::Type::USize,
-                            ptr: Vec::new(),
-                            loc: loc.clone(),
-                            tail: ast::Tail::None,
-                        },
-                        loc.clone(),
-                        Tags::new(),
-                    )?;
-                    let lensym = self
-                        .builtin
-                        .get("len")
-                        .expect("ICE: len theory not built in");
-                    self.ssa.invocation(
-                        *lensym,
-                        vec![(tmp, self.memory[tmp].temporal)],
-                        (len_of_opresult, 0),
-                    );
-
-                    let opposite_op = match op {
-                        ast::InfixOperator::Add => ast::InfixOperator::Subtract,
-
-                        //TODO need to check for wrap
-                        //ast::InfixOperator::Subtract => ast::InfixOperator::Add,
-                        _ => {
-                            return Err(self.trace(
-                                format!("unprovable pointer arithmetic"),
-                                vec![(expr.loc().clone(), format!("only + is possible"))],
-                            ));
-                        }
-                    };
-
-                    self.ssa.infix_op(
-                        len_of_opresult,
-                        (len_of_lhs, self.memory[len_of_lhs].temporal),
-                        (rhs_sym, self.memory[rhs_sym].temporal),
-                        opposite_op,
-                        self.memory[tmp].t.clone(),
-                        signed,
-                    );
-
-                    let mut value = Value::Unconstrained("pointer arithmetic".into());
-                    if let Value::Array { .. } = &self.memory[lhs_sym].value {
-                        if let Some(nuval) = self.ssa.value(
-                            (len_of_opresult, self.memory[len_of_opresult].temporal),
-                            |a, _| match a {
-                                smt::Assertion::Constrained(val) => {
-                                    let nuarray = HashMap::new();
-                                    Some(Value::Array {
-                                        len: val as usize,
-                                        array: nuarray,
-                                    })
-                                }
-                                _ => None,
-                            },
-                        ) {
-                            value = nuval;
-                        }
-                    };
-                    self.memory[tmp].value = value;
-
-                    return Ok(tmp);
-                }
-
-                let value = Value::InfixOp {
-                    lhs: (lhs_sym, self.memory[lhs_sym].temporal),
-                    rhs: (rhs_sym, self.memory[rhs_sym].temporal),
-                    op: op.clone(),
-                };
-                self.memory[tmp].value = value;
-
-                self.ssa.infix_op(
-                    tmp,
-                    (lhs_sym, self.memory[lhs_sym].temporal),
-                    (rhs_sym, self.memory[rhs_sym].temporal),
-                    op.clone(),
+                self.ssa.ite(
+                    (tmp, self.memory[tmp].temporal),
+                    cond_sym,
+                    (then_sym, self.memory[then_sym].temporal),
+                    (otherwise_sym, self.memory[otherwise_sym].temporal),
                     self.memory[tmp].t.clone(),
-                    signed,
                 );
                 Ok(tmp)
             }
@@ -2556,6 +3069,7 @@ impl Symbolic {
                 Ok(tmp)
             }
             ast::Expression::UnaryPost { expr, op, loc } => {
+                self.slice_member_write(expr)?;
                 let lhs_sym = self.execute_expr(expr)?;
 
                 let tmp = self.temporary(
@@ -2674,6 +3188,7 @@ impl Symbolic {
                     }
                     crate::ast::PrefixOperator::Increment
                     | crate::ast::PrefixOperator::Decrement => {
+                        self.slice_member_write(expr)?;
                         let rhs_sym = self.execute_expr(expr)?;
                         if self.memory[rhs_sym].t == smt::Type::Bool {
                             return Err(self.trace(
@@ -2716,6 +3231,11 @@ impl Symbolic {
                     loc.clone(),
                     Tags::new(),
                 )?;
+                if let Some(mem) = crate::slice::mem(typed) {
+                    let mem = mem.clone();
+                    return self.slice_init(aptr, &mem, initfields, loc);
+                }
+
                 let mut members = HashMap::new();
 
                 if let ast::Type::Other(o) = &typed.t {
@@ -2856,6 +3376,19 @@ impl Symbolic {
                 }
                 // shortcut, as calls to z3 are expensive and can pile up to unsolveable mess
                 let sym = self.execute_expr(&mut args[0])?;
+
+                // len(s) of a slice is s.len
+                if crate::slice::mem(&self.memory[sym].typed).is_some() {
+                    let r = self.member_access(sym, "len", loc);
+                    *expr = ast::Expression::MemberAccess {
+                        loc: loc.clone(),
+                        lhs: args[0].clone(),
+                        op: ".".to_string(),
+                        rhs: "len".to_string(),
+                    };
+                    self.current_call.pop();
+                    return r;
+                }
                 if let Value::Array { len, .. } = self.memory[sym].value {
                     if len > 0 {
                         let r = self.literal(
@@ -3252,6 +3785,11 @@ impl Symbolic {
                                 borrow = true;
                             }
                         }
+                        if let Some(mem) = crate::slice::mem(&farg.typed) {
+                            if crate::slice::mutable(mem) {
+                                borrow = true;
+                            }
+                        }
                     } else {
                         borrow = true;
                     }
@@ -3274,6 +3812,9 @@ impl Symbolic {
                 )?;
                 let value = Value::Unconstrained("return value".to_string());
                 self.memory[return_sym].value = value;
+                if self.memory[return_sym].typed.nullable() {
+                    self.ssa_mark_nullable(return_sym, loc)?;
+                }
 
                 //dont expose any symbols during callsite effect
                 let global_only = vec![self.stack[0].clone()];
@@ -3350,12 +3891,33 @@ impl Symbolic {
                 for arg in args {
                     self.execute_expr(arg)?;
                 }
+                let sizeof = self.memory[name_sym].typed.t
+                    == ast::Type::Other(Name::from("::ext::<stddef.h>::sizeof"));
+                // C does not say if a pointer may be null, so it may
+                let mut typed = self.memory[name_sym].typed.clone();
+                if sizeof {
+                    typed.t = ast::Type::USize;
+                } else if typed.ptr.is_empty() {
+                    let mut tags = Tags::new();
+                    tags.insert("nullable".to_string(), String::new(), loc.clone());
+                    typed.ptr.push(ast::Pointer {
+                        loc: loc.clone(),
+                        tags,
+                    });
+                }
                 let tmp = self.temporary(
                     format!("return value of {}", self.memory[name_sym].name),
-                    self.memory[name_sym].typed.clone(),
+                    typed,
                     loc.clone(),
                     Tags::new(),
                 )?;
+                if self.memory[tmp].typed.nullable() {
+                    self.ssa_mark_nullable(tmp, loc)?;
+                }
+                // every object is at least one byte, so sizeof can be divided by
+                if sizeof {
+                    self.ssa_mark_nonzero(tmp, loc)?;
+                }
                 self.current_call.pop();
                 Ok(tmp)
             }
@@ -3390,11 +3952,49 @@ impl Symbolic {
                     self.memory[*to].t.clone(),
                 );
             }
+            // a slice lends out its memory, which may be an offset into the original
+            Value::Struct { members } if crate::slice::mem(&self.memory[sym].typed).is_some() => {
+                if let Some(mem) = members.get("mem") {
+                    match &self.memory[*mem].value {
+                        Value::InfixOp { lhs, .. } => self.borrow_away(lhs.0),
+                        _ => self.borrow_away(*mem),
+                    }
+                }
+            }
             _ => (),
         }
     }
 
     fn assert_safe(&mut self, lhs_sym: Symbol, loc: &ast::Location) -> Result<(), Error> {
+        self.prove_safe(lhs_sym, loc, format!("deref of unsafe pointer"))
+    }
+
+    /// a nullable pointer becomes a non null T* only after a null check
+    fn assert_nonnull(
+        &mut self,
+        sym: Symbol,
+        typed: &ast::Typed,
+        loc: &ast::Location,
+    ) -> Result<(), Error> {
+        if typed.ptr.is_empty() || typed.nullable() {
+            return Ok(());
+        }
+        self.prove_safe(
+            sym,
+            loc,
+            format!(
+                "{} may be null, but is used as {}",
+                self.memory[sym].name, typed
+            ),
+        )
+    }
+
+    fn prove_safe(
+        &mut self,
+        lhs_sym: Symbol,
+        loc: &ast::Location,
+        message: String,
+    ) -> Result<(), Error> {
         self.ssa.debug("begin safe ptr check");
         let tmp1 = self.temporary(
             format!("safe({})", self.memory[lhs_sym].name),
@@ -3429,7 +4029,7 @@ impl Symbolic {
                         (tmp1, self.memory[tmp1].temporal),
                         0,
                     ));
-                    Err(self.trace(format!("deref of unsafe pointer"), estack))
+                    Err(self.trace(message.clone(), estack))
                 }
                 true => Ok(()),
             },
@@ -3437,6 +4037,402 @@ impl Symbolic {
         return Ok(());
     }
 
+    /// the payload of a tagged union can only be accessed while its variant is the active one
+    fn assert_variant(
+        &mut self,
+        lhs_sym: Symbol,
+        rhs: &str,
+        loc: &ast::Location,
+    ) -> Result<(), Error> {
+        let (tname, value) = match &self.memory[lhs_sym].typed.t {
+            ast::Type::Other(n) if self.memory[lhs_sym].typed.ptr.is_empty() => {
+                match self.defs.get(n) {
+                    Some(ast::Def::Struct { variants, .. }) => {
+                        match variants.iter().find(|(v, _)| v == rhs) {
+                            Some((_, value)) => (n.clone(), *value),
+                            None => return Ok(()),
+                        }
+                    }
+                    _ => return Ok(()),
+                }
+            }
+            _ => return Ok(()),
+        };
+
+        // same as deref, model calls are not checked in between
+        if self.in_model {
+            return Ok(());
+        }
+
+        let tagsym = self.member_access(lhs_sym, "tag", loc)?;
+        let valsym = self.literal(
+            loc,
+            Value::Integer(value),
+            ast::Typed {
+                t: ast::Type::ULiteral,
+                loc: loc.clone(),
+                ptr: Vec::new(),
+                tail: ast::Tail::None,
+            },
+        )?;
+        let (_, tagsym, valsym) = self.type_coersion(tagsym, valsym, loc)?;
+
+        let tmp = self.temporary(
+            format!("{}.tag == {}", self.memory[lhs_sym].name, rhs),
+            ast::Typed {
+                t: ast::Type::Bool,
+                ptr: Vec::new(),
+                loc: loc.clone(),
+                tail: ast::Tail::None,
+            },
+            loc.clone(),
+            Tags::new(),
+        )?;
+        self.ssa.infix_op(
+            tmp,
+            (tagsym, self.memory[tagsym].temporal),
+            (valsym, self.memory[valsym].temporal),
+            ast::InfixOperator::Equals,
+            self.memory[tmp].t.clone(),
+            false,
+        );
+
+        self.ssa.assert(
+            vec![(tmp, self.memory[tmp].temporal)],
+            |a, model| match a {
+                false => {
+                    let mut estack = vec![(
+                        loc.clone(),
+                        format!(
+                            "you may need a switch or an if condition to prove {} is the active variant",
+                            rhs
+                        ),
+                    )];
+                    estack.extend(self.demonstrate(
+                        model.as_ref().unwrap(),
+                        (tmp, self.memory[tmp].temporal),
+                        0,
+                    ));
+                    Err(self.trace(
+                        format!("access to variant {} of {} which may not be active", rhs, tname),
+                        estack,
+                    ))
+                }
+                true => Ok(()),
+            },
+        )
+    }
+
+    /// prove a condition of a slice
+    fn assert_slice(&mut self, cond: Symbol, message: &str, loc: &ast::Location) -> Result<(), Error> {
+        let cond = (cond, self.memory[cond].temporal);
+        self.ssa.assert(vec![cond], |a, model| match a {
+            false => {
+                let mut estack = vec![(
+                    loc.clone(),
+                    format!("you may need an if condition to prove it"),
+                )];
+                if let Some(model) = &model {
+                    estack.extend(self.demonstrate(model, cond, 0));
+                }
+                Err(self.trace(message.to_string(), estack))
+            }
+            true => Ok(()),
+        })
+    }
+
+    /// a new symbol for len(sym)
+    fn len_of(&mut self, sym: Symbol, loc: &ast::Location) -> Result<Symbol, Error> {
+        let tmp = self.temporary(
+            format!("len({})", self.memory[sym].name),
+            ast::Typed {
+                t: ast::Type::USize,
+                ptr: Vec::new(),
+                loc: loc.clone(),
+                tail: ast::Tail::None,
+            },
+            loc.clone(),
+            Tags::new(),
+        )?;
+        let lensym = self
+            .builtin
+            .get("len")
+            .expect("ICE: len theory not built in");
+        self.ssa.invocation(
+            *lensym,
+            vec![(sym, self.memory[sym].temporal)],
+            (tmp, 0),
+        );
+        Ok(tmp)
+    }
+
+    /// len(mem) >= len of a slice.
+    /// proven when a slice is made, so it can be attested wherever a slice comes from elsewhere
+    fn slice_bound(
+        &mut self,
+        mem: Symbol,
+        len: Symbol,
+        loc: &ast::Location,
+        attest: bool,
+    ) -> Result<(), Error> {
+        let len_of_mem = self.len_of(mem, loc)?;
+        let cond = self.infix(len, len_of_mem, &ast::InfixOperator::Lesseq, loc)?;
+        if !attest {
+            return self.assert_slice(cond, "slice may be longer than its memory", loc);
+        }
+        if !self.ssa.attest((cond, self.memory[cond].temporal), true) {
+            return Err(self.trace(
+                format!("slice breaks ssa"),
+                vec![(loc.clone(), format!("there may be conflicting constraints"))],
+            ));
+        }
+        Ok(())
+    }
+
+    /// mem + from of a slice. unlike pointer arithmetic, this may point right behind the memory,
+    /// because the slice is empty then
+    fn slice_offset(
+        &mut self,
+        mem: Symbol,
+        from: Symbol,
+        loc: &ast::Location,
+    ) -> Result<Symbol, Error> {
+        let len_of_mem = self.len_of(mem, loc)?;
+        let cond = self.infix(from, len_of_mem, &ast::InfixOperator::Lesseq, loc)?;
+        self.assert_slice(cond, "slice start may be behind the end of its memory", loc)?;
+
+        let tmp = self.temporary(
+            format!("{} + {}", self.memory[mem].name, self.memory[from].name),
+            self.memory[mem].typed.clone(),
+            loc.clone(),
+            self.memory[mem].tags.clone(),
+        )?;
+        let len_of_tmp = self.len_of(tmp, loc)?;
+        self.ssa.infix_op(
+            len_of_tmp,
+            (len_of_mem, self.memory[len_of_mem].temporal),
+            (from, self.memory[from].temporal),
+            ast::InfixOperator::Subtract,
+            self.memory[len_of_tmp].t.clone(),
+            false,
+        );
+        self.memory[tmp].value = Value::InfixOp {
+            lhs: (mem, self.memory[mem].temporal),
+            rhs: (from, self.memory[from].temporal),
+            op: ast::InfixOperator::Add,
+        };
+        Ok(tmp)
+    }
+
+    /// the members of a slice cannot be written, or a callee could no longer rely on them
+    fn slice_member_write(&mut self, expr: &mut ast::Expression) -> Result<(), Error> {
+        if let ast::Expression::MemberAccess { lhs, op, loc, .. } = expr {
+            let lhs_sym = self.execute_expr(lhs)?;
+            let mut typed = self.memory[lhs_sym].typed.clone();
+            if op == "->" {
+                typed.ptr.pop();
+            }
+            if crate::slice::mem(&typed).is_some() {
+                return Err(self.trace(
+                    format!("slice members are read only"),
+                    vec![(loc.clone(), format!("take a sub slice with [from..to] instead"))],
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// from or to of a slice range
+    fn slice_index(&mut self, expr: &mut ast::Expression) -> Result<Symbol, Error> {
+        let sym = self.execute_expr(expr)?;
+        if self.memory[sym].typed.t != ast::Type::USize
+            && self.memory[sym].typed.t != ast::Type::ULiteral
+        {
+            return Err(self.trace(
+                format!("slice range with something not a usize"),
+                vec![(expr.loc().clone(), format!("slice range must be of type usize"))],
+            ));
+        }
+        Ok(sym)
+    }
+
+    /// a slice literal, proven to fit into its memory
+    fn slice_init(
+        &mut self,
+        aptr: Symbol,
+        mem: &ast::Typed,
+        initfields: &mut Vec<(String, Box<ast::Expression>)>,
+        loc: &ast::Location,
+    ) -> Result<Symbol, Error> {
+        let mut members = HashMap::new();
+        for (name, expr) in initfields.iter_mut() {
+            let field = match crate::slice::fields(mem).into_iter().find(|f| &f.name == name) {
+                Some(field) => field,
+                None => {
+                    return Err(self.trace(
+                        format!("{} has no member {}", self.memory[aptr].typed, name),
+                        vec![(expr.loc().clone(), format!("here"))],
+                    ));
+                }
+            };
+            let sym = match expr.as_mut() {
+                ast::Expression::Infix {
+                    lhs,
+                    rhs,
+                    op: ast::InfixOperator::Add,
+                    loc,
+                } if field.name == "mem" => {
+                    let lhs_sym = self.execute_expr(lhs)?;
+                    let rhs_sym = self.execute_expr(rhs)?;
+                    self.slice_offset(lhs_sym, rhs_sym, loc)?
+                }
+                expr => self.autocast(expr, &field.typed)?,
+            };
+            members.insert(name.clone(), sym);
+        }
+
+        let (mem_sym, len_sym) = match (members.get("mem"), members.get("len")) {
+            (Some(mem), Some(len)) => (*mem, *len),
+            _ => {
+                return Err(self.trace(
+                    format!("incomplete slice literal"),
+                    vec![(loc.clone(), format!("a slice needs both mem and len"))],
+                ));
+            }
+        };
+        self.slice_bound(mem_sym, len_sym, loc, false)?;
+
+        self.memory[aptr].value = Value::Struct { members };
+        Ok(aptr)
+    }
+
+    /// true if the memory a pointer or array points to can be written
+    fn mutable_mem(&self, sym: Symbol) -> bool {
+        if crate::slice::mutable(&self.memory[sym].typed) {
+            return true;
+        }
+        // an array is as mutable as the local or member that holds it
+        match self.memory[sym].value {
+            Value::Array { .. } => self.memory[sym].tags.contains("mut"),
+            _ => false,
+        }
+    }
+
+    /// the slice literal that converts expr into a slice of mem, if it is an array,
+    /// a struct with a tail or a slice with more mutable elements
+    fn slice_from(
+        &mut self,
+        expr: &ast::Expression,
+        sym: Symbol,
+        mem: &ast::Typed,
+    ) -> Result<Option<ast::Expression>, Error> {
+        let loc = expr.loc().clone();
+        let typed = self.memory[sym].typed.clone();
+        let elem = crate::slice::element(mem);
+
+        let array = match self.memory[sym].value {
+            Value::Array { len, .. } if len > 0 => Some(len),
+            _ => None,
+        };
+
+        let (from_mem, len, mutable) = match (&typed.t, array) {
+            (ast::Type::Slice(have), _) if typed.ptr.is_empty() => {
+                if crate::slice::name(have) == crate::slice::name(mem) {
+                    return Ok(None);
+                }
+                if !crate::slice::pure(expr) {
+                    return Err(self.trace(
+                        format!("slice conversion with side effects"),
+                        vec![(loc.clone(), format!("this may be evaluated more than once. assign it to a local first"))],
+                    ));
+                }
+                let member = |rhs: &str| ast::Expression::MemberAccess {
+                    loc: loc.clone(),
+                    lhs: Box::new(expr.clone()),
+                    op: ".".to_string(),
+                    rhs: rhs.to_string(),
+                };
+                (member("mem"), member("len"), crate::slice::mutable(have))
+            }
+            (_, Some(len)) => {
+                if crate::slice::element(&typed) != elem {
+                    return Ok(None);
+                }
+                let len = ast::Expression::Literal {
+                    loc: loc.clone(),
+                    v: format!("{}", len),
+                };
+                (expr.clone(), len, self.mutable_mem(sym))
+            }
+            (ast::Type::Other(n), None) if typed.ptr.len() < 2 => {
+                let field = match self.defs.get(n) {
+                    Some(ast::Def::Struct { fields, .. }) => match fields.last() {
+                        Some(field) => match field.array {
+                            ast::Array::Unsized => field.clone(),
+                            _ => return Ok(None),
+                        },
+                        None => return Ok(None),
+                    },
+                    _ => return Ok(None),
+                };
+                let len = match &typed.tail {
+                    ast::Tail::Static(v, _) => ast::Expression::Literal {
+                        loc: loc.clone(),
+                        v: format!("{}", v),
+                    },
+                    ast::Tail::Bind(name, tailloc) => ast::Expression::Name(ast::Typed {
+                        t: ast::Type::Other(Name::from(name)),
+                        loc: tailloc.clone(),
+                        ptr: Vec::new(),
+                        tail: ast::Tail::None,
+                    }),
+                    _ => return Ok(None),
+                };
+                if field.typed != elem {
+                    return Ok(None);
+                }
+                let (op, mutable) = match typed.ptr.last() {
+                    Some(ptr) => ("->", ptr.tags.contains("mut")),
+                    None => (".", self.memory[sym].tags.contains("mut")),
+                };
+                let from_mem = ast::Expression::MemberAccess {
+                    loc: loc.clone(),
+                    lhs: Box::new(expr.clone()),
+                    op: op.to_string(),
+                    rhs: field.name.clone(),
+                };
+                (from_mem, len, mutable)
+            }
+            _ => return Ok(None),
+        };
+
+        if crate::slice::mutable(mem) && !mutable {
+            return Err(self.trace(
+                format!("cannot use immutable memory as {}", ast::Typed {
+                    t: ast::Type::Slice(Box::new(mem.clone())),
+                    loc: loc.clone(),
+                    ptr: Vec::new(),
+                    tail: ast::Tail::None,
+                }),
+                vec![(loc.clone(), format!("elements of {} are not mut", typed))],
+            ));
+        }
+
+        Ok(Some(ast::Expression::StructInit {
+            loc: loc.clone(),
+            typed: ast::Typed {
+                t: ast::Type::Slice(Box::new(mem.clone())),
+                loc: loc.clone(),
+                ptr: Vec::new(),
+                tail: ast::Tail::None,
+            },
+            fields: vec![
+                ("mem".to_string(), Box::new(from_mem)),
+                ("len".to_string(), Box::new(len)),
+            ],
+        }))
+    }
+
     fn deref(&mut self, lhs_sym: Symbol, loc: &ast::Location) -> Result<Symbol, Error> {
         if let Value::Address(to) = self.memory[lhs_sym].value.clone() {
             return Ok(to);
@@ -3500,6 +4496,7 @@ impl Symbolic {
         match t.t {
             ast::Type::Bool => crate::smt::Type::Bool,
             ast::Type::Other(_) => crate::smt::Type::Unsigned(64),
+            ast::Type::Slice(_) => crate::smt::Type::Unsigned(64),
             ast::Type::U8 => crate::smt::Type::Unsigned(8),
             ast::Type::U16 => crate::smt::Type::Unsigned(16),
             ast::Type::U32 => crate::smt::Type::Unsigned(32),
@@ -3587,7 +4584,7 @@ impl Symbolic {
         self.ssa.debug_loc(loc);
 
         let tailsym = match self.memory[sym].typed.tail.clone() {
-            ast::Tail::None => return Ok(()),
+            ast::Tail::None | ast::Tail::Generic(_) => return Ok(()),
             ast::Tail::Dynamic(_) => {
                 return Err(self.trace(
                     format!("tail size must be known for stack variables"),
@@ -3840,6 +4837,11 @@ impl Symbolic {
             );
         }
 
+        if self.memory[rhs].typed.nullable() {
+            let typed = self.memory[lhs].typed.clone();
+            self.assert_nonnull(rhs, &typed, used_here)?;
+        }
+
         let (newtype, lhs, rhs) = self.type_coersion(lhs, rhs, used_here)?;
 
         if self.memory[lhs].typed.ptr.len() != self.memory[rhs].typed.ptr.len() {
@@ -3916,6 +4918,95 @@ impl Symbolic {
         Ok(())
     }
 
+    /// a nullable pointer is either null or safe, so a null check proves it safe
+    fn ssa_mark_nullable(&mut self, sym: Symbol, loc: &ast::Location) -> Result<(), Error> {
+        if self.memory[sym].t != smt::Type::Unsigned(64) {
+            panic!("ICE: ssa_mark_nullable on non pointer");
+        }
+        let booltype = ast::Typed {
+            t: ast::Type::Bool,
+            ptr: Vec::new(),
+            loc: loc.clone(),
+            tail: ast::Tail::None,
+        };
+        let tmp_safe = self.temporary(
+            format!("safe({})", self.memory[sym].name),
+            booltype.clone(),
+            loc.clone(),
+            Tags::new(),
+        )?;
+        let thsym = self
+            .builtin
+            .get("safe")
+            .expect("ICE: safe theory not built in");
+        self.ssa.invocation(
+            *thsym,
+            vec![(sym, self.memory[sym].temporal)],
+            (tmp_safe, 0),
+        );
+
+        let tmp_null = self.temporary(
+            format!("null"),
+            ast::Typed {
+                t: ast::Type::USize,
+                ptr: Vec::new(),
+                loc: loc.clone(),
+                tail: ast::Tail::None,
+            },
+            loc.clone(),
+            Tags::new(),
+        )?;
+        self.ssa.literal(tmp_null, 0, smt::Type::Unsigned(64));
+
+        let tmp_nonnull = self.temporary(
+            format!("{} != null", self.memory[sym].name),
+            booltype,
+            loc.clone(),
+            Tags::new(),
+        )?;
+        self.ssa.infix_op(
+            tmp_nonnull,
+            (sym, self.memory[sym].temporal),
+            (tmp_null, 0),
+            ast::InfixOperator::Nequals,
+            smt::Type::Bool,
+            false,
+        );
+        self.ssa.assign((tmp_safe, 0), (tmp_nonnull, 0), smt::Type::Bool);
+        Ok(())
+    }
+
+    fn ssa_mark_nonzero(&mut self, sym: Symbol, loc: &ast::Location) -> Result<(), Error> {
+        let tmp_zero = self.temporary(
+            format!("literal 0"),
+            self.memory[sym].typed.clone(),
+            loc.clone(),
+            Tags::new(),
+        )?;
+        self.ssa.literal(tmp_zero, 0, self.memory[sym].t.clone());
+        let tmp = self.temporary(
+            format!("{} > 0", self.memory[sym].name),
+            ast::Typed {
+                t: ast::Type::Bool,
+                ptr: Vec::new(),
+                loc: loc.clone(),
+                tail: ast::Tail::None,
+            },
+            loc.clone(),
+            Tags::new(),
+        )?;
+        self.ssa.infix_op(
+            tmp,
+            (sym, self.memory[sym].temporal),
+            (tmp_zero, 0),
+            ast::InfixOperator::Morethan,
+            smt::Type::Bool,
+            false,
+        );
+        self.ssa.attest((tmp, 0), true);
+        Ok(())
+    }
+
     fn ssa_mark_nullterm(&mut self, sym: Symbol, loc: &ast::Location) -> Result<(), Error> {
         if self.memory[sym].t != smt::Type::Unsigned(64) {
             panic!("ICE: nullterm on non pointer");
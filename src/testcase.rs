@@ -0,0 +1,328 @@
+use super::ast::TestOp;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use wait_timeout::ChildExt;
+
+/// a testcase as emitted into target/<stage>/testcases/ by the c emitter
+#[derive(Clone)]
+pub struct Case {
+    pub name: String,
+    pub stdin: Option<Vec<u8>>,
+    pub stdout: Option<(TestOp, Vec<u8>)>,
+    pub stderr: Option<(TestOp, Vec<u8>)>,
+    pub exit: (TestOp, i32),
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    pub timeout: Option<Duration>,
+}
+
+impl Default for Case {
+    fn default() -> Self {
+        Case {
+            name: "default".to_string(),
+            stdin: None,
+            stdout: None,
+            stderr: None,
+            exit: (TestOp::Equals, 0),
+            args: Vec::new(),
+            env: Vec::new(),
+            timeout: None,
+        }
+    }
+}
+
+/// what a test binary did when running a case
+pub struct Run {
+    pub elapsed: Duration,
+    pub stdout: Vec<u8>,
+    pub stderr: Vec<u8>,
+    /// why the case failed, if it did
+    pub failure: Option<String>,
+}
+
+/// all cases of one test artifact. a test without cases is run once with default expectations
+pub fn load_dir(dir: &Path) -> Vec<Case> {
+    let mut cases = Vec::new();
+    if let Ok(dir) = std::fs::read_dir(dir) {
+        for entry in dir {
+            let entry = match entry {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+            if entry.path().is_dir() {
+                cases.push(Case::load(&entry.path()));
+            }
+        }
+    }
+    cases.sort_by(|a, b| a.name.cmp(&b.name));
+    if cases.is_empty() {
+        cases.push(Case::default());
+    }
+    cases
+}
+
+impl Case {
+    pub fn load(path: &Path) -> Self {
+        let mut case = Case::default();
+        case.name = path.file_name().unwrap().to_string_lossy().to_string();
+
+        case.stdin = read_field(path, "stdin");
+        case.stdout = read_field(path, "stdout").map(|v| (read_op(path, "stdout"), v));
+        case.stderr = read_field(path, "stderr").map(|v| (read_op(path, "stderr"), v));
+
+        if let Some(v) = read_field(path, "exit") {
+            let exit = match String::from_utf8_lossy(&v).trim().parse() {
+                Ok(v) => v,
+                Err(_) => malformed(path, "exit", "expected an exit code"),
+            };
+            case.exit = (read_op(path, "exit"), exit);
+        }
+        if let Some(v) = read_field(path, "args") {
+            case.args = split_words(path, "args", &String::from_utf8_lossy(&v));
+        }
+        if let Some(v) = read_field(path, "env") {
+            for kv in split_words(path, "env", &String::from_utf8_lossy(&v)) {
+                let mut kv = kv.splitn(2, '=');
+                let k = kv.next().unwrap().to_string();
+                let v = match kv.next() {
+                    Some(v) => v.to_string(),
+                    None => malformed(path, "env", "expected NAME=value"),
+                };
+                case.env.push((k, v));
+            }
+        }
+        if let Some(v) = read_field(path, "timeout") {
+            match String::from_utf8_lossy(&v).trim().parse() {
+                Ok(v) => case.timeout = Some(Duration::from_secs(v)),
+                Err(_) => malformed(path, "timeout", "expected seconds"),
+            }
+        }
+        case
+    }
+
+    /// run the test binary against this case.
+    /// the case's own timeout takes precedence over the default one
+    pub fn run(&self, exe: &Path, default_timeout: Option<Duration>) -> Run {
+        let start = Instant::now();
+        let mut run = Run {
+            elapsed: Duration::from_secs(0),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+            failure: None,
+        };
+
+        let mut command = Command::new(exe);
+        command
+            .args(&self.args)
+            .envs(self.env.iter().map(|(k, v)| (k, v)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        // in its own process group, so a timeout also kills the processes it forked
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+
+        let mut child = match command.spawn() {
+            Ok(v) => v,
+            Err(e) => {
+                run.failure = Some(format!("failed to execute {:?}: {}", exe, e));
+                return run;
+            }
+        };
+
+        // read in the background, so the child doesn't block on a full pipe while we wait
+        let stdout = child.stdout.take().map(|mut r| {
+            std::thread::spawn(move || {
+                let mut v = Vec::new();
+                r.read_to_end(&mut v).ok();
+                v
+            })
+        });
+        let stderr = child.stderr.take().map(|mut r| {
+            std::thread::spawn(move || {
+                let mut v = Vec::new();
+                r.read_to_end(&mut v).ok();
+                v
+            })
+        });
+
+        // a child that never reads stdin would block us on a full pipe
+        let stdin = child.stdin.take().map(|mut w| {
+            let input = self.stdin.clone().unwrap_or_default();
+            std::thread::spawn(move || {
+                w.write_all(&input).ok();
+            })
+        });
+
+        let status = match self.timeout.or(default_timeout) {
+            Some(timeout) => match child.wait_timeout(timeout).unwrap() {
+                Some(status) => Some(status),
+                None => {
+                    kill(&mut child);
+                    child.wait().ok();
+                    run.failure = Some(format!("timed out after {}s", timeout.as_secs()));
+                    None
+                }
+            },
+            None => Some(child.wait().unwrap()),
+        };
+        run.elapsed = start.elapsed();
+
+        if let Some(t) = stdin {
+            t.join().ok();
+        }
+        run.stdout = stdout.map(|t| t.join().unwrap()).unwrap_or_default();
+        run.stderr = stderr.map(|t| t.join().unwrap()).unwrap_or_default();
+        run.stdout.retain(|&i| i != b'\r');
+        run.stderr.retain(|&i| i != b'\r');
+
+        let status = match status {
+            Some(v) => v,
+            None => return run,
+        };
+
+        match status.code() {
+            Some(c) => {
+                if !compare_exit(&self.exit.0, self.exit.1, c) {
+                    run.failure = Some(format!(
+                        "exit: {} but expected {} {}",
+                        c,
+                        self.exit.0.as_str(),
+                        self.exit.1
+                    ));
+                    return run;
+                }
+            }
+            None => {
+                #[cfg(unix)]
+                {
+                    use std::os::unix::process::ExitStatusExt;
+                    run.failure = Some(format!("died by signal {}", status.signal().unwrap()));
+                }
+                #[cfg(not(unix))]
+                {
+                    run.failure = Some(format!("died by signal"));
+                }
+                return run;
+            }
+        }
+
+        for (stream, expect, got) in &[
+            ("stdout", &self.stdout, &run.stdout),
+            ("stderr", &self.stderr, &run.stderr),
+        ] {
+            if let Some((op, expect)) = expect {
+                if !compare_output(op, expect, got) {
+                    run.failure = Some(format!(
+                        "{} expected {} <{}>({}) but got <{}>({})",
+                        stream,
+                        op.as_str(),
+                        String::from_utf8_lossy(&expect),
+                        expect.len(),
+                        String::from_utf8_lossy(&got),
+                        got.len()
+                    ));
+                    return run;
+                }
+            }
+        }
+
+        run
+    }
+}
+
+/// kill the process group of the child, which includes forked test processes
+fn kill(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    unsafe {
+        libc::killpg(child.id() as libc::pid_t, libc::SIGKILL);
+    }
+    child.kill().ok();
+}
+
+fn read_field(path: &Path, field: &str) -> Option<Vec<u8>> {
+    std::fs::read(path.join(field)).ok()
+}
+
+fn read_op(path: &Path, field: &str) -> TestOp {
+    match std::fs::read_to_string(path.join(format!("{}.op", field))) {
+        Ok(v) => match TestOp::parse(v.trim()) {
+            Some(op) => op,
+            None => malformed(path, &format!("{}.op", field), "expected a comparison operator"),
+        },
+        Err(_) => TestOp::Equals,
+    }
+}
+
+fn malformed(path: &Path, field: &str, expected: &str) -> ! {
+    error!("malformed testcase {:?}: {}", path.join(field), expected);
+    super::exit(9);
+}
+
+/// split on whitespace, except inside single or double quotes. a backslash escapes the next char
+fn split_words(path: &Path, field: &str, s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (c, quote) {
+            ('\\', q) if q != Some('\'') => match chars.next() {
+                Some(c) => word.get_or_insert_with(String::new).push(c),
+                None => malformed(path, field, "trailing backslash"),
+            },
+            (c, None) if c == '"' || c == '\'' => {
+                quote = Some(c);
+                word.get_or_insert_with(String::new);
+            }
+            (c, Some(q)) if c == q => quote = None,
+            (c, None) if c.is_whitespace() => {
+                if let Some(w) = word.take() {
+                    words.push(w);
+                }
+            }
+            (c, _) => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if quote.is_some() {
+        malformed(path, field, "unterminated quote");
+    }
+    if let Some(w) = word {
+        words.push(w);
+    }
+    words
+}
+
+pub fn compare_exit(op: &TestOp, expect: i32, got: i32) -> bool {
+    match op {
+        TestOp::Equals => got == expect,
+        TestOp::Nequals => got != expect,
+        TestOp::Lessthan => got < expect,
+        TestOp::Moreeq => got >= expect,
+        TestOp::Morethan => got > expect,
+        TestOp::Lesseq => got <= expect,
+        TestOp::Contains | TestOp::Matches | TestOp::Nmatches => false,
+    }
+}
+
+pub fn compare_output(op: &TestOp, expect: &[u8], got: &[u8]) -> bool {
+    match op {
+        TestOp::Equals => got == expect,
+        TestOp::Nequals => got != expect,
+        TestOp::Contains => expect.len() == 0 || got.windows(expect.len()).any(|w| w == expect),
+        TestOp::Matches | TestOp::Nmatches => {
+            let re = match regex::bytes::Regex::new(&String::from_utf8_lossy(expect)) {
+                Ok(v) => v,
+                Err(_) => return false,
+            };
+            re.is_match(got) == (*op == TestOp::Matches)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,241 @@
+use super::ast::TestOp;
+use super::testcase::{Case, Run};
+use rayon::prelude::*;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub struct Job {
+    pub artifact: String,
+    pub exe: PathBuf,
+    pub case: Case,
+}
+
+pub struct Outcome {
+    pub artifact: String,
+    pub case: Case,
+    pub run: Run,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        self.run.failure.is_none()
+    }
+
+    /// unified diff of expected against actual stdout, when an exact match was expected
+    pub fn stdout_diff(&self) -> Option<String> {
+        match &self.case.stdout {
+            Some((TestOp::Equals, expect)) if expect != &self.run.stdout => {
+                let expect = String::from_utf8_lossy(expect);
+                let got = String::from_utf8_lossy(&self.run.stdout);
+                Some(
+                    similar::TextDiff::from_lines(&expect, &got)
+                        .unified_diff()
+                        .context_radius(3)
+                        .header("expected", "actual")
+                        .to_string(),
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+/// run all jobs, at most `parallel` at once. 0 means one per cpu
+pub fn run(jobs: Vec<Job>, parallel: usize, timeout: Option<Duration>) -> Vec<Outcome> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(parallel)
+        .build()
+        .expect("cannot create test thread pool");
+
+    pool.install(|| {
+        jobs.into_par_iter()
+            .map(|job| {
+                let run = job.case.run(&job.exe, timeout);
+                if run.failure.is_none() {
+                    info!(
+                        "PASS {} {} in {}ms",
+                        job.artifact,
+                        job.case.name,
+                        run.elapsed.as_millis()
+                    );
+                } else {
+                    error!("FAIL {} {}", job.artifact, job.case.name);
+                }
+                Outcome {
+                    artifact: job.artifact,
+                    case: job.case,
+                    run,
+                }
+            })
+            .collect()
+    })
+}
+
+/// print failure details and counts. returns true if everything passed
+pub fn summary(outcomes: &[Outcome]) -> bool {
+    let failed: Vec<&Outcome> = outcomes.iter().filter(|o| !o.passed()).collect();
+
+    for o in &failed {
+        println!("\n---- {} {} ----", o.artifact, o.case.name);
+        println!("{}", o.run.failure.as_ref().unwrap());
+        if let Some(diff) = o.stdout_diff() {
+            println!("{}", diff);
+        }
+        if o.run.stderr.len() > 0 {
+            println!("stderr:\n{}", String::from_utf8_lossy(&o.run.stderr));
+        }
+    }
+
+    let elapsed: Duration = outcomes.iter().map(|o| o.run.elapsed).sum();
+    println!(
+        "\ntest result: {}. {} passed; {} failed; {} total in {}ms",
+        if failed.is_empty() { "ok" } else { "FAILED" },
+        outcomes.len() - failed.len(),
+        failed.len(),
+        outcomes.len(),
+        elapsed.as_millis()
+    );
+    for o in &failed {
+        println!("    {} {}", o.artifact, o.case.name);
+    }
+
+    failed.is_empty()
+}
+
+#[derive(Serialize)]
+struct JsonCase<'a> {
+    artifact: &'a str,
+    case: &'a str,
+    passed: bool,
+    millis: u128,
+    failure: Option<&'a str>,
+    stdout: String,
+    stderr: String,
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    total: usize,
+    passed: usize,
+    failed: usize,
+    cases: Vec<JsonCase<'a>>,
+}
+
+pub fn write_json(path: &Path, outcomes: &[Outcome]) {
+    let cases: Vec<JsonCase> = outcomes
+        .iter()
+        .map(|o| JsonCase {
+            artifact: &o.artifact,
+            case: &o.case.name,
+            passed: o.passed(),
+            millis: o.run.elapsed.as_millis(),
+            failure: o.run.failure.as_ref().map(|s| s.as_str()),
+            stdout: String::from_utf8_lossy(&o.run.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&o.run.stderr).to_string(),
+        })
+        .collect();
+    let passed = cases.iter().filter(|c| c.passed).count();
+    let report = JsonReport {
+        total: cases.len(),
+        passed,
+        failed: cases.len() - passed,
+        cases,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect(&format!("cannot create {:?}", parent));
+    }
+    fs::write(path, serde_json::to_string_pretty(&report).unwrap())
+        .expect(&format!("cannot write {:?}", path));
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut r = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => r.push_str("&amp;"),
+            '<' => r.push_str("&lt;"),
+            '>' => r.push_str("&gt;"),
+            '"' => r.push_str("&quot;"),
+            '\'' => r.push_str("&apos;"),
+            c if (c as u32) < 0x20 && c != '\n' && c != '\t' && c != '\r' => (),
+            c => r.push(c),
+        }
+    }
+    r
+}
+
+pub fn write_junit(path: &Path, outcomes: &[Outcome]) {
+    let mut suites: Vec<(&str, Vec<&Outcome>)> = Vec::new();
+    for o in outcomes {
+        match suites.iter_mut().find(|(n, _)| *n == o.artifact.as_str()) {
+            Some((_, v)) => v.push(o),
+            None => suites.push((&o.artifact, vec![o])),
+        }
+    }
+
+    let total_failed = outcomes.iter().filter(|o| !o.passed()).count();
+    let total_time: Duration = outcomes.iter().map(|o| o.run.elapsed).sum();
+
+    let mut x = String::new();
+    x.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    x.push_str(&format!(
+        "<testsuites tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+        outcomes.len(),
+        total_failed,
+        total_time.as_secs_f64()
+    ));
+    for (name, cases) in suites {
+        let failed = cases.iter().filter(|o| !o.passed()).count();
+        let time: Duration = cases.iter().map(|o| o.run.elapsed).sum();
+        x.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+            xml_escape(name),
+            cases.len(),
+            failed,
+            time.as_secs_f64()
+        ));
+        for o in cases {
+            x.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.3}\"",
+                xml_escape(&o.artifact),
+                xml_escape(&o.case.name),
+                o.run.elapsed.as_secs_f64()
+            ));
+            match &o.run.failure {
+                None => x.push_str("/>\n"),
+                Some(failure) => {
+                    x.push_str(">\n");
+                    let mut body = failure.clone();
+                    if let Some(diff) = o.stdout_diff() {
+                        body.push('\n');
+                        body.push_str(&diff);
+                    }
+                    x.push_str(&format!(
+                        "      <failure message=\"{}\">{}</failure>\n",
+                        xml_escape(failure),
+                        xml_escape(&body)
+                    ));
+                    x.push_str(&format!(
+                        "      <system-out>{}</system-out>\n",
+                        xml_escape(&String::from_utf8_lossy(&o.run.stdout))
+                    ));
+                    x.push_str(&format!(
+                        "      <system-err>{}</system-err>\n",
+                        xml_escape(&String::from_utf8_lossy(&o.run.stderr))
+                    ));
+                    x.push_str("    </testcase>\n");
+                }
+            }
+        }
+        x.push_str("  </testsuite>\n");
+    }
+    x.push_str("</testsuites>\n");
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect(&format!("cannot create {:?}", parent));
+    }
+    fs::write(path, x).expect(&format!("cannot write {:?}", path));
+}
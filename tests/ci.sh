@@ -32,6 +32,145 @@ do
 done
 
 
+cd $THIS/install
+PREFIX=$(mktemp -d)
+../../target/release/zz clean
+../../target/release/zz install --prefix $PREFIX
+export PKG_CONFIG_PATH=$PREFIX/lib/pkgconfig
+# a static library must pull in its pkgconfig dependencies, a shared one links them itself
+pkg-config --libs installme_static | grep -q -- '-lz'
+if pkg-config --libs installme | grep -q -- '-lz'; then
+    echo "install: the shared library should only require zlib privately"
+    exit 1
+fi
+cc consumer.c -o $PREFIX/consumer $(pkg-config --cflags --libs installme)
+LD_LIBRARY_PATH=$PREFIX/lib $PREFIX/consumer
+cc consumer.c -o $PREFIX/consumer_static $(pkg-config --cflags --libs installme_static)
+$PREFIX/consumer_static
+unset PKG_CONFIG_PATH
+rm -rf $PREFIX
+echo "install passed"
+
+
+# abi-diff must notice reordered enum members, their implicit values change
+ABI=$(mktemp -d)
+cp -r $THIS/abi/* $ABI
+cd $ABI
+$THIS/../target/release/zz abi-diff --save
+$THIS/../target/release/zz abi-diff
+$THIS/../target/release/zz abi-diff --stage release
+sed -i 's/^    Red,$/    Swap,/; s/^    Blue,$/    Red,/; s/^    Swap,$/    Blue,/' src/lib.zz
+if $THIS/../target/release/zz abi-diff; then
+    echo "abi: reordering an enum should break the abi"
+    exit 1
+fi
+if $THIS/../target/release/zz abi-diff --stage release; then
+    echo "abi: reordering an enum should break the abi of every stage"
+    exit 1
+fi
+rm -rf $ABI
+echo "abi passed"
+
+
+cd $THIS/exporters
+../../target/release/zz clean
+../../target/release/zz build
+# the flag with a space and quotes must arrive as one argument
+python3 - <<'PY'
+def glob(patterns, allow_empty = False):
+    return patterns
+def cc_library(**kw):
+    assert kw["copts"] == ['-DEXPORTERS_GREETING="hello world"'], kw["copts"]
+exec(open("target/bazel/exporters/BUILD.bazel").read())
+PY
+if command -v cmake >/dev/null; then
+    cmake -S . -B target/cmake-build
+    cmake --build target/cmake-build
+else
+    echo "exporters: cmake skipped"
+fi
+if command -v meson >/dev/null && command -v ninja >/dev/null; then
+    meson setup target/meson-build
+    ninja -C target/meson-build
+else
+    echo "exporters: meson skipped"
+fi
+echo "exporters passed"
+
+
+cd $THIS/wasm
+if echo 'int x;' | clang --target=wasm32 -nostdlib -Wl,--no-entry -x c - -o /dev/null 2>/dev/null; then
+    ../../target/release/zz clean
+    ../../target/release/zz build
+    # every wasm module starts with the \0asm magic
+    test "$(head -c 4 target/test/wasm/wasmtest.wasm | tail -c 3)" = "asm"
+    if command -v node >/dev/null; then
+        node check.js
+    fi
+    echo "wasm passed"
+else
+    echo "wasm skipped, clang cannot target wasm32"
+fi
+
+
+cd $THIS/libfuzzer
+if echo 'int LLVMFuzzerTestOneInput(const char *d, unsigned long l) { return 0; }' | clang -fsanitize=fuzzer -x c - -o /dev/null 2>/dev/null; then
+    ../../target/release/zz clean
+    ../../target/release/zz fuzz --libfuzzer --max-time 5 checksum
+    ../../target/release/zz fuzz minimize checksum
+    ../../target/release/zz fuzz reproduce checksum
+    if ../../target/release/zz fuzz --libfuzzer --max-time 1 narrow; then
+        echo "libfuzzer: a fuzz entry with a where clause on its input should not build"
+        exit 1
+    fi
+    echo "libfuzzer passed"
+else
+    echo "libfuzzer skipped, clang has no -fsanitize=fuzzer"
+fi
+
+
+cd $THIS/coverage
+if command -v llvm-cov >/dev/null && command -v llvm-profdata >/dev/null; then
+    ../../target/release/zz clean
+    ../../target/release/zz test --coverage
+    # the upper bound of clamp is never reached, the rest is
+    grep -q '^DA:8,0$' target/coverage/coverage/src_lib.info
+    grep -q '^DA:5,1$' target/coverage/coverage/src_lib.info
+    test -f target/coverage/coverage/index.html
+    echo "coverage passed"
+else
+    echo "coverage skipped, no llvm-cov"
+fi
+
+
+cd $THIS/bench
+../../target/release/zz clean
+../../target/release/zz bench --iterations 20 --save-baseline before
+test -f target/bench/before.json
+# timings are noisy on ci, only check that comparing works
+../../target/release/zz bench --iterations 20 --baseline before --threshold 100000 fib_40
+echo "bench passed"
+
+
+cd $THIS/stages
+../../target/release/zz clean
+../../target/release/zz run --stage ubsan
+../../target/release/zz build --stage small
+test -f target/ubsan/bin/staged
+test -f target/small/bin/staged
+if ../../target/release/zz build --stage nope; then
+    echo "stages: an unknown stage should not build"
+    exit 1
+fi
+echo "stages passed"
+
+
+cd $THIS/properties
+../../target/release/zz clean
+../../target/release/zz test --properties clamp --count 50 --seed 1
+../../target/release/zz test --properties midpoint --count 50
+echo "properties passed"
+
 
 echo
 echo all passed
@@ -0,0 +1,5 @@
+#include <zz/installme.h>
+
+int main() {
+    return installme_add(1, 2) == 3 ? 0 : 1;
+}
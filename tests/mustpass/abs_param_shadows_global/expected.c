@@ -0,0 +1,71 @@
+/* abs_param_shadows_global_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_abs_param_shadows_global_main_bob
+#define ZZ_FORWARD_abs_param_shadows_global_main_bob
+#endif
+#ifndef ZZ_FORWARD_abs_param_shadows_global_main_main
+#define ZZ_FORWARD_abs_param_shadows_global_main_main
+#endif
+#ifndef ZZ_FORWARD_abs_param_shadows_global_main_x
+#define ZZ_FORWARD_abs_param_shadows_global_main_x
+#endif
+#ifndef ZZ_EXPORT_abs_param_shadows_global_main_bob
+#define ZZ_EXPORT_abs_param_shadows_global_main_bob
+
+#line 6 "./src/main.zz"
+static void abs_param_shadows_global_main_bob (intptr_t x);
+
+#endif
+#ifndef ZZ_EXPORT_abs_param_shadows_global_main_main
+#define ZZ_EXPORT_abs_param_shadows_global_main_main
+
+#line 10 "./src/main.zz"
+int abs_param_shadows_global_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_abs_param_shadows_global_main_x
+#define ZZ_EXPORT_abs_param_shadows_global_main_x
+
+#line 4 "./src/main.zz"
+static const  __attribute__ ((unused)) uintptr_t abs_param_shadows_global_main_x =    1;
+
+#endif
+#ifndef ZZ_IMPL_abs_param_shadows_global_main_bob
+#define ZZ_IMPL_abs_param_shadows_global_main_bob
+
+#line 6 "./src/main.zz"
+static void abs_param_shadows_global_main_bob (intptr_t x)
+#if 0
+#else
+{
+
+#line 7 "./src/main.zz"
+    x =     -1;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_abs_param_shadows_global_main_main
+#define ZZ_IMPL_abs_param_shadows_global_main_main
+
+#line 10 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
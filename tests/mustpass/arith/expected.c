@@ -0,0 +1,38 @@
+/* arith_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_arith_main_main
+#define ZZ_FORWARD_arith_main_main
+#endif
+#ifndef ZZ_EXPORT_arith_main_main
+#define ZZ_EXPORT_arith_main_main
+
+#line 3 "./src/main.zz"
+int arith_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_arith_main_main
+#define ZZ_IMPL_arith_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 4 "./src/main.zz"
+  unsigned int const  i  =     30;
+
+#line 5 "./src/main.zz"
+    ;
+
+#line 6 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,56 @@
+/* arith_arith_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_arith_arith_main_main
+#define ZZ_FORWARD_arith_arith_main_main
+#endif
+#ifndef ZZ_EXPORT_arith_arith_main_main
+#define ZZ_EXPORT_arith_arith_main_main
+
+#line 3 "./src/main.zz"
+int arith_arith_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_arith_arith_main_main
+#define ZZ_IMPL_arith_arith_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 6 "./src/main.zz"
+  int const  a  [     100 ]  = {    1,    2,    3,};
+
+#line 7 "./src/main.zz"
+    ;
+
+#line 9 "./src/main.zz"
+  int const *  const  b  =     a;
+
+#line 10 "./src/main.zz"
+    ;
+
+#line 12 "./src/main.zz"
+  int const  y  = ( *    b);
+
+#line 14 "./src/main.zz"
+  int const *  const  c  = (    b +    10  );
+
+#line 15 "./src/main.zz"
+    ;
+
+#line 17 "./src/main.zz"
+  int const  x  = ( *    c);
+
+#line 19 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,62 @@
+/* arith_member_array_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_arith_member_array_main_A
+#define ZZ_FORWARD_arith_member_array_main_A
+
+#line 4 "./src/main.zz"
+struct arith_member_array_main_A_t;
+typedef struct arith_member_array_main_A_t arith_member_array_main_A;
+#endif
+#ifndef ZZ_FORWARD_arith_member_array_main_main
+#define ZZ_FORWARD_arith_member_array_main_main
+#endif
+#ifndef ZZ_EXPORT_arith_member_array_main_A
+#define ZZ_EXPORT_arith_member_array_main_A
+struct arith_member_array_main_A_t {
+
+#line 5 "./src/main.zz"
+   uint8_t a[    10] ;
+
+#line 6 "./src/main.zz"
+   uint8_t b ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_arith_member_array_main_main
+#define ZZ_EXPORT_arith_member_array_main_main
+
+#line 9 "./src/main.zz"
+int arith_member_array_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_arith_member_array_main_main
+#define ZZ_IMPL_arith_member_array_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+  arith_member_array_main_A const  a  =     (arith_member_array_main_A){
+.b = 
+#line 12 "./src/main.zz"
+    1,
+};
+
+#line 14 "./src/main.zz"
+  uint8_t const *  const  x  = (    a .a +    a .b  );
+
+#line 16 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,140 @@
+/* array_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_array_main_SIZE
+#define ZZ_FORWARD_array_main_SIZE
+#endif
+#ifndef ZZ_FORWARD_array_main_fixed
+#define ZZ_FORWARD_array_main_fixed
+#endif
+#ifndef ZZ_FORWARD_array_main_memcpy
+#define ZZ_FORWARD_array_main_memcpy
+#endif
+#ifndef ZZ_FORWARD_array_main_main
+#define ZZ_FORWARD_array_main_main
+#endif
+#ifndef ZZ_FORWARD_array_main_memcpy
+#define ZZ_FORWARD_array_main_memcpy
+#endif
+#ifndef ZZ_EXPORT_array_main_SIZE
+#define ZZ_EXPORT_array_main_SIZE
+
+#line 11 "./src/main.zz"
+#define array_main_SIZE ((uintptr_t )    3)
+
+#endif
+#ifndef ZZ_EXPORT_array_main_fixed
+#define ZZ_EXPORT_array_main_fixed
+
+#line 13 "./src/main.zz"
+extern void array_main_fixed (uint8_t*  const  boh);
+
+#endif
+#ifndef ZZ_EXPORT_array_main_memcpy
+#define ZZ_EXPORT_array_main_memcpy
+
+#line 1 "./src/main.zz"
+extern void array_main_memcpy (uint8_t*  const  dst, uint8_t const *  const  src, uintptr_t const  l);
+
+#endif
+#ifndef ZZ_EXPORT_array_main_main
+#define ZZ_EXPORT_array_main_main
+
+#line 19 "./src/main.zz"
+extern int array_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_array_main_memcpy
+#define ZZ_EXPORT_array_main_memcpy
+
+#line 1 "./src/main.zz"
+extern void array_main_memcpy (uint8_t*  const  dst, uint8_t const *  const  src, uintptr_t const  l);
+
+#endif
+#ifndef ZZ_IMPL_array_main_fixed
+#define ZZ_IMPL_array_main_fixed
+
+#line 13 "./src/main.zz"
+void __attribute__ ((visibility ("hidden"))) array_main_fixed (uint8_t*  const  boh)
+#if 0
+#else
+{
+
+#line 16 "./src/main.zz"
+    boh [     2] =     3;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_array_main_main
+#define ZZ_IMPL_array_main_main
+
+#line 19 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 20 "./src/main.zz"
+  uint8_t src  [     23 ]  = {    0,};
+
+#line 21 "./src/main.zz"
+  uint8_t f  [     3 ]  = {    0,};
+
+#line 23 "./src/main.zz"
+    ;
+
+#line 24 "./src/main.zz"
+    ;
+
+#line 26 "./src/main.zz"
+    array_main_memcpy(    f,    src,    array_main_SIZE    );
+
+#line 28 "./src/main.zz"
+    src [     1] =     3;
+
+#line 30 "./src/main.zz"
+  uint8_t dst  [     20 ]  = {    0,};
+
+#line 31 "./src/main.zz"
+    array_main_memcpy(    dst,    src,    20    );
+
+#line 32 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_array_main_memcpy
+#define ZZ_IMPL_array_main_memcpy
+
+#line 1 "./src/main.zz"
+void __attribute__ ((visibility ("hidden"))) array_main_memcpy (uint8_t*  const  dst, uint8_t const *  const  src, uintptr_t const  l)
+#if 0
+#else
+{
+  for (
+
+#line 6 "./src/main.zz"
+  uintptr_t i  =     0;(    i <    l  );
+(    i ++)){
+
+#line 7 "./src/main.zz"
+    dst [     i] =     src [     i];
+
+}
+
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,81 @@
+/* auto_attest_tail_len_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_auto_attest_tail_len_main_A
+#define ZZ_FORWARD_auto_attest_tail_len_main_A
+
+#line 5 "./src/main.zz"
+struct auto_attest_tail_len_main_A_t;
+typedef struct auto_attest_tail_len_main_A_t auto_attest_tail_len_main_A;
+#endif
+#ifndef ZZ_FORWARD_auto_attest_tail_len_main_main
+#define ZZ_FORWARD_auto_attest_tail_len_main_main
+#endif
+#ifndef ZZ_FORWARD_auto_attest_tail_len_main_something
+#define ZZ_FORWARD_auto_attest_tail_len_main_something
+#endif
+#ifndef ZZ_EXPORT_auto_attest_tail_len_main_A
+#define ZZ_EXPORT_auto_attest_tail_len_main_A
+struct auto_attest_tail_len_main_A_t {
+
+#line 6 "./src/main.zz"
+   int dummy ;
+
+#line 7 "./src/main.zz"
+   int tail[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_auto_attest_tail_len_main_main
+#define ZZ_EXPORT_auto_attest_tail_len_main_main
+
+#line 15 "./src/main.zz"
+int auto_attest_tail_len_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_auto_attest_tail_len_main_something
+#define ZZ_EXPORT_auto_attest_tail_len_main_something
+
+#line 11 "./src/main.zz"
+static void auto_attest_tail_len_main_something (auto_attest_tail_len_main_A const *  const  self, uintptr_t const  t);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_auto_attest_tail_len_main_main
+#define ZZ_IMPL_auto_attest_tail_len_main_main
+
+#line 15 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 17 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_auto_attest_tail_len_main_something
+#define ZZ_IMPL_auto_attest_tail_len_main_something
+
+#line 11 "./src/main.zz"
+static void auto_attest_tail_len_main_something (auto_attest_tail_len_main_A const *  const  self, uintptr_t const  t)
+#if 0
+#else
+{
+
+#line 12 "./src/main.zz"
+    ;
+
+}
+#endif
+
+
+#endif
+
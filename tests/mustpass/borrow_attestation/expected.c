@@ -0,0 +1,154 @@
+/* borrow_attestation_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_borrow_attestation_main_Always1
+#define ZZ_FORWARD_borrow_attestation_main_Always1
+
+#line 4 "./src/main.zz"
+struct borrow_attestation_main_Always1_t;
+typedef struct borrow_attestation_main_Always1_t borrow_attestation_main_Always1;
+#endif
+#ifndef ZZ_FORWARD_borrow_attestation_main_borrow
+#define ZZ_FORWARD_borrow_attestation_main_borrow
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_borrow_attestation_main_main
+#define ZZ_FORWARD_borrow_attestation_main_main
+#endif
+#ifndef ZZ_FORWARD_borrow_attestation_main_new
+#define ZZ_FORWARD_borrow_attestation_main_new
+#endif
+#ifndef ZZ_FORWARD_borrow_attestation_main_something
+#define ZZ_FORWARD_borrow_attestation_main_something
+#endif
+#ifndef ZZ_EXPORT_borrow_attestation_main_Always1
+#define ZZ_EXPORT_borrow_attestation_main_Always1
+struct borrow_attestation_main_Always1_t {
+
+#line 5 "./src/main.zz"
+   int a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_borrow_attestation_main_borrow
+#define ZZ_EXPORT_borrow_attestation_main_borrow
+
+#line 8 "./src/main.zz"
+static borrow_attestation_main_Always1  const * borrow_attestation_main_borrow (borrow_attestation_main_Always1 const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_borrow_attestation_main_main
+#define ZZ_EXPORT_borrow_attestation_main_main
+
+#line 30 "./src/main.zz"
+int borrow_attestation_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_borrow_attestation_main_new
+#define ZZ_EXPORT_borrow_attestation_main_new
+
+#line 18 "./src/main.zz"
+static void borrow_attestation_main_new (borrow_attestation_main_Always1*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_borrow_attestation_main_something
+#define ZZ_EXPORT_borrow_attestation_main_something
+
+#line 25 "./src/main.zz"
+static void borrow_attestation_main_something (borrow_attestation_main_Always1 const *  const  self);
+
+#endif
+#ifndef ZZ_IMPL_borrow_attestation_main_borrow
+#define ZZ_IMPL_borrow_attestation_main_borrow
+
+#line 8 "./src/main.zz"
+static borrow_attestation_main_Always1  const * borrow_attestation_main_borrow (borrow_attestation_main_Always1 const *  const  self)
+#if 0
+#else
+{
+
+#line 14 "./src/main.zz"
+  return     self;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_borrow_attestation_main_main
+#define ZZ_IMPL_borrow_attestation_main_main
+
+#line 30 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 32 "./src/main.zz"
+  borrow_attestation_main_Always1 a  = {    0,};
+
+#line 33 "./src/main.zz"
+    borrow_attestation_main_new(( &    a)    );
+
+#line 34 "./src/main.zz"
+    borrow_attestation_main_something(    (borrow_attestation_main_Always1* )(    borrow_attestation_main_borrow(( &    a)    ))    );
+
+#line 36 "./src/main.zz"
+    printf(    "hello integrity\n"    );
+
+#line 37 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_borrow_attestation_main_new
+#define ZZ_IMPL_borrow_attestation_main_new
+
+#line 18 "./src/main.zz"
+static void borrow_attestation_main_new (borrow_attestation_main_Always1*  const  self)
+#if 0
+#else
+{
+
+#line 21 "./src/main.zz"
+    self ->a =     1;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_borrow_attestation_main_something
+#define ZZ_IMPL_borrow_attestation_main_something
+
+#line 25 "./src/main.zz"
+static void borrow_attestation_main_something (borrow_attestation_main_Always1 const *  const  self)
+#if 0
+#else
+{
+
+#line 27 "./src/main.zz"
+    ;
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,127 @@
+/* branch_condition_effect_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_branch_condition_effect_main_checked
+#define ZZ_FORWARD_branch_condition_effect_main_checked
+#endif
+#ifndef ZZ_FORWARD_branch_condition_effect_main_check
+#define ZZ_FORWARD_branch_condition_effect_main_check
+#endif
+#ifndef ZZ_FORWARD_branch_condition_effect_main_bob
+#define ZZ_FORWARD_branch_condition_effect_main_bob
+#endif
+#ifndef ZZ_FORWARD_branch_condition_effect_main_check
+#define ZZ_FORWARD_branch_condition_effect_main_check
+#endif
+#ifndef ZZ_FORWARD_branch_condition_effect_main_checked
+#define ZZ_FORWARD_branch_condition_effect_main_checked
+#endif
+#ifndef ZZ_FORWARD_branch_condition_effect_main_main
+#define ZZ_FORWARD_branch_condition_effect_main_main
+#endif
+#ifndef ZZ_EXPORT_branch_condition_effect_main_checked
+#define ZZ_EXPORT_branch_condition_effect_main_checked
+
+#endif
+#ifndef ZZ_EXPORT_branch_condition_effect_main_check
+#define ZZ_EXPORT_branch_condition_effect_main_check
+
+#line 7 "./src/main.zz"
+static bool branch_condition_effect_main_check (int const *  const  thing);
+
+#endif
+#ifndef ZZ_EXPORT_branch_condition_effect_main_bob
+#define ZZ_EXPORT_branch_condition_effect_main_bob
+
+#line 27 "./src/main.zz"
+int branch_condition_effect_main_bob (int const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_branch_condition_effect_main_check
+#define ZZ_EXPORT_branch_condition_effect_main_check
+
+#line 7 "./src/main.zz"
+static bool branch_condition_effect_main_check (int const *  const  thing);
+
+#endif
+#ifndef ZZ_EXPORT_branch_condition_effect_main_checked
+#define ZZ_EXPORT_branch_condition_effect_main_checked
+
+#endif
+#ifndef ZZ_EXPORT_branch_condition_effect_main_main
+#define ZZ_EXPORT_branch_condition_effect_main_main
+
+#line 40 "./src/main.zz"
+int branch_condition_effect_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_branch_condition_effect_main_bob
+#define ZZ_IMPL_branch_condition_effect_main_bob
+
+#line 27 "./src/main.zz"
+int __attribute__ ((visibility ("default"))) branch_condition_effect_main_bob (int const *  const  a)
+#if 0
+#else
+{
+if (
+#line 30 "./src/main.zz"
+    branch_condition_effect_main_check(    a    )){
+
+#line 31 "./src/main.zz"
+  return     2;
+
+}
+
+
+#line 34 "./src/main.zz"
+    ;
+
+#line 36 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_branch_condition_effect_main_check
+#define ZZ_IMPL_branch_condition_effect_main_check
+
+#line 7 "./src/main.zz"
+static bool branch_condition_effect_main_check (int const *  const  thing)
+#if 0
+#else
+{
+
+#line 10 "./src/main.zz"
+;
+
+#line 12 "./src/main.zz"
+  return     false;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_branch_condition_effect_main_main
+#define ZZ_IMPL_branch_condition_effect_main_main
+
+#line 40 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 41 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,170 @@
+/* branch_dont_loose_deref_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_branch_dont_loose_deref_main_Broker
+#define ZZ_FORWARD_branch_dont_loose_deref_main_Broker
+
+#line 6 "./src/main.zz"
+struct branch_dont_loose_deref_main_Broker_t;
+typedef struct branch_dont_loose_deref_main_Broker_t branch_dont_loose_deref_main_Broker;
+#endif
+#ifndef ZZ_FORWARD_branch_dont_loose_deref_main_MAX_BROKERS
+#define ZZ_FORWARD_branch_dont_loose_deref_main_MAX_BROKERS
+#endif
+#ifndef ZZ_FORWARD_branch_dont_loose_deref_main_Store
+#define ZZ_FORWARD_branch_dont_loose_deref_main_Store
+
+#line 9 "./src/main.zz"
+struct branch_dont_loose_deref_main_Store_t;
+typedef struct branch_dont_loose_deref_main_Store_t branch_dont_loose_deref_main_Store;
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_branch_dont_loose_deref_main_main
+#define ZZ_FORWARD_branch_dont_loose_deref_main_main
+#endif
+#ifndef ZZ_FORWARD__string_h_
+#define ZZ_FORWARD__string_h_
+#endif
+#ifndef ZZ_FORWARD_branch_dont_loose_deref_main_parse_query
+#define ZZ_FORWARD_branch_dont_loose_deref_main_parse_query
+#endif
+#ifndef ZZ_EXPORT_branch_dont_loose_deref_main_Broker
+#define ZZ_EXPORT_branch_dont_loose_deref_main_Broker
+
+#line 6 "./src/main.zz"
+struct branch_dont_loose_deref_main_Broker_t {
+
+#line 7 "./src/main.zz"
+   uint8_t protocol ;
+}
+ __attribute__((__packed__)) ;
+#endif
+#ifndef ZZ_EXPORT_SIZEOF_branch_dont_loose_deref_main_Broker
+#define ZZ_EXPORT_SIZEOF_branch_dont_loose_deref_main_Broker
+size_t sizeof_branch_dont_loose_deref_main_Broker() { return sizeof(branch_dont_loose_deref_main_Broker); }
+
+#endif
+#ifndef ZZ_EXPORT_branch_dont_loose_deref_main_MAX_BROKERS
+#define ZZ_EXPORT_branch_dont_loose_deref_main_MAX_BROKERS
+
+#line 5 "./src/main.zz"
+#define branch_dont_loose_deref_main_MAX_BROKERS ((uintptr_t )    16)
+
+#endif
+#ifndef ZZ_EXPORT_branch_dont_loose_deref_main_Store
+#define ZZ_EXPORT_branch_dont_loose_deref_main_Store
+
+#line 9 "./src/main.zz"
+struct branch_dont_loose_deref_main_Store_t {
+
+#line 10 "./src/main.zz"
+   branch_dont_loose_deref_main_Broker broker[    16] ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_SIZEOF_branch_dont_loose_deref_main_Store
+#define ZZ_EXPORT_SIZEOF_branch_dont_loose_deref_main_Store
+size_t sizeof_branch_dont_loose_deref_main_Store() { return sizeof(branch_dont_loose_deref_main_Store); }
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT_branch_dont_loose_deref_main_main
+#define ZZ_EXPORT_branch_dont_loose_deref_main_main
+
+#line 39 "./src/main.zz"
+int branch_dont_loose_deref_main_main (int const  argc, char const *  const *  const  argv);
+
+#endif
+#ifndef ZZ_EXPORT__string_h_
+#define ZZ_EXPORT__string_h_
+
+#line 2 "./src/main.zz"
+#include <string.h>
+
+#endif
+#ifndef ZZ_EXPORT_branch_dont_loose_deref_main_parse_query
+#define ZZ_EXPORT_branch_dont_loose_deref_main_parse_query
+
+#line 14 "./src/main.zz"
+extern bool branch_dont_loose_deref_main_parse_query (branch_dont_loose_deref_main_Store*  const  store, uintptr_t const  answers);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_branch_dont_loose_deref_main_main
+#define ZZ_IMPL_branch_dont_loose_deref_main_main
+
+#line 39 "./src/main.zz"
+int main (int const  argc, char const *  const *  const  argv)
+#if 0
+#else
+{
+
+#line 42 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_branch_dont_loose_deref_main_parse_query
+#define ZZ_IMPL_branch_dont_loose_deref_main_parse_query
+
+#line 14 "./src/main.zz"
+bool __attribute__ ((visibility ("hidden"))) branch_dont_loose_deref_main_parse_query (branch_dont_loose_deref_main_Store*  const  store, uintptr_t const  answers)
+#if 0
+#else
+{
+
+#line 16 "./src/main.zz"
+  uintptr_t storepos  =     0;
+
+#line 18 "./src/main.zz"
+  bool it ;
+while (
+#line 19 "./src/main.zz"
+    it){
+
+#line 21 "./src/main.zz"
+    store ->broker [     storepos] .protocol =     2;
+
+}
+
+if ((
+#line 27 "./src/main.zz"
+    store ->broker [     storepos] .protocol >    0  )){
+
+#line 28 "./src/main.zz"
+    storepos +=     1;
+
+}
+ else {
+
+#line 30 "./src/main.zz"
+;
+
+#line 31 "./src/main.zz"
+    memset(( &    store ->broker [     storepos]),    0,    sizeof(    branch_dont_loose_deref_main_Broker    )    );
+
+}
+
+
+#line 35 "./src/main.zz"
+  return     true;
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,47 @@
+/* branch_effect_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_branch_effect_main_main
+#define ZZ_FORWARD_branch_effect_main_main
+#endif
+#ifndef ZZ_EXPORT_branch_effect_main_main
+#define ZZ_EXPORT_branch_effect_main_main
+
+#line 3 "./src/main.zz"
+int branch_effect_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_branch_effect_main_main
+#define ZZ_IMPL_branch_effect_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 4 "./src/main.zz"
+  int const  x ;
+if ((
+#line 6 "./src/main.zz"
+    x !=    2  )){
+
+#line 7 "./src/main.zz"
+  return     0;
+
+}
+
+
+#line 10 "./src/main.zz"
+    ;
+
+#line 12 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
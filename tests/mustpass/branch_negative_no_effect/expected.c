@@ -0,0 +1,212 @@
+/* branch_negative_no_effect_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_branch_negative_no_effect_main_Enum
+#define ZZ_FORWARD_branch_negative_no_effect_main_Enum
+#endif
+#ifndef ZZ_FORWARD_branch_negative_no_effect_main_checked
+#define ZZ_FORWARD_branch_negative_no_effect_main_checked
+#endif
+#ifndef ZZ_FORWARD_branch_negative_no_effect_main_check
+#define ZZ_FORWARD_branch_negative_no_effect_main_check
+#endif
+#ifndef ZZ_FORWARD_branch_negative_no_effect_main_uncheck
+#define ZZ_FORWARD_branch_negative_no_effect_main_uncheck
+#endif
+#ifndef ZZ_FORWARD_branch_negative_no_effect_main_bob
+#define ZZ_FORWARD_branch_negative_no_effect_main_bob
+#endif
+#ifndef ZZ_FORWARD_branch_negative_no_effect_main_check
+#define ZZ_FORWARD_branch_negative_no_effect_main_check
+#endif
+#ifndef ZZ_FORWARD_branch_negative_no_effect_main_checked
+#define ZZ_FORWARD_branch_negative_no_effect_main_checked
+#endif
+#ifndef ZZ_FORWARD_branch_negative_no_effect_main_main
+#define ZZ_FORWARD_branch_negative_no_effect_main_main
+#endif
+#ifndef ZZ_FORWARD_branch_negative_no_effect_main_uncheck
+#define ZZ_FORWARD_branch_negative_no_effect_main_uncheck
+#endif
+#ifndef ZZ_EXPORT_branch_negative_no_effect_main_Enum
+#define ZZ_EXPORT_branch_negative_no_effect_main_Enum
+
+#line 24 "./src/main.zz"
+typedef enum {
+    branch_negative_no_effect_main_Enum_Bob = 0,
+    branch_negative_no_effect_main_Enum_Peter = 1,
+
+} branch_negative_no_effect_main_Enum;
+
+#endif
+#ifndef ZZ_EXPORT_branch_negative_no_effect_main_checked
+#define ZZ_EXPORT_branch_negative_no_effect_main_checked
+
+#endif
+#ifndef ZZ_EXPORT_branch_negative_no_effect_main_check
+#define ZZ_EXPORT_branch_negative_no_effect_main_check
+
+#line 7 "./src/main.zz"
+static bool branch_negative_no_effect_main_check (int const *  const  thing);
+
+#endif
+#ifndef ZZ_EXPORT_branch_negative_no_effect_main_uncheck
+#define ZZ_EXPORT_branch_negative_no_effect_main_uncheck
+
+#line 15 "./src/main.zz"
+static void branch_negative_no_effect_main_uncheck (int*  const  thing);
+
+#endif
+#ifndef ZZ_EXPORT_branch_negative_no_effect_main_bob
+#define ZZ_EXPORT_branch_negative_no_effect_main_bob
+
+#line 33 "./src/main.zz"
+int branch_negative_no_effect_main_bob (branch_negative_no_effect_main_Enum const  x, int*  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_branch_negative_no_effect_main_check
+#define ZZ_EXPORT_branch_negative_no_effect_main_check
+
+#line 7 "./src/main.zz"
+static bool branch_negative_no_effect_main_check (int const *  const  thing);
+
+#endif
+#ifndef ZZ_EXPORT_branch_negative_no_effect_main_checked
+#define ZZ_EXPORT_branch_negative_no_effect_main_checked
+
+#endif
+#ifndef ZZ_EXPORT_branch_negative_no_effect_main_main
+#define ZZ_EXPORT_branch_negative_no_effect_main_main
+
+#line 60 "./src/main.zz"
+int branch_negative_no_effect_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_branch_negative_no_effect_main_uncheck
+#define ZZ_EXPORT_branch_negative_no_effect_main_uncheck
+
+#line 15 "./src/main.zz"
+static void branch_negative_no_effect_main_uncheck (int*  const  thing);
+
+#endif
+#ifndef ZZ_IMPL_branch_negative_no_effect_main_bob
+#define ZZ_IMPL_branch_negative_no_effect_main_bob
+
+#line 33 "./src/main.zz"
+int __attribute__ ((visibility ("default"))) branch_negative_no_effect_main_bob (branch_negative_no_effect_main_Enum const  x, int*  const  a)
+#if 0
+#else
+{
+if (
+#line 36 "./src/main.zz"
+    branch_negative_no_effect_main_check(    a    )){
+
+#line 37 "./src/main.zz"
+  return     2;
+
+}
+
+
+#line 39 "./src/main.zz"
+    branch_negative_no_effect_main_uncheck(    a    );
+if (
+#line 41 "./src/main.zz"
+    branch_negative_no_effect_main_check(    a    )){
+
+#line 42 "./src/main.zz"
+  return     2;
+
+}
+
+
+#line 46 "./src/main.zz"
+switch (
+    x) {
+case 
+#line 47 "./src/main.zz"
+    branch_negative_no_effect_main_Enum_Bob:
+{
+{
+
+#line 48 "./src/main.zz"
+    branch_negative_no_effect_main_uncheck(    a    );
+
+}
+break;}
+case 
+#line 50 "./src/main.zz"
+    branch_negative_no_effect_main_Enum_Peter:
+{
+{
+
+#line 51 "./src/main.zz"
+    branch_negative_no_effect_main_uncheck(    a    );
+
+}
+break;}
+}
+
+
+#line 56 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_branch_negative_no_effect_main_check
+#define ZZ_IMPL_branch_negative_no_effect_main_check
+
+#line 7 "./src/main.zz"
+static bool branch_negative_no_effect_main_check (int const *  const  thing)
+#if 0
+#else
+{
+
+#line 10 "./src/main.zz"
+;
+
+#line 12 "./src/main.zz"
+  return     false;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_branch_negative_no_effect_main_main
+#define ZZ_IMPL_branch_negative_no_effect_main_main
+
+#line 60 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 61 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_branch_negative_no_effect_main_uncheck
+#define ZZ_IMPL_branch_negative_no_effect_main_uncheck
+
+#line 15 "./src/main.zz"
+static void branch_negative_no_effect_main_uncheck (int*  const  thing)
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+
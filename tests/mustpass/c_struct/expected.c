@@ -0,0 +1,52 @@
+/* c_struct_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD___tmp_zzcheck_tests_mustpass_c_struct_src_c_h_
+#define ZZ_FORWARD___tmp_zzcheck_tests_mustpass_c_struct_src_c_h_
+#endif
+#ifndef ZZ_FORWARD_c_struct_main_main
+#define ZZ_FORWARD_c_struct_main_main
+#endif
+#ifndef ZZ_EXPORT___tmp_zzcheck_tests_mustpass_c_struct_src_c_h_
+#define ZZ_EXPORT___tmp_zzcheck_tests_mustpass_c_struct_src_c_h_
+
+#line 2 "./src/main.zz"
+#include "../../../src/c.h"
+
+#endif
+#ifndef ZZ_EXPORT_c_struct_main_main
+#define ZZ_EXPORT_c_struct_main_main
+
+#line 4 "./src/main.zz"
+int c_struct_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_c_struct_main_main
+#define ZZ_IMPL_c_struct_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 6 "./src/main.zz"
+  struct A a  = {    0,};
+{
+
+#line 8 "./src/main.zz"
+    a .a =     1;
+
+}
+
+
+#line 11 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,66 @@
+/* callsitemodelleak_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_callsitemodelleak_main_main
+#define ZZ_FORWARD_callsitemodelleak_main_main
+#endif
+#ifndef ZZ_FORWARD_callsitemodelleak_main_rand
+#define ZZ_FORWARD_callsitemodelleak_main_rand
+#endif
+#ifndef ZZ_EXPORT_callsitemodelleak_main_main
+#define ZZ_EXPORT_callsitemodelleak_main_main
+
+#line 10 "./src/main.zz"
+int callsitemodelleak_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_callsitemodelleak_main_rand
+#define ZZ_EXPORT_callsitemodelleak_main_rand
+
+#line 1 "./src/main.zz"
+extern void callsitemodelleak_main_rand (uint8_t*  bytes);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_callsitemodelleak_main_main
+#define ZZ_IMPL_callsitemodelleak_main_main
+
+#line 10 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_callsitemodelleak_main_rand
+#define ZZ_IMPL_callsitemodelleak_main_rand
+
+#line 1 "./src/main.zz"
+void __attribute__ ((visibility ("hidden"))) callsitemodelleak_main_rand (uint8_t*  bytes)
+#if 0
+#else
+{
+  for (;;){
+
+#line 4 "./src/main.zz"
+;
+
+#line 5 "./src/main.zz"
+    bytes = (    bytes +    1  );
+
+}
+
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,50 @@
+/* cast_into_to_bool_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_cast_into_to_bool_main_main
+#define ZZ_FORWARD_cast_into_to_bool_main_main
+#endif
+#ifndef ZZ_EXPORT_cast_into_to_bool_main_main
+#define ZZ_EXPORT_cast_into_to_bool_main_main
+
+#line 3 "./src/main.zz"
+int cast_into_to_bool_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_cast_into_to_bool_main_main
+#define ZZ_IMPL_cast_into_to_bool_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 5 "./src/main.zz"
+  int const  a  =     0;
+
+#line 6 "./src/main.zz"
+  bool const  b  =     (bool)(    a);
+
+#line 7 "./src/main.zz"
+    ;
+
+#line 9 "./src/main.zz"
+  int const  a2  =     1;
+
+#line 10 "./src/main.zz"
+  bool const  b2  =     (bool)(    a2);
+
+#line 11 "./src/main.zz"
+    ;
+
+#line 13 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
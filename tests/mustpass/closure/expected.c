@@ -0,0 +1,124 @@
+/* closure_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_closure_main_add
+#define ZZ_FORWARD_closure_main_add
+#endif
+#ifndef ZZ_FORWARD_closure_main_add_t
+#define ZZ_FORWARD_closure_main_add_t
+#endif
+#ifndef ZZ_FORWARD_closure_main_bla
+#define ZZ_FORWARD_closure_main_bla
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_closure_main_main
+#define ZZ_FORWARD_closure_main_main
+#endif
+#ifndef ZZ_EXPORT_closure_main_add
+#define ZZ_EXPORT_closure_main_add
+
+#line 7 "./src/main.zz"
+static int closure_main_add (int const  a, int const  b);
+
+#endif
+#ifndef ZZ_EXPORT_closure_main_add_t
+#define ZZ_EXPORT_closure_main_add_t
+
+#line 4 "./src/main.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_closure_main_add_t)
+#define ZZ_HAS_DEFINED_CLOSURE_closure_main_add_t 1
+typedef struct {
+    void *ctx;
+    int (*fn) (int const  a, int const  b, void * _ctx);
+} closure_main_add_t;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_closure_main_add_t)
+
+#endif
+#ifndef ZZ_EXPORT_closure_main_bla
+#define ZZ_EXPORT_closure_main_bla
+
+#line 11 "./src/main.zz"
+static int closure_main_bla (closure_main_add_t const  adder);
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_closure_main_main
+#define ZZ_EXPORT_closure_main_main
+
+#line 18 "./src/main.zz"
+int closure_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_closure_main_add
+#define ZZ_IMPL_closure_main_add
+
+#line 7 "./src/main.zz"
+static int closure_main_add (int const  a, int const  b)
+#if 0
+#else
+{
+
+#line 8 "./src/main.zz"
+  return (    a +    b  );
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_closure_main_bla
+#define ZZ_IMPL_closure_main_bla
+
+#line 11 "./src/main.zz"
+static int closure_main_bla (closure_main_add_t const  adder)
+#if 0
+#else
+{
+
+#line 14 "./src/main.zz"
+  int const  r  =     adder .fn(    1,    2,    adder .ctx    );
+
+#line 15 "./src/main.zz"
+  return     r;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_closure_main_main
+#define ZZ_IMPL_closure_main_main
+
+#line 18 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 20 "./src/main.zz"
+    printf(    "hello %d\n",    closure_main_bla(    (closure_main_add_t){
+.fn =     (void const * )(    closure_main_add),
+
+.ctx =     0,
+}    )    );
+
+#line 21 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,271 @@
+/* constant_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_constant_main_ALL
+#define ZZ_FORWARD_constant_main_ALL
+#endif
+#ifndef ZZ_FORWARD_constant_main_BYTE
+#define ZZ_FORWARD_constant_main_BYTE
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_constant_main_SIZE
+#define ZZ_FORWARD_constant_main_SIZE
+#endif
+#ifndef ZZ_FORWARD_constant_main_Buf
+#define ZZ_FORWARD_constant_main_Buf
+
+#line 25 "./src/main.zz"
+struct constant_main_Buf_t;
+typedef struct constant_main_Buf_t constant_main_Buf;
+#endif
+#ifndef ZZ_FORWARD_constant_main_Buf_16
+#define ZZ_FORWARD_constant_main_Buf_16
+struct constant_main_Buf_16_t;
+typedef struct constant_main_Buf_16_t constant_main_Buf_16;
+#endif
+#ifndef ZZ_FORWARD_constant_main_Color
+#define ZZ_FORWARD_constant_main_Color
+#endif
+#ifndef ZZ_FORWARD_constant_main_COLORS
+#define ZZ_FORWARD_constant_main_COLORS
+#endif
+#ifndef ZZ_FORWARD_constant_main_HIGH
+#define ZZ_FORWARD_constant_main_HIGH
+#endif
+#ifndef ZZ_FORWARD_constant_main_MASK
+#define ZZ_FORWARD_constant_main_MASK
+#endif
+#ifndef ZZ_FORWARD_constant_main_SIZE_HALF
+#define ZZ_FORWARD_constant_main_SIZE_HALF
+#endif
+#ifndef ZZ_FORWARD_constant_main_NEGATIVE
+#define ZZ_FORWARD_constant_main_NEGATIVE
+#endif
+#ifndef ZZ_FORWARD_constant_main_above
+#define ZZ_FORWARD_constant_main_above
+#endif
+#ifndef ZZ_FORWARD__string_h_
+#define ZZ_FORWARD__string_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_constant_main_main
+#define ZZ_FORWARD_constant_main_main
+#endif
+#ifndef ZZ_FORWARD_constant_main_static_assert_355
+#define ZZ_FORWARD_constant_main_static_assert_355
+#endif
+#ifndef ZZ_FORWARD_constant_main_static_assert_407
+#define ZZ_FORWARD_constant_main_static_assert_407
+#endif
+#ifndef ZZ_FORWARD_constant_main_static_assert_486
+#define ZZ_FORWARD_constant_main_static_assert_486
+#endif
+#ifndef ZZ_FORWARD_constant_main_static_assert_540
+#define ZZ_FORWARD_constant_main_static_assert_540
+#endif
+#ifndef ZZ_FORWARD_constant_main_static_assert_628
+#define ZZ_FORWARD_constant_main_static_assert_628
+#endif
+#ifndef ZZ_EXPORT_constant_main_ALL
+#define ZZ_EXPORT_constant_main_ALL
+
+#line 8 "./src/main.zz"
+#define constant_main_ALL ((uint32_t )    4294967295)
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_BYTE
+#define ZZ_EXPORT_constant_main_BYTE
+
+#line 9 "./src/main.zz"
+#define constant_main_BYTE ((uint8_t )    255)
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_SIZE
+#define ZZ_EXPORT_constant_main_SIZE
+
+#line 4 "./src/main.zz"
+#define constant_main_SIZE ((uintptr_t )    16)
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_Buf
+#define ZZ_EXPORT_constant_main_Buf
+
+#line 25 "./src/main.zz"
+struct constant_main_Buf_t {
+
+#line 26 "./src/main.zz"
+   uint8_t head[    16] ;
+
+#line 27 "./src/main.zz"
+   uint8_t mem[] ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_constant_main_Buf_16_16
+#define ZZ_EXPORT_constant_main_Buf_16_16
+
+#line 25 "./src/main.zz"
+struct constant_main_Buf_16_t {
+
+#line 26 "./src/main.zz"
+   uint8_t head[    16] ;
+
+#line 27 "./src/main.zz"
+   uint8_t mem[16] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_Color
+#define ZZ_EXPORT_constant_main_Color
+
+#line 12 "./src/main.zz"
+typedef enum {
+    constant_main_Color_Red = 3,
+    constant_main_Color_Green = 4,
+
+} constant_main_Color;
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_COLORS
+#define ZZ_EXPORT_constant_main_COLORS
+
+#line 17 "./src/main.zz"
+#define constant_main_COLORS ((uintptr_t )    5)
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_HIGH
+#define ZZ_EXPORT_constant_main_HIGH
+
+#line 10 "./src/main.zz"
+#define constant_main_HIGH ((uint32_t )    2147483648)
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_MASK
+#define ZZ_EXPORT_constant_main_MASK
+
+#line 5 "./src/main.zz"
+#define constant_main_MASK ((uint8_t )    15)
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_SIZE_HALF
+#define ZZ_EXPORT_constant_main_SIZE_HALF
+
+#line 7 "./src/main.zz"
+#define constant_main_SIZE_HALF ((uintptr_t )    8)
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_NEGATIVE
+#define ZZ_EXPORT_constant_main_NEGATIVE
+
+#line 6 "./src/main.zz"
+#define constant_main_NEGATIVE ((int32_t )    -2)
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_above
+#define ZZ_EXPORT_constant_main_above
+
+#line 30 "./src/main.zz"
+static int32_t constant_main_above (int32_t const  a);
+
+#endif
+#ifndef ZZ_EXPORT__string_h_
+#define ZZ_EXPORT__string_h_
+
+#line 2 "./src/main.zz"
+#include <string.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_constant_main_main
+#define ZZ_EXPORT_constant_main_main
+
+#line 36 "./src/main.zz"
+int constant_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_constant_main_above
+#define ZZ_IMPL_constant_main_above
+
+#line 30 "./src/main.zz"
+static int32_t constant_main_above (int32_t const  a)
+#if 0
+#else
+{
+
+#line 33 "./src/main.zz"
+  return     a;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_constant_main_main
+#define ZZ_IMPL_constant_main_main
+
+#line 36 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 37 "./src/main.zz"
+  uint8_t buf  [     32 ] ;
+
+#line 38 "./src/main.zz"
+    memset(    buf,    constant_main_MASK,    sizeof(    buf    )    );
+
+#line 39 "./src/main.zz"
+    printf(    "buf %zu %u\n",    sizeof(    buf    ),    buf [ ((    constant_main_SIZE *    2  ) -    1  )]    );
+
+#line 41 "./src/main.zz"
+  uint8_t table  [     5 ] ;
+
+#line 43 "./src/main.zz"
+  constant_main_Buf_16 b ;
+
+#line 44 "./src/main.zz"
+    memset(( &    b),    1,    sizeof(    b    )    );
+
+#line 45 "./src/main.zz"
+    printf(    "tail %zu %u\n",    sizeof(    b    ),    b .mem [ (    constant_main_SIZE -    1  )]    );
+
+#line 48 "./src/main.zz"
+    table [     (uintptr_t)((    constant_main_NEGATIVE +    3  ))] =     1;
+
+#line 49 "./src/main.zz"
+    printf(    "colors %zu\n",    sizeof(    table    )    );
+
+#line 50 "./src/main.zz"
+    printf(    "negative %d %d\n",    constant_main_NEGATIVE,    constant_main_above(    1    )    );
+
+#line 51 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,116 @@
+/* ctype_in_decl_foh.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD___tmp_zzcheck_tests_mustpass_ctype_in_decl_src_foh_h_
+#define ZZ_FORWARD___tmp_zzcheck_tests_mustpass_ctype_in_decl_src_foh_h_
+#endif
+#ifndef ZZ_FORWARD_ctype_in_decl_foh_foh
+#define ZZ_FORWARD_ctype_in_decl_foh_foh
+#endif
+#ifndef ZZ_EXPORT___tmp_zzcheck_tests_mustpass_ctype_in_decl_src_foh_h_
+#define ZZ_EXPORT___tmp_zzcheck_tests_mustpass_ctype_in_decl_src_foh_h_
+
+#line 1 "./src/foh.zz"
+
+#line 1 "./src/foh.h"
+typedef struct {
+    int a;
+}A;
+
+#endif
+#ifndef ZZ_EXPORT_ctype_in_decl_foh_foh
+#define ZZ_EXPORT_ctype_in_decl_foh_foh
+
+#line 4 "./src/foh.zz"
+extern A  const * ctype_in_decl_foh_foh ();
+
+#endif
+#ifndef ZZ_IMPL_ctype_in_decl_foh_foh
+#define ZZ_IMPL_ctype_in_decl_foh_foh
+A  const * __attribute__ ((visibility ("hidden"))) ctype_in_decl_foh_foh ()
+#if 0
+#else
+{
+
+#line 5 "./src/foh.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+
+/* ctype_in_decl_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD___tmp_zzcheck_tests_mustpass_ctype_in_decl_src_foh_h_
+#define ZZ_FORWARD___tmp_zzcheck_tests_mustpass_ctype_in_decl_src_foh_h_
+#endif
+#ifndef ZZ_FORWARD_ctype_in_decl_foh_foh
+#define ZZ_FORWARD_ctype_in_decl_foh_foh
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_ctype_in_decl_main_main
+#define ZZ_FORWARD_ctype_in_decl_main_main
+#endif
+#ifndef ZZ_EXPORT___tmp_zzcheck_tests_mustpass_ctype_in_decl_src_foh_h_
+#define ZZ_EXPORT___tmp_zzcheck_tests_mustpass_ctype_in_decl_src_foh_h_
+
+#line 1 "./src/foh.zz"
+
+#line 1 "./src/foh.h"
+typedef struct {
+    int a;
+}A;
+
+#endif
+#ifndef ZZ_EXPORT_ctype_in_decl_foh_foh
+#define ZZ_EXPORT_ctype_in_decl_foh_foh
+
+#line 4 "./src/foh.zz"
+extern A  const * ctype_in_decl_foh_foh ();
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_ctype_in_decl_main_main
+#define ZZ_EXPORT_ctype_in_decl_main_main
+
+#line 4 "./src/main.zz"
+int ctype_in_decl_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_ctype_in_decl_main_main
+#define ZZ_IMPL_ctype_in_decl_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 6 "./src/main.zz"
+    ctype_in_decl_foh_foh(    );
+
+#line 8 "./src/main.zz"
+    printf(    "hello ctype_in_decl\n"    );
+
+#line 9 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,229 @@
+/* cxx_header_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_cxx_header_main_Color
+#define ZZ_FORWARD_cxx_header_main_Color
+#endif
+#ifndef ZZ_FORWARD_cxx_header_main_Counter
+#define ZZ_FORWARD_cxx_header_main_Counter
+
+#line 9 "./src/main.zz"
+struct cxx_header_main_Counter_t;
+typedef struct cxx_header_main_Counter_t cxx_header_main_Counter;
+#endif
+#ifndef ZZ_FORWARD_cxx_header_main_op_t
+#define ZZ_FORWARD_cxx_header_main_op_t
+#endif
+#ifndef ZZ_FORWARD_cxx_header_main_apply
+#define ZZ_FORWARD_cxx_header_main_apply
+#endif
+#ifndef ZZ_FORWARD_cxx_header_main_make
+#define ZZ_FORWARD_cxx_header_main_make
+#endif
+#ifndef ZZ_FORWARD___tmp_zzcheck_tests_mustpass_cxx_header_src_check_h_
+#define ZZ_FORWARD___tmp_zzcheck_tests_mustpass_cxx_header_src_check_h_
+#endif
+#ifndef ZZ_FORWARD_cxx_header_main_main
+#define ZZ_FORWARD_cxx_header_main_main
+#endif
+#ifndef ZZ_FORWARD_cxx_header_main_make
+#define ZZ_FORWARD_cxx_header_main_make
+#endif
+#ifndef ZZ_FORWARD_cxx_header_main_shade
+#define ZZ_FORWARD_cxx_header_main_shade
+#endif
+#ifndef ZZ_FORWARD_cxx_header_main_value
+#define ZZ_FORWARD_cxx_header_main_value
+#endif
+#ifndef ZZ_EXPORT_cxx_header_main_Color
+#define ZZ_EXPORT_cxx_header_main_Color
+
+#line 3 "./src/main.zz"
+typedef enum {
+    cxx_header_main_Color_Red = 0,
+    cxx_header_main_Color_Green = 4,
+    cxx_header_main_Color_Blue = 5,
+
+} cxx_header_main_Color;
+
+#endif
+#ifndef ZZ_EXPORT_cxx_header_main_Counter
+#define ZZ_EXPORT_cxx_header_main_Counter
+
+#line 9 "./src/main.zz"
+struct cxx_header_main_Counter_t {
+
+#line 10 "./src/main.zz"
+   int n ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_SIZEOF_cxx_header_main_Counter
+#define ZZ_EXPORT_SIZEOF_cxx_header_main_Counter
+size_t sizeof_cxx_header_main_Counter() { return sizeof(cxx_header_main_Counter); }
+
+#endif
+#ifndef ZZ_EXPORT_cxx_header_main_op_t
+#define ZZ_EXPORT_cxx_header_main_op_t
+
+#line 28 "./src/main.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_cxx_header_main_op_t)
+#define ZZ_HAS_DEFINED_CLOSURE_cxx_header_main_op_t 1
+typedef struct {
+    void *ctx;
+    int (*fn) (int const  a, int const  b, void * _ctx);
+} cxx_header_main_op_t;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_cxx_header_main_op_t)
+
+#endif
+#ifndef ZZ_EXPORT_cxx_header_main_apply
+#define ZZ_EXPORT_cxx_header_main_apply
+
+#line 30 "./src/main.zz"
+int cxx_header_main_apply (cxx_header_main_op_t const  op, int const  a, int const  b);
+
+#endif
+#ifndef ZZ_EXPORT_cxx_header_main_make
+#define ZZ_EXPORT_cxx_header_main_make
+
+#line 13 "./src/main.zz"
+void cxx_header_main_make (cxx_header_main_Counter*  const  self, int const  start);
+
+#endif
+#ifndef ZZ_EXPORT___tmp_zzcheck_tests_mustpass_cxx_header_src_check_h_
+#define ZZ_EXPORT___tmp_zzcheck_tests_mustpass_cxx_header_src_check_h_
+
+#line 1 "./src/main.zz"
+#include "../../../src/check.h"
+
+#endif
+#ifndef ZZ_EXPORT_cxx_header_main_main
+#define ZZ_EXPORT_cxx_header_main_main
+
+#line 36 "./src/main.zz"
+int cxx_header_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_cxx_header_main_make
+#define ZZ_EXPORT_cxx_header_main_make
+
+#line 13 "./src/main.zz"
+void cxx_header_main_make (cxx_header_main_Counter*  const  self, int const  start);
+
+#endif
+#ifndef ZZ_EXPORT_cxx_header_main_shade
+#define ZZ_EXPORT_cxx_header_main_shade
+
+#line 23 "./src/main.zz"
+cxx_header_main_Color cxx_header_main_shade (cxx_header_main_Color const  c);
+
+#endif
+#ifndef ZZ_EXPORT_cxx_header_main_value
+#define ZZ_EXPORT_cxx_header_main_value
+
+#line 18 "./src/main.zz"
+int cxx_header_main_value (cxx_header_main_Counter const *  const  self);
+
+#endif
+#ifndef ZZ_IMPL_cxx_header_main_apply
+#define ZZ_IMPL_cxx_header_main_apply
+
+#line 30 "./src/main.zz"
+int __attribute__ ((visibility ("default"))) cxx_header_main_apply (cxx_header_main_op_t const  op, int const  a, int const  b)
+#if 0
+#else
+{
+
+#line 33 "./src/main.zz"
+  return     op .fn(    a,    b,    op .ctx    );
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_cxx_header_main_main
+#define ZZ_IMPL_cxx_header_main_main
+
+#line 36 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 37 "./src/main.zz"
+  cxx_header_main_Counter c  = {    0,};
+    cxx_header_main_make(( &    c),    3    );
+if ((
+#line 38 "./src/main.zz"
+    cxx_header_main_value(( &    c)    ) !=    3  )){
+
+#line 39 "./src/main.zz"
+  return     1;
+
+}
+
+
+#line 41 "./src/main.zz"
+  return     (int)(    check_cxx(    ));
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_cxx_header_main_make
+#define ZZ_IMPL_cxx_header_main_make
+
+#line 13 "./src/main.zz"
+void __attribute__ ((visibility ("default"))) cxx_header_main_make (cxx_header_main_Counter*  const  self, int const  start)
+#if 0
+#else
+{
+
+#line 15 "./src/main.zz"
+    self ->n =     start;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_cxx_header_main_shade
+#define ZZ_IMPL_cxx_header_main_shade
+
+#line 23 "./src/main.zz"
+cxx_header_main_Color __attribute__ ((visibility ("default"))) cxx_header_main_shade (cxx_header_main_Color const  c)
+#if 0
+#else
+{
+
+#line 25 "./src/main.zz"
+  return     cxx_header_main_Color_Blue;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_cxx_header_main_value
+#define ZZ_IMPL_cxx_header_main_value
+
+#line 18 "./src/main.zz"
+int __attribute__ ((visibility ("default"))) cxx_header_main_value (cxx_header_main_Counter const *  const  self)
+#if 0
+#else
+{
+
+#line 20 "./src/main.zz"
+  return     self ->n;
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,19 @@
+#include "check.h"
+#include "target/include/zz/cxx_header_main.hpp"
+
+namespace m = cxx_header::main;
+
+extern "C" int check_cxx() {
+    m::Counter c(3);
+    if (c.value() != 3) {
+        return 1;
+    }
+    if (m::shade(m::Color::Red) != m::Color::Blue) {
+        return 2;
+    }
+    m::op_t add = [](int a, int b) { return a + b; };
+    if (m::apply(m::op_t_adapter(add), 1, 2) != 3) {
+        return 3;
+    }
+    return 0;
+}
@@ -0,0 +1,264 @@
+/* defer_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_defer_main_counter
+#define ZZ_FORWARD_defer_main_counter
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_defer_main_early
+#define ZZ_FORWARD_defer_main_early
+#endif
+#ifndef ZZ_FORWARD_defer_main_loop
+#define ZZ_FORWARD_defer_main_loop
+#endif
+#ifndef ZZ_FORWARD_defer_main_main
+#define ZZ_FORWARD_defer_main_main
+#endif
+#ifndef ZZ_EXPORT_defer_main_counter
+#define ZZ_EXPORT_defer_main_counter
+
+#line 12 "./src/main.zz"
+static uint32_t defer_main_counter (uint32_t*  const  c);
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_defer_main_early
+#define ZZ_EXPORT_defer_main_early
+
+#line 3 "./src/main.zz"
+static uint32_t defer_main_early (uint32_t const  a);
+
+#endif
+#ifndef ZZ_EXPORT_defer_main_loop
+#define ZZ_EXPORT_defer_main_loop
+
+#line 17 "./src/main.zz"
+static void defer_main_loop ();
+
+#endif
+#ifndef ZZ_EXPORT_defer_main_main
+#define ZZ_EXPORT_defer_main_main
+
+#line 32 "./src/main.zz"
+int defer_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_defer_main_counter
+#define ZZ_IMPL_defer_main_counter
+
+#line 12 "./src/main.zz"
+static uint32_t defer_main_counter (uint32_t*  const  c)
+#if 0
+#else
+{
+{
+
+#line 14 "./src/main.zz"
+  uint32_t const  __deferred_return  = ( *    c);
+
+#line 13 "./src/main.zz"
+( *    c) +=     1;
+
+#line 14 "./src/main.zz"
+  return     __deferred_return;
+
+}
+
+
+#line 13 "./src/main.zz"
+( *    c) +=     1;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_defer_main_early
+#define ZZ_IMPL_defer_main_early
+
+#line 3 "./src/main.zz"
+static uint32_t defer_main_early (uint32_t const  a)
+#if 0
+#else
+{
+if ((
+#line 5 "./src/main.zz"
+    a >    1  )){
+{
+
+#line 7 "./src/main.zz"
+  uint32_t const  __deferred_return  =     a;
+
+#line 6 "./src/main.zz"
+    printf(    "early big\n"    );
+
+#line 4 "./src/main.zz"
+    printf(    "early done\n"    );
+
+#line 7 "./src/main.zz"
+  return     __deferred_return;
+
+}
+
+
+#line 6 "./src/main.zz"
+    printf(    "early big\n"    );
+
+}
+
+{
+
+#line 9 "./src/main.zz"
+  uint32_t const  __deferred_return  =     0;
+
+#line 4 "./src/main.zz"
+    printf(    "early done\n"    );
+
+#line 9 "./src/main.zz"
+  return     __deferred_return;
+
+}
+
+
+#line 4 "./src/main.zz"
+    printf(    "early done\n"    );
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_defer_main_loop
+#define ZZ_IMPL_defer_main_loop
+
+#line 17 "./src/main.zz"
+static void defer_main_loop ()
+#if 0
+#else
+{
+  for (
+
+#line 18 "./src/main.zz"
+  uint32_t i  =     0;(    i <    4  );
+(    i ++)){
+if ((
+#line 22 "./src/main.zz"
+    i ==    1  )){
+{
+
+#line 20 "./src/main.zz"
+    printf(    "step %u\n",    i    );
+
+}
+
+
+#line 23 "./src/main.zz"
+continue;
+
+}
+
+if ((
+#line 25 "./src/main.zz"
+    i ==    3  )){
+{
+
+#line 20 "./src/main.zz"
+    printf(    "step %u\n",    i    );
+
+}
+
+
+#line 26 "./src/main.zz"
+break;
+
+}
+
+
+#line 28 "./src/main.zz"
+    printf(    "body %u\n",    i    );
+{
+
+#line 20 "./src/main.zz"
+    printf(    "step %u\n",    i    );
+
+}
+
+
+}
+
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_defer_main_main
+#define ZZ_IMPL_defer_main_main
+
+#line 32 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+{
+
+#line 36 "./src/main.zz"
+    printf(    "first\n"    );
+
+#line 35 "./src/main.zz"
+    printf(    "second\n"    );
+
+}
+
+
+#line 38 "./src/main.zz"
+    printf(    "early %u\n",    defer_main_early(    2    )    );
+
+#line 39 "./src/main.zz"
+    printf(    "early %u\n",    defer_main_early(    0    )    );
+
+#line 41 "./src/main.zz"
+  uint32_t c  =     5;
+
+#line 42 "./src/main.zz"
+  uint32_t const  r  =     defer_main_counter(( &    c)    );
+
+#line 43 "./src/main.zz"
+    printf(    "counter %u %u\n",    r,    c    );
+
+#line 45 "./src/main.zz"
+    defer_main_loop(    );
+{
+
+#line 46 "./src/main.zz"
+  int const  __deferred_return  =     0;
+
+#line 33 "./src/main.zz"
+    printf(    "main done\n"    );
+
+#line 46 "./src/main.zz"
+  return     __deferred_return;
+
+}
+
+
+#line 33 "./src/main.zz"
+    printf(    "main done\n"    );
+
+}
+#endif
+
+
+#endif
+#endif
+
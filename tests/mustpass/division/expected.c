@@ -0,0 +1,280 @@
+/* division_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__stdint_h_
+#define ZZ_FORWARD__stdint_h_
+#endif
+#ifndef ZZ_FORWARD_division_main_WORD
+#define ZZ_FORWARD_division_main_WORD
+#endif
+#ifndef ZZ_FORWARD_division_main_div
+#define ZZ_FORWARD_division_main_div
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_division_main_rem
+#define ZZ_FORWARD_division_main_rem
+#endif
+#ifndef ZZ_FORWARD_division_main_shift
+#define ZZ_FORWARD_division_main_shift
+#endif
+#ifndef ZZ_FORWARD_division_main_unchecked
+#define ZZ_FORWARD_division_main_unchecked
+#endif
+#ifndef ZZ_FORWARD_division_main_words
+#define ZZ_FORWARD_division_main_words
+#endif
+#ifndef ZZ_FORWARD_division_main_main
+#define ZZ_FORWARD_division_main_main
+#endif
+#ifndef ZZ_FORWARD_division_main_rem
+#define ZZ_FORWARD_division_main_rem
+#endif
+#ifndef ZZ_FORWARD_division_main_shift
+#define ZZ_FORWARD_division_main_shift
+#endif
+#ifndef ZZ_FORWARD_division_main_unchecked
+#define ZZ_FORWARD_division_main_unchecked
+#endif
+#ifndef ZZ_FORWARD_division_main_words
+#define ZZ_FORWARD_division_main_words
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdint_h_
+#define ZZ_EXPORT__stdint_h_
+
+#line 2 "./src/main.zz"
+#include <stdint.h>
+
+#endif
+#ifndef ZZ_EXPORT_division_main_WORD
+#define ZZ_EXPORT_division_main_WORD
+
+#line 4 "./src/main.zz"
+#define division_main_WORD ((uintptr_t )    (uintptr_t)(    sizeof(    uintptr_t    )))
+
+#endif
+#ifndef ZZ_EXPORT_division_main_div
+#define ZZ_EXPORT_division_main_div
+
+#line 6 "./src/main.zz"
+static uint32_t division_main_div (uint32_t const  a, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_division_main_rem
+#define ZZ_EXPORT_division_main_rem
+
+#line 12 "./src/main.zz"
+static int32_t division_main_rem (int32_t const  a, int32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_division_main_shift
+#define ZZ_EXPORT_division_main_shift
+
+#line 18 "./src/main.zz"
+static uint32_t division_main_shift (uint32_t const  a, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_division_main_unchecked
+#define ZZ_EXPORT_division_main_unchecked
+
+#line 29 "./src/main.zz"
+static uint32_t division_main_unchecked (uint32_t const  a, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_division_main_words
+#define ZZ_EXPORT_division_main_words
+
+#line 25 "./src/main.zz"
+static uintptr_t division_main_words (uintptr_t const  bytes);
+
+#endif
+#ifndef ZZ_EXPORT_division_main_main
+#define ZZ_EXPORT_division_main_main
+
+#line 37 "./src/main.zz"
+int division_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_division_main_rem
+#define ZZ_EXPORT_division_main_rem
+
+#line 12 "./src/main.zz"
+static int32_t division_main_rem (int32_t const  a, int32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_division_main_shift
+#define ZZ_EXPORT_division_main_shift
+
+#line 18 "./src/main.zz"
+static uint32_t division_main_shift (uint32_t const  a, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_division_main_unchecked
+#define ZZ_EXPORT_division_main_unchecked
+
+#line 29 "./src/main.zz"
+static uint32_t division_main_unchecked (uint32_t const  a, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_division_main_words
+#define ZZ_EXPORT_division_main_words
+
+#line 25 "./src/main.zz"
+static uintptr_t division_main_words (uintptr_t const  bytes);
+
+#endif
+#ifndef ZZ_IMPL_division_main_div
+#define ZZ_IMPL_division_main_div
+
+#line 6 "./src/main.zz"
+static uint32_t division_main_div (uint32_t const  a, uint32_t const  b)
+#if 0
+#else
+{
+
+#line 9 "./src/main.zz"
+  return (    a /    b  );
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_division_main_main
+#define ZZ_IMPL_division_main_main
+
+#line 37 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 38 "./src/main.zz"
+  uint32_t a  =     12;
+
+#line 39 "./src/main.zz"
+    a /=     4;
+
+#line 40 "./src/main.zz"
+    a %=     2;
+
+#line 41 "./src/main.zz"
+    printf(    "%u %u %d %u %u %u %zu\n",    division_main_div(    7,    2    ),    a,    division_main_rem(    -7,    3    ),    division_main_shift(    1,    4    ),    division_main_shift(    1,    40    ),    division_main_unchecked(    9,    3    ),(    division_main_words(    64    ) *    division_main_WORD  )    );
+
+#line 42 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_division_main_rem
+#define ZZ_IMPL_division_main_rem
+
+#line 12 "./src/main.zz"
+static int32_t division_main_rem (int32_t const  a, int32_t const  b)
+#if 0
+#else
+{
+
+#line 15 "./src/main.zz"
+  return (    a %    b  );
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_division_main_shift
+#define ZZ_IMPL_division_main_shift
+
+#line 18 "./src/main.zz"
+static uint32_t division_main_shift (uint32_t const  a, uint32_t const  b)
+#if 0
+#else
+{
+if ((
+#line 19 "./src/main.zz"
+    b <    32  )){
+
+#line 20 "./src/main.zz"
+  return (    a <<    b  );
+
+}
+
+
+#line 22 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_division_main_unchecked
+#define ZZ_IMPL_division_main_unchecked
+
+#line 29 "./src/main.zz"
+static uint32_t division_main_unchecked (uint32_t const  a, uint32_t const  b)
+#if 0
+#else
+{
+
+#line 30 "./src/main.zz"
+  uint32_t r  =     0;
+{
+
+#line 32 "./src/main.zz"
+    r = (    a /    b  );
+
+}
+
+
+#line 34 "./src/main.zz"
+  return     r;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_division_main_words
+#define ZZ_IMPL_division_main_words
+
+#line 25 "./src/main.zz"
+static uintptr_t division_main_words (uintptr_t const  bytes)
+#if 0
+#else
+{
+
+#line 26 "./src/main.zz"
+  return (    bytes /    division_main_WORD  );
+
+}
+#endif
+
+
+#endif
+
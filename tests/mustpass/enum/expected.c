@@ -0,0 +1,101 @@
+/* enum_bob.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_enum_bob_A
+#define ZZ_FORWARD_enum_bob_A
+#endif
+#ifndef ZZ_EXPORT_enum_bob_A
+#define ZZ_EXPORT_enum_bob_A
+
+#line 1 "./src/bob.zz"
+typedef enum {
+    enum_bob_A_Identity = 1,
+    enum_bob_A_Timestamp = 2,
+    enum_bob_A_Handshake = 3,
+    enum_bob_A_Route = 4,
+    enum_bob_A_Paths = 5,
+
+} enum_bob_A;
+
+#endif
+
+/* enum_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_enum_bob_A
+#define ZZ_FORWARD_enum_bob_A
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_enum_main_main
+#define ZZ_FORWARD_enum_main_main
+#endif
+#ifndef ZZ_EXPORT_enum_bob_A
+#define ZZ_EXPORT_enum_bob_A
+
+#line 1 "./src/bob.zz"
+typedef enum {
+    enum_bob_A_Identity = 1,
+    enum_bob_A_Timestamp = 2,
+    enum_bob_A_Handshake = 3,
+    enum_bob_A_Route = 4,
+    enum_bob_A_Paths = 5,
+
+} enum_bob_A;
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_enum_main_main
+#define ZZ_EXPORT_enum_main_main
+
+#line 5 "./src/main.zz"
+int enum_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_enum_main_main
+#define ZZ_IMPL_enum_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 7 "./src/main.zz"
+  uintptr_t const  i ;
+
+#line 9 "./src/main.zz"
+switch (
+    i) {
+case 
+#line 10 "./src/main.zz"
+    enum_bob_A_Identity:
+{
+{
+
+}
+break;}
+}
+
+
+#line 14 "./src/main.zz"
+    printf(    "hello enum %u\n",    i    );
+
+#line 15 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,77 @@
+/* escape_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__assert_h_
+#define ZZ_FORWARD__assert_h_
+#endif
+#ifndef ZZ_FORWARD__string_h_
+#define ZZ_FORWARD__string_h_
+#endif
+#ifndef ZZ_FORWARD_escape_main_main
+#define ZZ_FORWARD_escape_main_main
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__assert_h_
+#define ZZ_EXPORT__assert_h_
+
+#line 2 "./src/main.zz"
+#include <assert.h>
+
+#endif
+#ifndef ZZ_EXPORT__string_h_
+#define ZZ_EXPORT__string_h_
+
+#line 3 "./src/main.zz"
+#include <string.h>
+
+#endif
+#ifndef ZZ_EXPORT_escape_main_main
+#define ZZ_EXPORT_escape_main_main
+
+#line 5 "./src/main.zz"
+int escape_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_escape_main_main
+#define ZZ_IMPL_escape_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 6 "./src/main.zz"
+  char const  c1  =     ' ';
+
+#line 7 "./src/main.zz"
+  char const  c2  =     '\x12';
+
+#line 8 "./src/main.zz"
+  char const  c3  =     '\'';
+
+#line 10 "./src/main.zz"
+  char const *  const  str  =     "abc123 ' \" ? \\ ""\x7"" ""\x8"" ""\xc"" \n \r \t ""\xb"" ""\x12"" ""\x12"" abc1923u123    {} 23[123 ";
+
+#line 11 "./src/main.zz"
+    assert((    58 ==    strlen(    str    )  )    );
+
+#line 12 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
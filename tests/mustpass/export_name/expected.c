@@ -0,0 +1,120 @@
+/* export_name_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_stable_add
+#define ZZ_FORWARD_stable_add
+#endif
+#ifndef ZZ_FORWARD___tmp_zzcheck_tests_mustpass_export_name_src_names_h_
+#define ZZ_FORWARD___tmp_zzcheck_tests_mustpass_export_name_src_names_h_
+#endif
+#ifndef ZZ_FORWARD_myprefix_export_name_main_sub
+#define ZZ_FORWARD_myprefix_export_name_main_sub
+#endif
+#ifndef ZZ_FORWARD_myprefix_export_name_main_main
+#define ZZ_FORWARD_myprefix_export_name_main_main
+#endif
+#ifndef ZZ_FORWARD_myprefix_export_name_main_sub
+#define ZZ_FORWARD_myprefix_export_name_main_sub
+#endif
+#ifndef ZZ_EXPORT_stable_add
+#define ZZ_EXPORT_stable_add
+
+#line 3 "./src/main.zz"
+int stable_add (int const  a, int const  b);
+
+#endif
+#ifndef ZZ_EXPORT___tmp_zzcheck_tests_mustpass_export_name_src_names_h_
+#define ZZ_EXPORT___tmp_zzcheck_tests_mustpass_export_name_src_names_h_
+
+#line 1 "./src/main.zz"
+#include "../../../src/names.h"
+
+#endif
+#ifndef ZZ_EXPORT_myprefix_export_name_main_sub
+#define ZZ_EXPORT_myprefix_export_name_main_sub
+
+#line 11 "./src/main.zz"
+int myprefix_export_name_main_sub (int const  a, int const  b);
+
+#endif
+#ifndef ZZ_EXPORT_myprefix_export_name_main_main
+#define ZZ_EXPORT_myprefix_export_name_main_main
+
+#line 18 "./src/main.zz"
+int myprefix_export_name_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_myprefix_export_name_main_sub
+#define ZZ_EXPORT_myprefix_export_name_main_sub
+
+#line 11 "./src/main.zz"
+int myprefix_export_name_main_sub (int const  a, int const  b);
+
+#endif
+#ifndef ZZ_IMPL_stable_add
+#define ZZ_IMPL_stable_add
+
+#line 3 "./src/main.zz"
+int __attribute__ ((visibility ("default"))) stable_add (int const  a, int const  b)
+#if 0
+#else
+{
+
+#line 8 "./src/main.zz"
+  return (    a +    b  );
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_myprefix_export_name_main_main
+#define ZZ_IMPL_myprefix_export_name_main_main
+
+#line 18 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 19 "./src/main.zz"
+  int const  r  =     (int)(    check_names(    ));
+if ((
+#line 20 "./src/main.zz"
+    r !=    5  )){
+
+#line 21 "./src/main.zz"
+  return     1;
+
+}
+
+
+#line 23 "./src/main.zz"
+  return (    stable_add(    1,    1    ) -    myprefix_export_name_main_sub(    3,    1    )  );
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_myprefix_export_name_main_sub
+#define ZZ_IMPL_myprefix_export_name_main_sub
+
+#line 11 "./src/main.zz"
+int __attribute__ ((visibility ("default"))) myprefix_export_name_main_sub (int const  a, int const  b)
+#if 0
+#else
+{
+
+#line 15 "./src/main.zz"
+  return (    a -    b  );
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,7 @@
+// these must link against the stable and prefixed names emitted by zz
+int stable_add(int a, int b);
+int myprefix_export_name_main_sub(int a, int b);
+
+int check_names() {
+    return stable_add(1, 2) + myprefix_export_name_main_sub(3, 1);
+}
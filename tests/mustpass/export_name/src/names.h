@@ -0,0 +1 @@
+int check_names();
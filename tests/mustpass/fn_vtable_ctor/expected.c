@@ -0,0 +1,171 @@
+/* fn_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_fn_main_AdderFn
+#define ZZ_FORWARD_fn_main_AdderFn
+#endif
+#ifndef ZZ_FORWARD_fn_main_Math
+#define ZZ_FORWARD_fn_main_Math
+
+#line 6 "./src/main.zz"
+struct fn_main_Math_t;
+typedef struct fn_main_Math_t fn_main_Math;
+#endif
+#ifndef ZZ_FORWARD_fn_main_add_impl
+#define ZZ_FORWARD_fn_main_add_impl
+#endif
+#ifndef ZZ_FORWARD_fn_main_nu
+#define ZZ_FORWARD_fn_main_nu
+#endif
+#ifndef ZZ_FORWARD_fn_main_main
+#define ZZ_FORWARD_fn_main_main
+#endif
+#ifndef ZZ_FORWARD_fn_main_nu
+#define ZZ_FORWARD_fn_main_nu
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_AdderFn
+#define ZZ_EXPORT_fn_main_AdderFn
+
+#line 5 "./src/main.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_fn_main_AdderFn)
+#define ZZ_HAS_DEFINED_CLOSURE_fn_main_AdderFn 1
+typedef struct {
+    void *ctx;
+    int (*fn) (int const  a, int const  b, void * _ctx);
+} fn_main_AdderFn;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_fn_main_AdderFn)
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_Math
+#define ZZ_EXPORT_fn_main_Math
+
+#line 6 "./src/main.zz"
+struct fn_main_Math_t {
+
+#line 7 "./src/main.zz"
+   fn_main_AdderFn add ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_add_impl
+#define ZZ_EXPORT_fn_main_add_impl
+
+#line 11 "./src/main.zz"
+static int fn_main_add_impl (int const  a, int const  b);
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_nu
+#define ZZ_EXPORT_fn_main_nu
+
+#line 15 "./src/main.zz"
+static void fn_main_nu (fn_main_Math*  const  self, fn_main_AdderFn const  add);
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_main
+#define ZZ_EXPORT_fn_main_main
+
+#line 23 "./src/main.zz"
+int fn_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_nu
+#define ZZ_EXPORT_fn_main_nu
+
+#line 15 "./src/main.zz"
+static void fn_main_nu (fn_main_Math*  const  self, fn_main_AdderFn const  add);
+
+#endif
+#ifndef ZZ_IMPL_fn_main_add_impl
+#define ZZ_IMPL_fn_main_add_impl
+
+#line 11 "./src/main.zz"
+static int fn_main_add_impl (int const  a, int const  b)
+#if 0
+#else
+{
+
+#line 12 "./src/main.zz"
+  return (    a +    b  );
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_fn_main_main
+#define ZZ_IMPL_fn_main_main
+
+#line 23 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 24 "./src/main.zz"
+  fn_main_Math const  mm  =     (fn_main_Math){
+.add = 
+#line 25 "./src/main.zz"
+    (fn_main_AdderFn){
+.fn =     (void const * )(    fn_main_add_impl),
+
+.ctx =     0,
+},
+};
+
+#line 27 "./src/main.zz"
+  int const  x  =     mm .add .fn(    1,    8,    mm .add .ctx    );
+
+#line 29 "./src/main.zz"
+  fn_main_Math m  = {    0,};
+    fn_main_nu(( &    m),    (fn_main_AdderFn){
+.fn =     (void const * )(    fn_main_add_impl),
+
+.ctx =     0,
+}    );
+
+#line 31 "./src/main.zz"
+  int const  x2  =     m .add .fn(    1,    8,    m .add .ctx    );
+
+#line 32 "./src/main.zz"
+    printf(    "hello %u\n",    x2    );
+
+#line 33 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_fn_main_nu
+#define ZZ_IMPL_fn_main_nu
+
+#line 15 "./src/main.zz"
+static void fn_main_nu (fn_main_Math*  const  self, fn_main_AdderFn const  add)
+#if 0
+#else
+{
+
+#line 20 "./src/main.zz"
+    self ->add =     add;
+
+}
+#endif
+
+
+#endif
+
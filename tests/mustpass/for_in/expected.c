@@ -0,0 +1,281 @@
+/* for_in_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_zz_slice__u8
+#define ZZ_FORWARD_zz_slice__u8
+
+#line 3 "./src/main.zz"
+struct zz_slice__u8_t;
+typedef struct zz_slice__u8_t zz_slice__u8;
+#endif
+#ifndef ZZ_FORWARD_for_in_main_sum
+#define ZZ_FORWARD_for_in_main_sum
+#endif
+#ifndef ZZ_FORWARD_for_in_main_sum_of
+#define ZZ_FORWARD_for_in_main_sum_of
+#endif
+#ifndef ZZ_FORWARD_zz_mslice__u8
+#define ZZ_FORWARD_zz_mslice__u8
+
+#line 22 "./src/main.zz"
+struct zz_mslice__u8_t;
+typedef struct zz_mslice__u8_t zz_mslice__u8;
+#endif
+#ifndef ZZ_FORWARD_for_in_main_main
+#define ZZ_FORWARD_for_in_main_main
+#endif
+#ifndef ZZ_FORWARD_for_in_main_sum
+#define ZZ_FORWARD_for_in_main_sum
+#endif
+#ifndef ZZ_FORWARD_for_in_main_sum_of
+#define ZZ_FORWARD_for_in_main_sum_of
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_zz_slice__u8
+#define ZZ_EXPORT_zz_slice__u8
+
+#line 3 "./src/main.zz"
+struct zz_slice__u8_t {
+   uint8_t const *  mem ;
+   uintptr_t len ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_for_in_main_sum
+#define ZZ_EXPORT_for_in_main_sum
+static uint32_t for_in_main_sum (zz_slice__u8 const  s);
+
+#endif
+#ifndef ZZ_EXPORT_for_in_main_sum_of
+#define ZZ_EXPORT_for_in_main_sum_of
+
+#line 11 "./src/main.zz"
+static uint32_t for_in_main_sum_of (uint8_t const *  const  p, uintptr_t const  n);
+
+#endif
+#ifndef ZZ_EXPORT_zz_mslice__u8
+#define ZZ_EXPORT_zz_mslice__u8
+
+#line 22 "./src/main.zz"
+struct zz_mslice__u8_t {
+   uint8_t*  mem ;
+   uintptr_t len ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_for_in_main_main
+#define ZZ_EXPORT_for_in_main_main
+
+#line 21 "./src/main.zz"
+int for_in_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_for_in_main_sum
+#define ZZ_EXPORT_for_in_main_sum
+
+#line 3 "./src/main.zz"
+static uint32_t for_in_main_sum (zz_slice__u8 const  s);
+
+#endif
+#ifndef ZZ_EXPORT_for_in_main_sum_of
+#define ZZ_EXPORT_for_in_main_sum_of
+
+#line 11 "./src/main.zz"
+static uint32_t for_in_main_sum_of (uint8_t const *  const  p, uintptr_t const  n);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_for_in_main_main
+#define ZZ_IMPL_for_in_main_main
+
+#line 21 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 22 "./src/main.zz"
+  uint8_t a  [  ]  = {    1,    2,    3,    4,    5,};
+  for (
+
+#line 24 "./src/main.zz"
+  uintptr_t __for_index  =     0;(    __for_index <    5  );
+(    __for_index ++)){
+  uint8_t*  const  x  = ( &    a [     __for_index]);
+
+#line 25 "./src/main.zz"
+( *    x) = (( *    x) *    2  );
+
+}
+
+{
+
+#line 28 "./src/main.zz"
+  uintptr_t const  __for_end  =     5;
+  for (
+  uintptr_t __for_index  =     0;(    __for_index <    __for_end  );
+(    __for_index ++)){
+  uintptr_t const  i  =     __for_index;
+
+#line 29 "./src/main.zz"
+    printf(    "a[%zu] = %u\n",    i,    a [     i]    );
+
+}
+
+
+}
+
+{
+
+#line 32 "./src/main.zz"
+  uintptr_t const  __for_end  =     3;
+  for (
+  uintptr_t __for_index  =     1;(    __for_index <    __for_end  );
+(    __for_index ++)){
+  uintptr_t const  i  =     __for_index;
+{
+
+#line 33 "./src/main.zz"
+  uintptr_t const  __for_end  =     3;
+  for (
+  uintptr_t __for_index  =     i;(    __for_index <    __for_end  );
+(    __for_index ++)){
+  uintptr_t j  =     __for_index;
+
+#line 34 "./src/main.zz"
+    j +=     10;
+
+#line 35 "./src/main.zz"
+    printf(    "%zu %zu\n",    i,    j    );
+
+}
+
+
+}
+
+
+}
+
+
+}
+
+
+#line 39 "./src/main.zz"
+    printf(    "sum %u\n",    for_in_main_sum(    (zz_slice__u8){
+.mem =     a,
+
+.len =     5,
+}    )    );
+
+#line 40 "./src/main.zz"
+    printf(    "sum of 2 %u\n",    for_in_main_sum_of(    a,    2    )    );
+
+#line 41 "./src/main.zz"
+    printf(    "sum of a[1..4] %u\n",    for_in_main_sum(    (zz_slice__u8){
+.mem =     (zz_mslice__u8){
+.mem = (    a +    1  ),
+
+.len = (    4 -    1  ),
+} .mem,
+
+.len =     (zz_mslice__u8){
+.mem = (    a +    1  ),
+
+.len = (    4 -    1  ),
+} .len,
+}    )    );
+
+#line 43 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_for_in_main_sum
+#define ZZ_IMPL_for_in_main_sum
+
+#line 3 "./src/main.zz"
+static uint32_t for_in_main_sum (zz_slice__u8 const  s)
+#if 0
+#else
+{
+
+#line 4 "./src/main.zz"
+  uint32_t r  =     0;
+  for (
+
+#line 5 "./src/main.zz"
+  uintptr_t __for_index  =     0;(    __for_index <    s .len  );
+(    __for_index ++)){
+  uint8_t const *  const  x  = ( &    s .mem [     __for_index]);
+
+#line 6 "./src/main.zz"
+    r +=     (uint32_t)(( *    x));
+
+}
+
+
+#line 8 "./src/main.zz"
+  return     r;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_for_in_main_sum_of
+#define ZZ_IMPL_for_in_main_sum_of
+
+#line 11 "./src/main.zz"
+static uint32_t for_in_main_sum_of (uint8_t const *  const  p, uintptr_t const  n)
+#if 0
+#else
+{
+
+#line 14 "./src/main.zz"
+  uint32_t r  =     0;
+  for (
+
+#line 15 "./src/main.zz"
+  uintptr_t __for_index  =     0;(    __for_index <    (zz_slice__u8){
+.mem =     p,
+
+.len =     n,
+} .len  );
+(    __for_index ++)){
+  uint8_t const *  const  x  = ( &    (zz_slice__u8){
+.mem =     p,
+
+.len =     n,
+} .mem [     __for_index]);
+
+#line 16 "./src/main.zz"
+    r +=     (uint32_t)(( *    x));
+
+}
+
+
+#line 18 "./src/main.zz"
+  return     r;
+
+}
+#endif
+
+
+#endif
+
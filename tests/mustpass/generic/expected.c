@@ -0,0 +1,458 @@
+/* generic_boxed.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_Box__u32
+#define ZZ_FORWARD_generic_boxed_Box__u32
+
+#line 1 "./src/boxed.zz"
+struct generic_boxed_Box__u32_t;
+typedef struct generic_boxed_Box__u32_t generic_boxed_Box__u32;
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_Box__u8
+#define ZZ_FORWARD_generic_boxed_Box__u8
+struct generic_boxed_Box__u8_t;
+typedef struct generic_boxed_Box__u8_t generic_boxed_Box__u8;
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_Pair__u8__char_p
+#define ZZ_FORWARD_generic_boxed_Pair__u8__char_p
+
+#line 21 "./src/boxed.zz"
+struct generic_boxed_Pair__u8__char_p_t;
+typedef struct generic_boxed_Pair__u8__char_p_t generic_boxed_Pair__u8__char_p;
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_box_get__u32
+#define ZZ_FORWARD_generic_boxed_box_get__u32
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_box_get__u8
+#define ZZ_FORWARD_generic_boxed_box_get__u8
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_box_new__u32
+#define ZZ_FORWARD_generic_boxed_box_new__u32
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_box_new__u8
+#define ZZ_FORWARD_generic_boxed_box_new__u8
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_max__u32
+#define ZZ_FORWARD_generic_boxed_max__u32
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_max__u8
+#define ZZ_FORWARD_generic_boxed_max__u8
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_Box__u32
+#define ZZ_EXPORT_generic_boxed_Box__u32
+
+#line 1 "./src/boxed.zz"
+struct generic_boxed_Box__u32_t {
+
+#line 2 "./src/boxed.zz"
+   uint32_t value ;
+
+#line 3 "./src/boxed.zz"
+   uintptr_t count ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_SIZEOF_generic_boxed_Box__u32
+#define ZZ_EXPORT_SIZEOF_generic_boxed_Box__u32
+size_t sizeof_generic_boxed_Box__u32() { return sizeof(generic_boxed_Box__u32); }
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_Box__u8
+#define ZZ_EXPORT_generic_boxed_Box__u8
+
+#line 1 "./src/boxed.zz"
+struct generic_boxed_Box__u8_t {
+
+#line 2 "./src/boxed.zz"
+   uint8_t value ;
+
+#line 3 "./src/boxed.zz"
+   uintptr_t count ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_Pair__u8__char_p
+#define ZZ_EXPORT_generic_boxed_Pair__u8__char_p
+
+#line 21 "./src/boxed.zz"
+struct generic_boxed_Pair__u8__char_p_t {
+
+#line 22 "./src/boxed.zz"
+   generic_boxed_Box__u8 first ;
+
+#line 23 "./src/boxed.zz"
+   char const *  second ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_box_get__u32
+#define ZZ_EXPORT_generic_boxed_box_get__u32
+
+#line 10 "./src/boxed.zz"
+extern uint32_t generic_boxed_box_get__u32 (generic_boxed_Box__u32 const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_box_get__u8
+#define ZZ_EXPORT_generic_boxed_box_get__u8
+extern uint8_t generic_boxed_box_get__u8 (generic_boxed_Box__u8 const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_box_new__u32
+#define ZZ_EXPORT_generic_boxed_box_new__u32
+
+#line 6 "./src/boxed.zz"
+extern generic_boxed_Box__u32 generic_boxed_box_new__u32 (uint32_t const  value);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_box_new__u8
+#define ZZ_EXPORT_generic_boxed_box_new__u8
+extern generic_boxed_Box__u8 generic_boxed_box_new__u8 (uint8_t const  value);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_max__u32
+#define ZZ_EXPORT_generic_boxed_max__u32
+
+#line 14 "./src/boxed.zz"
+extern uint32_t generic_boxed_max__u32 (uint32_t const  a, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_max__u8
+#define ZZ_EXPORT_generic_boxed_max__u8
+extern uint8_t generic_boxed_max__u8 (uint8_t const  a, uint8_t const  b);
+
+#endif
+#ifndef ZZ_IMPL_generic_boxed_box_get__u32
+#define ZZ_IMPL_generic_boxed_box_get__u32
+
+#line 10 "./src/boxed.zz"
+uint32_t __attribute__ ((visibility ("hidden"))) generic_boxed_box_get__u32 (generic_boxed_Box__u32 const *  const  b)
+#if 0
+#else
+{
+
+#line 11 "./src/boxed.zz"
+  return     b ->value;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_generic_boxed_box_get__u8
+#define ZZ_IMPL_generic_boxed_box_get__u8
+
+#line 10 "./src/boxed.zz"
+uint8_t __attribute__ ((visibility ("hidden"))) generic_boxed_box_get__u8 (generic_boxed_Box__u8 const *  const  b)
+#if 0
+#else
+{
+
+#line 11 "./src/boxed.zz"
+  return     b ->value;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_generic_boxed_box_new__u32
+#define ZZ_IMPL_generic_boxed_box_new__u32
+
+#line 6 "./src/boxed.zz"
+generic_boxed_Box__u32 __attribute__ ((visibility ("hidden"))) generic_boxed_box_new__u32 (uint32_t const  value)
+#if 0
+#else
+{
+
+#line 7 "./src/boxed.zz"
+  return     (generic_boxed_Box__u32){
+.value =     value,
+
+.count =     1,
+};
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_generic_boxed_box_new__u8
+#define ZZ_IMPL_generic_boxed_box_new__u8
+
+#line 6 "./src/boxed.zz"
+generic_boxed_Box__u8 __attribute__ ((visibility ("hidden"))) generic_boxed_box_new__u8 (uint8_t const  value)
+#if 0
+#else
+{
+
+#line 7 "./src/boxed.zz"
+  return     (generic_boxed_Box__u8){
+.value =     value,
+
+.count =     1,
+};
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_generic_boxed_max__u32
+#define ZZ_IMPL_generic_boxed_max__u32
+
+#line 14 "./src/boxed.zz"
+uint32_t __attribute__ ((visibility ("hidden"))) generic_boxed_max__u32 (uint32_t const  a, uint32_t const  b)
+#if 0
+#else
+{
+if ((
+#line 15 "./src/boxed.zz"
+    a >    b  )){
+
+#line 16 "./src/boxed.zz"
+  return     a;
+
+}
+
+
+#line 18 "./src/boxed.zz"
+  return     b;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_generic_boxed_max__u8
+#define ZZ_IMPL_generic_boxed_max__u8
+
+#line 14 "./src/boxed.zz"
+uint8_t __attribute__ ((visibility ("hidden"))) generic_boxed_max__u8 (uint8_t const  a, uint8_t const  b)
+#if 0
+#else
+{
+if ((
+#line 15 "./src/boxed.zz"
+    a >    b  )){
+
+#line 16 "./src/boxed.zz"
+  return     a;
+
+}
+
+
+#line 18 "./src/boxed.zz"
+  return     b;
+
+}
+#endif
+
+
+#endif
+
+/* generic_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_Box__u32
+#define ZZ_FORWARD_generic_boxed_Box__u32
+
+#line 1 "./src/boxed.zz"
+struct generic_boxed_Box__u32_t;
+typedef struct generic_boxed_Box__u32_t generic_boxed_Box__u32;
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_Box__u8
+#define ZZ_FORWARD_generic_boxed_Box__u8
+struct generic_boxed_Box__u8_t;
+typedef struct generic_boxed_Box__u8_t generic_boxed_Box__u8;
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_Pair__u8__char_p
+#define ZZ_FORWARD_generic_boxed_Pair__u8__char_p
+
+#line 21 "./src/boxed.zz"
+struct generic_boxed_Pair__u8__char_p_t;
+typedef struct generic_boxed_Pair__u8__char_p_t generic_boxed_Pair__u8__char_p;
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_box_get__u32
+#define ZZ_FORWARD_generic_boxed_box_get__u32
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_box_get__u8
+#define ZZ_FORWARD_generic_boxed_box_get__u8
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_box_new__u32
+#define ZZ_FORWARD_generic_boxed_box_new__u32
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_box_new__u8
+#define ZZ_FORWARD_generic_boxed_box_new__u8
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_max__u32
+#define ZZ_FORWARD_generic_boxed_max__u32
+#endif
+#ifndef ZZ_FORWARD_generic_boxed_max__u8
+#define ZZ_FORWARD_generic_boxed_max__u8
+#endif
+#ifndef ZZ_FORWARD_generic_main_main
+#define ZZ_FORWARD_generic_main_main
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_Box__u32
+#define ZZ_EXPORT_generic_boxed_Box__u32
+
+#line 1 "./src/boxed.zz"
+struct generic_boxed_Box__u32_t {
+
+#line 2 "./src/boxed.zz"
+   uint32_t value ;
+
+#line 3 "./src/boxed.zz"
+   uintptr_t count ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_Box__u8
+#define ZZ_EXPORT_generic_boxed_Box__u8
+
+#line 1 "./src/boxed.zz"
+struct generic_boxed_Box__u8_t {
+
+#line 2 "./src/boxed.zz"
+   uint8_t value ;
+
+#line 3 "./src/boxed.zz"
+   uintptr_t count ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_Pair__u8__char_p
+#define ZZ_EXPORT_generic_boxed_Pair__u8__char_p
+
+#line 21 "./src/boxed.zz"
+struct generic_boxed_Pair__u8__char_p_t {
+
+#line 22 "./src/boxed.zz"
+   generic_boxed_Box__u8 first ;
+
+#line 23 "./src/boxed.zz"
+   char const *  second ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_box_get__u32
+#define ZZ_EXPORT_generic_boxed_box_get__u32
+
+#line 10 "./src/boxed.zz"
+extern uint32_t generic_boxed_box_get__u32 (generic_boxed_Box__u32 const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_box_get__u8
+#define ZZ_EXPORT_generic_boxed_box_get__u8
+extern uint8_t generic_boxed_box_get__u8 (generic_boxed_Box__u8 const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_box_new__u32
+#define ZZ_EXPORT_generic_boxed_box_new__u32
+
+#line 6 "./src/boxed.zz"
+extern generic_boxed_Box__u32 generic_boxed_box_new__u32 (uint32_t const  value);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_box_new__u8
+#define ZZ_EXPORT_generic_boxed_box_new__u8
+extern generic_boxed_Box__u8 generic_boxed_box_new__u8 (uint8_t const  value);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_max__u32
+#define ZZ_EXPORT_generic_boxed_max__u32
+
+#line 14 "./src/boxed.zz"
+extern uint32_t generic_boxed_max__u32 (uint32_t const  a, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_generic_boxed_max__u8
+#define ZZ_EXPORT_generic_boxed_max__u8
+extern uint8_t generic_boxed_max__u8 (uint8_t const  a, uint8_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_generic_main_main
+#define ZZ_EXPORT_generic_main_main
+
+#line 4 "./src/main.zz"
+int generic_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_generic_main_main
+#define ZZ_IMPL_generic_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 5 "./src/main.zz"
+  generic_boxed_Box__u32 a  =     generic_boxed_box_new__u32(    3    );
+
+#line 6 "./src/main.zz"
+  generic_boxed_Pair__u8__char_p const  p  =     (generic_boxed_Pair__u8__char_p){
+.first = 
+#line 7 "./src/main.zz"
+    generic_boxed_box_new__u8(    2    ),
+
+.second = 
+#line 8 "./src/main.zz"
+    "two",
+};
+
+#line 11 "./src/main.zz"
+  uint32_t const  x  =     generic_boxed_box_get__u32(( &    a)    );
+
+#line 12 "./src/main.zz"
+  uint8_t const  y  =     generic_boxed_max__u8(    generic_boxed_box_get__u8(( &    p .first)    ),    1    );
+
+#line 14 "./src/main.zz"
+    printf(    "hello generic %u %u %s\n",    generic_boxed_max__u32(    x,    2    ),    y,    p .second    );
+
+#line 15 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
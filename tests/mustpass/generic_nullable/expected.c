@@ -0,0 +1,160 @@
+/* generic_nullable_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_generic_nullable_main_Box__u8_np
+#define ZZ_FORWARD_generic_nullable_main_Box__u8_np
+
+#line 3 "./src/main.zz"
+struct generic_nullable_main_Box__u8_np_t;
+typedef struct generic_nullable_main_Box__u8_np_t generic_nullable_main_Box__u8_np;
+#endif
+#ifndef ZZ_FORWARD_generic_nullable_main_Box__u8_p
+#define ZZ_FORWARD_generic_nullable_main_Box__u8_p
+struct generic_nullable_main_Box__u8_p_t;
+typedef struct generic_nullable_main_Box__u8_p_t generic_nullable_main_Box__u8_p;
+#endif
+#ifndef ZZ_FORWARD_generic_nullable_main_box_get__u8_np
+#define ZZ_FORWARD_generic_nullable_main_box_get__u8_np
+#endif
+#ifndef ZZ_FORWARD_generic_nullable_main_box_get__u8_p
+#define ZZ_FORWARD_generic_nullable_main_box_get__u8_p
+#endif
+#ifndef ZZ_FORWARD_generic_nullable_main_main
+#define ZZ_FORWARD_generic_nullable_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_generic_nullable_main_Box__u8_np
+#define ZZ_EXPORT_generic_nullable_main_Box__u8_np
+
+#line 3 "./src/main.zz"
+struct generic_nullable_main_Box__u8_np_t {
+
+#line 4 "./src/main.zz"
+   uint8_t const *  value ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_generic_nullable_main_Box__u8_p
+#define ZZ_EXPORT_generic_nullable_main_Box__u8_p
+
+#line 3 "./src/main.zz"
+struct generic_nullable_main_Box__u8_p_t {
+
+#line 4 "./src/main.zz"
+   uint8_t const *  value ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_generic_nullable_main_box_get__u8_np
+#define ZZ_EXPORT_generic_nullable_main_box_get__u8_np
+
+#line 7 "./src/main.zz"
+extern uint8_t  const * generic_nullable_main_box_get__u8_np (generic_nullable_main_Box__u8_np const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_generic_nullable_main_box_get__u8_p
+#define ZZ_EXPORT_generic_nullable_main_box_get__u8_p
+extern uint8_t  const * generic_nullable_main_box_get__u8_p (generic_nullable_main_Box__u8_p const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_generic_nullable_main_main
+#define ZZ_EXPORT_generic_nullable_main_main
+
+#line 11 "./src/main.zz"
+int generic_nullable_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_generic_nullable_main_box_get__u8_np
+#define ZZ_IMPL_generic_nullable_main_box_get__u8_np
+
+#line 7 "./src/main.zz"
+uint8_t  const * __attribute__ ((visibility ("hidden"))) generic_nullable_main_box_get__u8_np (generic_nullable_main_Box__u8_np const *  const  b)
+#if 0
+#else
+{
+
+#line 8 "./src/main.zz"
+  return     b ->value;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_generic_nullable_main_box_get__u8_p
+#define ZZ_IMPL_generic_nullable_main_box_get__u8_p
+
+#line 7 "./src/main.zz"
+uint8_t  const * __attribute__ ((visibility ("hidden"))) generic_nullable_main_box_get__u8_p (generic_nullable_main_Box__u8_p const *  const  b)
+#if 0
+#else
+{
+
+#line 8 "./src/main.zz"
+  return     b ->value;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_generic_nullable_main_main
+#define ZZ_IMPL_generic_nullable_main_main
+
+#line 11 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 12 "./src/main.zz"
+  uint8_t const  x  =     7;
+
+#line 13 "./src/main.zz"
+  generic_nullable_main_Box__u8_p const  some  =     (generic_nullable_main_Box__u8_p){
+.value = ( &    x),
+};
+
+#line 14 "./src/main.zz"
+  generic_nullable_main_Box__u8_np const  none  =     (generic_nullable_main_Box__u8_np){
+.value =     0,
+};
+
+#line 17 "./src/main.zz"
+  uint8_t const *  const  p  =     generic_nullable_main_box_get__u8_p(( &    some)    );
+
+#line 18 "./src/main.zz"
+  uint8_t const *  const  q  =     generic_nullable_main_box_get__u8_np(( &    none)    );
+if (((
+#line 19 "./src/main.zz"
+    q ==    0  ) &&(    p ==( &    x)  )  )){
+
+#line 20 "./src/main.zz"
+    printf(    "hello nullable generic\n"    );
+
+}
+
+
+#line 22 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
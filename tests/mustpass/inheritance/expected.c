@@ -0,0 +1,102 @@
+/* inheritance_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_inheritance_main_A
+#define ZZ_FORWARD_inheritance_main_A
+
+#line 6 "./src/main.zz"
+struct inheritance_main_A_t;
+typedef struct inheritance_main_A_t inheritance_main_A;
+#endif
+#ifndef ZZ_FORWARD_inheritance_main_B
+#define ZZ_FORWARD_inheritance_main_B
+
+#line 10 "./src/main.zz"
+struct inheritance_main_B_t;
+typedef struct inheritance_main_B_t inheritance_main_B;
+#endif
+#ifndef ZZ_FORWARD_inheritance_main_bla
+#define ZZ_FORWARD_inheritance_main_bla
+#endif
+#ifndef ZZ_FORWARD_inheritance_main_main
+#define ZZ_FORWARD_inheritance_main_main
+#endif
+#ifndef ZZ_EXPORT_inheritance_main_A
+#define ZZ_EXPORT_inheritance_main_A
+
+#line 6 "./src/main.zz"
+struct inheritance_main_A_t {
+
+#line 7 "./src/main.zz"
+   int whatevs ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_inheritance_main_B
+#define ZZ_EXPORT_inheritance_main_B
+
+#line 10 "./src/main.zz"
+struct inheritance_main_B_t {
+
+#line 11 "./src/main.zz"
+   inheritance_main_A a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_inheritance_main_bla
+#define ZZ_EXPORT_inheritance_main_bla
+
+#line 15 "./src/main.zz"
+extern void inheritance_main_bla (inheritance_main_A*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_inheritance_main_main
+#define ZZ_EXPORT_inheritance_main_main
+
+#line 18 "./src/main.zz"
+int inheritance_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_inheritance_main_bla
+#define ZZ_IMPL_inheritance_main_bla
+
+#line 15 "./src/main.zz"
+void __attribute__ ((visibility ("hidden"))) inheritance_main_bla (inheritance_main_A*  const  self)
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_inheritance_main_main
+#define ZZ_IMPL_inheritance_main_main
+
+#line 18 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 20 "./src/main.zz"
+  inheritance_main_B b ;
+
+#line 21 "./src/main.zz"
+    inheritance_main_bla(( &( &    b) ->a)    );
+
+#line 23 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,69 @@
+/* inlineincludedeps_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_inlineincludedeps_main_A
+#define ZZ_FORWARD_inlineincludedeps_main_A
+
+#line 4 "./src/main.zz"
+struct inlineincludedeps_main_A_t;
+typedef struct inlineincludedeps_main_A_t inlineincludedeps_main_A;
+#endif
+#ifndef ZZ_FORWARD___tmp_zzcheck_tests_mustpass_inlineincludedeps_src_something_h_
+#define ZZ_FORWARD___tmp_zzcheck_tests_mustpass_inlineincludedeps_src_something_h_
+#endif
+#ifndef ZZ_FORWARD_inlineincludedeps_main_main
+#define ZZ_FORWARD_inlineincludedeps_main_main
+#endif
+#ifndef ZZ_EXPORT_inlineincludedeps_main_A
+#define ZZ_EXPORT_inlineincludedeps_main_A
+struct inlineincludedeps_main_A_t {
+
+#line 5 "./src/main.zz"
+   int x ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT___tmp_zzcheck_tests_mustpass_inlineincludedeps_src_something_h_
+#define ZZ_EXPORT___tmp_zzcheck_tests_mustpass_inlineincludedeps_src_something_h_
+
+#line 8 "./src/main.zz"
+
+#line 1 "./src/something.h"
+int bob (inlineincludedeps_main_A const *a) {
+    return a->x;
+}
+
+#endif
+#ifndef ZZ_EXPORT_inlineincludedeps_main_main
+#define ZZ_EXPORT_inlineincludedeps_main_main
+
+#line 10 "./src/main.zz"
+int inlineincludedeps_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_inlineincludedeps_main_main
+#define ZZ_IMPL_inlineincludedeps_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+  inlineincludedeps_main_A const  a ;
+
+#line 12 "./src/main.zz"
+    bob(( &    a)    );
+
+#line 13 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,146 @@
+/* interface_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_interface_main_Shape
+#define ZZ_FORWARD_interface_main_Shape
+#endif
+#ifndef ZZ_FORWARD_interface_main_Square
+#define ZZ_FORWARD_interface_main_Square
+
+#line 13 "./src/main.zz"
+struct interface_main_Square_t;
+typedef struct interface_main_Square_t interface_main_Square;
+#endif
+#ifndef ZZ_FORWARD_interface_main_area
+#define ZZ_FORWARD_interface_main_area
+#endif
+#ifndef ZZ_FORWARD_interface_main_main
+#define ZZ_FORWARD_interface_main_main
+#endif
+#ifndef ZZ_FORWARD_interface_main_scale
+#define ZZ_FORWARD_interface_main_scale
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_interface_main_Square
+#define ZZ_EXPORT_interface_main_Square
+
+#line 13 "./src/main.zz"
+struct interface_main_Square_t {
+
+#line 14 "./src/main.zz"
+   uint32_t side ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_interface_main_area
+#define ZZ_EXPORT_interface_main_area
+
+#line 17 "./src/main.zz"
+static uint32_t interface_main_area (interface_main_Square const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_interface_main_main
+#define ZZ_EXPORT_interface_main_main
+
+#line 35 "./src/main.zz"
+int interface_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_interface_main_scale
+#define ZZ_EXPORT_interface_main_scale
+
+#line 27 "./src/main.zz"
+static void interface_main_scale (interface_main_Square*  const  self, uint32_t const  by);
+
+#endif
+#ifndef ZZ_IMPL_interface_main_area
+#define ZZ_IMPL_interface_main_area
+
+#line 17 "./src/main.zz"
+static uint32_t interface_main_area (interface_main_Square const *  const  self)
+#if 0
+#else
+{
+if ((
+#line 20 "./src/main.zz"
+    self ->side <    100  )){
+
+#line 21 "./src/main.zz"
+  return (    self ->side *    self ->side  );
+
+}
+
+
+#line 23 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_interface_main_main
+#define ZZ_IMPL_interface_main_main
+
+#line 35 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 36 "./src/main.zz"
+  interface_main_Square s  =     (interface_main_Square){
+.side =     2,
+};
+
+#line 37 "./src/main.zz"
+    interface_main_scale(( &    s),    3    );
+
+#line 38 "./src/main.zz"
+    printf(    "area %u\n",    interface_main_area(( &    s)    )    );
+
+#line 39 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_interface_main_scale
+#define ZZ_IMPL_interface_main_scale
+
+#line 27 "./src/main.zz"
+static void interface_main_scale (interface_main_Square*  const  self, uint32_t const  by)
+#if 0
+#else
+{
+if ((
+#line 30 "./src/main.zz"
+    self ->side <    1000  )){
+
+#line 31 "./src/main.zz"
+    self ->side = (    self ->side *    by  );
+
+}
+
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,200 @@
+/* loop_condition_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_loop_condition_main_alias
+#define ZZ_FORWARD_loop_condition_main_alias
+#endif
+#ifndef ZZ_FORWARD_loop_condition_main_main
+#define ZZ_FORWARD_loop_condition_main_main
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_loop_condition_main_alias
+#define ZZ_EXPORT_loop_condition_main_alias
+
+#line 5 "./src/main.zz"
+static void  static inline loop_condition_main_alias (void const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_loop_condition_main_main
+#define ZZ_EXPORT_loop_condition_main_main
+
+#line 7 "./src/main.zz"
+int loop_condition_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_loop_condition_main_alias
+#define ZZ_IMPL_loop_condition_main_alias
+
+#line 5 "./src/main.zz"
+static  static inline void loop_condition_main_alias (void const *  const  a)
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_loop_condition_main_main
+#define ZZ_IMPL_loop_condition_main_main
+
+#line 7 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 10 "./src/main.zz"
+  uintptr_t const  no_changes_in_loop  =     1;
+
+#line 12 "./src/main.zz"
+  uintptr_t changes_in_loop1  =     1;
+
+#line 13 "./src/main.zz"
+  uintptr_t changes_in_loop2  =     1;
+
+#line 14 "./src/main.zz"
+  uintptr_t changes_in_loop3  =     1;
+
+#line 15 "./src/main.zz"
+  uintptr_t changes_in_loop4  =     1;
+  for (
+
+#line 17 "./src/main.zz"
+  uintptr_t i  =     0;(    i <    3  );
+(    i ++)){
+
+#line 18 "./src/main.zz"
+    ;
+if ((
+#line 21 "./src/main.zz"
+    i ==    0  )){
+
+}
+
+
+#line 24 "./src/main.zz"
+(    changes_in_loop1 ++);
+if ((
+#line 25 "./src/main.zz"
+    changes_in_loop1 >    2  )){
+
+}
+
+
+#line 28 "./src/main.zz"
+    changes_in_loop2 +=     1;
+if ((
+#line 29 "./src/main.zz"
+    changes_in_loop2 >    2  )){
+
+}
+
+
+#line 33 "./src/main.zz"
+    ;
+
+#line 35 "./src/main.zz"
+  int declared_in_loop  =     3;
+
+#line 36 "./src/main.zz"
+    ;
+if ((
+#line 38 "./src/main.zz"
+    i ==    0  )){
+
+}
+
+
+#line 42 "./src/main.zz"
+  int const  uninitialized ;
+if ((
+#line 44 "./src/main.zz"
+    uninitialized ==    2  )){
+
+#line 45 "./src/main.zz"
+continue;
+
+}
+
+
+#line 47 "./src/main.zz"
+    ;
+
+#line 52 "./src/main.zz"
+    loop_condition_main_alias(( &    changes_in_loop3)    );
+if ((
+#line 55 "./src/main.zz"
+    changes_in_loop3 ==    1  )){
+
+#line 56 "./src/main.zz"
+    changes_in_loop4 =     2;
+
+}
+
+
+#line 58 "./src/main.zz"
+    changes_in_loop3 =     8;
+
+}
+
+if ((
+#line 62 "./src/main.zz"
+    changes_in_loop1 ==    2  )){
+
+}
+
+if ((
+#line 64 "./src/main.zz"
+    changes_in_loop2 ==    2  )){
+
+}
+
+if ((
+#line 66 "./src/main.zz"
+    changes_in_loop3 ==    2  )){
+
+}
+
+if ((
+#line 68 "./src/main.zz"
+    changes_in_loop4 ==    2  )){
+
+}
+
+
+#line 71 "./src/main.zz"
+    printf(    "hello loop_condition\n"    );
+
+#line 72 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
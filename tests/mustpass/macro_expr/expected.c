@@ -0,0 +1,3448 @@
+/* macro_expr_bam.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_macro_expr_bam_happyness
+#define ZZ_FORWARD_macro_expr_bam_happyness
+#endif
+#ifndef ZZ_FORWARD_macro_expr_bam_yah
+#define ZZ_FORWARD_macro_expr_bam_yah
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 6 "$ZZ/modules/ast/src/lib.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_macro_expr_bam_happyness
+#define ZZ_EXPORT_macro_expr_bam_happyness
+
+#endif
+#ifndef ZZ_EXPORT_macro_expr_bam_yah
+#define ZZ_EXPORT_macro_expr_bam_yah
+
+#line 4 "./src/bam.zz"
+static void macro_expr_bam_yah ();
+
+#endif
+#ifndef ZZ_IMPL_macro_expr_bam_yah
+#define ZZ_IMPL_macro_expr_bam_yah
+static void macro_expr_bam_yah ()
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+
+/* macro_expr_bam_happyness.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_macro_expr_bam_happyness_main
+#define ZZ_FORWARD_macro_expr_bam_happyness_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 6 "$ZZ/modules/ast/src/lib.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_macro_expr_bam_happyness_main
+#define ZZ_EXPORT_macro_expr_bam_happyness_main
+
+#line 7 "./src/bam.zz"
+int macro_expr_bam_happyness_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_macro_expr_bam_happyness_main
+#define ZZ_IMPL_macro_expr_bam_happyness_main
+int main ()
+#if 0
+#else
+{
+
+#line 8 "./src/bam.zz"
+    printf(    "\n\n    printf(\":)))\\n\");\n    printf(\":)))\\n\");\n\n"    );
+
+#line 1 ""
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
+/* macro_expr_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_ast_Expression
+#define ZZ_FORWARD_ast_Expression
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_ast_ExpressionValue
+#define ZZ_FORWARD_ast_ExpressionValue
+
+#line 125 "$ZZ/modules/ast/src/lib.zz"
+union ast_ExpressionValue_t;
+typedef union ast_ExpressionValue_t ast_ExpressionValue;
+#endif
+#ifndef ZZ_FORWARD_pool_Pool
+#define ZZ_FORWARD_pool_Pool
+
+#line 21 "$ZZ/modules/pool/src/lib.zz"
+struct pool_Pool_t;
+typedef struct pool_Pool_t pool_Pool;
+#endif
+#ifndef ZZ_FORWARD_ast_ExpressionS
+#define ZZ_FORWARD_ast_ExpressionS
+
+#line 129 "$ZZ/modules/ast/src/lib.zz"
+struct ast_ExpressionS_t;
+typedef struct ast_ExpressionS_t ast_ExpressionS;
+#endif
+#ifndef ZZ_FORWARD_pool_Pool
+#define ZZ_FORWARD_pool_Pool
+
+#line 21 "$ZZ/modules/pool/src/lib.zz"
+struct pool_Pool_t;
+typedef struct pool_Pool_t pool_Pool;
+#endif
+#ifndef ZZ_FORWARD_ast_Ast
+#define ZZ_FORWARD_ast_Ast
+
+#line 136 "$ZZ/modules/ast/src/lib.zz"
+struct ast_Ast_t;
+typedef struct ast_Ast_t ast_Ast;
+#endif
+#ifndef ZZ_FORWARD_ast_from_macro
+#define ZZ_FORWARD_ast_from_macro
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_string_String
+#define ZZ_FORWARD_string_String
+
+#line 8 "$ZZ/modules/string/src/lib.zz"
+struct string_String_t;
+typedef struct string_String_t string_String;
+#endif
+#ifndef ZZ_FORWARD_err_Err
+#define ZZ_FORWARD_err_Err
+
+#line 18 "$ZZ/modules/err/src/lib.zz"
+struct err_Err_t;
+typedef struct err_Err_t err_Err;
+#endif
+#ifndef ZZ_FORWARD_err_checked
+#define ZZ_FORWARD_err_checked
+#endif
+#ifndef ZZ_FORWARD_err_abort
+#define ZZ_FORWARD_err_abort
+#endif
+#ifndef ZZ_FORWARD_err_assert2
+#define ZZ_FORWARD_err_assert2
+#endif
+#ifndef ZZ_FORWARD_err_backtrace
+#define ZZ_FORWARD_err_backtrace
+#endif
+#ifndef ZZ_FORWARD_err_check
+#define ZZ_FORWARD_err_check
+#endif
+#ifndef ZZ_FORWARD_err_elog
+#define ZZ_FORWARD_err_elog
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_err_eprintf
+#define ZZ_FORWARD_err_eprintf
+#endif
+#ifndef ZZ_FORWARD_err_fail
+#define ZZ_FORWARD_err_fail
+#endif
+#ifndef ZZ_FORWARD_err_fail_with_errno
+#define ZZ_FORWARD_err_fail_with_errno
+#endif
+#ifndef ZZ_FORWARD_err_make
+#define ZZ_FORWARD_err_make
+#endif
+#ifndef ZZ_FORWARD_err_to_str
+#define ZZ_FORWARD_err_to_str
+#endif
+#ifndef ZZ_FORWARD__stdarg_h_
+#define ZZ_FORWARD__stdarg_h_
+#endif
+#ifndef ZZ_FORWARD__stdlib_h_
+#define ZZ_FORWARD__stdlib_h_
+#endif
+#ifndef ZZ_FORWARD_json_MAX_DEPTH
+#define ZZ_FORWARD_json_MAX_DEPTH
+#endif
+#ifndef ZZ_FORWARD_json_ParserState
+#define ZZ_FORWARD_json_ParserState
+#endif
+#ifndef ZZ_FORWARD_json_ValueType
+#define ZZ_FORWARD_json_ValueType
+#endif
+#ifndef ZZ_FORWARD_json_Value
+#define ZZ_FORWARD_json_Value
+
+#line 21 "$ZZ/modules/json/src/lib.zz"
+struct json_Value_t;
+typedef struct json_Value_t json_Value;
+#endif
+#ifndef ZZ_FORWARD_json_deserialize_t
+#define ZZ_FORWARD_json_deserialize_t
+#endif
+#ifndef ZZ_FORWARD_json_ParserStack
+#define ZZ_FORWARD_json_ParserStack
+
+#line 39 "$ZZ/modules/json/src/lib.zz"
+struct json_ParserStack_t;
+typedef struct json_ParserStack_t json_ParserStack;
+#endif
+#ifndef ZZ_FORWARD_json_Parser
+#define ZZ_FORWARD_json_Parser
+
+#line 47 "$ZZ/modules/json/src/lib.zz"
+struct json_Parser_t;
+typedef struct json_Parser_t json_Parser;
+#endif
+#ifndef ZZ_FORWARD_json_advance
+#define ZZ_FORWARD_json_advance
+#endif
+#ifndef ZZ_FORWARD_json_next
+#define ZZ_FORWARD_json_next
+#endif
+#ifndef ZZ_FORWARD_json_parser
+#define ZZ_FORWARD_json_parser
+#endif
+#ifndef ZZ_FORWARD_json_push
+#define ZZ_FORWARD_json_push
+#endif
+#ifndef ZZ_FORWARD_macro_expr_main_main
+#define ZZ_FORWARD_macro_expr_main_main
+#endif
+#ifndef ZZ_FORWARD_macro_expr_main_make_int
+#define ZZ_FORWARD_macro_expr_main_make_int
+#endif
+#ifndef ZZ_FORWARD_macro_expr_main_repeat
+#define ZZ_FORWARD_macro_expr_main_repeat
+#endif
+#ifndef ZZ_FORWARD_pool_continuous
+#define ZZ_FORWARD_pool_continuous
+#endif
+#ifndef ZZ_FORWARD_pool_member
+#define ZZ_FORWARD_pool_member
+#endif
+#ifndef ZZ_FORWARD_pool_alloc
+#define ZZ_FORWARD_pool_alloc
+#endif
+#ifndef ZZ_FORWARD_pool_iterator
+#define ZZ_FORWARD_pool_iterator
+#endif
+#ifndef ZZ_FORWARD_pool_each
+#define ZZ_FORWARD_pool_each
+#endif
+#ifndef ZZ_FORWARD_pool_free
+#define ZZ_FORWARD_pool_free
+#endif
+#ifndef ZZ_FORWARD_pool_free_bytes
+#define ZZ_FORWARD_pool_free_bytes
+#endif
+#ifndef ZZ_FORWARD_pool_make
+#define ZZ_FORWARD_pool_make
+#endif
+#ifndef ZZ_FORWARD_pool_malloc
+#define ZZ_FORWARD_pool_malloc
+#endif
+#ifndef ZZ_FORWARD_slice_slice_Slice
+#define ZZ_FORWARD_slice_slice_Slice
+
+#line 4 "$ZZ/modules/slice/src/slice.zz"
+struct slice_slice_Slice_t;
+typedef struct slice_slice_Slice_t slice_slice_Slice;
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_MutSlice
+#define ZZ_FORWARD_slice_mut_slice_MutSlice
+
+#line 4 "$ZZ/modules/slice/src/mut_slice.zz"
+struct slice_mut_slice_MutSlice_t;
+typedef struct slice_mut_slice_MutSlice_t slice_mut_slice_MutSlice;
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_bytes
+#define ZZ_FORWARD_slice_mut_slice_append_bytes
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_cstr
+#define ZZ_FORWARD_slice_mut_slice_append_cstr
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_slice
+#define ZZ_FORWARD_slice_mut_slice_append_slice
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_borrow
+#define ZZ_FORWARD_slice_mut_slice_borrow
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_make
+#define ZZ_FORWARD_slice_mut_slice_make
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_mem
+#define ZZ_FORWARD_slice_mut_slice_mem
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push
+#define ZZ_FORWARD_slice_mut_slice_push
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push16
+#define ZZ_FORWARD_slice_mut_slice_push16
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push32
+#define ZZ_FORWARD_slice_mut_slice_push32
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push64
+#define ZZ_FORWARD_slice_mut_slice_push64
+#endif
+#ifndef ZZ_FORWARD_slice_slice_borrow
+#define ZZ_FORWARD_slice_slice_borrow
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq
+#define ZZ_FORWARD_slice_slice_eq
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq_bytes
+#define ZZ_FORWARD_slice_slice_eq_bytes
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq_cstr
+#define ZZ_FORWARD_slice_slice_eq_cstr
+#endif
+#ifndef ZZ_FORWARD_slice_slice_make
+#define ZZ_FORWARD_slice_slice_make
+#endif
+#ifndef ZZ_FORWARD_string_append
+#define ZZ_FORWARD_string_append
+#endif
+#ifndef ZZ_FORWARD_string_append_bytes
+#define ZZ_FORWARD_string_append_bytes
+#endif
+#ifndef ZZ_FORWARD_string_append_cstr
+#define ZZ_FORWARD_string_append_cstr
+#endif
+#ifndef ZZ_FORWARD_string_append_slice
+#define ZZ_FORWARD_string_append_slice
+#endif
+#ifndef ZZ_FORWARD_string_as_mut_slice
+#define ZZ_FORWARD_string_as_mut_slice
+#endif
+#ifndef ZZ_FORWARD_string_clear
+#define ZZ_FORWARD_string_clear
+#endif
+#ifndef ZZ_FORWARD_string_cstr
+#define ZZ_FORWARD_string_cstr
+#endif
+#ifndef ZZ_FORWARD_string_ends_with_cstr
+#define ZZ_FORWARD_string_ends_with_cstr
+#endif
+#ifndef ZZ_FORWARD_string_eq_cstr
+#define ZZ_FORWARD_string_eq_cstr
+#endif
+#ifndef ZZ_FORWARD_string_fgets
+#define ZZ_FORWARD_string_fgets
+#endif
+#ifndef ZZ_FORWARD_string_format
+#define ZZ_FORWARD_string_format
+#endif
+#ifndef ZZ_FORWARD_string_from
+#define ZZ_FORWARD_string_from
+#endif
+#ifndef ZZ_FORWARD_string_from_cstr
+#define ZZ_FORWARD_string_from_cstr
+#endif
+#ifndef ZZ_FORWARD_string_from_slice
+#define ZZ_FORWARD_string_from_slice
+#endif
+#ifndef ZZ_FORWARD_string_make
+#define ZZ_FORWARD_string_make
+#endif
+#ifndef ZZ_FORWARD_string_pop
+#define ZZ_FORWARD_string_pop
+#endif
+#ifndef ZZ_FORWARD_string_push
+#define ZZ_FORWARD_string_push
+#endif
+#ifndef ZZ_FORWARD_string_slen
+#define ZZ_FORWARD_string_slen
+#endif
+#ifndef ZZ_FORWARD_string_slice
+#define ZZ_FORWARD_string_slice
+#endif
+#ifndef ZZ_FORWARD_string_space
+#define ZZ_FORWARD_string_space
+#endif
+#ifndef ZZ_FORWARD_string_split
+#define ZZ_FORWARD_string_split
+#endif
+#ifndef ZZ_FORWARD_string_starts_with_cstr
+#define ZZ_FORWARD_string_starts_with_cstr
+#endif
+#ifndef ZZ_FORWARD_string_substr
+#define ZZ_FORWARD_string_substr
+#endif
+#ifndef ZZ_FORWARD__stdarg_h_
+#define ZZ_FORWARD__stdarg_h_
+#endif
+#ifndef ZZ_FORWARD_string_vformat
+#define ZZ_FORWARD_string_vformat
+#endif
+#ifndef ZZ_EXPORT_ast_Expression
+#define ZZ_EXPORT_ast_Expression
+
+#line 118 "$ZZ/modules/ast/src/lib.zz"
+typedef enum {
+    ast_Expression_Invalid = 0,
+    ast_Expression_Literal = 1,
+    ast_Expression_LiteralString = 2,
+
+} ast_Expression;
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT_ast_ExpressionValue
+#define ZZ_EXPORT_ast_ExpressionValue
+
+#line 125 "$ZZ/modules/ast/src/lib.zz"
+union ast_ExpressionValue_t {
+
+#line 126 "$ZZ/modules/ast/src/lib.zz"
+   char const *  string ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_ast_ExpressionS
+#define ZZ_EXPORT_ast_ExpressionS
+
+#line 129 "$ZZ/modules/ast/src/lib.zz"
+struct ast_ExpressionS_t {
+
+#line 131 "$ZZ/modules/ast/src/lib.zz"
+   ast_Expression t ;
+
+#line 132 "$ZZ/modules/ast/src/lib.zz"
+   ast_ExpressionValue v ;
+
+#line 133 "$ZZ/modules/ast/src/lib.zz"
+   pool_Pool*  pool ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_pool_Pool
+#define ZZ_EXPORT_pool_Pool
+
+#line 21 "$ZZ/modules/pool/src/lib.zz"
+struct pool_Pool_t {
+
+#line 22 "$ZZ/modules/pool/src/lib.zz"
+   uintptr_t blocksize ;
+
+#line 23 "$ZZ/modules/pool/src/lib.zz"
+   uintptr_t poolsize ;
+
+#line 25 "$ZZ/modules/pool/src/lib.zz"
+   uint8_t*  used ;
+
+#line 26 "$ZZ/modules/pool/src/lib.zz"
+   uint8_t*  pool ;
+
+#line 29 "$ZZ/modules/pool/src/lib.zz"
+   uint8_t mem[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_ast_Ast
+#define ZZ_EXPORT_ast_Ast
+
+#line 136 "$ZZ/modules/ast/src/lib.zz"
+struct ast_Ast_t {
+
+#line 138 "$ZZ/modules/ast/src/lib.zz"
+   ast_ExpressionS args[    100] ;
+
+#line 139 "$ZZ/modules/ast/src/lib.zz"
+   pool_Pool pool ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_ast_from_macro
+#define ZZ_EXPORT_ast_from_macro
+
+#line 142 "$ZZ/modules/ast/src/lib.zz"
+extern void ast_from_macro (ast_Ast*  const  self, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#endif
+#ifndef ZZ_EXPORT_string_String
+#define ZZ_EXPORT_string_String
+
+#line 8 "$ZZ/modules/string/src/lib.zz"
+struct string_String_t {
+
+#line 9 "$ZZ/modules/string/src/lib.zz"
+   uintptr_t len ;
+
+#line 10 "$ZZ/modules/string/src/lib.zz"
+   char mem[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_err_Err
+#define ZZ_EXPORT_err_Err
+
+#line 18 "$ZZ/modules/err/src/lib.zz"
+struct err_Err_t {
+
+#line 19 "$ZZ/modules/err/src/lib.zz"
+   uintptr_t error ;
+
+#line 20 "$ZZ/modules/err/src/lib.zz"
+   int system ;
+
+#line 21 "$ZZ/modules/err/src/lib.zz"
+   string_String trace ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_err_checked
+#define ZZ_EXPORT_err_checked
+
+#endif
+#ifndef ZZ_EXPORT_err_abort
+#define ZZ_EXPORT_err_abort
+
+#line 122 "$ZZ/modules/err/src/lib.zz"
+void err_abort (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line);
+
+#endif
+#ifndef ZZ_EXPORT_err_assert2
+#define ZZ_EXPORT_err_assert2
+
+#line 199 "$ZZ/modules/err/src/lib.zz"
+void err_assert2 (bool const  a, char const *  const  file, char const *  const  scope, uintptr_t const  line, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_err_backtrace
+#define ZZ_EXPORT_err_backtrace
+
+#line 52 "$ZZ/modules/err/src/lib.zz"
+void err_backtrace (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line);
+
+#endif
+#ifndef ZZ_EXPORT_err_check
+#define ZZ_EXPORT_err_check
+
+#line 36 "$ZZ/modules/err/src/lib.zz"
+bool err_check (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line);
+
+#endif
+#ifndef ZZ_EXPORT_err_elog
+#define ZZ_EXPORT_err_elog
+
+#line 141 "$ZZ/modules/err/src/lib.zz"
+void err_elog (err_Err*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 6 "$ZZ/modules/ast/src/lib.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_err_eprintf
+#define ZZ_EXPORT_err_eprintf
+
+#line 146 "$ZZ/modules/err/src/lib.zz"
+void err_eprintf (err_Err*  const  self, uintptr_t const  tail, FILE*  const  out);
+
+#endif
+#ifndef ZZ_EXPORT_err_fail
+#define ZZ_EXPORT_err_fail
+
+#line 87 "$ZZ/modules/err/src/lib.zz"
+void err_fail (err_Err*  const  self, uintptr_t const  tail, uintptr_t const  e, char const *  const  file, char const *  const  scope, uintptr_t const  line, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_err_fail_with_errno
+#define ZZ_EXPORT_err_fail_with_errno
+
+#line 58 "$ZZ/modules/err/src/lib.zz"
+void err_fail_with_errno (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_err_make
+#define ZZ_EXPORT_err_make
+
+#line 25 "$ZZ/modules/err/src/lib.zz"
+void err_make (err_Err*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_err_to_str
+#define ZZ_EXPORT_err_to_str
+
+#line 151 "$ZZ/modules/err/src/lib.zz"
+void err_to_str (err_Err const *  const  self, char*  const  dest, uintptr_t const  dest_len);
+
+#endif
+#ifndef ZZ_EXPORT__stdarg_h_
+#define ZZ_EXPORT__stdarg_h_
+
+#line 5 "$ZZ/modules/err/src/lib.zz"
+#include <stdarg.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdlib_h_
+#define ZZ_EXPORT__stdlib_h_
+
+#line 4 "$ZZ/modules/err/src/lib.zz"
+#include <stdlib.h>
+
+#endif
+#ifndef ZZ_EXPORT_json_MAX_DEPTH
+#define ZZ_EXPORT_json_MAX_DEPTH
+
+#line 8 "$ZZ/modules/json/src/lib.zz"
+#define json_MAX_DEPTH ((uintptr_t )    64)
+
+#endif
+#ifndef ZZ_EXPORT_json_ParserState
+#define ZZ_EXPORT_json_ParserState
+
+#line 28 "$ZZ/modules/json/src/lib.zz"
+typedef enum {
+    json_ParserState_Document = 0,
+    json_ParserState_Object = 1,
+    json_ParserState_Key = 2,
+    json_ParserState_PostKey = 3,
+    json_ParserState_PreVal = 4,
+    json_ParserState_StringVal = 5,
+    json_ParserState_IntVal = 6,
+    json_ParserState_PostVal = 7,
+
+} json_ParserState;
+
+#endif
+#ifndef ZZ_EXPORT_json_ValueType
+#define ZZ_EXPORT_json_ValueType
+
+#line 14 "$ZZ/modules/json/src/lib.zz"
+typedef enum {
+    json_ValueType_String = 0,
+    json_ValueType_Object = 1,
+    json_ValueType_Integer = 2,
+    json_ValueType_Array = 3,
+
+} json_ValueType;
+
+#endif
+#ifndef ZZ_EXPORT_json_Value
+#define ZZ_EXPORT_json_Value
+
+#line 21 "$ZZ/modules/json/src/lib.zz"
+struct json_Value_t {
+
+#line 22 "$ZZ/modules/json/src/lib.zz"
+   json_ValueType t ;
+
+#line 23 "$ZZ/modules/json/src/lib.zz"
+   char const *  string ;
+
+#line 24 "$ZZ/modules/json/src/lib.zz"
+   int integer ;
+
+#line 25 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t index ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_json_deserialize_t
+#define ZZ_EXPORT_json_deserialize_t
+
+#line 10 "$ZZ/modules/json/src/lib.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_json_deserialize_t)
+#define ZZ_HAS_DEFINED_CLOSURE_json_deserialize_t 1
+typedef struct {
+    void *ctx;
+    void (*fn) (err_Err*  const  e, uintptr_t const  et, json_Parser*  const  p, uintptr_t const  pt, void*  const  user, char const *  const  k, json_Value const  v, void * _ctx);
+} json_deserialize_t;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_json_deserialize_t)
+
+#endif
+#ifndef ZZ_EXPORT_json_ParserStack
+#define ZZ_EXPORT_json_ParserStack
+
+#line 39 "$ZZ/modules/json/src/lib.zz"
+struct json_ParserStack_t {
+
+#line 40 "$ZZ/modules/json/src/lib.zz"
+   json_ParserState state ;
+
+#line 41 "$ZZ/modules/json/src/lib.zz"
+   json_deserialize_t de ;
+
+#line 42 "$ZZ/modules/json/src/lib.zz"
+   void*  user ;
+
+#line 43 "$ZZ/modules/json/src/lib.zz"
+   bool in_array ;
+
+#line 44 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t index ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_json_Parser
+#define ZZ_EXPORT_json_Parser
+
+#line 47 "$ZZ/modules/json/src/lib.zz"
+struct json_Parser_t {
+
+#line 48 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t col ;
+
+#line 49 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t line ;
+
+#line 50 "$ZZ/modules/json/src/lib.zz"
+   bool esc ;
+
+#line 52 "$ZZ/modules/json/src/lib.zz"
+   json_ParserStack state[    64] ;
+
+#line 53 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t depth ;
+
+#line 55 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t keylen ;
+
+#line 56 "$ZZ/modules/json/src/lib.zz"
+   string_String capture ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_json_advance
+#define ZZ_EXPORT_json_advance
+
+#line 203 "$ZZ/modules/json/src/lib.zz"
+static void json_advance (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, char const  token);
+
+#endif
+#ifndef ZZ_EXPORT_json_next
+#define ZZ_EXPORT_json_next
+
+#line 74 "$ZZ/modules/json/src/lib.zz"
+void json_next (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, json_deserialize_t const  de, void*  const  user);
+
+#endif
+#ifndef ZZ_EXPORT_json_parser
+#define ZZ_EXPORT_json_parser
+
+#line 59 "$ZZ/modules/json/src/lib.zz"
+void json_parser (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, json_deserialize_t const  de, void*  const  user);
+
+#endif
+#ifndef ZZ_EXPORT_json_push
+#define ZZ_EXPORT_json_push
+
+#line 95 "$ZZ/modules/json/src/lib.zz"
+void json_push (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, char const *  const  str, uintptr_t const  strlen);
+
+#endif
+#ifndef ZZ_EXPORT_macro_expr_main_main
+#define ZZ_EXPORT_macro_expr_main_main
+
+#line 35 "./src/main.zz"
+int macro_expr_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_macro_expr_main_make_int
+#define ZZ_EXPORT_macro_expr_main_make_int
+
+#endif
+#ifndef ZZ_EXPORT_macro_expr_main_repeat
+#define ZZ_EXPORT_macro_expr_main_repeat
+
+#endif
+#ifndef ZZ_EXPORT_pool_continuous
+#define ZZ_EXPORT_pool_continuous
+
+#endif
+#ifndef ZZ_EXPORT_pool_member
+#define ZZ_EXPORT_pool_member
+
+#endif
+#ifndef ZZ_EXPORT_pool_alloc
+#define ZZ_EXPORT_pool_alloc
+
+#line 105 "$ZZ/modules/pool/src/lib.zz"
+void * pool_alloc (pool_Pool*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_pool_iterator
+#define ZZ_EXPORT_pool_iterator
+
+#line 267 "$ZZ/modules/pool/src/lib.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_pool_iterator)
+#define ZZ_HAS_DEFINED_CLOSURE_pool_iterator 1
+typedef struct {
+    void *ctx;
+    void (*fn) (pool_Pool*  const  self, void*  const  block, void*  const  user, void * _ctx);
+} pool_iterator;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_pool_iterator)
+
+#endif
+#ifndef ZZ_EXPORT_pool_each
+#define ZZ_EXPORT_pool_each
+
+#line 275 "$ZZ/modules/pool/src/lib.zz"
+void pool_each (pool_Pool*  const  self, pool_iterator const  it, void*  const  user);
+
+#endif
+#ifndef ZZ_EXPORT_pool_free
+#define ZZ_EXPORT_pool_free
+
+#line 206 "$ZZ/modules/pool/src/lib.zz"
+void pool_free (pool_Pool*  const  self, void const *  ptr_);
+
+#endif
+#ifndef ZZ_EXPORT_pool_free_bytes
+#define ZZ_EXPORT_pool_free_bytes
+
+#line 73 "$ZZ/modules/pool/src/lib.zz"
+uintptr_t pool_free_bytes (pool_Pool const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_pool_make
+#define ZZ_EXPORT_pool_make
+
+#line 38 "$ZZ/modules/pool/src/lib.zz"
+void pool_make (pool_Pool*  const  self, uintptr_t const  pt, uintptr_t blocksize);
+
+#endif
+#ifndef ZZ_EXPORT_pool_malloc
+#define ZZ_EXPORT_pool_malloc
+
+#line 122 "$ZZ/modules/pool/src/lib.zz"
+void * pool_malloc (pool_Pool*  const  self, uintptr_t size);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_Slice
+#define ZZ_EXPORT_slice_slice_Slice
+
+#line 4 "$ZZ/modules/slice/src/slice.zz"
+struct slice_slice_Slice_t {
+
+#line 5 "$ZZ/modules/slice/src/slice.zz"
+   uint8_t const *  mem ;
+
+#line 6 "$ZZ/modules/slice/src/slice.zz"
+   uintptr_t size ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_MutSlice
+#define ZZ_EXPORT_slice_mut_slice_MutSlice
+
+#line 4 "$ZZ/modules/slice/src/mut_slice.zz"
+struct slice_mut_slice_MutSlice_t {
+
+#line 5 "$ZZ/modules/slice/src/mut_slice.zz"
+   slice_slice_Slice slice ;
+
+#line 6 "$ZZ/modules/slice/src/mut_slice.zz"
+   uintptr_t at ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_bytes
+#define ZZ_EXPORT_slice_mut_slice_append_bytes
+
+#line 62 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_bytes (slice_mut_slice_MutSlice*  const  self, uint8_t const *  const  b, uintptr_t const  l);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_cstr
+#define ZZ_EXPORT_slice_mut_slice_append_cstr
+
+#line 76 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_cstr (slice_mut_slice_MutSlice*  const  self, char const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_slice
+#define ZZ_EXPORT_slice_mut_slice_append_slice
+
+#line 50 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_slice (slice_mut_slice_MutSlice*  const  self, slice_slice_Slice const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_borrow
+#define ZZ_EXPORT_slice_mut_slice_borrow
+
+#line 9 "$ZZ/modules/slice/src/mut_slice.zz"
+slice_mut_slice_MutSlice  const * slice_mut_slice_borrow (slice_mut_slice_MutSlice*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_make
+#define ZZ_EXPORT_slice_mut_slice_make
+
+#line 24 "$ZZ/modules/slice/src/mut_slice.zz"
+void slice_mut_slice_make (slice_mut_slice_MutSlice*  const  self, uint8_t*  const  mem, uintptr_t const  size);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_mem
+#define ZZ_EXPORT_slice_mut_slice_mem
+
+#line 38 "$ZZ/modules/slice/src/mut_slice.zz"
+uint8_t * slice_mut_slice_mem (slice_mut_slice_MutSlice*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push
+#define ZZ_EXPORT_slice_mut_slice_push
+
+#line 85 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push (slice_mut_slice_MutSlice*  const  self, uint8_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push16
+#define ZZ_EXPORT_slice_mut_slice_push16
+
+#line 100 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push16 (slice_mut_slice_MutSlice*  const  self, uint16_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push32
+#define ZZ_EXPORT_slice_mut_slice_push32
+
+#line 115 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push32 (slice_mut_slice_MutSlice*  const  self, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push64
+#define ZZ_EXPORT_slice_mut_slice_push64
+
+#line 130 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push64 (slice_mut_slice_MutSlice*  const  self, uint64_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_borrow
+#define ZZ_EXPORT_slice_slice_borrow
+
+#line 9 "$ZZ/modules/slice/src/slice.zz"
+slice_slice_Slice  const * slice_slice_borrow (slice_slice_Slice const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq
+#define ZZ_EXPORT_slice_slice_eq
+
+#line 17 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq (slice_slice_Slice const *  const  self, slice_slice_Slice const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq_bytes
+#define ZZ_EXPORT_slice_slice_eq_bytes
+
+#line 33 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq_bytes (slice_slice_Slice const *  const  self, uint8_t const *  const  other, uintptr_t const  othersize);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq_cstr
+#define ZZ_EXPORT_slice_slice_eq_cstr
+
+#line 25 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq_cstr (slice_slice_Slice const *  const  self, char const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_make
+#define ZZ_EXPORT_slice_slice_make
+
+#line 42 "$ZZ/modules/slice/src/slice.zz"
+void slice_slice_make (slice_slice_Slice*  const  self, uint8_t const *  const  mem, uintptr_t const  size);
+
+#endif
+#ifndef ZZ_EXPORT_string_append
+#define ZZ_EXPORT_string_append
+
+#line 184 "$ZZ/modules/string/src/lib.zz"
+void string_append (string_String*  const  self, uintptr_t const  t, string_String const *  const  other, uintptr_t const  t2);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_bytes
+#define ZZ_EXPORT_string_append_bytes
+
+#line 204 "$ZZ/modules/string/src/lib.zz"
+void string_append_bytes (string_String*  const  self, uintptr_t const  t, uint8_t const *  const  bytes, uintptr_t inlen);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_cstr
+#define ZZ_EXPORT_string_append_cstr
+
+#line 163 "$ZZ/modules/string/src/lib.zz"
+void string_append_cstr (string_String*  const  self, uintptr_t const  t, char const *  const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_slice
+#define ZZ_EXPORT_string_append_slice
+
+#line 222 "$ZZ/modules/string/src/lib.zz"
+void string_append_slice (string_String*  const  self, uintptr_t const  t, slice_slice_Slice const *  const  slice);
+
+#endif
+#ifndef ZZ_EXPORT_string_as_mut_slice
+#define ZZ_EXPORT_string_as_mut_slice
+
+#line 55 "$ZZ/modules/string/src/lib.zz"
+slice_mut_slice_MutSlice string_as_mut_slice (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_clear
+#define ZZ_EXPORT_string_clear
+
+#line 115 "$ZZ/modules/string/src/lib.zz"
+void string_clear (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_cstr
+#define ZZ_EXPORT_string_cstr
+
+#line 34 "$ZZ/modules/string/src/lib.zz"
+char  const * string_cstr (string_String const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_string_ends_with_cstr
+#define ZZ_EXPORT_string_ends_with_cstr
+
+#line 333 "$ZZ/modules/string/src/lib.zz"
+bool string_ends_with_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_string_eq_cstr
+#define ZZ_EXPORT_string_eq_cstr
+
+#line 283 "$ZZ/modules/string/src/lib.zz"
+bool string_eq_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_string_fgets
+#define ZZ_EXPORT_string_fgets
+
+#line 352 "$ZZ/modules/string/src/lib.zz"
+bool string_fgets (string_String*  const  self, uintptr_t const  tail, FILE*  const  stream);
+
+#endif
+#ifndef ZZ_EXPORT_string_format
+#define ZZ_EXPORT_string_format
+
+#line 240 "$ZZ/modules/string/src/lib.zz"
+int string_format (string_String*  const  self, uintptr_t const  tail, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_string_from
+#define ZZ_EXPORT_string_from
+
+#line 81 "$ZZ/modules/string/src/lib.zz"
+void string_from (string_String*  const  self, uintptr_t const  t, uint8_t const *  const  bytes, uintptr_t inlen);
+
+#endif
+#ifndef ZZ_EXPORT_string_from_cstr
+#define ZZ_EXPORT_string_from_cstr
+
+#line 105 "$ZZ/modules/string/src/lib.zz"
+void string_from_cstr (string_String*  const  self, uintptr_t const  t, char const *  const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_from_slice
+#define ZZ_EXPORT_string_from_slice
+
+#line 91 "$ZZ/modules/string/src/lib.zz"
+void string_from_slice (string_String*  const  self, uintptr_t const  t, slice_slice_Slice const *  const  slice);
+
+#endif
+#ifndef ZZ_EXPORT_string_make
+#define ZZ_EXPORT_string_make
+
+#line 73 "$ZZ/modules/string/src/lib.zz"
+void string_make (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_pop
+#define ZZ_EXPORT_string_pop
+
+#line 149 "$ZZ/modules/string/src/lib.zz"
+bool string_pop (string_String*  const  self, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT_string_push
+#define ZZ_EXPORT_string_push
+
+#line 126 "$ZZ/modules/string/src/lib.zz"
+bool string_push (string_String*  const  self, uintptr_t const  t, char const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_slen
+#define ZZ_EXPORT_string_slen
+
+#line 29 "$ZZ/modules/string/src/lib.zz"
+uintptr_t string_slen (string_String const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_string_slice
+#define ZZ_EXPORT_string_slice
+
+#line 43 "$ZZ/modules/string/src/lib.zz"
+slice_slice_Slice string_slice (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_space
+#define ZZ_EXPORT_string_space
+
+#line 448 "$ZZ/modules/string/src/lib.zz"
+uintptr_t string_space (string_String const *  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_split
+#define ZZ_EXPORT_string_split
+
+#line 417 "$ZZ/modules/string/src/lib.zz"
+bool string_split (string_String const *  const  self, uintptr_t const  tail, char const  token, uintptr_t*  const  iterator, string_String*  const  other, uintptr_t const  tail2);
+
+#endif
+#ifndef ZZ_EXPORT_string_starts_with_cstr
+#define ZZ_EXPORT_string_starts_with_cstr
+
+#line 317 "$ZZ/modules/string/src/lib.zz"
+bool string_starts_with_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_string_substr
+#define ZZ_EXPORT_string_substr
+
+#line 369 "$ZZ/modules/string/src/lib.zz"
+void string_substr (string_String const *  const  self, uintptr_t const  tail, uintptr_t const  from, uintptr_t size, string_String*  const  other, uintptr_t const  tail2);
+
+#endif
+#ifndef ZZ_EXPORT__stdarg_h_
+#define ZZ_EXPORT__stdarg_h_
+
+#endif
+#ifndef ZZ_EXPORT_string_vformat
+#define ZZ_EXPORT_string_vformat
+
+#line 252 "$ZZ/modules/string/src/lib.zz"
+int string_vformat (string_String*  const  self, uintptr_t const  tail, char const *  const  fmt, va_list args);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_macro_expr_main_main
+#define ZZ_IMPL_macro_expr_main_main
+
+#line 35 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 3 "generated<ceee2fb3fbaf25ae611923ed3add40a61f4a8fd775a64fab3bab56480d84b4d1> from ./src/main.zz:37"
+    printf(    ":)))\n"    );
+
+#line 4 "generated<ceee2fb3fbaf25ae611923ed3add40a61f4a8fd775a64fab3bab56480d84b4d1> from ./src/main.zz:37"
+    printf(    ":)))\n"    );
+
+#line 39 "./src/main.zz"
+    printf(    "hello %s\n",
+#line 1 "generated<2d54947606f34e8036853a9239663cd3a5e20e5498cffc186ad1552909796a8f> from ./src/main.zz:39"
+    "world world world world world world world world world world world world world world world world world world world world world world world world world world world world world world world world "    );
+
+#line 1 "generated<eb35c258a5aedb7471e91a1e859db72d7e2a5ccab75641caeb73357dfe68a6da> from ./src/main.zz:40"
+  int a  =     0;
+
+#line 2 "generated<eb35c258a5aedb7471e91a1e859db72d7e2a5ccab75641caeb73357dfe68a6da> from ./src/main.zz:40"
+  return     a;
+
+}
+#endif
+
+
+#endif
+#endif
+
+/* macro_expr_main_make_int.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_ast_Expression
+#define ZZ_FORWARD_ast_Expression
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_ast_ExpressionValue
+#define ZZ_FORWARD_ast_ExpressionValue
+
+#line 125 "$ZZ/modules/ast/src/lib.zz"
+union ast_ExpressionValue_t;
+typedef union ast_ExpressionValue_t ast_ExpressionValue;
+#endif
+#ifndef ZZ_FORWARD_pool_Pool
+#define ZZ_FORWARD_pool_Pool
+
+#line 21 "$ZZ/modules/pool/src/lib.zz"
+struct pool_Pool_t;
+typedef struct pool_Pool_t pool_Pool;
+#endif
+#ifndef ZZ_FORWARD_ast_ExpressionS
+#define ZZ_FORWARD_ast_ExpressionS
+
+#line 129 "$ZZ/modules/ast/src/lib.zz"
+struct ast_ExpressionS_t;
+typedef struct ast_ExpressionS_t ast_ExpressionS;
+#endif
+#ifndef ZZ_FORWARD_pool_Pool
+#define ZZ_FORWARD_pool_Pool
+
+#line 21 "$ZZ/modules/pool/src/lib.zz"
+struct pool_Pool_t;
+typedef struct pool_Pool_t pool_Pool;
+#endif
+#ifndef ZZ_FORWARD_ast_Ast
+#define ZZ_FORWARD_ast_Ast
+
+#line 136 "$ZZ/modules/ast/src/lib.zz"
+struct ast_Ast_t;
+typedef struct ast_Ast_t ast_Ast;
+#endif
+#ifndef ZZ_FORWARD_ast_from_macro
+#define ZZ_FORWARD_ast_from_macro
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_string_String
+#define ZZ_FORWARD_string_String
+
+#line 8 "$ZZ/modules/string/src/lib.zz"
+struct string_String_t;
+typedef struct string_String_t string_String;
+#endif
+#ifndef ZZ_FORWARD_err_Err
+#define ZZ_FORWARD_err_Err
+
+#line 18 "$ZZ/modules/err/src/lib.zz"
+struct err_Err_t;
+typedef struct err_Err_t err_Err;
+#endif
+#ifndef ZZ_FORWARD_err_checked
+#define ZZ_FORWARD_err_checked
+#endif
+#ifndef ZZ_FORWARD_err_abort
+#define ZZ_FORWARD_err_abort
+#endif
+#ifndef ZZ_FORWARD_err_backtrace
+#define ZZ_FORWARD_err_backtrace
+#endif
+#ifndef ZZ_FORWARD_err_check
+#define ZZ_FORWARD_err_check
+#endif
+#ifndef ZZ_FORWARD_err_elog
+#define ZZ_FORWARD_err_elog
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_err_eprintf
+#define ZZ_FORWARD_err_eprintf
+#endif
+#ifndef ZZ_FORWARD_err_fail
+#define ZZ_FORWARD_err_fail
+#endif
+#ifndef ZZ_FORWARD_err_fail_with_errno
+#define ZZ_FORWARD_err_fail_with_errno
+#endif
+#ifndef ZZ_FORWARD_err_make
+#define ZZ_FORWARD_err_make
+#endif
+#ifndef ZZ_FORWARD_err_to_str
+#define ZZ_FORWARD_err_to_str
+#endif
+#ifndef ZZ_FORWARD__stdarg_h_
+#define ZZ_FORWARD__stdarg_h_
+#endif
+#ifndef ZZ_FORWARD_json_MAX_DEPTH
+#define ZZ_FORWARD_json_MAX_DEPTH
+#endif
+#ifndef ZZ_FORWARD_json_ParserState
+#define ZZ_FORWARD_json_ParserState
+#endif
+#ifndef ZZ_FORWARD_json_ValueType
+#define ZZ_FORWARD_json_ValueType
+#endif
+#ifndef ZZ_FORWARD_json_Value
+#define ZZ_FORWARD_json_Value
+
+#line 21 "$ZZ/modules/json/src/lib.zz"
+struct json_Value_t;
+typedef struct json_Value_t json_Value;
+#endif
+#ifndef ZZ_FORWARD_json_deserialize_t
+#define ZZ_FORWARD_json_deserialize_t
+#endif
+#ifndef ZZ_FORWARD_json_ParserStack
+#define ZZ_FORWARD_json_ParserStack
+
+#line 39 "$ZZ/modules/json/src/lib.zz"
+struct json_ParserStack_t;
+typedef struct json_ParserStack_t json_ParserStack;
+#endif
+#ifndef ZZ_FORWARD_json_Parser
+#define ZZ_FORWARD_json_Parser
+
+#line 47 "$ZZ/modules/json/src/lib.zz"
+struct json_Parser_t;
+typedef struct json_Parser_t json_Parser;
+#endif
+#ifndef ZZ_FORWARD_json_advance
+#define ZZ_FORWARD_json_advance
+#endif
+#ifndef ZZ_FORWARD_json_next
+#define ZZ_FORWARD_json_next
+#endif
+#ifndef ZZ_FORWARD_json_parser
+#define ZZ_FORWARD_json_parser
+#endif
+#ifndef ZZ_FORWARD_json_push
+#define ZZ_FORWARD_json_push
+#endif
+#ifndef ZZ_FORWARD_macro_expr_main_make_int_main
+#define ZZ_FORWARD_macro_expr_main_make_int_main
+#endif
+#ifndef ZZ_FORWARD_pool_continuous
+#define ZZ_FORWARD_pool_continuous
+#endif
+#ifndef ZZ_FORWARD_pool_member
+#define ZZ_FORWARD_pool_member
+#endif
+#ifndef ZZ_FORWARD_pool_alloc
+#define ZZ_FORWARD_pool_alloc
+#endif
+#ifndef ZZ_FORWARD_pool_iterator
+#define ZZ_FORWARD_pool_iterator
+#endif
+#ifndef ZZ_FORWARD_pool_each
+#define ZZ_FORWARD_pool_each
+#endif
+#ifndef ZZ_FORWARD_pool_free
+#define ZZ_FORWARD_pool_free
+#endif
+#ifndef ZZ_FORWARD_pool_free_bytes
+#define ZZ_FORWARD_pool_free_bytes
+#endif
+#ifndef ZZ_FORWARD_pool_make
+#define ZZ_FORWARD_pool_make
+#endif
+#ifndef ZZ_FORWARD_pool_malloc
+#define ZZ_FORWARD_pool_malloc
+#endif
+#ifndef ZZ_FORWARD_slice_slice_Slice
+#define ZZ_FORWARD_slice_slice_Slice
+
+#line 4 "$ZZ/modules/slice/src/slice.zz"
+struct slice_slice_Slice_t;
+typedef struct slice_slice_Slice_t slice_slice_Slice;
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_MutSlice
+#define ZZ_FORWARD_slice_mut_slice_MutSlice
+
+#line 4 "$ZZ/modules/slice/src/mut_slice.zz"
+struct slice_mut_slice_MutSlice_t;
+typedef struct slice_mut_slice_MutSlice_t slice_mut_slice_MutSlice;
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_bytes
+#define ZZ_FORWARD_slice_mut_slice_append_bytes
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_cstr
+#define ZZ_FORWARD_slice_mut_slice_append_cstr
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_slice
+#define ZZ_FORWARD_slice_mut_slice_append_slice
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_borrow
+#define ZZ_FORWARD_slice_mut_slice_borrow
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_make
+#define ZZ_FORWARD_slice_mut_slice_make
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_mem
+#define ZZ_FORWARD_slice_mut_slice_mem
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push
+#define ZZ_FORWARD_slice_mut_slice_push
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push16
+#define ZZ_FORWARD_slice_mut_slice_push16
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push32
+#define ZZ_FORWARD_slice_mut_slice_push32
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push64
+#define ZZ_FORWARD_slice_mut_slice_push64
+#endif
+#ifndef ZZ_FORWARD_slice_slice_borrow
+#define ZZ_FORWARD_slice_slice_borrow
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq
+#define ZZ_FORWARD_slice_slice_eq
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq_bytes
+#define ZZ_FORWARD_slice_slice_eq_bytes
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq_cstr
+#define ZZ_FORWARD_slice_slice_eq_cstr
+#endif
+#ifndef ZZ_FORWARD_slice_slice_make
+#define ZZ_FORWARD_slice_slice_make
+#endif
+#ifndef ZZ_FORWARD_string_append
+#define ZZ_FORWARD_string_append
+#endif
+#ifndef ZZ_FORWARD_string_append_bytes
+#define ZZ_FORWARD_string_append_bytes
+#endif
+#ifndef ZZ_FORWARD_string_append_cstr
+#define ZZ_FORWARD_string_append_cstr
+#endif
+#ifndef ZZ_FORWARD_string_append_slice
+#define ZZ_FORWARD_string_append_slice
+#endif
+#ifndef ZZ_FORWARD_string_as_mut_slice
+#define ZZ_FORWARD_string_as_mut_slice
+#endif
+#ifndef ZZ_FORWARD_string_clear
+#define ZZ_FORWARD_string_clear
+#endif
+#ifndef ZZ_FORWARD_string_cstr
+#define ZZ_FORWARD_string_cstr
+#endif
+#ifndef ZZ_FORWARD_string_ends_with_cstr
+#define ZZ_FORWARD_string_ends_with_cstr
+#endif
+#ifndef ZZ_FORWARD_string_eq_cstr
+#define ZZ_FORWARD_string_eq_cstr
+#endif
+#ifndef ZZ_FORWARD_string_fgets
+#define ZZ_FORWARD_string_fgets
+#endif
+#ifndef ZZ_FORWARD_string_format
+#define ZZ_FORWARD_string_format
+#endif
+#ifndef ZZ_FORWARD_string_from
+#define ZZ_FORWARD_string_from
+#endif
+#ifndef ZZ_FORWARD_string_from_cstr
+#define ZZ_FORWARD_string_from_cstr
+#endif
+#ifndef ZZ_FORWARD_string_from_slice
+#define ZZ_FORWARD_string_from_slice
+#endif
+#ifndef ZZ_FORWARD_string_make
+#define ZZ_FORWARD_string_make
+#endif
+#ifndef ZZ_FORWARD_string_pop
+#define ZZ_FORWARD_string_pop
+#endif
+#ifndef ZZ_FORWARD_string_push
+#define ZZ_FORWARD_string_push
+#endif
+#ifndef ZZ_FORWARD_string_slen
+#define ZZ_FORWARD_string_slen
+#endif
+#ifndef ZZ_FORWARD_string_slice
+#define ZZ_FORWARD_string_slice
+#endif
+#ifndef ZZ_FORWARD_string_space
+#define ZZ_FORWARD_string_space
+#endif
+#ifndef ZZ_FORWARD_string_split
+#define ZZ_FORWARD_string_split
+#endif
+#ifndef ZZ_FORWARD_string_starts_with_cstr
+#define ZZ_FORWARD_string_starts_with_cstr
+#endif
+#ifndef ZZ_FORWARD_string_substr
+#define ZZ_FORWARD_string_substr
+#endif
+#ifndef ZZ_FORWARD__stdarg_h_
+#define ZZ_FORWARD__stdarg_h_
+#endif
+#ifndef ZZ_FORWARD_string_vformat
+#define ZZ_FORWARD_string_vformat
+#endif
+#ifndef ZZ_EXPORT_ast_Expression
+#define ZZ_EXPORT_ast_Expression
+
+#line 118 "$ZZ/modules/ast/src/lib.zz"
+typedef enum {
+    ast_Expression_Invalid = 0,
+    ast_Expression_Literal = 1,
+    ast_Expression_LiteralString = 2,
+
+} ast_Expression;
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT_ast_ExpressionValue
+#define ZZ_EXPORT_ast_ExpressionValue
+
+#line 125 "$ZZ/modules/ast/src/lib.zz"
+union ast_ExpressionValue_t {
+
+#line 126 "$ZZ/modules/ast/src/lib.zz"
+   char const *  string ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_ast_ExpressionS
+#define ZZ_EXPORT_ast_ExpressionS
+
+#line 129 "$ZZ/modules/ast/src/lib.zz"
+struct ast_ExpressionS_t {
+
+#line 131 "$ZZ/modules/ast/src/lib.zz"
+   ast_Expression t ;
+
+#line 132 "$ZZ/modules/ast/src/lib.zz"
+   ast_ExpressionValue v ;
+
+#line 133 "$ZZ/modules/ast/src/lib.zz"
+   pool_Pool*  pool ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_pool_Pool
+#define ZZ_EXPORT_pool_Pool
+
+#line 21 "$ZZ/modules/pool/src/lib.zz"
+struct pool_Pool_t {
+
+#line 22 "$ZZ/modules/pool/src/lib.zz"
+   uintptr_t blocksize ;
+
+#line 23 "$ZZ/modules/pool/src/lib.zz"
+   uintptr_t poolsize ;
+
+#line 25 "$ZZ/modules/pool/src/lib.zz"
+   uint8_t*  used ;
+
+#line 26 "$ZZ/modules/pool/src/lib.zz"
+   uint8_t*  pool ;
+
+#line 29 "$ZZ/modules/pool/src/lib.zz"
+   uint8_t mem[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_ast_Ast
+#define ZZ_EXPORT_ast_Ast
+
+#line 136 "$ZZ/modules/ast/src/lib.zz"
+struct ast_Ast_t {
+
+#line 138 "$ZZ/modules/ast/src/lib.zz"
+   ast_ExpressionS args[    100] ;
+
+#line 139 "$ZZ/modules/ast/src/lib.zz"
+   pool_Pool pool ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_ast_from_macro
+#define ZZ_EXPORT_ast_from_macro
+
+#line 142 "$ZZ/modules/ast/src/lib.zz"
+extern void ast_from_macro (ast_Ast*  const  self, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#endif
+#ifndef ZZ_EXPORT_string_String
+#define ZZ_EXPORT_string_String
+
+#line 8 "$ZZ/modules/string/src/lib.zz"
+struct string_String_t {
+
+#line 9 "$ZZ/modules/string/src/lib.zz"
+   uintptr_t len ;
+
+#line 10 "$ZZ/modules/string/src/lib.zz"
+   char mem[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_err_Err
+#define ZZ_EXPORT_err_Err
+
+#line 18 "$ZZ/modules/err/src/lib.zz"
+struct err_Err_t {
+
+#line 19 "$ZZ/modules/err/src/lib.zz"
+   uintptr_t error ;
+
+#line 20 "$ZZ/modules/err/src/lib.zz"
+   int system ;
+
+#line 21 "$ZZ/modules/err/src/lib.zz"
+   string_String trace ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_err_checked
+#define ZZ_EXPORT_err_checked
+
+#endif
+#ifndef ZZ_EXPORT_err_abort
+#define ZZ_EXPORT_err_abort
+
+#line 122 "$ZZ/modules/err/src/lib.zz"
+void err_abort (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line);
+
+#endif
+#ifndef ZZ_EXPORT_err_backtrace
+#define ZZ_EXPORT_err_backtrace
+
+#line 52 "$ZZ/modules/err/src/lib.zz"
+void err_backtrace (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line);
+
+#endif
+#ifndef ZZ_EXPORT_err_check
+#define ZZ_EXPORT_err_check
+
+#line 36 "$ZZ/modules/err/src/lib.zz"
+bool err_check (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line);
+
+#endif
+#ifndef ZZ_EXPORT_err_elog
+#define ZZ_EXPORT_err_elog
+
+#line 141 "$ZZ/modules/err/src/lib.zz"
+void err_elog (err_Err*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 6 "$ZZ/modules/ast/src/lib.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_err_eprintf
+#define ZZ_EXPORT_err_eprintf
+
+#line 146 "$ZZ/modules/err/src/lib.zz"
+void err_eprintf (err_Err*  const  self, uintptr_t const  tail, FILE*  const  out);
+
+#endif
+#ifndef ZZ_EXPORT_err_fail
+#define ZZ_EXPORT_err_fail
+
+#line 87 "$ZZ/modules/err/src/lib.zz"
+void err_fail (err_Err*  const  self, uintptr_t const  tail, uintptr_t const  e, char const *  const  file, char const *  const  scope, uintptr_t const  line, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_err_fail_with_errno
+#define ZZ_EXPORT_err_fail_with_errno
+
+#line 58 "$ZZ/modules/err/src/lib.zz"
+void err_fail_with_errno (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_err_make
+#define ZZ_EXPORT_err_make
+
+#line 25 "$ZZ/modules/err/src/lib.zz"
+void err_make (err_Err*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_err_to_str
+#define ZZ_EXPORT_err_to_str
+
+#line 151 "$ZZ/modules/err/src/lib.zz"
+void err_to_str (err_Err const *  const  self, char*  const  dest, uintptr_t const  dest_len);
+
+#endif
+#ifndef ZZ_EXPORT__stdarg_h_
+#define ZZ_EXPORT__stdarg_h_
+
+#line 5 "$ZZ/modules/err/src/lib.zz"
+#include <stdarg.h>
+
+#endif
+#ifndef ZZ_EXPORT_json_MAX_DEPTH
+#define ZZ_EXPORT_json_MAX_DEPTH
+
+#line 8 "$ZZ/modules/json/src/lib.zz"
+#define json_MAX_DEPTH ((uintptr_t )    64)
+
+#endif
+#ifndef ZZ_EXPORT_json_ParserState
+#define ZZ_EXPORT_json_ParserState
+
+#line 28 "$ZZ/modules/json/src/lib.zz"
+typedef enum {
+    json_ParserState_Document = 0,
+    json_ParserState_Object = 1,
+    json_ParserState_Key = 2,
+    json_ParserState_PostKey = 3,
+    json_ParserState_PreVal = 4,
+    json_ParserState_StringVal = 5,
+    json_ParserState_IntVal = 6,
+    json_ParserState_PostVal = 7,
+
+} json_ParserState;
+
+#endif
+#ifndef ZZ_EXPORT_json_ValueType
+#define ZZ_EXPORT_json_ValueType
+
+#line 14 "$ZZ/modules/json/src/lib.zz"
+typedef enum {
+    json_ValueType_String = 0,
+    json_ValueType_Object = 1,
+    json_ValueType_Integer = 2,
+    json_ValueType_Array = 3,
+
+} json_ValueType;
+
+#endif
+#ifndef ZZ_EXPORT_json_Value
+#define ZZ_EXPORT_json_Value
+
+#line 21 "$ZZ/modules/json/src/lib.zz"
+struct json_Value_t {
+
+#line 22 "$ZZ/modules/json/src/lib.zz"
+   json_ValueType t ;
+
+#line 23 "$ZZ/modules/json/src/lib.zz"
+   char const *  string ;
+
+#line 24 "$ZZ/modules/json/src/lib.zz"
+   int integer ;
+
+#line 25 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t index ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_json_deserialize_t
+#define ZZ_EXPORT_json_deserialize_t
+
+#line 10 "$ZZ/modules/json/src/lib.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_json_deserialize_t)
+#define ZZ_HAS_DEFINED_CLOSURE_json_deserialize_t 1
+typedef struct {
+    void *ctx;
+    void (*fn) (err_Err*  const  e, uintptr_t const  et, json_Parser*  const  p, uintptr_t const  pt, void*  const  user, char const *  const  k, json_Value const  v, void * _ctx);
+} json_deserialize_t;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_json_deserialize_t)
+
+#endif
+#ifndef ZZ_EXPORT_json_ParserStack
+#define ZZ_EXPORT_json_ParserStack
+
+#line 39 "$ZZ/modules/json/src/lib.zz"
+struct json_ParserStack_t {
+
+#line 40 "$ZZ/modules/json/src/lib.zz"
+   json_ParserState state ;
+
+#line 41 "$ZZ/modules/json/src/lib.zz"
+   json_deserialize_t de ;
+
+#line 42 "$ZZ/modules/json/src/lib.zz"
+   void*  user ;
+
+#line 43 "$ZZ/modules/json/src/lib.zz"
+   bool in_array ;
+
+#line 44 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t index ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_json_Parser
+#define ZZ_EXPORT_json_Parser
+
+#line 47 "$ZZ/modules/json/src/lib.zz"
+struct json_Parser_t {
+
+#line 48 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t col ;
+
+#line 49 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t line ;
+
+#line 50 "$ZZ/modules/json/src/lib.zz"
+   bool esc ;
+
+#line 52 "$ZZ/modules/json/src/lib.zz"
+   json_ParserStack state[    64] ;
+
+#line 53 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t depth ;
+
+#line 55 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t keylen ;
+
+#line 56 "$ZZ/modules/json/src/lib.zz"
+   string_String capture ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_json_advance
+#define ZZ_EXPORT_json_advance
+
+#line 203 "$ZZ/modules/json/src/lib.zz"
+static void json_advance (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, char const  token);
+
+#endif
+#ifndef ZZ_EXPORT_json_next
+#define ZZ_EXPORT_json_next
+
+#line 74 "$ZZ/modules/json/src/lib.zz"
+void json_next (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, json_deserialize_t const  de, void*  const  user);
+
+#endif
+#ifndef ZZ_EXPORT_json_parser
+#define ZZ_EXPORT_json_parser
+
+#line 59 "$ZZ/modules/json/src/lib.zz"
+void json_parser (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, json_deserialize_t const  de, void*  const  user);
+
+#endif
+#ifndef ZZ_EXPORT_json_push
+#define ZZ_EXPORT_json_push
+
+#line 95 "$ZZ/modules/json/src/lib.zz"
+void json_push (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, char const *  const  str, uintptr_t const  strlen);
+
+#endif
+#ifndef ZZ_EXPORT_macro_expr_main_make_int_main
+#define ZZ_EXPORT_macro_expr_main_make_int_main
+
+#line 30 "./src/main.zz"
+int macro_expr_main_make_int_main ();
+
+#endif
+#ifndef ZZ_EXPORT_pool_continuous
+#define ZZ_EXPORT_pool_continuous
+
+#endif
+#ifndef ZZ_EXPORT_pool_member
+#define ZZ_EXPORT_pool_member
+
+#endif
+#ifndef ZZ_EXPORT_pool_alloc
+#define ZZ_EXPORT_pool_alloc
+
+#line 105 "$ZZ/modules/pool/src/lib.zz"
+void * pool_alloc (pool_Pool*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_pool_iterator
+#define ZZ_EXPORT_pool_iterator
+
+#line 267 "$ZZ/modules/pool/src/lib.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_pool_iterator)
+#define ZZ_HAS_DEFINED_CLOSURE_pool_iterator 1
+typedef struct {
+    void *ctx;
+    void (*fn) (pool_Pool*  const  self, void*  const  block, void*  const  user, void * _ctx);
+} pool_iterator;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_pool_iterator)
+
+#endif
+#ifndef ZZ_EXPORT_pool_each
+#define ZZ_EXPORT_pool_each
+
+#line 275 "$ZZ/modules/pool/src/lib.zz"
+void pool_each (pool_Pool*  const  self, pool_iterator const  it, void*  const  user);
+
+#endif
+#ifndef ZZ_EXPORT_pool_free
+#define ZZ_EXPORT_pool_free
+
+#line 206 "$ZZ/modules/pool/src/lib.zz"
+void pool_free (pool_Pool*  const  self, void const *  ptr_);
+
+#endif
+#ifndef ZZ_EXPORT_pool_free_bytes
+#define ZZ_EXPORT_pool_free_bytes
+
+#line 73 "$ZZ/modules/pool/src/lib.zz"
+uintptr_t pool_free_bytes (pool_Pool const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_pool_make
+#define ZZ_EXPORT_pool_make
+
+#line 38 "$ZZ/modules/pool/src/lib.zz"
+void pool_make (pool_Pool*  const  self, uintptr_t const  pt, uintptr_t blocksize);
+
+#endif
+#ifndef ZZ_EXPORT_pool_malloc
+#define ZZ_EXPORT_pool_malloc
+
+#line 122 "$ZZ/modules/pool/src/lib.zz"
+void * pool_malloc (pool_Pool*  const  self, uintptr_t size);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_Slice
+#define ZZ_EXPORT_slice_slice_Slice
+
+#line 4 "$ZZ/modules/slice/src/slice.zz"
+struct slice_slice_Slice_t {
+
+#line 5 "$ZZ/modules/slice/src/slice.zz"
+   uint8_t const *  mem ;
+
+#line 6 "$ZZ/modules/slice/src/slice.zz"
+   uintptr_t size ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_MutSlice
+#define ZZ_EXPORT_slice_mut_slice_MutSlice
+
+#line 4 "$ZZ/modules/slice/src/mut_slice.zz"
+struct slice_mut_slice_MutSlice_t {
+
+#line 5 "$ZZ/modules/slice/src/mut_slice.zz"
+   slice_slice_Slice slice ;
+
+#line 6 "$ZZ/modules/slice/src/mut_slice.zz"
+   uintptr_t at ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_bytes
+#define ZZ_EXPORT_slice_mut_slice_append_bytes
+
+#line 62 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_bytes (slice_mut_slice_MutSlice*  const  self, uint8_t const *  const  b, uintptr_t const  l);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_cstr
+#define ZZ_EXPORT_slice_mut_slice_append_cstr
+
+#line 76 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_cstr (slice_mut_slice_MutSlice*  const  self, char const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_slice
+#define ZZ_EXPORT_slice_mut_slice_append_slice
+
+#line 50 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_slice (slice_mut_slice_MutSlice*  const  self, slice_slice_Slice const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_borrow
+#define ZZ_EXPORT_slice_mut_slice_borrow
+
+#line 9 "$ZZ/modules/slice/src/mut_slice.zz"
+slice_mut_slice_MutSlice  const * slice_mut_slice_borrow (slice_mut_slice_MutSlice*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_make
+#define ZZ_EXPORT_slice_mut_slice_make
+
+#line 24 "$ZZ/modules/slice/src/mut_slice.zz"
+void slice_mut_slice_make (slice_mut_slice_MutSlice*  const  self, uint8_t*  const  mem, uintptr_t const  size);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_mem
+#define ZZ_EXPORT_slice_mut_slice_mem
+
+#line 38 "$ZZ/modules/slice/src/mut_slice.zz"
+uint8_t * slice_mut_slice_mem (slice_mut_slice_MutSlice*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push
+#define ZZ_EXPORT_slice_mut_slice_push
+
+#line 85 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push (slice_mut_slice_MutSlice*  const  self, uint8_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push16
+#define ZZ_EXPORT_slice_mut_slice_push16
+
+#line 100 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push16 (slice_mut_slice_MutSlice*  const  self, uint16_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push32
+#define ZZ_EXPORT_slice_mut_slice_push32
+
+#line 115 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push32 (slice_mut_slice_MutSlice*  const  self, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push64
+#define ZZ_EXPORT_slice_mut_slice_push64
+
+#line 130 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push64 (slice_mut_slice_MutSlice*  const  self, uint64_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_borrow
+#define ZZ_EXPORT_slice_slice_borrow
+
+#line 9 "$ZZ/modules/slice/src/slice.zz"
+slice_slice_Slice  const * slice_slice_borrow (slice_slice_Slice const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq
+#define ZZ_EXPORT_slice_slice_eq
+
+#line 17 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq (slice_slice_Slice const *  const  self, slice_slice_Slice const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq_bytes
+#define ZZ_EXPORT_slice_slice_eq_bytes
+
+#line 33 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq_bytes (slice_slice_Slice const *  const  self, uint8_t const *  const  other, uintptr_t const  othersize);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq_cstr
+#define ZZ_EXPORT_slice_slice_eq_cstr
+
+#line 25 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq_cstr (slice_slice_Slice const *  const  self, char const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_make
+#define ZZ_EXPORT_slice_slice_make
+
+#line 42 "$ZZ/modules/slice/src/slice.zz"
+void slice_slice_make (slice_slice_Slice*  const  self, uint8_t const *  const  mem, uintptr_t const  size);
+
+#endif
+#ifndef ZZ_EXPORT_string_append
+#define ZZ_EXPORT_string_append
+
+#line 184 "$ZZ/modules/string/src/lib.zz"
+void string_append (string_String*  const  self, uintptr_t const  t, string_String const *  const  other, uintptr_t const  t2);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_bytes
+#define ZZ_EXPORT_string_append_bytes
+
+#line 204 "$ZZ/modules/string/src/lib.zz"
+void string_append_bytes (string_String*  const  self, uintptr_t const  t, uint8_t const *  const  bytes, uintptr_t inlen);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_cstr
+#define ZZ_EXPORT_string_append_cstr
+
+#line 163 "$ZZ/modules/string/src/lib.zz"
+void string_append_cstr (string_String*  const  self, uintptr_t const  t, char const *  const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_slice
+#define ZZ_EXPORT_string_append_slice
+
+#line 222 "$ZZ/modules/string/src/lib.zz"
+void string_append_slice (string_String*  const  self, uintptr_t const  t, slice_slice_Slice const *  const  slice);
+
+#endif
+#ifndef ZZ_EXPORT_string_as_mut_slice
+#define ZZ_EXPORT_string_as_mut_slice
+
+#line 55 "$ZZ/modules/string/src/lib.zz"
+slice_mut_slice_MutSlice string_as_mut_slice (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_clear
+#define ZZ_EXPORT_string_clear
+
+#line 115 "$ZZ/modules/string/src/lib.zz"
+void string_clear (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_cstr
+#define ZZ_EXPORT_string_cstr
+
+#line 34 "$ZZ/modules/string/src/lib.zz"
+char  const * string_cstr (string_String const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_string_ends_with_cstr
+#define ZZ_EXPORT_string_ends_with_cstr
+
+#line 333 "$ZZ/modules/string/src/lib.zz"
+bool string_ends_with_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_string_eq_cstr
+#define ZZ_EXPORT_string_eq_cstr
+
+#line 283 "$ZZ/modules/string/src/lib.zz"
+bool string_eq_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_string_fgets
+#define ZZ_EXPORT_string_fgets
+
+#line 352 "$ZZ/modules/string/src/lib.zz"
+bool string_fgets (string_String*  const  self, uintptr_t const  tail, FILE*  const  stream);
+
+#endif
+#ifndef ZZ_EXPORT_string_format
+#define ZZ_EXPORT_string_format
+
+#line 240 "$ZZ/modules/string/src/lib.zz"
+int string_format (string_String*  const  self, uintptr_t const  tail, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_string_from
+#define ZZ_EXPORT_string_from
+
+#line 81 "$ZZ/modules/string/src/lib.zz"
+void string_from (string_String*  const  self, uintptr_t const  t, uint8_t const *  const  bytes, uintptr_t inlen);
+
+#endif
+#ifndef ZZ_EXPORT_string_from_cstr
+#define ZZ_EXPORT_string_from_cstr
+
+#line 105 "$ZZ/modules/string/src/lib.zz"
+void string_from_cstr (string_String*  const  self, uintptr_t const  t, char const *  const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_from_slice
+#define ZZ_EXPORT_string_from_slice
+
+#line 91 "$ZZ/modules/string/src/lib.zz"
+void string_from_slice (string_String*  const  self, uintptr_t const  t, slice_slice_Slice const *  const  slice);
+
+#endif
+#ifndef ZZ_EXPORT_string_make
+#define ZZ_EXPORT_string_make
+
+#line 73 "$ZZ/modules/string/src/lib.zz"
+void string_make (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_pop
+#define ZZ_EXPORT_string_pop
+
+#line 149 "$ZZ/modules/string/src/lib.zz"
+bool string_pop (string_String*  const  self, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT_string_push
+#define ZZ_EXPORT_string_push
+
+#line 126 "$ZZ/modules/string/src/lib.zz"
+bool string_push (string_String*  const  self, uintptr_t const  t, char const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_slen
+#define ZZ_EXPORT_string_slen
+
+#line 29 "$ZZ/modules/string/src/lib.zz"
+uintptr_t string_slen (string_String const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_string_slice
+#define ZZ_EXPORT_string_slice
+
+#line 43 "$ZZ/modules/string/src/lib.zz"
+slice_slice_Slice string_slice (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_space
+#define ZZ_EXPORT_string_space
+
+#line 448 "$ZZ/modules/string/src/lib.zz"
+uintptr_t string_space (string_String const *  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_split
+#define ZZ_EXPORT_string_split
+
+#line 417 "$ZZ/modules/string/src/lib.zz"
+bool string_split (string_String const *  const  self, uintptr_t const  tail, char const  token, uintptr_t*  const  iterator, string_String*  const  other, uintptr_t const  tail2);
+
+#endif
+#ifndef ZZ_EXPORT_string_starts_with_cstr
+#define ZZ_EXPORT_string_starts_with_cstr
+
+#line 317 "$ZZ/modules/string/src/lib.zz"
+bool string_starts_with_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_string_substr
+#define ZZ_EXPORT_string_substr
+
+#line 369 "$ZZ/modules/string/src/lib.zz"
+void string_substr (string_String const *  const  self, uintptr_t const  tail, uintptr_t const  from, uintptr_t size, string_String*  const  other, uintptr_t const  tail2);
+
+#endif
+#ifndef ZZ_EXPORT__stdarg_h_
+#define ZZ_EXPORT__stdarg_h_
+
+#endif
+#ifndef ZZ_EXPORT_string_vformat
+#define ZZ_EXPORT_string_vformat
+
+#line 252 "$ZZ/modules/string/src/lib.zz"
+int string_vformat (string_String*  const  self, uintptr_t const  tail, char const *  const  fmt, va_list args);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_macro_expr_main_make_int_main
+#define ZZ_IMPL_macro_expr_main_make_int_main
+
+#line 30 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 31 "./src/main.zz"
+    printf(    "int mut a = 0;\n"    );
+
+#line 32 "./src/main.zz"
+    printf(    "return a;\n"    );
+
+#line 1 ""
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
+/* macro_expr_main_repeat.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_ast_Expression
+#define ZZ_FORWARD_ast_Expression
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_ast_ExpressionValue
+#define ZZ_FORWARD_ast_ExpressionValue
+
+#line 125 "$ZZ/modules/ast/src/lib.zz"
+union ast_ExpressionValue_t;
+typedef union ast_ExpressionValue_t ast_ExpressionValue;
+#endif
+#ifndef ZZ_FORWARD_pool_Pool
+#define ZZ_FORWARD_pool_Pool
+
+#line 21 "$ZZ/modules/pool/src/lib.zz"
+struct pool_Pool_t;
+typedef struct pool_Pool_t pool_Pool;
+#endif
+#ifndef ZZ_FORWARD_ast_ExpressionS
+#define ZZ_FORWARD_ast_ExpressionS
+
+#line 129 "$ZZ/modules/ast/src/lib.zz"
+struct ast_ExpressionS_t;
+typedef struct ast_ExpressionS_t ast_ExpressionS;
+#endif
+#ifndef ZZ_FORWARD_pool_Pool
+#define ZZ_FORWARD_pool_Pool
+
+#line 21 "$ZZ/modules/pool/src/lib.zz"
+struct pool_Pool_t;
+typedef struct pool_Pool_t pool_Pool;
+#endif
+#ifndef ZZ_FORWARD_ast_Ast
+#define ZZ_FORWARD_ast_Ast
+
+#line 136 "$ZZ/modules/ast/src/lib.zz"
+struct ast_Ast_t;
+typedef struct ast_Ast_t ast_Ast;
+#endif
+#ifndef ZZ_FORWARD_ast_Ast_1000
+#define ZZ_FORWARD_ast_Ast_1000
+struct ast_Ast_1000_t;
+typedef struct ast_Ast_1000_t ast_Ast_1000;
+#endif
+#ifndef ZZ_FORWARD_ast_from_macro
+#define ZZ_FORWARD_ast_from_macro
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_string_String
+#define ZZ_FORWARD_string_String
+
+#line 8 "$ZZ/modules/string/src/lib.zz"
+struct string_String_t;
+typedef struct string_String_t string_String;
+#endif
+#ifndef ZZ_FORWARD_err_Err
+#define ZZ_FORWARD_err_Err
+
+#line 18 "$ZZ/modules/err/src/lib.zz"
+struct err_Err_t;
+typedef struct err_Err_t err_Err;
+#endif
+#ifndef ZZ_FORWARD_err_checked
+#define ZZ_FORWARD_err_checked
+#endif
+#ifndef ZZ_FORWARD_err_abort
+#define ZZ_FORWARD_err_abort
+#endif
+#ifndef ZZ_FORWARD_err_assert2
+#define ZZ_FORWARD_err_assert2
+#endif
+#ifndef ZZ_FORWARD_err_backtrace
+#define ZZ_FORWARD_err_backtrace
+#endif
+#ifndef ZZ_FORWARD_err_check
+#define ZZ_FORWARD_err_check
+#endif
+#ifndef ZZ_FORWARD_err_elog
+#define ZZ_FORWARD_err_elog
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_err_eprintf
+#define ZZ_FORWARD_err_eprintf
+#endif
+#ifndef ZZ_FORWARD_err_fail
+#define ZZ_FORWARD_err_fail
+#endif
+#ifndef ZZ_FORWARD_err_fail_with_errno
+#define ZZ_FORWARD_err_fail_with_errno
+#endif
+#ifndef ZZ_FORWARD_err_make
+#define ZZ_FORWARD_err_make
+#endif
+#ifndef ZZ_FORWARD_err_to_str
+#define ZZ_FORWARD_err_to_str
+#endif
+#ifndef ZZ_FORWARD__stdarg_h_
+#define ZZ_FORWARD__stdarg_h_
+#endif
+#ifndef ZZ_FORWARD__stdlib_h_
+#define ZZ_FORWARD__stdlib_h_
+#endif
+#ifndef ZZ_FORWARD_json_MAX_DEPTH
+#define ZZ_FORWARD_json_MAX_DEPTH
+#endif
+#ifndef ZZ_FORWARD_json_ParserState
+#define ZZ_FORWARD_json_ParserState
+#endif
+#ifndef ZZ_FORWARD_json_ValueType
+#define ZZ_FORWARD_json_ValueType
+#endif
+#ifndef ZZ_FORWARD_json_Value
+#define ZZ_FORWARD_json_Value
+
+#line 21 "$ZZ/modules/json/src/lib.zz"
+struct json_Value_t;
+typedef struct json_Value_t json_Value;
+#endif
+#ifndef ZZ_FORWARD_json_deserialize_t
+#define ZZ_FORWARD_json_deserialize_t
+#endif
+#ifndef ZZ_FORWARD_json_ParserStack
+#define ZZ_FORWARD_json_ParserStack
+
+#line 39 "$ZZ/modules/json/src/lib.zz"
+struct json_ParserStack_t;
+typedef struct json_ParserStack_t json_ParserStack;
+#endif
+#ifndef ZZ_FORWARD_json_Parser
+#define ZZ_FORWARD_json_Parser
+
+#line 47 "$ZZ/modules/json/src/lib.zz"
+struct json_Parser_t;
+typedef struct json_Parser_t json_Parser;
+#endif
+#ifndef ZZ_FORWARD_json_advance
+#define ZZ_FORWARD_json_advance
+#endif
+#ifndef ZZ_FORWARD_json_next
+#define ZZ_FORWARD_json_next
+#endif
+#ifndef ZZ_FORWARD_json_parser
+#define ZZ_FORWARD_json_parser
+#endif
+#ifndef ZZ_FORWARD_json_push
+#define ZZ_FORWARD_json_push
+#endif
+#ifndef ZZ_FORWARD_macro_expr_main_repeat_main
+#define ZZ_FORWARD_macro_expr_main_repeat_main
+#endif
+#ifndef ZZ_FORWARD_pool_continuous
+#define ZZ_FORWARD_pool_continuous
+#endif
+#ifndef ZZ_FORWARD_pool_member
+#define ZZ_FORWARD_pool_member
+#endif
+#ifndef ZZ_FORWARD_pool_alloc
+#define ZZ_FORWARD_pool_alloc
+#endif
+#ifndef ZZ_FORWARD_pool_iterator
+#define ZZ_FORWARD_pool_iterator
+#endif
+#ifndef ZZ_FORWARD_pool_each
+#define ZZ_FORWARD_pool_each
+#endif
+#ifndef ZZ_FORWARD_pool_free
+#define ZZ_FORWARD_pool_free
+#endif
+#ifndef ZZ_FORWARD_pool_free_bytes
+#define ZZ_FORWARD_pool_free_bytes
+#endif
+#ifndef ZZ_FORWARD_pool_make
+#define ZZ_FORWARD_pool_make
+#endif
+#ifndef ZZ_FORWARD_pool_malloc
+#define ZZ_FORWARD_pool_malloc
+#endif
+#ifndef ZZ_FORWARD_slice_slice_Slice
+#define ZZ_FORWARD_slice_slice_Slice
+
+#line 4 "$ZZ/modules/slice/src/slice.zz"
+struct slice_slice_Slice_t;
+typedef struct slice_slice_Slice_t slice_slice_Slice;
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_MutSlice
+#define ZZ_FORWARD_slice_mut_slice_MutSlice
+
+#line 4 "$ZZ/modules/slice/src/mut_slice.zz"
+struct slice_mut_slice_MutSlice_t;
+typedef struct slice_mut_slice_MutSlice_t slice_mut_slice_MutSlice;
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_bytes
+#define ZZ_FORWARD_slice_mut_slice_append_bytes
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_cstr
+#define ZZ_FORWARD_slice_mut_slice_append_cstr
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_slice
+#define ZZ_FORWARD_slice_mut_slice_append_slice
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_borrow
+#define ZZ_FORWARD_slice_mut_slice_borrow
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_make
+#define ZZ_FORWARD_slice_mut_slice_make
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_mem
+#define ZZ_FORWARD_slice_mut_slice_mem
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push
+#define ZZ_FORWARD_slice_mut_slice_push
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push16
+#define ZZ_FORWARD_slice_mut_slice_push16
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push32
+#define ZZ_FORWARD_slice_mut_slice_push32
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push64
+#define ZZ_FORWARD_slice_mut_slice_push64
+#endif
+#ifndef ZZ_FORWARD_slice_slice_borrow
+#define ZZ_FORWARD_slice_slice_borrow
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq
+#define ZZ_FORWARD_slice_slice_eq
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq_bytes
+#define ZZ_FORWARD_slice_slice_eq_bytes
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq_cstr
+#define ZZ_FORWARD_slice_slice_eq_cstr
+#endif
+#ifndef ZZ_FORWARD_slice_slice_make
+#define ZZ_FORWARD_slice_slice_make
+#endif
+#ifndef ZZ_FORWARD_string_append
+#define ZZ_FORWARD_string_append
+#endif
+#ifndef ZZ_FORWARD_string_append_bytes
+#define ZZ_FORWARD_string_append_bytes
+#endif
+#ifndef ZZ_FORWARD_string_append_cstr
+#define ZZ_FORWARD_string_append_cstr
+#endif
+#ifndef ZZ_FORWARD_string_append_slice
+#define ZZ_FORWARD_string_append_slice
+#endif
+#ifndef ZZ_FORWARD_string_as_mut_slice
+#define ZZ_FORWARD_string_as_mut_slice
+#endif
+#ifndef ZZ_FORWARD_string_clear
+#define ZZ_FORWARD_string_clear
+#endif
+#ifndef ZZ_FORWARD_string_cstr
+#define ZZ_FORWARD_string_cstr
+#endif
+#ifndef ZZ_FORWARD_string_ends_with_cstr
+#define ZZ_FORWARD_string_ends_with_cstr
+#endif
+#ifndef ZZ_FORWARD_string_eq_cstr
+#define ZZ_FORWARD_string_eq_cstr
+#endif
+#ifndef ZZ_FORWARD_string_fgets
+#define ZZ_FORWARD_string_fgets
+#endif
+#ifndef ZZ_FORWARD_string_format
+#define ZZ_FORWARD_string_format
+#endif
+#ifndef ZZ_FORWARD_string_from
+#define ZZ_FORWARD_string_from
+#endif
+#ifndef ZZ_FORWARD_string_from_cstr
+#define ZZ_FORWARD_string_from_cstr
+#endif
+#ifndef ZZ_FORWARD_string_from_slice
+#define ZZ_FORWARD_string_from_slice
+#endif
+#ifndef ZZ_FORWARD_string_make
+#define ZZ_FORWARD_string_make
+#endif
+#ifndef ZZ_FORWARD_string_pop
+#define ZZ_FORWARD_string_pop
+#endif
+#ifndef ZZ_FORWARD_string_push
+#define ZZ_FORWARD_string_push
+#endif
+#ifndef ZZ_FORWARD_string_slen
+#define ZZ_FORWARD_string_slen
+#endif
+#ifndef ZZ_FORWARD_string_slice
+#define ZZ_FORWARD_string_slice
+#endif
+#ifndef ZZ_FORWARD_string_space
+#define ZZ_FORWARD_string_space
+#endif
+#ifndef ZZ_FORWARD_string_split
+#define ZZ_FORWARD_string_split
+#endif
+#ifndef ZZ_FORWARD_string_starts_with_cstr
+#define ZZ_FORWARD_string_starts_with_cstr
+#endif
+#ifndef ZZ_FORWARD_string_substr
+#define ZZ_FORWARD_string_substr
+#endif
+#ifndef ZZ_FORWARD__stdarg_h_
+#define ZZ_FORWARD__stdarg_h_
+#endif
+#ifndef ZZ_FORWARD_string_vformat
+#define ZZ_FORWARD_string_vformat
+#endif
+#ifndef ZZ_EXPORT_ast_Expression
+#define ZZ_EXPORT_ast_Expression
+
+#line 118 "$ZZ/modules/ast/src/lib.zz"
+typedef enum {
+    ast_Expression_Invalid = 0,
+    ast_Expression_Literal = 1,
+    ast_Expression_LiteralString = 2,
+
+} ast_Expression;
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT_ast_ExpressionValue
+#define ZZ_EXPORT_ast_ExpressionValue
+
+#line 125 "$ZZ/modules/ast/src/lib.zz"
+union ast_ExpressionValue_t {
+
+#line 126 "$ZZ/modules/ast/src/lib.zz"
+   char const *  string ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_ast_ExpressionS
+#define ZZ_EXPORT_ast_ExpressionS
+
+#line 129 "$ZZ/modules/ast/src/lib.zz"
+struct ast_ExpressionS_t {
+
+#line 131 "$ZZ/modules/ast/src/lib.zz"
+   ast_Expression t ;
+
+#line 132 "$ZZ/modules/ast/src/lib.zz"
+   ast_ExpressionValue v ;
+
+#line 133 "$ZZ/modules/ast/src/lib.zz"
+   pool_Pool*  pool ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_pool_Pool
+#define ZZ_EXPORT_pool_Pool
+
+#line 21 "$ZZ/modules/pool/src/lib.zz"
+struct pool_Pool_t {
+
+#line 22 "$ZZ/modules/pool/src/lib.zz"
+   uintptr_t blocksize ;
+
+#line 23 "$ZZ/modules/pool/src/lib.zz"
+   uintptr_t poolsize ;
+
+#line 25 "$ZZ/modules/pool/src/lib.zz"
+   uint8_t*  used ;
+
+#line 26 "$ZZ/modules/pool/src/lib.zz"
+   uint8_t*  pool ;
+
+#line 29 "$ZZ/modules/pool/src/lib.zz"
+   uint8_t mem[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_ast_Ast
+#define ZZ_EXPORT_ast_Ast
+
+#line 136 "$ZZ/modules/ast/src/lib.zz"
+struct ast_Ast_t {
+
+#line 138 "$ZZ/modules/ast/src/lib.zz"
+   ast_ExpressionS args[    100] ;
+
+#line 139 "$ZZ/modules/ast/src/lib.zz"
+   pool_Pool pool ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_ast_Ast_1000_1000
+#define ZZ_EXPORT_ast_Ast_1000_1000
+
+#line 136 "$ZZ/modules/ast/src/lib.zz"
+struct ast_Ast_1000_t {
+
+#line 138 "$ZZ/modules/ast/src/lib.zz"
+   ast_ExpressionS args[    100] ;
+
+#line 139 "$ZZ/modules/ast/src/lib.zz"
+   pool_Pool pool ;
+   uint8_t _____tail [1000];
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_ast_from_macro
+#define ZZ_EXPORT_ast_from_macro
+
+#line 142 "$ZZ/modules/ast/src/lib.zz"
+extern void ast_from_macro (ast_Ast*  const  self, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#endif
+#ifndef ZZ_EXPORT_string_String
+#define ZZ_EXPORT_string_String
+
+#line 8 "$ZZ/modules/string/src/lib.zz"
+struct string_String_t {
+
+#line 9 "$ZZ/modules/string/src/lib.zz"
+   uintptr_t len ;
+
+#line 10 "$ZZ/modules/string/src/lib.zz"
+   char mem[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_err_Err
+#define ZZ_EXPORT_err_Err
+
+#line 18 "$ZZ/modules/err/src/lib.zz"
+struct err_Err_t {
+
+#line 19 "$ZZ/modules/err/src/lib.zz"
+   uintptr_t error ;
+
+#line 20 "$ZZ/modules/err/src/lib.zz"
+   int system ;
+
+#line 21 "$ZZ/modules/err/src/lib.zz"
+   string_String trace ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_err_checked
+#define ZZ_EXPORT_err_checked
+
+#endif
+#ifndef ZZ_EXPORT_err_abort
+#define ZZ_EXPORT_err_abort
+
+#line 122 "$ZZ/modules/err/src/lib.zz"
+void err_abort (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line);
+
+#endif
+#ifndef ZZ_EXPORT_err_assert2
+#define ZZ_EXPORT_err_assert2
+
+#line 199 "$ZZ/modules/err/src/lib.zz"
+void err_assert2 (bool const  a, char const *  const  file, char const *  const  scope, uintptr_t const  line, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_err_backtrace
+#define ZZ_EXPORT_err_backtrace
+
+#line 52 "$ZZ/modules/err/src/lib.zz"
+void err_backtrace (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line);
+
+#endif
+#ifndef ZZ_EXPORT_err_check
+#define ZZ_EXPORT_err_check
+
+#line 36 "$ZZ/modules/err/src/lib.zz"
+bool err_check (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line);
+
+#endif
+#ifndef ZZ_EXPORT_err_elog
+#define ZZ_EXPORT_err_elog
+
+#line 141 "$ZZ/modules/err/src/lib.zz"
+void err_elog (err_Err*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 6 "$ZZ/modules/ast/src/lib.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_err_eprintf
+#define ZZ_EXPORT_err_eprintf
+
+#line 146 "$ZZ/modules/err/src/lib.zz"
+void err_eprintf (err_Err*  const  self, uintptr_t const  tail, FILE*  const  out);
+
+#endif
+#ifndef ZZ_EXPORT_err_fail
+#define ZZ_EXPORT_err_fail
+
+#line 87 "$ZZ/modules/err/src/lib.zz"
+void err_fail (err_Err*  const  self, uintptr_t const  tail, uintptr_t const  e, char const *  const  file, char const *  const  scope, uintptr_t const  line, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_err_fail_with_errno
+#define ZZ_EXPORT_err_fail_with_errno
+
+#line 58 "$ZZ/modules/err/src/lib.zz"
+void err_fail_with_errno (err_Err*  const  self, uintptr_t const  tail, char const *  const  file, char const *  const  scope, uintptr_t const  line, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_err_make
+#define ZZ_EXPORT_err_make
+
+#line 25 "$ZZ/modules/err/src/lib.zz"
+void err_make (err_Err*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_err_to_str
+#define ZZ_EXPORT_err_to_str
+
+#line 151 "$ZZ/modules/err/src/lib.zz"
+void err_to_str (err_Err const *  const  self, char*  const  dest, uintptr_t const  dest_len);
+
+#endif
+#ifndef ZZ_EXPORT__stdarg_h_
+#define ZZ_EXPORT__stdarg_h_
+
+#line 5 "$ZZ/modules/err/src/lib.zz"
+#include <stdarg.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdlib_h_
+#define ZZ_EXPORT__stdlib_h_
+
+#line 4 "$ZZ/modules/err/src/lib.zz"
+#include <stdlib.h>
+
+#endif
+#ifndef ZZ_EXPORT_json_MAX_DEPTH
+#define ZZ_EXPORT_json_MAX_DEPTH
+
+#line 8 "$ZZ/modules/json/src/lib.zz"
+#define json_MAX_DEPTH ((uintptr_t )    64)
+
+#endif
+#ifndef ZZ_EXPORT_json_ParserState
+#define ZZ_EXPORT_json_ParserState
+
+#line 28 "$ZZ/modules/json/src/lib.zz"
+typedef enum {
+    json_ParserState_Document = 0,
+    json_ParserState_Object = 1,
+    json_ParserState_Key = 2,
+    json_ParserState_PostKey = 3,
+    json_ParserState_PreVal = 4,
+    json_ParserState_StringVal = 5,
+    json_ParserState_IntVal = 6,
+    json_ParserState_PostVal = 7,
+
+} json_ParserState;
+
+#endif
+#ifndef ZZ_EXPORT_json_ValueType
+#define ZZ_EXPORT_json_ValueType
+
+#line 14 "$ZZ/modules/json/src/lib.zz"
+typedef enum {
+    json_ValueType_String = 0,
+    json_ValueType_Object = 1,
+    json_ValueType_Integer = 2,
+    json_ValueType_Array = 3,
+
+} json_ValueType;
+
+#endif
+#ifndef ZZ_EXPORT_json_Value
+#define ZZ_EXPORT_json_Value
+
+#line 21 "$ZZ/modules/json/src/lib.zz"
+struct json_Value_t {
+
+#line 22 "$ZZ/modules/json/src/lib.zz"
+   json_ValueType t ;
+
+#line 23 "$ZZ/modules/json/src/lib.zz"
+   char const *  string ;
+
+#line 24 "$ZZ/modules/json/src/lib.zz"
+   int integer ;
+
+#line 25 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t index ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_json_deserialize_t
+#define ZZ_EXPORT_json_deserialize_t
+
+#line 10 "$ZZ/modules/json/src/lib.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_json_deserialize_t)
+#define ZZ_HAS_DEFINED_CLOSURE_json_deserialize_t 1
+typedef struct {
+    void *ctx;
+    void (*fn) (err_Err*  const  e, uintptr_t const  et, json_Parser*  const  p, uintptr_t const  pt, void*  const  user, char const *  const  k, json_Value const  v, void * _ctx);
+} json_deserialize_t;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_json_deserialize_t)
+
+#endif
+#ifndef ZZ_EXPORT_json_ParserStack
+#define ZZ_EXPORT_json_ParserStack
+
+#line 39 "$ZZ/modules/json/src/lib.zz"
+struct json_ParserStack_t {
+
+#line 40 "$ZZ/modules/json/src/lib.zz"
+   json_ParserState state ;
+
+#line 41 "$ZZ/modules/json/src/lib.zz"
+   json_deserialize_t de ;
+
+#line 42 "$ZZ/modules/json/src/lib.zz"
+   void*  user ;
+
+#line 43 "$ZZ/modules/json/src/lib.zz"
+   bool in_array ;
+
+#line 44 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t index ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_json_Parser
+#define ZZ_EXPORT_json_Parser
+
+#line 47 "$ZZ/modules/json/src/lib.zz"
+struct json_Parser_t {
+
+#line 48 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t col ;
+
+#line 49 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t line ;
+
+#line 50 "$ZZ/modules/json/src/lib.zz"
+   bool esc ;
+
+#line 52 "$ZZ/modules/json/src/lib.zz"
+   json_ParserStack state[    64] ;
+
+#line 53 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t depth ;
+
+#line 55 "$ZZ/modules/json/src/lib.zz"
+   uintptr_t keylen ;
+
+#line 56 "$ZZ/modules/json/src/lib.zz"
+   string_String capture ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_json_advance
+#define ZZ_EXPORT_json_advance
+
+#line 203 "$ZZ/modules/json/src/lib.zz"
+static void json_advance (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, char const  token);
+
+#endif
+#ifndef ZZ_EXPORT_json_next
+#define ZZ_EXPORT_json_next
+
+#line 74 "$ZZ/modules/json/src/lib.zz"
+void json_next (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, json_deserialize_t const  de, void*  const  user);
+
+#endif
+#ifndef ZZ_EXPORT_json_parser
+#define ZZ_EXPORT_json_parser
+
+#line 59 "$ZZ/modules/json/src/lib.zz"
+void json_parser (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, json_deserialize_t const  de, void*  const  user);
+
+#endif
+#ifndef ZZ_EXPORT_json_push
+#define ZZ_EXPORT_json_push
+
+#line 95 "$ZZ/modules/json/src/lib.zz"
+void json_push (json_Parser*  const  self, uintptr_t const  tail, err_Err*  const  e, uintptr_t const  et, char const *  const  str, uintptr_t const  strlen);
+
+#endif
+#ifndef ZZ_EXPORT_macro_expr_main_repeat_main
+#define ZZ_EXPORT_macro_expr_main_repeat_main
+
+#line 9 "./src/main.zz"
+int macro_expr_main_repeat_main ();
+
+#endif
+#ifndef ZZ_EXPORT_pool_continuous
+#define ZZ_EXPORT_pool_continuous
+
+#endif
+#ifndef ZZ_EXPORT_pool_member
+#define ZZ_EXPORT_pool_member
+
+#endif
+#ifndef ZZ_EXPORT_pool_alloc
+#define ZZ_EXPORT_pool_alloc
+
+#line 105 "$ZZ/modules/pool/src/lib.zz"
+void * pool_alloc (pool_Pool*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_pool_iterator
+#define ZZ_EXPORT_pool_iterator
+
+#line 267 "$ZZ/modules/pool/src/lib.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_pool_iterator)
+#define ZZ_HAS_DEFINED_CLOSURE_pool_iterator 1
+typedef struct {
+    void *ctx;
+    void (*fn) (pool_Pool*  const  self, void*  const  block, void*  const  user, void * _ctx);
+} pool_iterator;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_pool_iterator)
+
+#endif
+#ifndef ZZ_EXPORT_pool_each
+#define ZZ_EXPORT_pool_each
+
+#line 275 "$ZZ/modules/pool/src/lib.zz"
+void pool_each (pool_Pool*  const  self, pool_iterator const  it, void*  const  user);
+
+#endif
+#ifndef ZZ_EXPORT_pool_free
+#define ZZ_EXPORT_pool_free
+
+#line 206 "$ZZ/modules/pool/src/lib.zz"
+void pool_free (pool_Pool*  const  self, void const *  ptr_);
+
+#endif
+#ifndef ZZ_EXPORT_pool_free_bytes
+#define ZZ_EXPORT_pool_free_bytes
+
+#line 73 "$ZZ/modules/pool/src/lib.zz"
+uintptr_t pool_free_bytes (pool_Pool const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_pool_make
+#define ZZ_EXPORT_pool_make
+
+#line 38 "$ZZ/modules/pool/src/lib.zz"
+void pool_make (pool_Pool*  const  self, uintptr_t const  pt, uintptr_t blocksize);
+
+#endif
+#ifndef ZZ_EXPORT_pool_malloc
+#define ZZ_EXPORT_pool_malloc
+
+#line 122 "$ZZ/modules/pool/src/lib.zz"
+void * pool_malloc (pool_Pool*  const  self, uintptr_t size);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_Slice
+#define ZZ_EXPORT_slice_slice_Slice
+
+#line 4 "$ZZ/modules/slice/src/slice.zz"
+struct slice_slice_Slice_t {
+
+#line 5 "$ZZ/modules/slice/src/slice.zz"
+   uint8_t const *  mem ;
+
+#line 6 "$ZZ/modules/slice/src/slice.zz"
+   uintptr_t size ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_MutSlice
+#define ZZ_EXPORT_slice_mut_slice_MutSlice
+
+#line 4 "$ZZ/modules/slice/src/mut_slice.zz"
+struct slice_mut_slice_MutSlice_t {
+
+#line 5 "$ZZ/modules/slice/src/mut_slice.zz"
+   slice_slice_Slice slice ;
+
+#line 6 "$ZZ/modules/slice/src/mut_slice.zz"
+   uintptr_t at ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_bytes
+#define ZZ_EXPORT_slice_mut_slice_append_bytes
+
+#line 62 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_bytes (slice_mut_slice_MutSlice*  const  self, uint8_t const *  const  b, uintptr_t const  l);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_cstr
+#define ZZ_EXPORT_slice_mut_slice_append_cstr
+
+#line 76 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_cstr (slice_mut_slice_MutSlice*  const  self, char const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_slice
+#define ZZ_EXPORT_slice_mut_slice_append_slice
+
+#line 50 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_slice (slice_mut_slice_MutSlice*  const  self, slice_slice_Slice const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_borrow
+#define ZZ_EXPORT_slice_mut_slice_borrow
+
+#line 9 "$ZZ/modules/slice/src/mut_slice.zz"
+slice_mut_slice_MutSlice  const * slice_mut_slice_borrow (slice_mut_slice_MutSlice*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_make
+#define ZZ_EXPORT_slice_mut_slice_make
+
+#line 24 "$ZZ/modules/slice/src/mut_slice.zz"
+void slice_mut_slice_make (slice_mut_slice_MutSlice*  const  self, uint8_t*  const  mem, uintptr_t const  size);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_mem
+#define ZZ_EXPORT_slice_mut_slice_mem
+
+#line 38 "$ZZ/modules/slice/src/mut_slice.zz"
+uint8_t * slice_mut_slice_mem (slice_mut_slice_MutSlice*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push
+#define ZZ_EXPORT_slice_mut_slice_push
+
+#line 85 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push (slice_mut_slice_MutSlice*  const  self, uint8_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push16
+#define ZZ_EXPORT_slice_mut_slice_push16
+
+#line 100 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push16 (slice_mut_slice_MutSlice*  const  self, uint16_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push32
+#define ZZ_EXPORT_slice_mut_slice_push32
+
+#line 115 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push32 (slice_mut_slice_MutSlice*  const  self, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push64
+#define ZZ_EXPORT_slice_mut_slice_push64
+
+#line 130 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push64 (slice_mut_slice_MutSlice*  const  self, uint64_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_borrow
+#define ZZ_EXPORT_slice_slice_borrow
+
+#line 9 "$ZZ/modules/slice/src/slice.zz"
+slice_slice_Slice  const * slice_slice_borrow (slice_slice_Slice const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq
+#define ZZ_EXPORT_slice_slice_eq
+
+#line 17 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq (slice_slice_Slice const *  const  self, slice_slice_Slice const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq_bytes
+#define ZZ_EXPORT_slice_slice_eq_bytes
+
+#line 33 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq_bytes (slice_slice_Slice const *  const  self, uint8_t const *  const  other, uintptr_t const  othersize);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq_cstr
+#define ZZ_EXPORT_slice_slice_eq_cstr
+
+#line 25 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq_cstr (slice_slice_Slice const *  const  self, char const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_make
+#define ZZ_EXPORT_slice_slice_make
+
+#line 42 "$ZZ/modules/slice/src/slice.zz"
+void slice_slice_make (slice_slice_Slice*  const  self, uint8_t const *  const  mem, uintptr_t const  size);
+
+#endif
+#ifndef ZZ_EXPORT_string_append
+#define ZZ_EXPORT_string_append
+
+#line 184 "$ZZ/modules/string/src/lib.zz"
+void string_append (string_String*  const  self, uintptr_t const  t, string_String const *  const  other, uintptr_t const  t2);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_bytes
+#define ZZ_EXPORT_string_append_bytes
+
+#line 204 "$ZZ/modules/string/src/lib.zz"
+void string_append_bytes (string_String*  const  self, uintptr_t const  t, uint8_t const *  const  bytes, uintptr_t inlen);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_cstr
+#define ZZ_EXPORT_string_append_cstr
+
+#line 163 "$ZZ/modules/string/src/lib.zz"
+void string_append_cstr (string_String*  const  self, uintptr_t const  t, char const *  const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_slice
+#define ZZ_EXPORT_string_append_slice
+
+#line 222 "$ZZ/modules/string/src/lib.zz"
+void string_append_slice (string_String*  const  self, uintptr_t const  t, slice_slice_Slice const *  const  slice);
+
+#endif
+#ifndef ZZ_EXPORT_string_as_mut_slice
+#define ZZ_EXPORT_string_as_mut_slice
+
+#line 55 "$ZZ/modules/string/src/lib.zz"
+slice_mut_slice_MutSlice string_as_mut_slice (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_clear
+#define ZZ_EXPORT_string_clear
+
+#line 115 "$ZZ/modules/string/src/lib.zz"
+void string_clear (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_cstr
+#define ZZ_EXPORT_string_cstr
+
+#line 34 "$ZZ/modules/string/src/lib.zz"
+char  const * string_cstr (string_String const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_string_ends_with_cstr
+#define ZZ_EXPORT_string_ends_with_cstr
+
+#line 333 "$ZZ/modules/string/src/lib.zz"
+bool string_ends_with_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_string_eq_cstr
+#define ZZ_EXPORT_string_eq_cstr
+
+#line 283 "$ZZ/modules/string/src/lib.zz"
+bool string_eq_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_string_fgets
+#define ZZ_EXPORT_string_fgets
+
+#line 352 "$ZZ/modules/string/src/lib.zz"
+bool string_fgets (string_String*  const  self, uintptr_t const  tail, FILE*  const  stream);
+
+#endif
+#ifndef ZZ_EXPORT_string_format
+#define ZZ_EXPORT_string_format
+
+#line 240 "$ZZ/modules/string/src/lib.zz"
+int string_format (string_String*  const  self, uintptr_t const  tail, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_string_from
+#define ZZ_EXPORT_string_from
+
+#line 81 "$ZZ/modules/string/src/lib.zz"
+void string_from (string_String*  const  self, uintptr_t const  t, uint8_t const *  const  bytes, uintptr_t inlen);
+
+#endif
+#ifndef ZZ_EXPORT_string_from_cstr
+#define ZZ_EXPORT_string_from_cstr
+
+#line 105 "$ZZ/modules/string/src/lib.zz"
+void string_from_cstr (string_String*  const  self, uintptr_t const  t, char const *  const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_from_slice
+#define ZZ_EXPORT_string_from_slice
+
+#line 91 "$ZZ/modules/string/src/lib.zz"
+void string_from_slice (string_String*  const  self, uintptr_t const  t, slice_slice_Slice const *  const  slice);
+
+#endif
+#ifndef ZZ_EXPORT_string_make
+#define ZZ_EXPORT_string_make
+
+#line 73 "$ZZ/modules/string/src/lib.zz"
+void string_make (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_pop
+#define ZZ_EXPORT_string_pop
+
+#line 149 "$ZZ/modules/string/src/lib.zz"
+bool string_pop (string_String*  const  self, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT_string_push
+#define ZZ_EXPORT_string_push
+
+#line 126 "$ZZ/modules/string/src/lib.zz"
+bool string_push (string_String*  const  self, uintptr_t const  t, char const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_slen
+#define ZZ_EXPORT_string_slen
+
+#line 29 "$ZZ/modules/string/src/lib.zz"
+uintptr_t string_slen (string_String const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_string_slice
+#define ZZ_EXPORT_string_slice
+
+#line 43 "$ZZ/modules/string/src/lib.zz"
+slice_slice_Slice string_slice (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_space
+#define ZZ_EXPORT_string_space
+
+#line 448 "$ZZ/modules/string/src/lib.zz"
+uintptr_t string_space (string_String const *  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_split
+#define ZZ_EXPORT_string_split
+
+#line 417 "$ZZ/modules/string/src/lib.zz"
+bool string_split (string_String const *  const  self, uintptr_t const  tail, char const  token, uintptr_t*  const  iterator, string_String*  const  other, uintptr_t const  tail2);
+
+#endif
+#ifndef ZZ_EXPORT_string_starts_with_cstr
+#define ZZ_EXPORT_string_starts_with_cstr
+
+#line 317 "$ZZ/modules/string/src/lib.zz"
+bool string_starts_with_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_string_substr
+#define ZZ_EXPORT_string_substr
+
+#line 369 "$ZZ/modules/string/src/lib.zz"
+void string_substr (string_String const *  const  self, uintptr_t const  tail, uintptr_t const  from, uintptr_t size, string_String*  const  other, uintptr_t const  tail2);
+
+#endif
+#ifndef ZZ_EXPORT__stdarg_h_
+#define ZZ_EXPORT__stdarg_h_
+
+#endif
+#ifndef ZZ_EXPORT_string_vformat
+#define ZZ_EXPORT_string_vformat
+
+#line 252 "$ZZ/modules/string/src/lib.zz"
+int string_vformat (string_String*  const  self, uintptr_t const  tail, char const *  const  fmt, va_list args);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_macro_expr_main_repeat_main
+#define ZZ_IMPL_macro_expr_main_repeat_main
+
+#line 9 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+  ast_Ast_1000 a  = {    0,};
+    ast_from_macro(    (ast_Ast* )(( &    a)),    1000    );
+
+#line 12 "./src/main.zz"
+    err_assert2((    a .args [     0] .t ==    ast_Expression_LiteralString  ),
+#line 201 "$ZZ/modules/err/src/lib.zz"
+    "./src/main.zz",
+#line 202 "$ZZ/modules/err/src/lib.zz"
+    "::macro_expr::main_repeat::main",
+#line 203 "$ZZ/modules/err/src/lib.zz"
+    12,
+#line 12 "./src/main.zz"
+    "expected arg0: string"    );
+
+#line 13 "./src/main.zz"
+    err_assert2((    a .args [     1] .t ==    ast_Expression_Literal  ),
+#line 201 "$ZZ/modules/err/src/lib.zz"
+    "./src/main.zz",
+#line 202 "$ZZ/modules/err/src/lib.zz"
+    "::macro_expr::main_repeat::main",
+#line 203 "$ZZ/modules/err/src/lib.zz"
+    13,
+#line 13 "./src/main.zz"
+    "expected arg1: number"    );
+
+#line 14 "./src/main.zz"
+  int const  num  =     (int)(    atoi(    a .args [     1] .v .string    ));
+
+#line 16 "./src/main.zz"
+    printf(    "\""    );
+  for (
+
+#line 17 "./src/main.zz"
+  int i  =     0;(    i <    num  );
+(    i ++)){
+
+#line 18 "./src/main.zz"
+    printf(    "%s",    a .args [     0] .v .string    );
+
+}
+
+
+#line 20 "./src/main.zz"
+    printf(    "\""    );
+
+#line 1 ""
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
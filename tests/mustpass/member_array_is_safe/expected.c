@@ -0,0 +1,96 @@
+/* member_array_is_safe_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_member_array_is_safe_main_A
+#define ZZ_FORWARD_member_array_is_safe_main_A
+
+#line 4 "./src/main.zz"
+struct member_array_is_safe_main_A_t;
+typedef struct member_array_is_safe_main_A_t member_array_is_safe_main_A;
+#endif
+#ifndef ZZ_FORWARD_member_array_is_safe_main_bob
+#define ZZ_FORWARD_member_array_is_safe_main_bob
+#endif
+#ifndef ZZ_FORWARD_member_array_is_safe_main_main
+#define ZZ_FORWARD_member_array_is_safe_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_member_array_is_safe_main_A
+#define ZZ_EXPORT_member_array_is_safe_main_A
+
+#line 4 "./src/main.zz"
+struct member_array_is_safe_main_A_t {
+
+#line 5 "./src/main.zz"
+   int a[    100] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_member_array_is_safe_main_bob
+#define ZZ_EXPORT_member_array_is_safe_main_bob
+
+#line 10 "./src/main.zz"
+static void member_array_is_safe_main_bob (int const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_member_array_is_safe_main_main
+#define ZZ_EXPORT_member_array_is_safe_main_main
+
+#line 13 "./src/main.zz"
+int member_array_is_safe_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_member_array_is_safe_main_bob
+#define ZZ_IMPL_member_array_is_safe_main_bob
+
+#line 10 "./src/main.zz"
+static void member_array_is_safe_main_bob (int const *  const  a)
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_member_array_is_safe_main_main
+#define ZZ_IMPL_member_array_is_safe_main_main
+
+#line 13 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 15 "./src/main.zz"
+  member_array_is_safe_main_A const  a ;
+
+#line 16 "./src/main.zz"
+    member_array_is_safe_main_bob(    a .a    );
+
+#line 18 "./src/main.zz"
+    printf(    "hello member_array_is_safe\n"    );
+
+#line 19 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
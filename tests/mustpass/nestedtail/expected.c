@@ -0,0 +1,189 @@
+/* nestedtail_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD__string_h_
+#define ZZ_FORWARD__string_h_
+#endif
+#ifndef ZZ_FORWARD_nestedtail_main_A
+#define ZZ_FORWARD_nestedtail_main_A
+
+#line 6 "./src/main.zz"
+struct nestedtail_main_A_t;
+typedef struct nestedtail_main_A_t nestedtail_main_A;
+#endif
+#ifndef ZZ_FORWARD_nestedtail_main_B
+#define ZZ_FORWARD_nestedtail_main_B
+
+#line 12 "./src/main.zz"
+struct nestedtail_main_B_t;
+typedef struct nestedtail_main_B_t nestedtail_main_B;
+#endif
+#ifndef ZZ_FORWARD_nestedtail_main_B_100
+#define ZZ_FORWARD_nestedtail_main_B_100
+struct nestedtail_main_B_100_t;
+typedef struct nestedtail_main_B_100_t nestedtail_main_B_100;
+#endif
+#ifndef ZZ_FORWARD_nestedtail_main_gimme_a
+#define ZZ_FORWARD_nestedtail_main_gimme_a
+#endif
+#ifndef ZZ_FORWARD_nestedtail_main_gimme_b
+#define ZZ_FORWARD_nestedtail_main_gimme_b
+#endif
+#ifndef ZZ_FORWARD_nestedtail_main_main
+#define ZZ_FORWARD_nestedtail_main_main
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT__string_h_
+#define ZZ_EXPORT__string_h_
+
+#line 2 "./src/main.zz"
+#include <string.h>
+
+#endif
+#ifndef ZZ_EXPORT_nestedtail_main_A
+#define ZZ_EXPORT_nestedtail_main_A
+
+#line 6 "./src/main.zz"
+struct nestedtail_main_A_t {
+
+#line 7 "./src/main.zz"
+   uint8_t nothing ;
+
+#line 8 "./src/main.zz"
+   uint8_t a[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_nestedtail_main_B
+#define ZZ_EXPORT_nestedtail_main_B
+
+#line 12 "./src/main.zz"
+struct nestedtail_main_B_t {
+
+#line 13 "./src/main.zz"
+   nestedtail_main_A a ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_nestedtail_main_B_100_100
+#define ZZ_EXPORT_nestedtail_main_B_100_100
+
+#line 12 "./src/main.zz"
+struct nestedtail_main_B_100_t {
+
+#line 13 "./src/main.zz"
+   nestedtail_main_A a ;
+   uint8_t _____tail [100];
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_nestedtail_main_gimme_a
+#define ZZ_EXPORT_nestedtail_main_gimme_a
+
+#line 18 "./src/main.zz"
+static uint8_t nestedtail_main_gimme_a (nestedtail_main_A const *  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_nestedtail_main_gimme_b
+#define ZZ_EXPORT_nestedtail_main_gimme_b
+
+#line 24 "./src/main.zz"
+static uint8_t nestedtail_main_gimme_b (nestedtail_main_B const *  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_nestedtail_main_main
+#define ZZ_EXPORT_nestedtail_main_main
+
+#line 30 "./src/main.zz"
+int nestedtail_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_nestedtail_main_gimme_a
+#define ZZ_IMPL_nestedtail_main_gimme_a
+
+#line 18 "./src/main.zz"
+static uint8_t nestedtail_main_gimme_a (nestedtail_main_A const *  const  self, uintptr_t const  tail)
+#if 0
+#else
+{
+
+#line 21 "./src/main.zz"
+  return     self ->a [     99];
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_nestedtail_main_gimme_b
+#define ZZ_IMPL_nestedtail_main_gimme_b
+
+#line 24 "./src/main.zz"
+static uint8_t nestedtail_main_gimme_b (nestedtail_main_B const *  const  self, uintptr_t const  tail)
+#if 0
+#else
+{
+
+#line 27 "./src/main.zz"
+  return     nestedtail_main_gimme_a(    (nestedtail_main_A const * )(( &    self ->a)),
+#line 24 "./src/main.zz"
+    tail    );
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_nestedtail_main_main
+#define ZZ_IMPL_nestedtail_main_main
+
+#line 30 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 31 "./src/main.zz"
+  nestedtail_main_B_100 b ;
+
+#line 32 "./src/main.zz"
+    memset(( &    b),    12,    sizeof(    b    )    );
+
+#line 34 "./src/main.zz"
+    printf(    "hello nestedtail %zu %u\n",    sizeof(    b    ),    nestedtail_main_gimme_b(    (nestedtail_main_B* )(( &    b)),
+#line 31 "./src/main.zz"
+    100    )    );
+
+#line 35 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
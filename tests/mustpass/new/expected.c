@@ -0,0 +1,249 @@
+/* new_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_new_main_A
+#define ZZ_FORWARD_new_main_A
+
+#line 4 "./src/main.zz"
+struct new_main_A_t;
+typedef struct new_main_A_t new_main_A;
+#endif
+#ifndef ZZ_FORWARD_new_main_B
+#define ZZ_FORWARD_new_main_B
+
+#line 9 "./src/main.zz"
+struct new_main_B_t;
+typedef struct new_main_B_t new_main_B;
+#endif
+#ifndef ZZ_FORWARD_new_main_B_100
+#define ZZ_FORWARD_new_main_B_100
+struct new_main_B_100_t;
+typedef struct new_main_B_100_t new_main_B_100;
+#endif
+#ifndef ZZ_FORWARD_new_main_borrow
+#define ZZ_FORWARD_new_main_borrow
+#endif
+#ifndef ZZ_FORWARD_new_main_get
+#define ZZ_FORWARD_new_main_get
+#endif
+#ifndef ZZ_FORWARD_new_main_null
+#define ZZ_FORWARD_new_main_null
+#endif
+#ifndef ZZ_FORWARD_new_main_none
+#define ZZ_FORWARD_new_main_none
+#endif
+#ifndef ZZ_FORWARD_new_main_main
+#define ZZ_FORWARD_new_main_main
+#endif
+#ifndef ZZ_FORWARD_new_main_none
+#define ZZ_FORWARD_new_main_none
+#endif
+#ifndef ZZ_FORWARD_new_main_null
+#define ZZ_FORWARD_new_main_null
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_new_main_A
+#define ZZ_EXPORT_new_main_A
+
+#line 4 "./src/main.zz"
+struct new_main_A_t {
+
+#line 6 "./src/main.zz"
+   int f ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_new_main_B
+#define ZZ_EXPORT_new_main_B
+
+#line 9 "./src/main.zz"
+struct new_main_B_t {
+
+#line 11 "./src/main.zz"
+   new_main_A a ;
+
+#line 12 "./src/main.zz"
+   int b[] ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_new_main_B_100_100
+#define ZZ_EXPORT_new_main_B_100_100
+
+#line 9 "./src/main.zz"
+struct new_main_B_100_t {
+
+#line 11 "./src/main.zz"
+   new_main_A a ;
+
+#line 12 "./src/main.zz"
+   int b[100] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_new_main_borrow
+#define ZZ_EXPORT_new_main_borrow
+
+#line 15 "./src/main.zz"
+static new_main_A * new_main_borrow (new_main_A*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_new_main_get
+#define ZZ_EXPORT_new_main_get
+
+#line 34 "./src/main.zz"
+int new_main_get (new_main_A*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_new_main_null
+#define ZZ_EXPORT_new_main_null
+
+#line 24 "./src/main.zz"
+void new_main_null (new_main_A*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_new_main_none
+#define ZZ_EXPORT_new_main_none
+
+#line 29 "./src/main.zz"
+void new_main_none (new_main_B*  const  self, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT_new_main_main
+#define ZZ_EXPORT_new_main_main
+
+#line 39 "./src/main.zz"
+int new_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_new_main_none
+#define ZZ_EXPORT_new_main_none
+
+#line 29 "./src/main.zz"
+void new_main_none (new_main_B*  const  self, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT_new_main_null
+#define ZZ_EXPORT_new_main_null
+
+#line 24 "./src/main.zz"
+void new_main_null (new_main_A*  const  self);
+
+#endif
+#ifndef ZZ_IMPL_new_main_borrow
+#define ZZ_IMPL_new_main_borrow
+
+#line 15 "./src/main.zz"
+static new_main_A * new_main_borrow (new_main_A*  const  self)
+#if 0
+#else
+{
+
+#line 20 "./src/main.zz"
+  return     self;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_new_main_get
+#define ZZ_IMPL_new_main_get
+
+#line 34 "./src/main.zz"
+int __attribute__ ((visibility ("default"))) new_main_get (new_main_A*  const  self)
+#if 0
+#else
+{
+
+#line 36 "./src/main.zz"
+  return     self ->f;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_new_main_main
+#define ZZ_IMPL_new_main_main
+
+#line 39 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 41 "./src/main.zz"
+  new_main_A a  = {    0,};
+    new_main_null(( &    a)    );
+
+#line 42 "./src/main.zz"
+    new_main_get(    (new_main_A* )(    new_main_borrow(( &    a)    ))    );
+
+#line 43 "./src/main.zz"
+    new_main_get(    (new_main_A* )(    new_main_borrow(( &    a)    ))    );
+
+#line 44 "./src/main.zz"
+    printf(    "hello %d\n",    a .f    );
+
+#line 47 "./src/main.zz"
+  new_main_B_100 b  = {    0,};
+    new_main_none(    (new_main_B* )(( &    b)),    100    );
+
+#line 49 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_new_main_none
+#define ZZ_IMPL_new_main_none
+
+#line 29 "./src/main.zz"
+void __attribute__ ((visibility ("default"))) new_main_none (new_main_B*  const  self, uintptr_t const  t)
+#if 0
+#else
+{
+
+#line 31 "./src/main.zz"
+    new_main_null(( &    self ->a)    );
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_new_main_null
+#define ZZ_IMPL_new_main_null
+
+#line 24 "./src/main.zz"
+void __attribute__ ((visibility ("default"))) new_main_null (new_main_A*  const  self)
+#if 0
+#else
+{
+
+#line 26 "./src/main.zz"
+    self ->f =     3;
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,278 @@
+/* nullable_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD__stdlib_h_
+#define ZZ_FORWARD__stdlib_h_
+#endif
+#ifndef ZZ_FORWARD_nullable_main_early
+#define ZZ_FORWARD_nullable_main_early
+#endif
+#ifndef ZZ_FORWARD_nullable_main_first
+#define ZZ_FORWARD_nullable_main_first
+#endif
+#ifndef ZZ_FORWARD_nullable_main_get
+#define ZZ_FORWARD_nullable_main_get
+#endif
+#ifndef ZZ_FORWARD_nullable_main_len
+#define ZZ_FORWARD_nullable_main_len
+#endif
+#ifndef ZZ_FORWARD_nullable_main_maybe
+#define ZZ_FORWARD_nullable_main_maybe
+#endif
+#ifndef ZZ_FORWARD_nullable_main_main
+#define ZZ_FORWARD_nullable_main_main
+#endif
+#ifndef ZZ_FORWARD_nullable_main_maybe
+#define ZZ_FORWARD_nullable_main_maybe
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdlib_h_
+#define ZZ_EXPORT__stdlib_h_
+
+#line 2 "./src/main.zz"
+#include <stdlib.h>
+
+#endif
+#ifndef ZZ_EXPORT_nullable_main_early
+#define ZZ_EXPORT_nullable_main_early
+
+#line 15 "./src/main.zz"
+static void nullable_main_early (int*  const  p);
+
+#endif
+#ifndef ZZ_EXPORT_nullable_main_first
+#define ZZ_EXPORT_nullable_main_first
+
+#line 23 "./src/main.zz"
+static int  const * nullable_main_first (int const *  const  a, int const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_nullable_main_get
+#define ZZ_EXPORT_nullable_main_get
+
+#line 4 "./src/main.zz"
+static int nullable_main_get (int const *  const  p);
+
+#endif
+#ifndef ZZ_EXPORT_nullable_main_len
+#define ZZ_EXPORT_nullable_main_len
+
+#line 30 "./src/main.zz"
+static uintptr_t nullable_main_len (char const *  const  s);
+
+#endif
+#ifndef ZZ_EXPORT_nullable_main_maybe
+#define ZZ_EXPORT_nullable_main_maybe
+
+#line 8 "./src/main.zz"
+static int nullable_main_maybe (int const *  const  p);
+
+#endif
+#ifndef ZZ_EXPORT_nullable_main_main
+#define ZZ_EXPORT_nullable_main_main
+
+#line 35 "./src/main.zz"
+int nullable_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_nullable_main_maybe
+#define ZZ_EXPORT_nullable_main_maybe
+
+#line 8 "./src/main.zz"
+static int nullable_main_maybe (int const *  const  p);
+
+#endif
+#ifndef ZZ_IMPL_nullable_main_early
+#define ZZ_IMPL_nullable_main_early
+
+#line 15 "./src/main.zz"
+static void nullable_main_early (int*  const  p)
+#if 0
+#else
+{
+if ((
+#line 16 "./src/main.zz"
+    p ==    0  )){
+
+#line 17 "./src/main.zz"
+  return ;
+
+}
+
+
+#line 19 "./src/main.zz"
+  int*  const  q  =     p;
+
+#line 20 "./src/main.zz"
+( *    q) =     3;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_nullable_main_first
+#define ZZ_IMPL_nullable_main_first
+
+#line 23 "./src/main.zz"
+static int  const * nullable_main_first (int const *  const  a, int const *  const  b)
+#if 0
+#else
+{
+if ((
+#line 24 "./src/main.zz"
+    a !=    0  )){
+
+#line 25 "./src/main.zz"
+  return     a;
+
+}
+
+
+#line 27 "./src/main.zz"
+  return     b;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_nullable_main_get
+#define ZZ_IMPL_nullable_main_get
+
+#line 4 "./src/main.zz"
+static int nullable_main_get (int const *  const  p)
+#if 0
+#else
+{
+
+#line 5 "./src/main.zz"
+  return ( *    p);
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_nullable_main_len
+#define ZZ_IMPL_nullable_main_len
+
+#line 30 "./src/main.zz"
+static uintptr_t nullable_main_len (char const *  const  s)
+#if 0
+#else
+{
+
+#line 31 "./src/main.zz"
+  uintptr_t l  =     0;
+
+#line 32 "./src/main.zz"
+  return     l;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_nullable_main_main
+#define ZZ_IMPL_nullable_main_main
+
+#line 35 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 36 "./src/main.zz"
+  int a  =     2;
+
+#line 37 "./src/main.zz"
+    nullable_main_early(( &    a)    );
+
+#line 38 "./src/main.zz"
+    nullable_main_early(    0    );
+
+#line 40 "./src/main.zz"
+  int const *  const  p  =     nullable_main_first(    0,( &    a)    );
+if ((
+#line 41 "./src/main.zz"
+    p !=    0  )){
+
+#line 42 "./src/main.zz"
+    nullable_main_get(    p    );
+
+}
+
+
+#line 45 "./src/main.zz"
+  char const *  const  home  =     getenv(    "HOME"    );
+if ((
+#line 46 "./src/main.zz"
+    home !=    0  )){
+
+#line 47 "./src/main.zz"
+    nullable_main_len(    home    );
+
+}
+
+
+#line 50 "./src/main.zz"
+    printf(    "hello nullable %d\n",(    nullable_main_maybe(( &    a)    ) +    nullable_main_maybe(    0    )  )    );
+
+#line 51 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_nullable_main_maybe
+#define ZZ_IMPL_nullable_main_maybe
+
+#line 8 "./src/main.zz"
+static int nullable_main_maybe (int const *  const  p)
+#if 0
+#else
+{
+if ((
+#line 9 "./src/main.zz"
+    p !=    0  )){
+
+#line 10 "./src/main.zz"
+  return (( *    p) +    nullable_main_get(    p    )  );
+
+}
+
+
+#line 12 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,86 @@
+/* nullterm_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_nullterm_main_bob
+#define ZZ_FORWARD_nullterm_main_bob
+#endif
+#ifndef ZZ_FORWARD_nullterm_main_main
+#define ZZ_FORWARD_nullterm_main_main
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT_nullterm_main_bob
+#define ZZ_EXPORT_nullterm_main_bob
+
+#line 6 "./src/main.zz"
+static void nullterm_main_bob (char const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_nullterm_main_main
+#define ZZ_EXPORT_nullterm_main_main
+
+#line 12 "./src/main.zz"
+int nullterm_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_nullterm_main_bob
+#define ZZ_IMPL_nullterm_main_bob
+
+#line 6 "./src/main.zz"
+static void nullterm_main_bob (char const *  const  a)
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_nullterm_main_main
+#define ZZ_IMPL_nullterm_main_main
+
+#line 12 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 13 "./src/main.zz"
+  char const *  const  b  =     "hello nullterm\n";
+
+#line 14 "./src/main.zz"
+    ;
+
+#line 16 "./src/main.zz"
+  char const *  const  c  =     b;
+
+#line 17 "./src/main.zz"
+    ;
+
+#line 20 "./src/main.zz"
+    nullterm_main_bob(    c    );
+
+#line 21 "./src/main.zz"
+    nullterm_main_bob(    "yoo"    );
+
+#line 23 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
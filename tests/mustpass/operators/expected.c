@@ -0,0 +1,150 @@
+/* operators_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_operators_main_above
+#define ZZ_FORWARD_operators_main_above
+#endif
+#ifndef ZZ_FORWARD_operators_main_pick
+#define ZZ_FORWARD_operators_main_pick
+#endif
+#ifndef ZZ_FORWARD_operators_main_main
+#define ZZ_FORWARD_operators_main_main
+#endif
+#ifndef ZZ_FORWARD_operators_main_pick
+#define ZZ_FORWARD_operators_main_pick
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_operators_main_above
+#define ZZ_EXPORT_operators_main_above
+
+#line 9 "./src/main.zz"
+static uint32_t operators_main_above (uint32_t const  a, uint32_t const  limit);
+
+#endif
+#ifndef ZZ_EXPORT_operators_main_pick
+#define ZZ_EXPORT_operators_main_pick
+
+#line 3 "./src/main.zz"
+static uint32_t operators_main_pick (bool const  big, uint32_t const  a, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_operators_main_main
+#define ZZ_EXPORT_operators_main_main
+
+#line 14 "./src/main.zz"
+int operators_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_operators_main_pick
+#define ZZ_EXPORT_operators_main_pick
+
+#line 3 "./src/main.zz"
+static uint32_t operators_main_pick (bool const  big, uint32_t const  a, uint32_t const  b);
+
+#endif
+#ifndef ZZ_IMPL_operators_main_above
+#define ZZ_IMPL_operators_main_above
+
+#line 9 "./src/main.zz"
+static uint32_t operators_main_above (uint32_t const  a, uint32_t const  limit)
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+  return ((    a >    limit  ) ? (    a -    limit  ) :     0  );
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_operators_main_main
+#define ZZ_IMPL_operators_main_main
+
+#line 14 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 15 "./src/main.zz"
+  uint32_t a  =     6;
+
+#line 16 "./src/main.zz"
+    a *=     7;
+
+#line 17 "./src/main.zz"
+    a /=     2;
+
+#line 18 "./src/main.zz"
+    a %=     8;
+
+#line 19 "./src/main.zz"
+    a ^=     3;
+
+#line 20 "./src/main.zz"
+    a <<=     2;
+
+#line 21 "./src/main.zz"
+    a >>=     1;
+
+#line 22 "./src/main.zz"
+    a |=     1;
+
+#line 23 "./src/main.zz"
+    a &=     13;
+
+#line 24 "./src/main.zz"
+    printf(    "a %u\n",    a    );
+
+#line 26 "./src/main.zz"
+    printf(    "above %u %u\n",    operators_main_above(    a,    10    ),    operators_main_above(    a,    20    )    );
+
+#line 27 "./src/main.zz"
+    printf(    "pick %u %u\n",    operators_main_pick(    true,    1,    2    ),    operators_main_pick(    false,    1,    2    )    );
+
+#line 29 "./src/main.zz"
+  uint32_t const  n  = ((    a ==    1  ) ?     1 : ((    a ==    13  ) ?     2 :     3  )  );
+
+#line 30 "./src/main.zz"
+    printf(    "nested %u\n",    n    );
+
+#line 31 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_operators_main_pick
+#define ZZ_IMPL_operators_main_pick
+
+#line 3 "./src/main.zz"
+static uint32_t operators_main_pick (bool const  big, uint32_t const  a, uint32_t const  b)
+#if 0
+#else
+{
+
+#line 6 "./src/main.zz"
+  return (    big ?     a :     b  );
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,83 @@
+/* packed_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__assert_h_
+#define ZZ_FORWARD__assert_h_
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD_packed_main_A
+#define ZZ_FORWARD_packed_main_A
+
+#line 4 "./src/main.zz"
+struct packed_main_A_t;
+typedef struct packed_main_A_t packed_main_A;
+#endif
+#ifndef ZZ_FORWARD_packed_main_main
+#define ZZ_FORWARD_packed_main_main
+#endif
+#ifndef ZZ_EXPORT__assert_h_
+#define ZZ_EXPORT__assert_h_
+
+#line 2 "./src/main.zz"
+#include <assert.h>
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT_packed_main_A
+#define ZZ_EXPORT_packed_main_A
+
+#line 4 "./src/main.zz"
+struct packed_main_A_t {
+
+#line 5 "./src/main.zz"
+   uint8_t a ;
+
+#line 6 "./src/main.zz"
+   uint16_t b ;
+
+#line 7 "./src/main.zz"
+   uint64_t c ;
+
+#line 8 "./src/main.zz"
+   uint8_t d ;
+}
+ __attribute__((__packed__)) ;
+
+#endif
+#ifndef ZZ_EXPORT_packed_main_main
+#define ZZ_EXPORT_packed_main_main
+
+#line 11 "./src/main.zz"
+int packed_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_packed_main_main
+#define ZZ_IMPL_packed_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 12 "./src/main.zz"
+    assert((    sizeof(    packed_main_A    ) ==    12  )    );
+
+#line 14 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,72 @@
+/* pointer_arith_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_pointer_arith_main_main
+#define ZZ_FORWARD_pointer_arith_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_pointer_arith_main_main
+#define ZZ_EXPORT_pointer_arith_main_main
+
+#line 3 "./src/main.zz"
+int pointer_arith_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_pointer_arith_main_main
+#define ZZ_IMPL_pointer_arith_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 5 "./src/main.zz"
+  int const  a  [  ]  = {    1,    2,    3,    4,};
+
+#line 6 "./src/main.zz"
+    ;
+
+#line 7 "./src/main.zz"
+    ;
+
+#line 9 "./src/main.zz"
+  int const *  const  b  =     a;
+
+#line 10 "./src/main.zz"
+    ;
+
+#line 11 "./src/main.zz"
+    ;
+
+#line 13 "./src/main.zz"
+  int const *  const  c  = (    a +    1  );
+
+#line 14 "./src/main.zz"
+    ;
+
+#line 15 "./src/main.zz"
+    ;
+
+#line 16 "./src/main.zz"
+    printf(    "%zu\n",    3    );
+
+#line 18 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
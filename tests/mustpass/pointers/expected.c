@@ -0,0 +1,73 @@
+/* pointers_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_pointers_main_boh
+#define ZZ_FORWARD_pointers_main_boh
+#endif
+#ifndef ZZ_FORWARD_pointers_main_main
+#define ZZ_FORWARD_pointers_main_main
+#endif
+#ifndef ZZ_EXPORT_pointers_main_boh
+#define ZZ_EXPORT_pointers_main_boh
+
+#line 1 "./src/main.zz"
+extern void pointers_main_boh (uint8_t*  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_pointers_main_main
+#define ZZ_EXPORT_pointers_main_main
+
+#line 9 "./src/main.zz"
+extern int pointers_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_pointers_main_boh
+#define ZZ_IMPL_pointers_main_boh
+
+#line 1 "./src/main.zz"
+void __attribute__ ((visibility ("hidden"))) pointers_main_boh (uint8_t*  const  a)
+#if 0
+#else
+{
+
+#line 5 "./src/main.zz"
+( *    a) =     12;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_pointers_main_main
+#define ZZ_IMPL_pointers_main_main
+
+#line 9 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 10 "./src/main.zz"
+  uint8_t a ;
+
+#line 11 "./src/main.zz"
+    a =     3;
+
+#line 13 "./src/main.zz"
+    pointers_main_boh(( &    a)    );
+
+#line 14 "./src/main.zz"
+    ;
+
+#line 15 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
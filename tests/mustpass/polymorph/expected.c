@@ -0,0 +1,105 @@
+/* polymorph_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_polymorph_main_A
+#define ZZ_FORWARD_polymorph_main_A
+
+#line 4 "./src/main.zz"
+struct polymorph_main_A_t;
+typedef struct polymorph_main_A_t polymorph_main_A;
+#endif
+#ifndef ZZ_FORWARD_polymorph_main_B
+#define ZZ_FORWARD_polymorph_main_B
+
+#line 9 "./src/main.zz"
+struct polymorph_main_B_t;
+typedef struct polymorph_main_B_t polymorph_main_B;
+#endif
+#ifndef ZZ_FORWARD_polymorph_main_main
+#define ZZ_FORWARD_polymorph_main_main
+#endif
+#ifndef ZZ_FORWARD_polymorph_main_x
+#define ZZ_FORWARD_polymorph_main_x
+#endif
+#ifndef ZZ_EXPORT_polymorph_main_A
+#define ZZ_EXPORT_polymorph_main_A
+
+#line 4 "./src/main.zz"
+struct polymorph_main_A_t {
+
+#line 5 "./src/main.zz"
+   int n ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_polymorph_main_B
+#define ZZ_EXPORT_polymorph_main_B
+
+#line 9 "./src/main.zz"
+struct polymorph_main_B_t {
+
+#line 10 "./src/main.zz"
+   polymorph_main_A a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_polymorph_main_main
+#define ZZ_EXPORT_polymorph_main_main
+
+#line 18 "./src/main.zz"
+int polymorph_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_polymorph_main_x
+#define ZZ_EXPORT_polymorph_main_x
+
+#line 13 "./src/main.zz"
+static void polymorph_main_x (polymorph_main_A const *  const  self);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_polymorph_main_main
+#define ZZ_IMPL_polymorph_main_main
+
+#line 18 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 20 "./src/main.zz"
+  polymorph_main_B const  b  = {    0,};
+
+#line 21 "./src/main.zz"
+;
+
+#line 22 "./src/main.zz"
+    polymorph_main_x(( &( &    b) ->a)    );
+
+#line 24 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_polymorph_main_x
+#define ZZ_IMPL_polymorph_main_x
+
+#line 13 "./src/main.zz"
+static void polymorph_main_x (polymorph_main_A const *  const  self)
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+
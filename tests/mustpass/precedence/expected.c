@@ -0,0 +1,65 @@
+/* precedence_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__assert_h_
+#define ZZ_FORWARD__assert_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_precedence_main_main
+#define ZZ_FORWARD_precedence_main_main
+#endif
+#ifndef ZZ_EXPORT__assert_h_
+#define ZZ_EXPORT__assert_h_
+
+#line 2 "./src/main.zz"
+#include <assert.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_precedence_main_main
+#define ZZ_EXPORT_precedence_main_main
+
+#line 4 "./src/main.zz"
+int precedence_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_precedence_main_main
+#define ZZ_IMPL_precedence_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 5 "./src/main.zz"
+    assert(    1    );
+
+#line 6 "./src/main.zz"
+  int const  x  = (((    1 +(    3 %    8  )  ) +(    7 *    8  )  ) <<(    8 /    2  )  );
+
+#line 7 "./src/main.zz"
+    printf(    "%d\n",    x    );
+
+#line 9 "./src/main.zz"
+int y = 1 + 3 % 8 + 7 * 8 << 8 / 2;
+        assert(x == y);
+
+#line 12 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
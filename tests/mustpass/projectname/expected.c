@@ -0,0 +1,45 @@
+/* proje_ct___name_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_proje_ct___name_main_main
+#define ZZ_FORWARD_proje_ct___name_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_proje_ct___name_main_main
+#define ZZ_EXPORT_proje_ct___name_main_main
+
+#line 3 "./src/main.zz"
+int proje_ct___name_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_proje_ct___name_main_main
+#define ZZ_IMPL_proje_ct___name_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 4 "./src/main.zz"
+    printf(    "hello projectname\n"    );
+
+#line 5 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,86 @@
+/* recursive_fn_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_recursive_fn_main_bob
+#define ZZ_FORWARD_recursive_fn_main_bob
+#endif
+#ifndef ZZ_FORWARD_recursive_fn_main_main
+#define ZZ_FORWARD_recursive_fn_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_recursive_fn_main_bob
+#define ZZ_EXPORT_recursive_fn_main_bob
+
+#line 5 "./src/main.zz"
+static void recursive_fn_main_bob (bool const  more);
+
+#endif
+#ifndef ZZ_EXPORT_recursive_fn_main_main
+#define ZZ_EXPORT_recursive_fn_main_main
+
+#line 13 "./src/main.zz"
+int recursive_fn_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_recursive_fn_main_bob
+#define ZZ_IMPL_recursive_fn_main_bob
+
+#line 5 "./src/main.zz"
+static void recursive_fn_main_bob (bool const  more)
+#if 0
+#else
+{
+
+#line 6 "./src/main.zz"
+    printf(    "bob\n"    );
+if (
+#line 7 "./src/main.zz"
+    more){
+
+#line 8 "./src/main.zz"
+    recursive_fn_main_bob(    false    );
+
+}
+
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_recursive_fn_main_main
+#define ZZ_IMPL_recursive_fn_main_main
+
+#line 13 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 14 "./src/main.zz"
+    recursive_fn_main_bob(    true    );
+
+#line 15 "./src/main.zz"
+    printf(    "hello recursive_fn\n"    );
+
+#line 16 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
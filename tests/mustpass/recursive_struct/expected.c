@@ -0,0 +1,249 @@
+/* recursive_struct_a.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_recursive_struct_c_C
+#define ZZ_FORWARD_recursive_struct_c_C
+
+#line 2 "./src/c.zz"
+struct recursive_struct_c_C_t;
+typedef struct recursive_struct_c_C_t recursive_struct_c_C;
+#endif
+#ifndef ZZ_FORWARD_recursive_struct_b_B
+#define ZZ_FORWARD_recursive_struct_b_B
+
+#line 3 "./src/b.zz"
+struct recursive_struct_b_B_t;
+typedef struct recursive_struct_b_B_t recursive_struct_b_B;
+#endif
+#ifndef ZZ_FORWARD_recursive_struct_a_A
+#define ZZ_FORWARD_recursive_struct_a_A
+
+#line 3 "./src/a.zz"
+struct recursive_struct_a_A_t;
+typedef struct recursive_struct_a_A_t recursive_struct_a_A;
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_c_C
+#define ZZ_EXPORT_recursive_struct_c_C
+
+#line 2 "./src/c.zz"
+struct recursive_struct_c_C_t {
+
+#line 3 "./src/c.zz"
+   recursive_struct_a_A const *  a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_b_B
+#define ZZ_EXPORT_recursive_struct_b_B
+
+#line 3 "./src/b.zz"
+struct recursive_struct_b_B_t {
+
+#line 4 "./src/b.zz"
+   recursive_struct_a_A const *  a ;
+
+#line 5 "./src/b.zz"
+   recursive_struct_c_C c ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_a_A
+#define ZZ_EXPORT_recursive_struct_a_A
+
+#line 3 "./src/a.zz"
+struct recursive_struct_a_A_t {
+
+#line 4 "./src/a.zz"
+   recursive_struct_b_B b ;
+
+#line 5 "./src/a.zz"
+   recursive_struct_c_C c ;
+}
+;
+
+#endif
+
+/* recursive_struct_b.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_recursive_struct_c_C
+#define ZZ_FORWARD_recursive_struct_c_C
+
+#line 2 "./src/c.zz"
+struct recursive_struct_c_C_t;
+typedef struct recursive_struct_c_C_t recursive_struct_c_C;
+#endif
+#ifndef ZZ_FORWARD_recursive_struct_b_B
+#define ZZ_FORWARD_recursive_struct_b_B
+
+#line 3 "./src/b.zz"
+struct recursive_struct_b_B_t;
+typedef struct recursive_struct_b_B_t recursive_struct_b_B;
+#endif
+#ifndef ZZ_FORWARD_recursive_struct_a_A
+#define ZZ_FORWARD_recursive_struct_a_A
+
+#line 3 "./src/a.zz"
+struct recursive_struct_a_A_t;
+typedef struct recursive_struct_a_A_t recursive_struct_a_A;
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_c_C
+#define ZZ_EXPORT_recursive_struct_c_C
+
+#line 2 "./src/c.zz"
+struct recursive_struct_c_C_t {
+
+#line 3 "./src/c.zz"
+   recursive_struct_a_A const *  a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_b_B
+#define ZZ_EXPORT_recursive_struct_b_B
+
+#line 3 "./src/b.zz"
+struct recursive_struct_b_B_t {
+
+#line 4 "./src/b.zz"
+   recursive_struct_a_A const *  a ;
+
+#line 5 "./src/b.zz"
+   recursive_struct_c_C c ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_a_A
+#define ZZ_EXPORT_recursive_struct_a_A
+
+#line 3 "./src/a.zz"
+struct recursive_struct_a_A_t {
+
+#line 4 "./src/a.zz"
+   recursive_struct_b_B b ;
+
+#line 5 "./src/a.zz"
+   recursive_struct_c_C c ;
+}
+;
+
+#endif
+
+/* recursive_struct_c.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_recursive_struct_c_C
+#define ZZ_FORWARD_recursive_struct_c_C
+
+#line 2 "./src/c.zz"
+struct recursive_struct_c_C_t;
+typedef struct recursive_struct_c_C_t recursive_struct_c_C;
+#endif
+#ifndef ZZ_FORWARD_recursive_struct_b_B
+#define ZZ_FORWARD_recursive_struct_b_B
+
+#line 3 "./src/b.zz"
+struct recursive_struct_b_B_t;
+typedef struct recursive_struct_b_B_t recursive_struct_b_B;
+#endif
+#ifndef ZZ_FORWARD_recursive_struct_a_A
+#define ZZ_FORWARD_recursive_struct_a_A
+
+#line 3 "./src/a.zz"
+struct recursive_struct_a_A_t;
+typedef struct recursive_struct_a_A_t recursive_struct_a_A;
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_c_C
+#define ZZ_EXPORT_recursive_struct_c_C
+
+#line 2 "./src/c.zz"
+struct recursive_struct_c_C_t {
+
+#line 3 "./src/c.zz"
+   recursive_struct_a_A const *  a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_b_B
+#define ZZ_EXPORT_recursive_struct_b_B
+
+#line 3 "./src/b.zz"
+struct recursive_struct_b_B_t {
+
+#line 4 "./src/b.zz"
+   recursive_struct_a_A const *  a ;
+
+#line 5 "./src/b.zz"
+   recursive_struct_c_C c ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_a_A
+#define ZZ_EXPORT_recursive_struct_a_A
+
+#line 3 "./src/a.zz"
+struct recursive_struct_a_A_t {
+
+#line 4 "./src/a.zz"
+   recursive_struct_b_B b ;
+
+#line 5 "./src/a.zz"
+   recursive_struct_c_C c ;
+}
+;
+
+#endif
+
+/* recursive_struct_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_recursive_struct_main_main
+#define ZZ_FORWARD_recursive_struct_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_recursive_struct_main_main
+#define ZZ_EXPORT_recursive_struct_main_main
+
+#line 4 "./src/main.zz"
+int recursive_struct_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_recursive_struct_main_main
+#define ZZ_IMPL_recursive_struct_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 5 "./src/main.zz"
+    printf(    "hello recursive_struct\n"    );
+
+#line 6 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
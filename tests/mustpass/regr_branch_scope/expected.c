@@ -0,0 +1,47 @@
+/* regr_branch_scope_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_regr_branch_scope_main_main
+#define ZZ_FORWARD_regr_branch_scope_main_main
+#endif
+#ifndef ZZ_EXPORT_regr_branch_scope_main_main
+#define ZZ_EXPORT_regr_branch_scope_main_main
+
+#line 3 "./src/main.zz"
+int regr_branch_scope_main_main (int const  argc);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_regr_branch_scope_main_main
+#define ZZ_IMPL_regr_branch_scope_main_main
+int main (int const  argc)
+#if 0
+#else
+{
+if ((
+#line 5 "./src/main.zz"
+    argc ==    0  )){
+
+#line 6 "./src/main.zz"
+  int const  x  =     0;
+
+}
+ else {
+
+#line 8 "./src/main.zz"
+  int const  x  =     0;
+
+}
+
+
+#line 11 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
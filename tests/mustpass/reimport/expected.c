@@ -0,0 +1,177 @@
+/* reimport_a.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_reimport_a_A
+#define ZZ_FORWARD_reimport_a_A
+
+#line 1 "./src/a.zz"
+struct reimport_a_A_t;
+typedef struct reimport_a_A_t reimport_a_A;
+#endif
+#ifndef ZZ_FORWARD_reimport_a_a
+#define ZZ_FORWARD_reimport_a_a
+#endif
+#ifndef ZZ_FORWARD_reimport_a_m
+#define ZZ_FORWARD_reimport_a_m
+#endif
+#ifndef ZZ_EXPORT_reimport_a_A
+#define ZZ_EXPORT_reimport_a_A
+struct reimport_a_A_t {
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_reimport_a_a
+#define ZZ_EXPORT_reimport_a_a
+
+#line 5 "./src/a.zz"
+extern int reimport_a_a ();
+
+#endif
+#ifndef ZZ_EXPORT_reimport_a_m
+#define ZZ_EXPORT_reimport_a_m
+
+#line 11 "./src/a.zz"
+extern void reimport_a_m (reimport_a_A const *  const  self);
+
+#endif
+#ifndef ZZ_IMPL_reimport_a_a
+#define ZZ_IMPL_reimport_a_a
+
+#line 5 "./src/a.zz"
+int __attribute__ ((visibility ("hidden"))) reimport_a_a ()
+#if 0
+#else
+{
+
+#line 6 "./src/a.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_reimport_a_m
+#define ZZ_IMPL_reimport_a_m
+
+#line 11 "./src/a.zz"
+void __attribute__ ((visibility ("hidden"))) reimport_a_m (reimport_a_A const *  const  self)
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+
+/* reimport_b.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+
+/* reimport_f.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_reimport_f_f
+#define ZZ_FORWARD_reimport_f_f
+#endif
+#ifndef ZZ_EXPORT_reimport_f_f
+#define ZZ_EXPORT_reimport_f_f
+
+#line 1 "./src/f.zz"
+extern int reimport_f_f ();
+
+#endif
+#ifndef ZZ_IMPL_reimport_f_f
+#define ZZ_IMPL_reimport_f_f
+int __attribute__ ((visibility ("hidden"))) reimport_f_f ()
+#if 0
+#else
+{
+
+#line 2 "./src/f.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+
+/* reimport_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_reimport_a_A
+#define ZZ_FORWARD_reimport_a_A
+
+#line 1 "./src/a.zz"
+struct reimport_a_A_t;
+typedef struct reimport_a_A_t reimport_a_A;
+#endif
+#ifndef ZZ_FORWARD_reimport_a_m
+#define ZZ_FORWARD_reimport_a_m
+#endif
+#ifndef ZZ_FORWARD_reimport_f_f
+#define ZZ_FORWARD_reimport_f_f
+#endif
+#ifndef ZZ_FORWARD_reimport_main_main
+#define ZZ_FORWARD_reimport_main_main
+#endif
+#ifndef ZZ_EXPORT_reimport_a_A
+#define ZZ_EXPORT_reimport_a_A
+struct reimport_a_A_t {
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_reimport_a_m
+#define ZZ_EXPORT_reimport_a_m
+
+#line 11 "./src/a.zz"
+extern void reimport_a_m (reimport_a_A const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_reimport_f_f
+#define ZZ_EXPORT_reimport_f_f
+
+#line 1 "./src/f.zz"
+extern int reimport_f_f ();
+
+#endif
+#ifndef ZZ_EXPORT_reimport_main_main
+#define ZZ_EXPORT_reimport_main_main
+
+#line 6 "./src/main.zz"
+int reimport_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_reimport_main_main
+#define ZZ_IMPL_reimport_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 7 "./src/main.zz"
+  reimport_a_A const  x ;
+
+#line 8 "./src/main.zz"
+    reimport_a_m(( &    x)    );
+
+#line 10 "./src/main.zz"
+  return     reimport_f_f(    );
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,136 @@
+/* return_model_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_return_model_main_A
+#define ZZ_FORWARD_return_model_main_A
+
+#line 4 "./src/main.zz"
+struct return_model_main_A_t;
+typedef struct return_model_main_A_t return_model_main_A;
+#endif
+#ifndef ZZ_FORWARD_return_model_main_bob
+#define ZZ_FORWARD_return_model_main_bob
+#endif
+#ifndef ZZ_FORWARD_return_model_main_dab
+#define ZZ_FORWARD_return_model_main_dab
+#endif
+#ifndef ZZ_FORWARD_return_model_main_main
+#define ZZ_FORWARD_return_model_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_return_model_main_A
+#define ZZ_EXPORT_return_model_main_A
+
+#line 4 "./src/main.zz"
+struct return_model_main_A_t {
+
+#line 5 "./src/main.zz"
+   int a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_return_model_main_bob
+#define ZZ_EXPORT_return_model_main_bob
+
+#line 16 "./src/main.zz"
+static int return_model_main_bob ();
+
+#endif
+#ifndef ZZ_EXPORT_return_model_main_dab
+#define ZZ_EXPORT_return_model_main_dab
+
+#line 8 "./src/main.zz"
+static return_model_main_A return_model_main_dab ();
+
+#endif
+#ifndef ZZ_EXPORT_return_model_main_main
+#define ZZ_EXPORT_return_model_main_main
+
+#line 22 "./src/main.zz"
+int return_model_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_return_model_main_bob
+#define ZZ_IMPL_return_model_main_bob
+
+#line 16 "./src/main.zz"
+static int return_model_main_bob ()
+#if 0
+#else
+{
+
+#line 19 "./src/main.zz"
+  return     2;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_return_model_main_dab
+#define ZZ_IMPL_return_model_main_dab
+
+#line 8 "./src/main.zz"
+static return_model_main_A return_model_main_dab ()
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+  return     (return_model_main_A){
+.a = 
+#line 12 "./src/main.zz"
+    2,
+};
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_return_model_main_main
+#define ZZ_IMPL_return_model_main_main
+
+#line 22 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 24 "./src/main.zz"
+  int const  b  =     return_model_main_bob(    );
+
+#line 25 "./src/main.zz"
+    ;
+
+#line 28 "./src/main.zz"
+  return_model_main_A const  a  =     return_model_main_dab(    );
+
+#line 29 "./src/main.zz"
+    ;
+
+#line 31 "./src/main.zz"
+    printf(    "hello return_model\n"    );
+
+#line 32 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
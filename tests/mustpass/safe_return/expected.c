@@ -0,0 +1,98 @@
+/* safe_return_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_safe_return_main_A
+#define ZZ_FORWARD_safe_return_main_A
+
+#line 4 "./src/main.zz"
+struct safe_return_main_A_t;
+typedef struct safe_return_main_A_t safe_return_main_A;
+#endif
+#ifndef ZZ_FORWARD_safe_return_main_x
+#define ZZ_FORWARD_safe_return_main_x
+#endif
+#ifndef ZZ_FORWARD_safe_return_main_bob
+#define ZZ_FORWARD_safe_return_main_bob
+#endif
+#ifndef ZZ_FORWARD_safe_return_main_main
+#define ZZ_FORWARD_safe_return_main_main
+#endif
+#ifndef ZZ_EXPORT_safe_return_main_A
+#define ZZ_EXPORT_safe_return_main_A
+struct safe_return_main_A_t {
+
+#line 5 "./src/main.zz"
+   int b ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_safe_return_main_x
+#define ZZ_EXPORT_safe_return_main_x
+
+#line 8 "./src/main.zz"
+static const  __attribute__ ((unused)) safe_return_main_A safe_return_main_x ={
+.b = 
+#line 9 "./src/main.zz"
+    2,
+};
+
+#endif
+#ifndef ZZ_EXPORT_safe_return_main_bob
+#define ZZ_EXPORT_safe_return_main_bob
+
+#line 12 "./src/main.zz"
+static safe_return_main_A  const * safe_return_main_bob ();
+
+#endif
+#ifndef ZZ_EXPORT_safe_return_main_main
+#define ZZ_EXPORT_safe_return_main_main
+
+#line 20 "./src/main.zz"
+int safe_return_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_safe_return_main_bob
+#define ZZ_IMPL_safe_return_main_bob
+
+#line 12 "./src/main.zz"
+static safe_return_main_A  const * safe_return_main_bob ()
+#if 0
+#else
+{
+
+#line 16 "./src/main.zz"
+  return ( &    safe_return_main_x);
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_safe_return_main_main
+#define ZZ_IMPL_safe_return_main_main
+
+#line 20 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 21 "./src/main.zz"
+  safe_return_main_A const *  const  a  =     safe_return_main_bob(    );
+
+#line 22 "./src/main.zz"
+( *    a);
+
+#line 24 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
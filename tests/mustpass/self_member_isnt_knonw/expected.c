@@ -0,0 +1,84 @@
+/* self_member_isnt_knonw_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_self_member_isnt_knonw_main_A
+#define ZZ_FORWARD_self_member_isnt_knonw_main_A
+
+#line 4 "./src/main.zz"
+struct self_member_isnt_knonw_main_A_t;
+typedef struct self_member_isnt_knonw_main_A_t self_member_isnt_knonw_main_A;
+#endif
+#ifndef ZZ_FORWARD_self_member_isnt_knonw_main_d
+#define ZZ_FORWARD_self_member_isnt_knonw_main_d
+#endif
+#ifndef ZZ_FORWARD_self_member_isnt_knonw_main_main
+#define ZZ_FORWARD_self_member_isnt_knonw_main_main
+#endif
+#ifndef ZZ_EXPORT_self_member_isnt_knonw_main_A
+#define ZZ_EXPORT_self_member_isnt_knonw_main_A
+struct self_member_isnt_knonw_main_A_t {
+
+#line 5 "./src/main.zz"
+   uintptr_t a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_self_member_isnt_knonw_main_d
+#define ZZ_EXPORT_self_member_isnt_knonw_main_d
+
+#line 8 "./src/main.zz"
+static void self_member_isnt_knonw_main_d (self_member_isnt_knonw_main_A*  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_self_member_isnt_knonw_main_main
+#define ZZ_EXPORT_self_member_isnt_knonw_main_main
+
+#line 14 "./src/main.zz"
+int self_member_isnt_knonw_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_self_member_isnt_knonw_main_d
+#define ZZ_IMPL_self_member_isnt_knonw_main_d
+
+#line 8 "./src/main.zz"
+static void self_member_isnt_knonw_main_d (self_member_isnt_knonw_main_A*  const  a)
+#if 0
+#else
+{
+
+#line 9 "./src/main.zz"
+    a ->a +=     2;
+if ((
+#line 10 "./src/main.zz"
+    a ->a >    2  )){
+
+}
+
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_self_member_isnt_knonw_main_main
+#define ZZ_IMPL_self_member_isnt_knonw_main_main
+
+#line 14 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 15 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,100 @@
+/* selfcall_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_selfcall_main_A
+#define ZZ_FORWARD_selfcall_main_A
+
+#line 5 "./src/main.zz"
+struct selfcall_main_A_t;
+typedef struct selfcall_main_A_t selfcall_main_A;
+#endif
+#ifndef ZZ_FORWARD_selfcall_main_get
+#define ZZ_FORWARD_selfcall_main_get
+#endif
+#ifndef ZZ_FORWARD_selfcall_main_main
+#define ZZ_FORWARD_selfcall_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_selfcall_main_A
+#define ZZ_EXPORT_selfcall_main_A
+
+#line 5 "./src/main.zz"
+struct selfcall_main_A_t {
+
+#line 6 "./src/main.zz"
+   int a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_selfcall_main_get
+#define ZZ_EXPORT_selfcall_main_get
+
+#line 10 "./src/main.zz"
+static int selfcall_main_get (selfcall_main_A const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_selfcall_main_main
+#define ZZ_EXPORT_selfcall_main_main
+
+#line 14 "./src/main.zz"
+int selfcall_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_selfcall_main_get
+#define ZZ_IMPL_selfcall_main_get
+
+#line 10 "./src/main.zz"
+static int selfcall_main_get (selfcall_main_A const *  const  self)
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+  return     self ->a;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_selfcall_main_main
+#define ZZ_IMPL_selfcall_main_main
+
+#line 14 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 16 "./src/main.zz"
+  selfcall_main_A const  a  =     (selfcall_main_A){
+.a = 
+#line 17 "./src/main.zz"
+    1,
+};
+
+#line 20 "./src/main.zz"
+    printf(    "hello selfcall %d\n",    selfcall_main_get(( &    a)    )    );
+
+#line 21 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
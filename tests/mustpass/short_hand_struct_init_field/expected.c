@@ -0,0 +1,592 @@
+/* short_hand_struct_init_field_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdarg_h_
+#define ZZ_FORWARD__stdarg_h_
+#endif
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_log_info
+#define ZZ_FORWARD_log_info
+#endif
+#ifndef ZZ_FORWARD_short_hand_struct_init_field_main_Object
+#define ZZ_FORWARD_short_hand_struct_init_field_main_Object
+
+#line 3 "./src/main.zz"
+struct short_hand_struct_init_field_main_Object_t;
+typedef struct short_hand_struct_init_field_main_Object_t short_hand_struct_init_field_main_Object;
+#endif
+#ifndef ZZ_FORWARD_short_hand_struct_init_field_main_main
+#define ZZ_FORWARD_short_hand_struct_init_field_main_main
+#endif
+#ifndef ZZ_FORWARD_slice_slice_Slice
+#define ZZ_FORWARD_slice_slice_Slice
+
+#line 4 "$ZZ/modules/slice/src/slice.zz"
+struct slice_slice_Slice_t;
+typedef struct slice_slice_Slice_t slice_slice_Slice;
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_MutSlice
+#define ZZ_FORWARD_slice_mut_slice_MutSlice
+
+#line 4 "$ZZ/modules/slice/src/mut_slice.zz"
+struct slice_mut_slice_MutSlice_t;
+typedef struct slice_mut_slice_MutSlice_t slice_mut_slice_MutSlice;
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_bytes
+#define ZZ_FORWARD_slice_mut_slice_append_bytes
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_cstr
+#define ZZ_FORWARD_slice_mut_slice_append_cstr
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_append_slice
+#define ZZ_FORWARD_slice_mut_slice_append_slice
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_borrow
+#define ZZ_FORWARD_slice_mut_slice_borrow
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_make
+#define ZZ_FORWARD_slice_mut_slice_make
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_mem
+#define ZZ_FORWARD_slice_mut_slice_mem
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push
+#define ZZ_FORWARD_slice_mut_slice_push
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push16
+#define ZZ_FORWARD_slice_mut_slice_push16
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push32
+#define ZZ_FORWARD_slice_mut_slice_push32
+#endif
+#ifndef ZZ_FORWARD_slice_mut_slice_push64
+#define ZZ_FORWARD_slice_mut_slice_push64
+#endif
+#ifndef ZZ_FORWARD_slice_slice_borrow
+#define ZZ_FORWARD_slice_slice_borrow
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq
+#define ZZ_FORWARD_slice_slice_eq
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq_bytes
+#define ZZ_FORWARD_slice_slice_eq_bytes
+#endif
+#ifndef ZZ_FORWARD_slice_slice_eq_cstr
+#define ZZ_FORWARD_slice_slice_eq_cstr
+#endif
+#ifndef ZZ_FORWARD_slice_slice_make
+#define ZZ_FORWARD_slice_slice_make
+#endif
+#ifndef ZZ_FORWARD_string_String
+#define ZZ_FORWARD_string_String
+
+#line 8 "$ZZ/modules/string/src/lib.zz"
+struct string_String_t;
+typedef struct string_String_t string_String;
+#endif
+#ifndef ZZ_FORWARD_string_append
+#define ZZ_FORWARD_string_append
+#endif
+#ifndef ZZ_FORWARD_string_append_bytes
+#define ZZ_FORWARD_string_append_bytes
+#endif
+#ifndef ZZ_FORWARD_string_append_cstr
+#define ZZ_FORWARD_string_append_cstr
+#endif
+#ifndef ZZ_FORWARD_string_append_slice
+#define ZZ_FORWARD_string_append_slice
+#endif
+#ifndef ZZ_FORWARD_string_as_mut_slice
+#define ZZ_FORWARD_string_as_mut_slice
+#endif
+#ifndef ZZ_FORWARD_string_clear
+#define ZZ_FORWARD_string_clear
+#endif
+#ifndef ZZ_FORWARD_string_cstr
+#define ZZ_FORWARD_string_cstr
+#endif
+#ifndef ZZ_FORWARD_string_ends_with_cstr
+#define ZZ_FORWARD_string_ends_with_cstr
+#endif
+#ifndef ZZ_FORWARD_string_eq_cstr
+#define ZZ_FORWARD_string_eq_cstr
+#endif
+#ifndef ZZ_FORWARD_string_fgets
+#define ZZ_FORWARD_string_fgets
+#endif
+#ifndef ZZ_FORWARD_string_format
+#define ZZ_FORWARD_string_format
+#endif
+#ifndef ZZ_FORWARD_string_from
+#define ZZ_FORWARD_string_from
+#endif
+#ifndef ZZ_FORWARD_string_from_cstr
+#define ZZ_FORWARD_string_from_cstr
+#endif
+#ifndef ZZ_FORWARD_string_from_slice
+#define ZZ_FORWARD_string_from_slice
+#endif
+#ifndef ZZ_FORWARD_string_make
+#define ZZ_FORWARD_string_make
+#endif
+#ifndef ZZ_FORWARD_string_pop
+#define ZZ_FORWARD_string_pop
+#endif
+#ifndef ZZ_FORWARD_string_push
+#define ZZ_FORWARD_string_push
+#endif
+#ifndef ZZ_FORWARD_string_slen
+#define ZZ_FORWARD_string_slen
+#endif
+#ifndef ZZ_FORWARD_string_slice
+#define ZZ_FORWARD_string_slice
+#endif
+#ifndef ZZ_FORWARD_string_space
+#define ZZ_FORWARD_string_space
+#endif
+#ifndef ZZ_FORWARD_string_split
+#define ZZ_FORWARD_string_split
+#endif
+#ifndef ZZ_FORWARD_string_starts_with_cstr
+#define ZZ_FORWARD_string_starts_with_cstr
+#endif
+#ifndef ZZ_FORWARD_string_substr
+#define ZZ_FORWARD_string_substr
+#endif
+#ifndef ZZ_FORWARD__stdarg_h_
+#define ZZ_FORWARD__stdarg_h_
+#endif
+#ifndef ZZ_FORWARD_string_vformat
+#define ZZ_FORWARD_string_vformat
+#endif
+#ifndef ZZ_EXPORT__stdarg_h_
+#define ZZ_EXPORT__stdarg_h_
+
+#line 1 "$ZZ/modules/log/src/lib.zz"
+#include <stdarg.h>
+
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 5 "$ZZ/modules/string/src/lib.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_log_info
+#define ZZ_EXPORT_log_info
+
+#line 68 "$ZZ/modules/log/src/lib.zz"
+void log_info (char const *  const  module, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_short_hand_struct_init_field_main_Object
+#define ZZ_EXPORT_short_hand_struct_init_field_main_Object
+
+#line 3 "./src/main.zz"
+struct short_hand_struct_init_field_main_Object_t {
+
+#line 4 "./src/main.zz"
+   char const *  string ;
+
+#line 5 "./src/main.zz"
+   int number ;
+
+#line 6 "./src/main.zz"
+   bool boolean ;
+
+#line 7 "./src/main.zz"
+   void const *  data ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_short_hand_struct_init_field_main_main
+#define ZZ_EXPORT_short_hand_struct_init_field_main_main
+
+#line 10 "./src/main.zz"
+int short_hand_struct_init_field_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_Slice
+#define ZZ_EXPORT_slice_slice_Slice
+
+#line 4 "$ZZ/modules/slice/src/slice.zz"
+struct slice_slice_Slice_t {
+
+#line 5 "$ZZ/modules/slice/src/slice.zz"
+   uint8_t const *  mem ;
+
+#line 6 "$ZZ/modules/slice/src/slice.zz"
+   uintptr_t size ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_MutSlice
+#define ZZ_EXPORT_slice_mut_slice_MutSlice
+
+#line 4 "$ZZ/modules/slice/src/mut_slice.zz"
+struct slice_mut_slice_MutSlice_t {
+
+#line 5 "$ZZ/modules/slice/src/mut_slice.zz"
+   slice_slice_Slice slice ;
+
+#line 6 "$ZZ/modules/slice/src/mut_slice.zz"
+   uintptr_t at ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_bytes
+#define ZZ_EXPORT_slice_mut_slice_append_bytes
+
+#line 62 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_bytes (slice_mut_slice_MutSlice*  const  self, uint8_t const *  const  b, uintptr_t const  l);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_cstr
+#define ZZ_EXPORT_slice_mut_slice_append_cstr
+
+#line 76 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_cstr (slice_mut_slice_MutSlice*  const  self, char const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_append_slice
+#define ZZ_EXPORT_slice_mut_slice_append_slice
+
+#line 50 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_append_slice (slice_mut_slice_MutSlice*  const  self, slice_slice_Slice const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_borrow
+#define ZZ_EXPORT_slice_mut_slice_borrow
+
+#line 9 "$ZZ/modules/slice/src/mut_slice.zz"
+slice_mut_slice_MutSlice  const * slice_mut_slice_borrow (slice_mut_slice_MutSlice*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_make
+#define ZZ_EXPORT_slice_mut_slice_make
+
+#line 24 "$ZZ/modules/slice/src/mut_slice.zz"
+void slice_mut_slice_make (slice_mut_slice_MutSlice*  const  self, uint8_t*  const  mem, uintptr_t const  size);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_mem
+#define ZZ_EXPORT_slice_mut_slice_mem
+
+#line 38 "$ZZ/modules/slice/src/mut_slice.zz"
+uint8_t * slice_mut_slice_mem (slice_mut_slice_MutSlice*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push
+#define ZZ_EXPORT_slice_mut_slice_push
+
+#line 85 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push (slice_mut_slice_MutSlice*  const  self, uint8_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push16
+#define ZZ_EXPORT_slice_mut_slice_push16
+
+#line 100 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push16 (slice_mut_slice_MutSlice*  const  self, uint16_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push32
+#define ZZ_EXPORT_slice_mut_slice_push32
+
+#line 115 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push32 (slice_mut_slice_MutSlice*  const  self, uint32_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_mut_slice_push64
+#define ZZ_EXPORT_slice_mut_slice_push64
+
+#line 130 "$ZZ/modules/slice/src/mut_slice.zz"
+bool slice_mut_slice_push64 (slice_mut_slice_MutSlice*  const  self, uint64_t const  b);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_borrow
+#define ZZ_EXPORT_slice_slice_borrow
+
+#line 9 "$ZZ/modules/slice/src/slice.zz"
+slice_slice_Slice  const * slice_slice_borrow (slice_slice_Slice const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq
+#define ZZ_EXPORT_slice_slice_eq
+
+#line 17 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq (slice_slice_Slice const *  const  self, slice_slice_Slice const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq_bytes
+#define ZZ_EXPORT_slice_slice_eq_bytes
+
+#line 33 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq_bytes (slice_slice_Slice const *  const  self, uint8_t const *  const  other, uintptr_t const  othersize);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_eq_cstr
+#define ZZ_EXPORT_slice_slice_eq_cstr
+
+#line 25 "$ZZ/modules/slice/src/slice.zz"
+bool slice_slice_eq_cstr (slice_slice_Slice const *  const  self, char const *  const  other);
+
+#endif
+#ifndef ZZ_EXPORT_slice_slice_make
+#define ZZ_EXPORT_slice_slice_make
+
+#line 42 "$ZZ/modules/slice/src/slice.zz"
+void slice_slice_make (slice_slice_Slice*  const  self, uint8_t const *  const  mem, uintptr_t const  size);
+
+#endif
+#ifndef ZZ_EXPORT_string_String
+#define ZZ_EXPORT_string_String
+
+#line 8 "$ZZ/modules/string/src/lib.zz"
+struct string_String_t {
+
+#line 9 "$ZZ/modules/string/src/lib.zz"
+   uintptr_t len ;
+
+#line 10 "$ZZ/modules/string/src/lib.zz"
+   char mem[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_string_append
+#define ZZ_EXPORT_string_append
+
+#line 184 "$ZZ/modules/string/src/lib.zz"
+void string_append (string_String*  const  self, uintptr_t const  t, string_String const *  const  other, uintptr_t const  t2);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_bytes
+#define ZZ_EXPORT_string_append_bytes
+
+#line 204 "$ZZ/modules/string/src/lib.zz"
+void string_append_bytes (string_String*  const  self, uintptr_t const  t, uint8_t const *  const  bytes, uintptr_t inlen);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_cstr
+#define ZZ_EXPORT_string_append_cstr
+
+#line 163 "$ZZ/modules/string/src/lib.zz"
+void string_append_cstr (string_String*  const  self, uintptr_t const  t, char const *  const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_append_slice
+#define ZZ_EXPORT_string_append_slice
+
+#line 222 "$ZZ/modules/string/src/lib.zz"
+void string_append_slice (string_String*  const  self, uintptr_t const  t, slice_slice_Slice const *  const  slice);
+
+#endif
+#ifndef ZZ_EXPORT_string_as_mut_slice
+#define ZZ_EXPORT_string_as_mut_slice
+
+#line 55 "$ZZ/modules/string/src/lib.zz"
+slice_mut_slice_MutSlice string_as_mut_slice (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_clear
+#define ZZ_EXPORT_string_clear
+
+#line 115 "$ZZ/modules/string/src/lib.zz"
+void string_clear (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_cstr
+#define ZZ_EXPORT_string_cstr
+
+#line 34 "$ZZ/modules/string/src/lib.zz"
+char  const * string_cstr (string_String const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_string_ends_with_cstr
+#define ZZ_EXPORT_string_ends_with_cstr
+
+#line 333 "$ZZ/modules/string/src/lib.zz"
+bool string_ends_with_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_string_eq_cstr
+#define ZZ_EXPORT_string_eq_cstr
+
+#line 283 "$ZZ/modules/string/src/lib.zz"
+bool string_eq_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  b);
+
+#endif
+#ifndef ZZ_EXPORT_string_fgets
+#define ZZ_EXPORT_string_fgets
+
+#line 352 "$ZZ/modules/string/src/lib.zz"
+bool string_fgets (string_String*  const  self, uintptr_t const  tail, FILE*  const  stream);
+
+#endif
+#ifndef ZZ_EXPORT_string_format
+#define ZZ_EXPORT_string_format
+
+#line 240 "$ZZ/modules/string/src/lib.zz"
+int string_format (string_String*  const  self, uintptr_t const  tail, char const *  const  fmt, ...);
+
+#endif
+#ifndef ZZ_EXPORT_string_from
+#define ZZ_EXPORT_string_from
+
+#line 81 "$ZZ/modules/string/src/lib.zz"
+void string_from (string_String*  const  self, uintptr_t const  t, uint8_t const *  const  bytes, uintptr_t inlen);
+
+#endif
+#ifndef ZZ_EXPORT_string_from_cstr
+#define ZZ_EXPORT_string_from_cstr
+
+#line 105 "$ZZ/modules/string/src/lib.zz"
+void string_from_cstr (string_String*  const  self, uintptr_t const  t, char const *  const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_from_slice
+#define ZZ_EXPORT_string_from_slice
+
+#line 91 "$ZZ/modules/string/src/lib.zz"
+void string_from_slice (string_String*  const  self, uintptr_t const  t, slice_slice_Slice const *  const  slice);
+
+#endif
+#ifndef ZZ_EXPORT_string_make
+#define ZZ_EXPORT_string_make
+
+#line 73 "$ZZ/modules/string/src/lib.zz"
+void string_make (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_pop
+#define ZZ_EXPORT_string_pop
+
+#line 149 "$ZZ/modules/string/src/lib.zz"
+bool string_pop (string_String*  const  self, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT_string_push
+#define ZZ_EXPORT_string_push
+
+#line 126 "$ZZ/modules/string/src/lib.zz"
+bool string_push (string_String*  const  self, uintptr_t const  t, char const  cstr);
+
+#endif
+#ifndef ZZ_EXPORT_string_slen
+#define ZZ_EXPORT_string_slen
+
+#line 29 "$ZZ/modules/string/src/lib.zz"
+uintptr_t string_slen (string_String const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_string_slice
+#define ZZ_EXPORT_string_slice
+
+#line 43 "$ZZ/modules/string/src/lib.zz"
+slice_slice_Slice string_slice (string_String*  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_space
+#define ZZ_EXPORT_string_space
+
+#line 448 "$ZZ/modules/string/src/lib.zz"
+uintptr_t string_space (string_String const *  const  self, uintptr_t const  tail);
+
+#endif
+#ifndef ZZ_EXPORT_string_split
+#define ZZ_EXPORT_string_split
+
+#line 417 "$ZZ/modules/string/src/lib.zz"
+bool string_split (string_String const *  const  self, uintptr_t const  tail, char const  token, uintptr_t*  const  iterator, string_String*  const  other, uintptr_t const  tail2);
+
+#endif
+#ifndef ZZ_EXPORT_string_starts_with_cstr
+#define ZZ_EXPORT_string_starts_with_cstr
+
+#line 317 "$ZZ/modules/string/src/lib.zz"
+bool string_starts_with_cstr (string_String const *  const  self, uintptr_t const  tail, char const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_string_substr
+#define ZZ_EXPORT_string_substr
+
+#line 369 "$ZZ/modules/string/src/lib.zz"
+void string_substr (string_String const *  const  self, uintptr_t const  tail, uintptr_t const  from, uintptr_t size, string_String*  const  other, uintptr_t const  tail2);
+
+#endif
+#ifndef ZZ_EXPORT__stdarg_h_
+#define ZZ_EXPORT__stdarg_h_
+
+#endif
+#ifndef ZZ_EXPORT_string_vformat
+#define ZZ_EXPORT_string_vformat
+
+#line 252 "$ZZ/modules/string/src/lib.zz"
+int string_vformat (string_String*  const  self, uintptr_t const  tail, char const *  const  fmt, va_list args);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_short_hand_struct_init_field_main_main
+#define ZZ_IMPL_short_hand_struct_init_field_main_main
+
+#line 10 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+  char const *  const  string  =     "hello";
+
+#line 12 "./src/main.zz"
+  int const  number  =     123;
+
+#line 13 "./src/main.zz"
+  bool const  boolean  =     true;
+
+#line 14 "./src/main.zz"
+  short_hand_struct_init_field_main_Object const  object  =     (short_hand_struct_init_field_main_Object){
+.string =     string,
+
+.number =     number,
+
+.boolean =     boolean,
+
+.data =     "hello",
+};
+
+#line 15 "./src/main.zz"
+    log_info(
+#line 68 "$ZZ/modules/log/src/lib.zz"
+    "short_hand_struct_init_field::main",
+#line 15 "./src/main.zz"
+    "hello %s\n",    "short_hand_struct_init_field"    );
+
+#line 16 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,457 @@
+/* slice_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_slice_main_Buf
+#define ZZ_FORWARD_slice_main_Buf
+
+#line 3 "./src/main.zz"
+struct slice_main_Buf_t;
+typedef struct slice_main_Buf_t slice_main_Buf;
+#endif
+#ifndef ZZ_FORWARD_slice_main_Buf_8
+#define ZZ_FORWARD_slice_main_Buf_8
+struct slice_main_Buf_8_t;
+typedef struct slice_main_Buf_8_t slice_main_Buf_8;
+#endif
+#ifndef ZZ_FORWARD_zz_mslice__u8
+#define ZZ_FORWARD_zz_mslice__u8
+
+#line 23 "./src/main.zz"
+struct zz_mslice__u8_t;
+typedef struct zz_mslice__u8_t zz_mslice__u8;
+#endif
+#ifndef ZZ_FORWARD_slice_main_fill
+#define ZZ_FORWARD_slice_main_fill
+#endif
+#ifndef ZZ_FORWARD_zz_slice__u8
+#define ZZ_FORWARD_zz_slice__u8
+
+#line 16 "./src/main.zz"
+struct zz_slice__u8_t;
+typedef struct zz_slice__u8_t zz_slice__u8;
+#endif
+#ifndef ZZ_FORWARD_slice_main_first
+#define ZZ_FORWARD_slice_main_first
+#endif
+#ifndef ZZ_FORWARD_slice_main_sum
+#define ZZ_FORWARD_slice_main_sum
+#endif
+#ifndef ZZ_FORWARD_slice_main_tail
+#define ZZ_FORWARD_slice_main_tail
+#endif
+#ifndef ZZ_FORWARD_slice_main_sum_of
+#define ZZ_FORWARD_slice_main_sum_of
+#endif
+#ifndef ZZ_FORWARD_slice_main_main
+#define ZZ_FORWARD_slice_main_main
+#endif
+#ifndef ZZ_FORWARD_slice_main_sum
+#define ZZ_FORWARD_slice_main_sum
+#endif
+#ifndef ZZ_FORWARD_slice_main_sum_of
+#define ZZ_FORWARD_slice_main_sum_of
+#endif
+#ifndef ZZ_FORWARD_slice_main_tail
+#define ZZ_FORWARD_slice_main_tail
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_Buf
+#define ZZ_EXPORT_slice_main_Buf
+
+#line 3 "./src/main.zz"
+struct slice_main_Buf_t {
+
+#line 4 "./src/main.zz"
+   uintptr_t used ;
+
+#line 5 "./src/main.zz"
+   uint8_t mem[] ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_slice_main_Buf_8_8
+#define ZZ_EXPORT_slice_main_Buf_8_8
+
+#line 3 "./src/main.zz"
+struct slice_main_Buf_8_t {
+
+#line 4 "./src/main.zz"
+   uintptr_t used ;
+
+#line 5 "./src/main.zz"
+   uint8_t mem[8] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_zz_mslice__u8
+#define ZZ_EXPORT_zz_mslice__u8
+
+#line 23 "./src/main.zz"
+struct zz_mslice__u8_t {
+   uint8_t*  mem ;
+   uintptr_t len ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_fill
+#define ZZ_EXPORT_slice_main_fill
+static void slice_main_fill (zz_mslice__u8 const  s, uint8_t const  v);
+
+#endif
+#ifndef ZZ_EXPORT_zz_slice__u8
+#define ZZ_EXPORT_zz_slice__u8
+
+#line 16 "./src/main.zz"
+struct zz_slice__u8_t {
+   uint8_t const *  mem ;
+   uintptr_t len ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_first
+#define ZZ_EXPORT_slice_main_first
+static uint8_t slice_main_first (zz_slice__u8 const  s);
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_sum
+#define ZZ_EXPORT_slice_main_sum
+
+#line 8 "./src/main.zz"
+static uint32_t slice_main_sum (zz_slice__u8 const  s);
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_tail
+#define ZZ_EXPORT_slice_main_tail
+
+#line 29 "./src/main.zz"
+static zz_slice__u8 slice_main_tail (zz_slice__u8 const  s, uintptr_t const  n);
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_sum_of
+#define ZZ_EXPORT_slice_main_sum_of
+
+#line 35 "./src/main.zz"
+static uint32_t slice_main_sum_of (uint8_t const *  const  p, uintptr_t const  n);
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_main
+#define ZZ_EXPORT_slice_main_main
+
+#line 41 "./src/main.zz"
+int slice_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_sum
+#define ZZ_EXPORT_slice_main_sum
+
+#line 8 "./src/main.zz"
+static uint32_t slice_main_sum (zz_slice__u8 const  s);
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_sum_of
+#define ZZ_EXPORT_slice_main_sum_of
+
+#line 35 "./src/main.zz"
+static uint32_t slice_main_sum_of (uint8_t const *  const  p, uintptr_t const  n);
+
+#endif
+#ifndef ZZ_EXPORT_slice_main_tail
+#define ZZ_EXPORT_slice_main_tail
+
+#line 29 "./src/main.zz"
+static zz_slice__u8 slice_main_tail (zz_slice__u8 const  s, uintptr_t const  n);
+
+#endif
+#ifndef ZZ_IMPL_slice_main_fill
+#define ZZ_IMPL_slice_main_fill
+
+#line 23 "./src/main.zz"
+static void slice_main_fill (zz_mslice__u8 const  s, uint8_t const  v)
+#if 0
+#else
+{
+  for (
+
+#line 24 "./src/main.zz"
+  uintptr_t i  =     0;(    i <    s .len  );
+(    i ++)){
+
+#line 25 "./src/main.zz"
+    s .mem [     i] =     v;
+
+}
+
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_slice_main_first
+#define ZZ_IMPL_slice_main_first
+
+#line 16 "./src/main.zz"
+static uint8_t slice_main_first (zz_slice__u8 const  s)
+#if 0
+#else
+{
+if ((
+#line 17 "./src/main.zz"
+    s .len >    0  )){
+
+#line 18 "./src/main.zz"
+  return     s .mem [     0];
+
+}
+
+
+#line 20 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_slice_main_main
+#define ZZ_IMPL_slice_main_main
+
+#line 41 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 42 "./src/main.zz"
+  uint8_t a  [  ]  = {    1,    2,    3,    4,    5,};
+
+#line 44 "./src/main.zz"
+    printf(    "sum %u\n",    slice_main_sum(    (zz_slice__u8){
+.mem =     a,
+
+.len =     5,
+}    )    );
+
+#line 45 "./src/main.zz"
+    printf(    "sum of a[1..3] %u\n",    slice_main_sum(    (zz_slice__u8){
+.mem =     (zz_mslice__u8){
+.mem = (    a +    1  ),
+
+.len = (    3 -    1  ),
+} .mem,
+
+.len =     (zz_mslice__u8){
+.mem = (    a +    1  ),
+
+.len = (    3 -    1  ),
+} .len,
+}    )    );
+
+#line 46 "./src/main.zz"
+    printf(    "sum of a[..2] %u\n",    slice_main_sum(    (zz_slice__u8){
+.mem =     (zz_mslice__u8){
+.mem =     a,
+
+.len =     2,
+} .mem,
+
+.len =     (zz_mslice__u8){
+.mem =     a,
+
+.len =     2,
+} .len,
+}    )    );
+
+#line 47 "./src/main.zz"
+    printf(    "sum of a[3..] %u\n",    slice_main_sum(    (zz_slice__u8){
+.mem =     (zz_mslice__u8){
+.mem = (    a +    3  ),
+
+.len = (    5 -    3  ),
+} .mem,
+
+.len =     (zz_mslice__u8){
+.mem = (    a +    3  ),
+
+.len = (    5 -    3  ),
+} .len,
+}    )    );
+
+#line 49 "./src/main.zz"
+  zz_slice__u8 const  t  =     slice_main_tail(    (zz_slice__u8){
+.mem =     a,
+
+.len =     5,
+},    2    );
+
+#line 50 "./src/main.zz"
+    printf(    "first of tail %u, len %zu\n",    slice_main_first(    t    ),    t .len    );
+if ((
+#line 52 "./src/main.zz"
+    t .len ==    3  )){
+
+#line 53 "./src/main.zz"
+  zz_slice__u8 const  e  =     (zz_slice__u8){
+.mem = (    t .mem +    3  ),
+
+.len = (    t .len -    3  ),
+};
+
+#line 54 "./src/main.zz"
+    printf(    "first of empty %u, len %zu\n",    slice_main_first(    e    ),    e .len    );
+
+}
+
+
+#line 57 "./src/main.zz"
+    slice_main_fill(    (zz_mslice__u8){
+.mem =     a,
+
+.len =     2,
+},    9    );
+
+#line 58 "./src/main.zz"
+    printf(    "sum after fill %u\n",    slice_main_sum(    (zz_slice__u8){
+.mem =     a,
+
+.len =     5,
+}    )    );
+
+#line 60 "./src/main.zz"
+  zz_mslice__u8 const  m  =     (zz_mslice__u8){
+.mem =     a,
+
+.len =     5,
+};
+
+#line 61 "./src/main.zz"
+    slice_main_fill(    (zz_mslice__u8){
+.mem = (    m .mem +    4  ),
+
+.len = (    m .len -    4  ),
+},    0    );
+
+#line 62 "./src/main.zz"
+    printf(    "sum after second fill %u\n",    slice_main_sum(    (zz_slice__u8){
+.mem =     m .mem,
+
+.len =     m .len,
+}    )    );
+
+#line 64 "./src/main.zz"
+  slice_main_Buf_8 b  = {    0,};
+
+#line 65 "./src/main.zz"
+    slice_main_fill(    (zz_mslice__u8){
+.mem = ( &    b) ->mem,
+
+.len =     8,
+},    2    );
+
+#line 66 "./src/main.zz"
+    printf(    "sum of tail %u\n",    slice_main_sum(    (zz_slice__u8){
+.mem = ( &    b) ->mem,
+
+.len =     8,
+}    )    );
+
+#line 68 "./src/main.zz"
+    printf(    "sum of pointer %u\n",    slice_main_sum_of(    a,    3    )    );
+
+#line 70 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_slice_main_sum
+#define ZZ_IMPL_slice_main_sum
+
+#line 8 "./src/main.zz"
+static uint32_t slice_main_sum (zz_slice__u8 const  s)
+#if 0
+#else
+{
+
+#line 9 "./src/main.zz"
+  uint32_t r  =     0;
+  for (
+
+#line 10 "./src/main.zz"
+  uintptr_t i  =     0;(    i <    s .len  );
+(    i ++)){
+
+#line 11 "./src/main.zz"
+    r = (    r +    (uint32_t)(    s .mem [     i])  );
+
+}
+
+
+#line 13 "./src/main.zz"
+  return     r;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_slice_main_sum_of
+#define ZZ_IMPL_slice_main_sum_of
+
+#line 35 "./src/main.zz"
+static uint32_t slice_main_sum_of (uint8_t const *  const  p, uintptr_t const  n)
+#if 0
+#else
+{
+
+#line 38 "./src/main.zz"
+  return     slice_main_sum(    (zz_slice__u8){
+.mem =     p,
+
+.len =     n,
+}    );
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_slice_main_tail
+#define ZZ_IMPL_slice_main_tail
+
+#line 29 "./src/main.zz"
+static zz_slice__u8 slice_main_tail (zz_slice__u8 const  s, uintptr_t const  n)
+#if 0
+#else
+{
+
+#line 32 "./src/main.zz"
+  return     (zz_slice__u8){
+.mem = (    s .mem +    n  ),
+
+.len = (    s .len -    n  ),
+};
+
+}
+#endif
+
+
+#endif
+
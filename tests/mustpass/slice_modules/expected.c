@@ -0,0 +1,182 @@
+/* slice_modules_bytes.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_zz_slice__u8
+#define ZZ_FORWARD_zz_slice__u8
+
+#line 1 "./src/bytes.zz"
+struct zz_slice__u8_t;
+typedef struct zz_slice__u8_t zz_slice__u8;
+#endif
+#ifndef ZZ_FORWARD_slice_modules_bytes_sum
+#define ZZ_FORWARD_slice_modules_bytes_sum
+#endif
+#ifndef ZZ_EXPORT_zz_slice__u8
+#define ZZ_EXPORT_zz_slice__u8
+struct zz_slice__u8_t {
+   uint8_t const *  mem ;
+   uintptr_t len ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_modules_bytes_sum
+#define ZZ_EXPORT_slice_modules_bytes_sum
+extern uintptr_t slice_modules_bytes_sum (zz_slice__u8 const  s);
+
+#endif
+#ifndef ZZ_IMPL_slice_modules_bytes_sum
+#define ZZ_IMPL_slice_modules_bytes_sum
+uintptr_t __attribute__ ((visibility ("hidden"))) slice_modules_bytes_sum (zz_slice__u8 const  s)
+#if 0
+#else
+{
+
+#line 2 "./src/bytes.zz"
+  uintptr_t r  =     0;
+  for (
+
+#line 3 "./src/bytes.zz"
+  uintptr_t __for_index  =     0;(    __for_index <    s .len  );
+(    __for_index ++)){
+  uint8_t const *  const  b  = ( &    s .mem [     __for_index]);
+
+#line 4 "./src/bytes.zz"
+    r = (    r +    (uintptr_t)(( *    b))  );
+
+}
+
+
+#line 6 "./src/bytes.zz"
+  return     r;
+
+}
+#endif
+
+
+#endif
+
+/* slice_modules_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD___tmp_zzcheck_tests_mustpass_slice_modules_src_both_h_
+#define ZZ_FORWARD___tmp_zzcheck_tests_mustpass_slice_modules_src_both_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_zz_slice__u8
+#define ZZ_FORWARD_zz_slice__u8
+
+#line 1 "./src/bytes.zz"
+struct zz_slice__u8_t;
+typedef struct zz_slice__u8_t zz_slice__u8;
+#endif
+#ifndef ZZ_FORWARD_slice_modules_bytes_sum
+#define ZZ_FORWARD_slice_modules_bytes_sum
+#endif
+#ifndef ZZ_FORWARD_slice_modules_main_count
+#define ZZ_FORWARD_slice_modules_main_count
+#endif
+#ifndef ZZ_FORWARD_slice_modules_main_main
+#define ZZ_FORWARD_slice_modules_main_main
+#endif
+#ifndef ZZ_EXPORT___tmp_zzcheck_tests_mustpass_slice_modules_src_both_h_
+#define ZZ_EXPORT___tmp_zzcheck_tests_mustpass_slice_modules_src_both_h_
+
+#line 2 "./src/main.zz"
+#include "../../../src/both.h"
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_zz_slice__u8
+#define ZZ_EXPORT_zz_slice__u8
+
+#line 1 "./src/bytes.zz"
+struct zz_slice__u8_t {
+   uint8_t const *  mem ;
+   uintptr_t len ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_slice_modules_bytes_sum
+#define ZZ_EXPORT_slice_modules_bytes_sum
+extern uintptr_t slice_modules_bytes_sum (zz_slice__u8 const  s);
+
+#endif
+#ifndef ZZ_EXPORT_slice_modules_main_count
+#define ZZ_EXPORT_slice_modules_main_count
+
+#line 5 "./src/main.zz"
+static uintptr_t slice_modules_main_count (zz_slice__u8 const  s);
+
+#endif
+#ifndef ZZ_EXPORT_slice_modules_main_main
+#define ZZ_EXPORT_slice_modules_main_main
+
+#line 9 "./src/main.zz"
+int slice_modules_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_slice_modules_main_count
+#define ZZ_IMPL_slice_modules_main_count
+
+#line 5 "./src/main.zz"
+static uintptr_t slice_modules_main_count (zz_slice__u8 const  s)
+#if 0
+#else
+{
+
+#line 6 "./src/main.zz"
+  return     s .len;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_slice_modules_main_main
+#define ZZ_IMPL_slice_modules_main_main
+
+#line 9 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 10 "./src/main.zz"
+  uint8_t buf  [     4 ]  = {    1,    2,    3,    4,};
+
+#line 11 "./src/main.zz"
+  zz_slice__u8 const  s  =     (zz_slice__u8){
+.mem =     buf,
+
+.len =     4,
+};
+
+#line 12 "./src/main.zz"
+    printf(    "sum %zu count %zu\n",    slice_modules_bytes_sum(    s    ),    slice_modules_main_count(    s    )    );
+
+#line 13 "./src/main.zz"
+    printf(    "from c %zu\n",    both_sum(    buf,    4    )    );
+
+#line 14 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
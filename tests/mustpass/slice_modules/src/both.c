@@ -0,0 +1,8 @@
+// both headers declare the struct of u8[]. the export guard keeps it to one definition
+#include "target/include/zz/slice_modules_bytes.h"
+#include "target/include/zz/slice_modules_main.h"
+
+size_t both_sum(uint8_t const * mem, size_t len) {
+    zz_slice__u8 s = { mem, len };
+    return slice_modules_bytes_sum(s);
+}
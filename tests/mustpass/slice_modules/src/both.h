@@ -0,0 +1,4 @@
+#include <stddef.h>
+#include <stdint.h>
+
+size_t both_sum(uint8_t const * mem, size_t len);
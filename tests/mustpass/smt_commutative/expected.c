@@ -0,0 +1,83 @@
+/* smt_commutative_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_smt_commutative_main_bob
+#define ZZ_FORWARD_smt_commutative_main_bob
+#endif
+#ifndef ZZ_FORWARD_smt_commutative_main_main
+#define ZZ_FORWARD_smt_commutative_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_smt_commutative_main_bob
+#define ZZ_EXPORT_smt_commutative_main_bob
+
+#line 6 "./src/main.zz"
+void smt_commutative_main_bob (int const *  const  i, uintptr_t const  y);
+
+#endif
+#ifndef ZZ_EXPORT_smt_commutative_main_main
+#define ZZ_EXPORT_smt_commutative_main_main
+
+#line 13 "./src/main.zz"
+int smt_commutative_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_smt_commutative_main_bob
+#define ZZ_IMPL_smt_commutative_main_bob
+
+#line 6 "./src/main.zz"
+void __attribute__ ((visibility ("default"))) smt_commutative_main_bob (int const *  const  i, uintptr_t const  y)
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_smt_commutative_main_main
+#define ZZ_IMPL_smt_commutative_main_main
+
+#line 13 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 16 "./src/main.zz"
+  int const *  const  i ;
+
+#line 17 "./src/main.zz"
+  uintptr_t const  y ;
+
+#line 18 "./src/main.zz"
+;
+
+#line 20 "./src/main.zz"
+    smt_commutative_main_bob(    i,    y    );
+
+#line 22 "./src/main.zz"
+    printf(    "hello smt_lesseq\n"    );
+
+#line 23 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
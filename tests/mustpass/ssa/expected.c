@@ -0,0 +1,116 @@
+/* ssa_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_ssa_main_foo
+#define ZZ_FORWARD_ssa_main_foo
+#endif
+#ifndef ZZ_FORWARD_ssa_main_main
+#define ZZ_FORWARD_ssa_main_main
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_ssa_main_foo
+#define ZZ_EXPORT_ssa_main_foo
+
+#line 3 "./src/main.zz"
+static unsigned int ssa_main_foo (unsigned int const  b);
+
+#endif
+#ifndef ZZ_EXPORT_ssa_main_main
+#define ZZ_EXPORT_ssa_main_main
+
+#line 8 "./src/main.zz"
+int ssa_main_main (int const  argc, char const *  const *  const  argv);
+
+#endif
+#ifndef ZZ_IMPL_ssa_main_foo
+#define ZZ_IMPL_ssa_main_foo
+
+#line 3 "./src/main.zz"
+static unsigned int ssa_main_foo (unsigned int const  b)
+#if 0
+#else
+{
+
+#line 5 "./src/main.zz"
+  return (    b +    3  );
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_ssa_main_main
+#define ZZ_IMPL_ssa_main_main
+
+#line 8 "./src/main.zz"
+int main (int const  argc, char const *  const *  const  argv)
+#if 0
+#else
+{
+
+#line 10 "./src/main.zz"
+  unsigned int x  =     1;
+
+#line 11 "./src/main.zz"
+    ;
+
+#line 12 "./src/main.zz"
+  unsigned int b  = (    x ++);
+
+#line 13 "./src/main.zz"
+    ;
+
+#line 14 "./src/main.zz"
+    ;
+  for (
+
+#line 17 "./src/main.zz"
+  int i  =     0;(    i <    10  );
+(    i ++)){
+
+#line 18 "./src/main.zz"
+    ;
+
+}
+
+
+#line 21 "./src/main.zz"
+    ;
+
+#line 27 "./src/main.zz"
+  unsigned int const  y  =     ssa_main_foo(    b    );
+
+#line 28 "./src/main.zz"
+    printf(    "%u\n",    y    );
+
+#line 30 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
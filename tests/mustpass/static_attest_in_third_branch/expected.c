@@ -0,0 +1,125 @@
+/* static_attest_in_third_branch_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_static_attest_in_third_branch_main_bob
+#define ZZ_FORWARD_static_attest_in_third_branch_main_bob
+#endif
+#ifndef ZZ_FORWARD_static_attest_in_third_branch_main_doh
+#define ZZ_FORWARD_static_attest_in_third_branch_main_doh
+#endif
+#ifndef ZZ_FORWARD_static_attest_in_third_branch_main_from_str
+#define ZZ_FORWARD_static_attest_in_third_branch_main_from_str
+#endif
+#ifndef ZZ_FORWARD_static_attest_in_third_branch_main_main
+#define ZZ_FORWARD_static_attest_in_third_branch_main_main
+#endif
+#ifndef ZZ_EXPORT_static_attest_in_third_branch_main_bob
+#define ZZ_EXPORT_static_attest_in_third_branch_main_bob
+
+#endif
+#ifndef ZZ_EXPORT_static_attest_in_third_branch_main_doh
+#define ZZ_EXPORT_static_attest_in_third_branch_main_doh
+
+#line 3 "./src/main.zz"
+static void static_attest_in_third_branch_main_doh (int const *  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_static_attest_in_third_branch_main_from_str
+#define ZZ_EXPORT_static_attest_in_third_branch_main_from_str
+
+#line 9 "./src/main.zz"
+static void static_attest_in_third_branch_main_from_str (bool const  whatever, int const *  const  i);
+
+#endif
+#ifndef ZZ_EXPORT_static_attest_in_third_branch_main_main
+#define ZZ_EXPORT_static_attest_in_third_branch_main_main
+
+#line 32 "./src/main.zz"
+int static_attest_in_third_branch_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_static_attest_in_third_branch_main_doh
+#define ZZ_IMPL_static_attest_in_third_branch_main_doh
+
+#line 3 "./src/main.zz"
+static void static_attest_in_third_branch_main_doh (int const *  const  a)
+#if 0
+#else
+{
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_static_attest_in_third_branch_main_from_str
+#define ZZ_IMPL_static_attest_in_third_branch_main_from_str
+
+#line 9 "./src/main.zz"
+static void static_attest_in_third_branch_main_from_str (bool const  whatever, int const *  const  i)
+#if 0
+#else
+{
+if (
+#line 12 "./src/main.zz"
+    whatever){
+
+}
+ else {
+
+}
+
+if (
+#line 16 "./src/main.zz"
+    whatever){
+
+#line 17 "./src/main.zz"
+    static_attest_in_third_branch_main_doh(    i    );
+
+#line 18 "./src/main.zz"
+  return ;
+
+}
+
+
+#line 21 "./src/main.zz"
+;
+
+#line 23 "./src/main.zz"
+  bool const  somethingelse ;
+if (
+#line 25 "./src/main.zz"
+    somethingelse){
+
+#line 26 "./src/main.zz"
+    static_attest_in_third_branch_main_doh(    i    );
+
+}
+
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_static_attest_in_third_branch_main_main
+#define ZZ_IMPL_static_attest_in_third_branch_main_main
+
+#line 32 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 33 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
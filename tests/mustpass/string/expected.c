@@ -0,0 +1,106 @@
+/* string_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_string_main_print
+#define ZZ_FORWARD_string_main_print
+#endif
+#ifndef ZZ_FORWARD_string_main_main
+#define ZZ_FORWARD_string_main_main
+#endif
+#ifndef ZZ_FORWARD_string_main_print
+#define ZZ_FORWARD_string_main_print
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_string_main_print
+#define ZZ_EXPORT_string_main_print
+
+#line 3 "./src/main.zz"
+static void string_main_print (char const *  const  s);
+
+#endif
+#ifndef ZZ_EXPORT_string_main_main
+#define ZZ_EXPORT_string_main_main
+
+#line 7 "./src/main.zz"
+int string_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_string_main_print
+#define ZZ_EXPORT_string_main_print
+
+#line 3 "./src/main.zz"
+static void string_main_print (char const *  const  s);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_string_main_main
+#define ZZ_IMPL_string_main_main
+
+#line 7 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 8 "./src/main.zz"
+  char const *  const  bob  =     "hello";
+
+#line 9 "./src/main.zz"
+  char const  b  =     bob [     0];
+
+#line 10 "./src/main.zz"
+    printf(    "hello %c\n",    b    );
+
+#line 11 "./src/main.zz"
+    string_main_print(    bob    );
+
+#line 12 "./src/main.zz"
+    string_main_print(    "mop"    );
+
+#line 13 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_string_main_print
+#define ZZ_IMPL_string_main_print
+
+#line 3 "./src/main.zz"
+static void string_main_print (char const *  const  s)
+#if 0
+#else
+{
+
+#line 4 "./src/main.zz"
+    printf(    "hello %s\n",    s    );
+
+}
+#endif
+
+
+#endif
+
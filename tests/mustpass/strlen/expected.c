@@ -0,0 +1,106 @@
+/* strlen_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stddef_h_
+#define ZZ_FORWARD__stddef_h_
+#endif
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD__string_h_
+#define ZZ_FORWARD__string_h_
+#endif
+#ifndef ZZ_FORWARD_strlen_main_bob
+#define ZZ_FORWARD_strlen_main_bob
+#endif
+#ifndef ZZ_FORWARD_strlen_main_main
+#define ZZ_FORWARD_strlen_main_main
+#endif
+#ifndef ZZ_EXPORT__stddef_h_
+#define ZZ_EXPORT__stddef_h_
+
+#line 1 ""
+#include <stddef.h>
+
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT__string_h_
+#define ZZ_EXPORT__string_h_
+
+#line 2 "./src/main.zz"
+#include <string.h>
+
+#endif
+#ifndef ZZ_EXPORT_strlen_main_bob
+#define ZZ_EXPORT_strlen_main_bob
+
+#line 8 "./src/main.zz"
+static void strlen_main_bob (char const *  const  s, uintptr_t const  l);
+
+#endif
+#ifndef ZZ_EXPORT_strlen_main_main
+#define ZZ_EXPORT_strlen_main_main
+
+#line 14 "./src/main.zz"
+int strlen_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_strlen_main_bob
+#define ZZ_IMPL_strlen_main_bob
+
+#line 8 "./src/main.zz"
+static void strlen_main_bob (char const *  const  s, uintptr_t const  l)
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+    1;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_strlen_main_main
+#define ZZ_IMPL_strlen_main_main
+
+#line 14 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 17 "./src/main.zz"
+  char const *  const  s  =     "asdaksmdaklsmdaklsmdklad";
+
+#line 18 "./src/main.zz"
+  uintptr_t const  l  =     (uintptr_t)(    strlen(    s    ));
+
+#line 19 "./src/main.zz"
+;
+
+#line 20 "./src/main.zz"
+    strlen_main_bob(    s,    l    );
+
+#line 22 "./src/main.zz"
+    printf(    "hello strlen\n"    );
+
+#line 23 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,128 @@
+/* struct_init_inline_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_struct_init_inline_main_A
+#define ZZ_FORWARD_struct_init_inline_main_A
+
+#line 6 "./src/main.zz"
+struct struct_init_inline_main_A_t;
+typedef struct struct_init_inline_main_A_t struct_init_inline_main_A;
+#endif
+#ifndef ZZ_FORWARD_struct_init_inline_main_take
+#define ZZ_FORWARD_struct_init_inline_main_take
+#endif
+#ifndef ZZ_FORWARD_struct_init_inline_main_main
+#define ZZ_FORWARD_struct_init_inline_main_main
+#endif
+#ifndef ZZ_FORWARD_struct_init_inline_main_take
+#define ZZ_FORWARD_struct_init_inline_main_take
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_struct_init_inline_main_A
+#define ZZ_EXPORT_struct_init_inline_main_A
+
+#line 6 "./src/main.zz"
+struct struct_init_inline_main_A_t {
+
+#line 7 "./src/main.zz"
+   int x ;
+
+#line 8 "./src/main.zz"
+   int y ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_struct_init_inline_main_take
+#define ZZ_EXPORT_struct_init_inline_main_take
+
+#line 13 "./src/main.zz"
+void struct_init_inline_main_take (struct_init_inline_main_A const  a);
+
+#endif
+#ifndef ZZ_EXPORT_struct_init_inline_main_main
+#define ZZ_EXPORT_struct_init_inline_main_main
+
+#line 17 "./src/main.zz"
+int struct_init_inline_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_struct_init_inline_main_take
+#define ZZ_EXPORT_struct_init_inline_main_take
+
+#line 13 "./src/main.zz"
+void struct_init_inline_main_take (struct_init_inline_main_A const  a);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_struct_init_inline_main_main
+#define ZZ_IMPL_struct_init_inline_main_main
+
+#line 17 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 19 "./src/main.zz"
+  struct_init_inline_main_A const  a  =     (struct_init_inline_main_A){
+.x = 
+#line 20 "./src/main.zz"
+    1,
+
+.y = 
+#line 21 "./src/main.zz"
+    2,
+};
+
+#line 24 "./src/main.zz"
+    struct_init_inline_main_take(    a    );
+
+#line 25 "./src/main.zz"
+    struct_init_inline_main_take(    (struct_init_inline_main_A){
+.x = 
+#line 26 "./src/main.zz"
+    1,
+
+.y = 
+#line 27 "./src/main.zz"
+    2,
+}    );
+
+#line 31 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_struct_init_inline_main_take
+#define ZZ_IMPL_struct_init_inline_main_take
+
+#line 13 "./src/main.zz"
+void __attribute__ ((visibility ("default"))) struct_init_inline_main_take (struct_init_inline_main_A const  a)
+#if 0
+#else
+{
+
+#line 14 "./src/main.zz"
+    printf(    "%d\n",    a .y    );
+
+}
+#endif
+
+
+#endif
+
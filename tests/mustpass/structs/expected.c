@@ -0,0 +1,206 @@
+/* structs_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_structs_main_A
+#define ZZ_FORWARD_structs_main_A
+
+#line 4 "./src/main.zz"
+struct structs_main_A_t;
+typedef struct structs_main_A_t structs_main_A;
+#endif
+#ifndef ZZ_FORWARD_structs_main_V
+#define ZZ_FORWARD_structs_main_V
+
+#line 9 "./src/main.zz"
+struct structs_main_V_t;
+typedef struct structs_main_V_t structs_main_V;
+#endif
+#ifndef ZZ_FORWARD_structs_main_bob
+#define ZZ_FORWARD_structs_main_bob
+#endif
+#ifndef ZZ_FORWARD_structs_main_peter
+#define ZZ_FORWARD_structs_main_peter
+#endif
+#ifndef ZZ_FORWARD_structs_main_main
+#define ZZ_FORWARD_structs_main_main
+#endif
+#ifndef ZZ_FORWARD_structs_main_peter
+#define ZZ_FORWARD_structs_main_peter
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_structs_main_A
+#define ZZ_EXPORT_structs_main_A
+
+#line 4 "./src/main.zz"
+struct structs_main_A_t {
+
+#line 5 "./src/main.zz"
+   unsigned int a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_structs_main_V
+#define ZZ_EXPORT_structs_main_V
+
+#line 9 "./src/main.zz"
+struct structs_main_V_t {
+
+#line 10 "./src/main.zz"
+   structs_main_A a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_structs_main_bob
+#define ZZ_EXPORT_structs_main_bob
+
+#line 25 "./src/main.zz"
+void structs_main_bob (structs_main_A*  const  a);
+
+#endif
+#ifndef ZZ_EXPORT_structs_main_peter
+#define ZZ_EXPORT_structs_main_peter
+
+#line 16 "./src/main.zz"
+static void structs_main_peter (structs_main_V const *  const  v);
+
+#endif
+#ifndef ZZ_EXPORT_structs_main_main
+#define ZZ_EXPORT_structs_main_main
+
+#line 31 "./src/main.zz"
+int structs_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_structs_main_peter
+#define ZZ_EXPORT_structs_main_peter
+
+#line 16 "./src/main.zz"
+static void structs_main_peter (structs_main_V const *  const  v);
+
+#endif
+#ifndef ZZ_IMPL_structs_main_bob
+#define ZZ_IMPL_structs_main_bob
+
+#line 25 "./src/main.zz"
+void __attribute__ ((visibility ("default"))) structs_main_bob (structs_main_A*  const  a)
+#if 0
+#else
+{
+
+#line 27 "./src/main.zz"
+    a ->a =     1;
+
+#line 28 "./src/main.zz"
+    ;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_structs_main_main
+#define ZZ_IMPL_structs_main_main
+
+#line 31 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 34 "./src/main.zz"
+  structs_main_A a  = {    0,};
+
+#line 35 "./src/main.zz"
+    a .a =     3;
+
+#line 36 "./src/main.zz"
+    printf(    "typeid(a) == %s\n",    "::structs::main::A"    );
+
+#line 37 "./src/main.zz"
+    printf(    "typeid(a.a) == %s\n",    "uint"    );
+
+#line 39 "./src/main.zz"
+  structs_main_A*  const  b  = ( &    a);
+
+#line 40 "./src/main.zz"
+    ;
+
+#line 41 "./src/main.zz"
+    ;
+
+#line 42 "./src/main.zz"
+    ;
+
+#line 44 "./src/main.zz"
+    b ->a =     8;
+
+#line 46 "./src/main.zz"
+    ;
+
+#line 47 "./src/main.zz"
+    ;
+
+#line 49 "./src/main.zz"
+    structs_main_bob(    b    );
+
+#line 56 "./src/main.zz"
+  structs_main_V v ;
+
+#line 57 "./src/main.zz"
+    v .a .a =     1;
+
+#line 59 "./src/main.zz"
+    structs_main_peter(( &    v)    );
+
+#line 62 "./src/main.zz"
+    printf(    "hello structs %d\n",    a .a    );
+
+#line 63 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_structs_main_peter
+#define ZZ_IMPL_structs_main_peter
+
+#line 16 "./src/main.zz"
+static void structs_main_peter (structs_main_V const *  const  v)
+#if 0
+#else
+{
+if (((
+#line 18 "./src/main.zz"
+    v ->a .a >    0  ) &&(    v ->a .a <    2  )  )){
+
+#line 19 "./src/main.zz"
+    ;
+
+#line 20 "./src/main.zz"
+    printf(    "yep\n"    );
+
+}
+
+
+}
+#endif
+
+
+#endif
+
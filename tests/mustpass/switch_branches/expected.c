@@ -0,0 +1,92 @@
+/* switch_branches_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_switch_branches_main_main
+#define ZZ_FORWARD_switch_branches_main_main
+#endif
+#ifndef ZZ_EXPORT_switch_branches_main_main
+#define ZZ_EXPORT_switch_branches_main_main
+
+#line 3 "./src/main.zz"
+int switch_branches_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_switch_branches_main_main
+#define ZZ_IMPL_switch_branches_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 6 "./src/main.zz"
+  int const  i  =     0;
+
+#line 7 "./src/main.zz"
+  int x  =     3;
+
+#line 9 "./src/main.zz"
+switch (
+    i) {
+case 
+#line 10 "./src/main.zz"
+    0:
+{
+{
+
+#line 11 "./src/main.zz"
+    x =     16;
+
+}
+break;}
+case 
+#line 13 "./src/main.zz"
+    1:
+{
+{
+
+#line 14 "./src/main.zz"
+    x =     32;
+
+}
+break;}
+}
+
+
+#line 18 "./src/main.zz"
+    ;
+
+#line 23 "./src/main.zz"
+switch (
+    i) {
+case 
+#line 24 "./src/main.zz"
+    0:
+{
+{
+
+#line 25 "./src/main.zz"
+    x =     32;
+
+#line 26 "./src/main.zz"
+  return     0;
+
+}
+break;}
+}
+
+
+#line 31 "./src/main.zz"
+    ;
+
+#line 34 "./src/main.zz"
+  return     1;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,88 @@
+/* syntax_accesspreceeedence_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_syntax_accesspreceeedence_main_A
+#define ZZ_FORWARD_syntax_accesspreceeedence_main_A
+
+#line 5 "./src/main.zz"
+struct syntax_accesspreceeedence_main_A_t;
+typedef struct syntax_accesspreceeedence_main_A_t syntax_accesspreceeedence_main_A;
+#endif
+#ifndef ZZ_FORWARD_syntax_accesspreceeedence_main_B
+#define ZZ_FORWARD_syntax_accesspreceeedence_main_B
+
+#line 10 "./src/main.zz"
+struct syntax_accesspreceeedence_main_B_t;
+typedef struct syntax_accesspreceeedence_main_B_t syntax_accesspreceeedence_main_B;
+#endif
+#ifndef ZZ_FORWARD_syntax_accesspreceeedence_main_main
+#define ZZ_FORWARD_syntax_accesspreceeedence_main_main
+#endif
+#ifndef ZZ_EXPORT_syntax_accesspreceeedence_main_A
+#define ZZ_EXPORT_syntax_accesspreceeedence_main_A
+
+#line 5 "./src/main.zz"
+struct syntax_accesspreceeedence_main_A_t {
+
+#line 6 "./src/main.zz"
+   int a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_syntax_accesspreceeedence_main_B
+#define ZZ_EXPORT_syntax_accesspreceeedence_main_B
+
+#line 10 "./src/main.zz"
+struct syntax_accesspreceeedence_main_B_t {
+
+#line 11 "./src/main.zz"
+   syntax_accesspreceeedence_main_A a ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_syntax_accesspreceeedence_main_main
+#define ZZ_EXPORT_syntax_accesspreceeedence_main_main
+
+#line 14 "./src/main.zz"
+int syntax_accesspreceeedence_main_main ();
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_syntax_accesspreceeedence_main_main
+#define ZZ_IMPL_syntax_accesspreceeedence_main_main
+int main ()
+#if 0
+#else
+{
+
+#line 16 "./src/main.zz"
+  syntax_accesspreceeedence_main_B b ;
+
+#line 17 "./src/main.zz"
+    b .a .a =     3;
+
+#line 20 "./src/main.zz"
+  syntax_accesspreceeedence_main_B*  const  n  = ( &    b);
+
+#line 21 "./src/main.zz"
+    n ->a .a =     2;
+
+#line 25 "./src/main.zz"
+  int x  [     100 ] ;
+
+#line 26 "./src/main.zz"
+    x [     1] =     3;
+
+#line 28 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
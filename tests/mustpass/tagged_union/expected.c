@@ -0,0 +1,247 @@
+/* tagged_union_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_tagged_union_main_Shape_Circle_Body
+#define ZZ_FORWARD_tagged_union_main_Shape_Circle_Body
+
+#line 4 "./src/main.zz"
+struct tagged_union_main_Shape_Circle_Body_t;
+typedef struct tagged_union_main_Shape_Circle_Body_t tagged_union_main_Shape_Circle_Body;
+#endif
+#ifndef ZZ_FORWARD_tagged_union_main_Shape_Rect_Body
+#define ZZ_FORWARD_tagged_union_main_Shape_Rect_Body
+
+#line 7 "./src/main.zz"
+struct tagged_union_main_Shape_Rect_Body_t;
+typedef struct tagged_union_main_Shape_Rect_Body_t tagged_union_main_Shape_Rect_Body;
+#endif
+#ifndef ZZ_FORWARD_tagged_union_main_Shape
+#define ZZ_FORWARD_tagged_union_main_Shape
+
+#line 3 "./src/main.zz"
+struct tagged_union_main_Shape_t;
+typedef struct tagged_union_main_Shape_t tagged_union_main_Shape;
+#endif
+#ifndef ZZ_FORWARD_tagged_union_main_height
+#define ZZ_FORWARD_tagged_union_main_height
+#endif
+#ifndef ZZ_FORWARD_tagged_union_main_width
+#define ZZ_FORWARD_tagged_union_main_width
+#endif
+#ifndef ZZ_FORWARD_tagged_union_main_main
+#define ZZ_FORWARD_tagged_union_main_main
+#endif
+#ifndef ZZ_FORWARD_tagged_union_main_width
+#define ZZ_FORWARD_tagged_union_main_width
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_tagged_union_main_Shape_Circle_Body
+#define ZZ_EXPORT_tagged_union_main_Shape_Circle_Body
+
+#line 4 "./src/main.zz"
+struct tagged_union_main_Shape_Circle_Body_t {
+
+#line 5 "./src/main.zz"
+   uint32_t r ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_tagged_union_main_Shape_Rect_Body
+#define ZZ_EXPORT_tagged_union_main_Shape_Rect_Body
+
+#line 7 "./src/main.zz"
+struct tagged_union_main_Shape_Rect_Body_t {
+
+#line 8 "./src/main.zz"
+   uint32_t w ;
+
+#line 9 "./src/main.zz"
+   uint32_t h ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_tagged_union_main_Shape
+#define ZZ_EXPORT_tagged_union_main_Shape
+
+#line 3 "./src/main.zz"
+enum {
+    tagged_union_main_Shape_Circle = 0,
+    tagged_union_main_Shape_Rect = 1,
+    tagged_union_main_Shape_Empty = 2,
+};
+struct tagged_union_main_Shape_t {
+   unsigned int tag ;
+   union {
+
+#line 4 "./src/main.zz"
+   tagged_union_main_Shape_Circle_Body Circle ;
+
+#line 7 "./src/main.zz"
+   tagged_union_main_Shape_Rect_Body Rect ;
+   };
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_tagged_union_main_height
+#define ZZ_EXPORT_tagged_union_main_height
+
+#line 29 "./src/main.zz"
+static uint32_t tagged_union_main_height (tagged_union_main_Shape const *  const  s);
+
+#endif
+#ifndef ZZ_EXPORT_tagged_union_main_width
+#define ZZ_EXPORT_tagged_union_main_width
+
+#line 14 "./src/main.zz"
+static uint32_t tagged_union_main_width (tagged_union_main_Shape const *  const  s);
+
+#endif
+#ifndef ZZ_EXPORT_tagged_union_main_main
+#define ZZ_EXPORT_tagged_union_main_main
+
+#line 36 "./src/main.zz"
+int tagged_union_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_tagged_union_main_width
+#define ZZ_EXPORT_tagged_union_main_width
+
+#line 14 "./src/main.zz"
+static uint32_t tagged_union_main_width (tagged_union_main_Shape const *  const  s);
+
+#endif
+#ifndef ZZ_IMPL_tagged_union_main_height
+#define ZZ_IMPL_tagged_union_main_height
+
+#line 29 "./src/main.zz"
+static uint32_t tagged_union_main_height (tagged_union_main_Shape const *  const  s)
+#if 0
+#else
+{
+if ((
+#line 30 "./src/main.zz"
+    s ->tag ==    tagged_union_main_Shape_Rect  )){
+
+#line 31 "./src/main.zz"
+  return     s ->Rect .h;
+
+}
+
+
+#line 33 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_tagged_union_main_main
+#define ZZ_IMPL_tagged_union_main_main
+
+#line 36 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 37 "./src/main.zz"
+  tagged_union_main_Shape s  =     (tagged_union_main_Shape){
+.tag =     tagged_union_main_Shape_Rect,
+};
+
+#line 38 "./src/main.zz"
+    s .Rect .w =     2;
+
+#line 39 "./src/main.zz"
+    s .Rect .h =     3;
+
+#line 41 "./src/main.zz"
+    printf(    "hello tagged union %u %u\n",    tagged_union_main_width(( &    s)    ),    tagged_union_main_height(( &    s)    )    );
+
+#line 42 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_tagged_union_main_width
+#define ZZ_IMPL_tagged_union_main_width
+
+#line 14 "./src/main.zz"
+static uint32_t tagged_union_main_width (tagged_union_main_Shape const *  const  s)
+#if 0
+#else
+{
+
+#line 15 "./src/main.zz"
+switch (
+( *    s) .tag) {
+case 
+#line 16 "./src/main.zz"
+    tagged_union_main_Shape_Circle:
+{
+{
+  tagged_union_main_Shape_Circle_Body const  c  = (
+#line 15 "./src/main.zz"
+ *    s) .Circle;
+
+#line 17 "./src/main.zz"
+  return     c .r;
+
+}
+break;}
+case 
+#line 19 "./src/main.zz"
+    tagged_union_main_Shape_Rect:
+{
+{
+  tagged_union_main_Shape_Rect_Body const  r  = (
+#line 15 "./src/main.zz"
+ *    s) .Rect;
+
+#line 20 "./src/main.zz"
+  return     r .w;
+
+}
+break;}
+case 
+#line 22 "./src/main.zz"
+    tagged_union_main_Shape_Empty:
+{
+{
+
+#line 23 "./src/main.zz"
+  return     0;
+
+}
+break;}
+}
+
+
+#line 26 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,139 @@
+/* tail_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_tail_main_Bob
+#define ZZ_FORWARD_tail_main_Bob
+
+#line 5 "./src/main.zz"
+struct tail_main_Bob_t;
+typedef struct tail_main_Bob_t tail_main_Bob;
+#endif
+#ifndef ZZ_FORWARD_tail_main_Bob_100
+#define ZZ_FORWARD_tail_main_Bob_100
+struct tail_main_Bob_100_t;
+typedef struct tail_main_Bob_100_t tail_main_Bob_100;
+#endif
+#ifndef ZZ_FORWARD_tail_main_whutup
+#define ZZ_FORWARD_tail_main_whutup
+#endif
+#ifndef ZZ_FORWARD_tail_main_main
+#define ZZ_FORWARD_tail_main_main
+#endif
+#ifndef ZZ_FORWARD_tail_main_whutup
+#define ZZ_FORWARD_tail_main_whutup
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_tail_main_Bob
+#define ZZ_EXPORT_tail_main_Bob
+
+#line 5 "./src/main.zz"
+struct tail_main_Bob_t {
+
+#line 6 "./src/main.zz"
+   int a ;
+
+#line 7 "./src/main.zz"
+   int b[] ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_tail_main_Bob_100_100
+#define ZZ_EXPORT_tail_main_Bob_100_100
+
+#line 5 "./src/main.zz"
+struct tail_main_Bob_100_t {
+
+#line 6 "./src/main.zz"
+   int a ;
+
+#line 7 "./src/main.zz"
+   int b[100] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_tail_main_whutup
+#define ZZ_EXPORT_tail_main_whutup
+
+#line 11 "./src/main.zz"
+static void tail_main_whutup (tail_main_Bob const *  const  bob, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT_tail_main_main
+#define ZZ_EXPORT_tail_main_main
+
+#line 19 "./src/main.zz"
+int tail_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_tail_main_whutup
+#define ZZ_EXPORT_tail_main_whutup
+
+#line 11 "./src/main.zz"
+static void tail_main_whutup (tail_main_Bob const *  const  bob, uintptr_t const  t);
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_tail_main_main
+#define ZZ_IMPL_tail_main_main
+
+#line 19 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 20 "./src/main.zz"
+  tail_main_Bob_100 b  = {    0,};
+
+#line 21 "./src/main.zz"
+    b .b [     99] =     99;
+
+#line 22 "./src/main.zz"
+    tail_main_whutup(    (tail_main_Bob* )(( &    b)),
+#line 20 "./src/main.zz"
+    100    );
+
+#line 23 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_tail_main_whutup
+#define ZZ_IMPL_tail_main_whutup
+
+#line 11 "./src/main.zz"
+static void tail_main_whutup (tail_main_Bob const *  const  bob, uintptr_t const  t)
+#if 0
+#else
+{
+
+#line 14 "./src/main.zz"
+    printf(    "hello bob and a %zu integers\n",    t    );
+
+#line 15 "./src/main.zz"
+  int const  x  =     bob ->b [ (    t -    1  )];
+
+#line 16 "./src/main.zz"
+    printf(    " the %zu'th integer is %d\n",(    t -    1  ),    x    );
+
+}
+#endif
+
+
+#endif
+
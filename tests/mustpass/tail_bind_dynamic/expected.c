@@ -0,0 +1,144 @@
+/* tail_bind_dynamic_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__assert_h_
+#define ZZ_FORWARD__assert_h_
+#endif
+#ifndef ZZ_FORWARD_tail_bind_dynamic_main_A
+#define ZZ_FORWARD_tail_bind_dynamic_main_A
+
+#line 7 "./src/main.zz"
+struct tail_bind_dynamic_main_A_t;
+typedef struct tail_bind_dynamic_main_A_t tail_bind_dynamic_main_A;
+#endif
+#ifndef ZZ_FORWARD_tail_bind_dynamic_main_gimmetail
+#define ZZ_FORWARD_tail_bind_dynamic_main_gimmetail
+#endif
+#ifndef ZZ_FORWARD_tail_bind_dynamic_main_use_as_A
+#define ZZ_FORWARD_tail_bind_dynamic_main_use_as_A
+#endif
+#ifndef ZZ_FORWARD_tail_bind_dynamic_main_main
+#define ZZ_FORWARD_tail_bind_dynamic_main_main
+#endif
+#ifndef ZZ_FORWARD_tail_bind_dynamic_main_use_as_A
+#define ZZ_FORWARD_tail_bind_dynamic_main_use_as_A
+#endif
+#ifndef ZZ_EXPORT__assert_h_
+#define ZZ_EXPORT__assert_h_
+
+#line 2 "./src/main.zz"
+#include <assert.h>
+
+#endif
+#ifndef ZZ_EXPORT_tail_bind_dynamic_main_A
+#define ZZ_EXPORT_tail_bind_dynamic_main_A
+
+#line 7 "./src/main.zz"
+struct tail_bind_dynamic_main_A_t {
+
+#line 8 "./src/main.zz"
+   uint8_t blah ;
+
+#line 9 "./src/main.zz"
+   uint8_t mem[] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_tail_bind_dynamic_main_gimmetail
+#define ZZ_EXPORT_tail_bind_dynamic_main_gimmetail
+
+#line 14 "./src/main.zz"
+uintptr_t tail_bind_dynamic_main_gimmetail (tail_bind_dynamic_main_A const *  const  a, uintptr_t const  t);
+
+#endif
+#ifndef ZZ_EXPORT_tail_bind_dynamic_main_use_as_A
+#define ZZ_EXPORT_tail_bind_dynamic_main_use_as_A
+
+#line 20 "./src/main.zz"
+uintptr_t tail_bind_dynamic_main_use_as_A (uint8_t const *  const  mem, uintptr_t const  l);
+
+#endif
+#ifndef ZZ_EXPORT_tail_bind_dynamic_main_main
+#define ZZ_EXPORT_tail_bind_dynamic_main_main
+
+#line 29 "./src/main.zz"
+int tail_bind_dynamic_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_tail_bind_dynamic_main_use_as_A
+#define ZZ_EXPORT_tail_bind_dynamic_main_use_as_A
+
+#line 20 "./src/main.zz"
+uintptr_t tail_bind_dynamic_main_use_as_A (uint8_t const *  const  mem, uintptr_t const  l);
+
+#endif
+#ifndef ZZ_IMPL_tail_bind_dynamic_main_gimmetail
+#define ZZ_IMPL_tail_bind_dynamic_main_gimmetail
+
+#line 14 "./src/main.zz"
+uintptr_t __attribute__ ((visibility ("default"))) tail_bind_dynamic_main_gimmetail (tail_bind_dynamic_main_A const *  const  a, uintptr_t const  t)
+#if 0
+#else
+{
+
+#line 16 "./src/main.zz"
+  return     t;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_tail_bind_dynamic_main_main
+#define ZZ_IMPL_tail_bind_dynamic_main_main
+
+#line 29 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 31 "./src/main.zz"
+  uint8_t const  mem  [     100 ] ;
+
+#line 32 "./src/main.zz"
+  uintptr_t const  tail  =     tail_bind_dynamic_main_use_as_A(    mem,    100    );
+
+#line 34 "./src/main.zz"
+    assert((    tail ==    100  )    );
+
+#line 36 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_tail_bind_dynamic_main_use_as_A
+#define ZZ_IMPL_tail_bind_dynamic_main_use_as_A
+
+#line 20 "./src/main.zz"
+uintptr_t __attribute__ ((visibility ("default"))) tail_bind_dynamic_main_use_as_A (uint8_t const *  const  mem, uintptr_t const  l)
+#if 0
+#else
+{
+
+#line 23 "./src/main.zz"
+  tail_bind_dynamic_main_A const *  const  a  =     (tail_bind_dynamic_main_A const * )(    mem);
+
+#line 26 "./src/main.zz"
+  return     tail_bind_dynamic_main_gimmetail(    (tail_bind_dynamic_main_A const * )(    a),
+#line 23 "./src/main.zz"
+    l    );
+
+}
+#endif
+
+
+#endif
+
@@ -0,0 +1,194 @@
+/* tail_multiple_in_struct_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__assert_h_
+#define ZZ_FORWARD__assert_h_
+#endif
+#ifndef ZZ_FORWARD_tail_multiple_in_struct_main_B
+#define ZZ_FORWARD_tail_multiple_in_struct_main_B
+
+#line 3 "./src/main.zz"
+struct tail_multiple_in_struct_main_B_t;
+typedef struct tail_multiple_in_struct_main_B_t tail_multiple_in_struct_main_B;
+#endif
+#ifndef ZZ_FORWARD_tail_multiple_in_struct_main_B_1
+#define ZZ_FORWARD_tail_multiple_in_struct_main_B_1
+struct tail_multiple_in_struct_main_B_1_t;
+typedef struct tail_multiple_in_struct_main_B_1_t tail_multiple_in_struct_main_B_1;
+#endif
+#ifndef ZZ_FORWARD_tail_multiple_in_struct_main_A
+#define ZZ_FORWARD_tail_multiple_in_struct_main_A
+
+#line 9 "./src/main.zz"
+struct tail_multiple_in_struct_main_A_t;
+typedef struct tail_multiple_in_struct_main_A_t tail_multiple_in_struct_main_A;
+#endif
+#ifndef ZZ_FORWARD_tail_multiple_in_struct_main_A_100
+#define ZZ_FORWARD_tail_multiple_in_struct_main_A_100
+struct tail_multiple_in_struct_main_A_100_t;
+typedef struct tail_multiple_in_struct_main_A_100_t tail_multiple_in_struct_main_A_100;
+#endif
+#ifndef ZZ_FORWARD_tail_multiple_in_struct_main_assert_correct_tailx
+#define ZZ_FORWARD_tail_multiple_in_struct_main_assert_correct_tailx
+#endif
+#ifndef ZZ_FORWARD_tail_multiple_in_struct_main_assert_correct_taily
+#define ZZ_FORWARD_tail_multiple_in_struct_main_assert_correct_taily
+#endif
+#ifndef ZZ_FORWARD_tail_multiple_in_struct_main_main
+#define ZZ_FORWARD_tail_multiple_in_struct_main_main
+#endif
+#ifndef ZZ_EXPORT__assert_h_
+#define ZZ_EXPORT__assert_h_
+
+#line 1 "./src/main.zz"
+#include <assert.h>
+
+#endif
+#ifndef ZZ_EXPORT_tail_multiple_in_struct_main_B
+#define ZZ_EXPORT_tail_multiple_in_struct_main_B
+
+#line 3 "./src/main.zz"
+struct tail_multiple_in_struct_main_B_t {
+
+#line 4 "./src/main.zz"
+   int bla ;
+
+#line 5 "./src/main.zz"
+   uint8_t mem[] ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_tail_multiple_in_struct_main_B_1_1
+#define ZZ_EXPORT_tail_multiple_in_struct_main_B_1_1
+
+#line 3 "./src/main.zz"
+struct tail_multiple_in_struct_main_B_1_t {
+
+#line 4 "./src/main.zz"
+   int bla ;
+
+#line 5 "./src/main.zz"
+   uint8_t mem[1] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_tail_multiple_in_struct_main_A
+#define ZZ_EXPORT_tail_multiple_in_struct_main_A
+
+#line 9 "./src/main.zz"
+struct tail_multiple_in_struct_main_A_t {
+
+#line 10 "./src/main.zz"
+   tail_multiple_in_struct_main_B_1 x ;
+
+#line 11 "./src/main.zz"
+   tail_multiple_in_struct_main_B y ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_tail_multiple_in_struct_main_A_100_100
+#define ZZ_EXPORT_tail_multiple_in_struct_main_A_100_100
+
+#line 9 "./src/main.zz"
+struct tail_multiple_in_struct_main_A_100_t {
+
+#line 10 "./src/main.zz"
+   tail_multiple_in_struct_main_B_1 x ;
+
+#line 11 "./src/main.zz"
+   tail_multiple_in_struct_main_B y ;
+   uint8_t _____tail [100];
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_tail_multiple_in_struct_main_assert_correct_tailx
+#define ZZ_EXPORT_tail_multiple_in_struct_main_assert_correct_tailx
+
+#line 14 "./src/main.zz"
+void tail_multiple_in_struct_main_assert_correct_tailx (tail_multiple_in_struct_main_B const *  const  b, uintptr_t const  bt);
+
+#endif
+#ifndef ZZ_EXPORT_tail_multiple_in_struct_main_assert_correct_taily
+#define ZZ_EXPORT_tail_multiple_in_struct_main_assert_correct_taily
+
+#line 18 "./src/main.zz"
+void tail_multiple_in_struct_main_assert_correct_taily (tail_multiple_in_struct_main_B const *  const  b, uintptr_t const  bt);
+
+#endif
+#ifndef ZZ_EXPORT_tail_multiple_in_struct_main_main
+#define ZZ_EXPORT_tail_multiple_in_struct_main_main
+
+#line 22 "./src/main.zz"
+int tail_multiple_in_struct_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_tail_multiple_in_struct_main_assert_correct_tailx
+#define ZZ_IMPL_tail_multiple_in_struct_main_assert_correct_tailx
+
+#line 14 "./src/main.zz"
+void __attribute__ ((visibility ("default"))) tail_multiple_in_struct_main_assert_correct_tailx (tail_multiple_in_struct_main_B const *  const  b, uintptr_t const  bt)
+#if 0
+#else
+{
+
+#line 15 "./src/main.zz"
+    assert((    bt ==    1  )    );
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_tail_multiple_in_struct_main_assert_correct_taily
+#define ZZ_IMPL_tail_multiple_in_struct_main_assert_correct_taily
+
+#line 18 "./src/main.zz"
+void __attribute__ ((visibility ("default"))) tail_multiple_in_struct_main_assert_correct_taily (tail_multiple_in_struct_main_B const *  const  b, uintptr_t const  bt)
+#if 0
+#else
+{
+
+#line 19 "./src/main.zz"
+    assert((    bt ==    100  )    );
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_tail_multiple_in_struct_main_main
+#define ZZ_IMPL_tail_multiple_in_struct_main_main
+
+#line 22 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 24 "./src/main.zz"
+  tail_multiple_in_struct_main_A_100 const  a ;
+
+#line 26 "./src/main.zz"
+    tail_multiple_in_struct_main_assert_correct_tailx(    (tail_multiple_in_struct_main_B const * )(( &    a .x)),
+#line 10 "./src/main.zz"
+    1    );
+
+#line 27 "./src/main.zz"
+    tail_multiple_in_struct_main_assert_correct_taily(    (tail_multiple_in_struct_main_B const * )(( &    a .y)),
+#line 24 "./src/main.zz"
+    100    );
+
+#line 29 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,199 @@
+/* tail_static_in_struct_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_tail_static_in_struct_main_A
+#define ZZ_FORWARD_tail_static_in_struct_main_A
+
+#line 4 "./src/main.zz"
+struct tail_static_in_struct_main_A_t;
+typedef struct tail_static_in_struct_main_A_t tail_static_in_struct_main_A;
+#endif
+#ifndef ZZ_FORWARD_tail_static_in_struct_main_A_100
+#define ZZ_FORWARD_tail_static_in_struct_main_A_100
+struct tail_static_in_struct_main_A_100_t;
+typedef struct tail_static_in_struct_main_A_100_t tail_static_in_struct_main_A_100;
+#endif
+#ifndef ZZ_FORWARD_tail_static_in_struct_main_A_200
+#define ZZ_FORWARD_tail_static_in_struct_main_A_200
+struct tail_static_in_struct_main_A_200_t;
+typedef struct tail_static_in_struct_main_A_200_t tail_static_in_struct_main_A_200;
+#endif
+#ifndef ZZ_FORWARD_tail_static_in_struct_main_B
+#define ZZ_FORWARD_tail_static_in_struct_main_B
+
+#line 14 "./src/main.zz"
+struct tail_static_in_struct_main_B_t;
+typedef struct tail_static_in_struct_main_B_t tail_static_in_struct_main_B;
+#endif
+#ifndef ZZ_FORWARD_tail_static_in_struct_main_bob
+#define ZZ_FORWARD_tail_static_in_struct_main_bob
+#endif
+#ifndef ZZ_FORWARD_tail_static_in_struct_main_foh
+#define ZZ_FORWARD_tail_static_in_struct_main_foh
+#endif
+#ifndef ZZ_FORWARD_tail_static_in_struct_main_main
+#define ZZ_FORWARD_tail_static_in_struct_main_main
+#endif
+#ifndef ZZ_EXPORT_tail_static_in_struct_main_A
+#define ZZ_EXPORT_tail_static_in_struct_main_A
+
+#line 4 "./src/main.zz"
+struct tail_static_in_struct_main_A_t {
+
+#line 5 "./src/main.zz"
+   int bla ;
+
+#line 6 "./src/main.zz"
+   int mem[] ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_tail_static_in_struct_main_A_100_100
+#define ZZ_EXPORT_tail_static_in_struct_main_A_100_100
+
+#line 4 "./src/main.zz"
+struct tail_static_in_struct_main_A_100_t {
+
+#line 5 "./src/main.zz"
+   int bla ;
+
+#line 6 "./src/main.zz"
+   int mem[100] ;
+}
+;
+#endif
+#ifndef ZZ_EXPORT_tail_static_in_struct_main_A_200_200
+#define ZZ_EXPORT_tail_static_in_struct_main_A_200_200
+
+#line 4 "./src/main.zz"
+struct tail_static_in_struct_main_A_200_t {
+
+#line 5 "./src/main.zz"
+   int bla ;
+
+#line 6 "./src/main.zz"
+   int mem[200] ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_tail_static_in_struct_main_B
+#define ZZ_EXPORT_tail_static_in_struct_main_B
+
+#line 14 "./src/main.zz"
+struct tail_static_in_struct_main_B_t {
+
+#line 15 "./src/main.zz"
+   tail_static_in_struct_main_A_100 a1 ;
+
+#line 16 "./src/main.zz"
+   tail_static_in_struct_main_A_200 a2 ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_tail_static_in_struct_main_bob
+#define ZZ_EXPORT_tail_static_in_struct_main_bob
+
+#line 20 "./src/main.zz"
+static void tail_static_in_struct_main_bob (tail_static_in_struct_main_B const *  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_tail_static_in_struct_main_foh
+#define ZZ_EXPORT_tail_static_in_struct_main_foh
+
+#line 10 "./src/main.zz"
+static void tail_static_in_struct_main_foh (tail_static_in_struct_main_A const *  const  self, uintptr_t const  at);
+
+#endif
+#ifndef ZZ_EXPORT_tail_static_in_struct_main_main
+#define ZZ_EXPORT_tail_static_in_struct_main_main
+
+#line 27 "./src/main.zz"
+int tail_static_in_struct_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_tail_static_in_struct_main_bob
+#define ZZ_IMPL_tail_static_in_struct_main_bob
+
+#line 20 "./src/main.zz"
+static void tail_static_in_struct_main_bob (tail_static_in_struct_main_B const *  const  self)
+#if 0
+#else
+{
+
+#line 21 "./src/main.zz"
+    tail_static_in_struct_main_foh(    (tail_static_in_struct_main_A const * )(( &    self ->a1)),
+#line 15 "./src/main.zz"
+    100    );
+
+#line 22 "./src/main.zz"
+    tail_static_in_struct_main_foh(    (tail_static_in_struct_main_A const * )(( &    self ->a2)),
+#line 16 "./src/main.zz"
+    200    );
+
+#line 23 "./src/main.zz"
+    ;
+
+#line 24 "./src/main.zz"
+    ;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_tail_static_in_struct_main_foh
+#define ZZ_IMPL_tail_static_in_struct_main_foh
+
+#line 10 "./src/main.zz"
+static void tail_static_in_struct_main_foh (tail_static_in_struct_main_A const *  const  self, uintptr_t const  at)
+#if 0
+#else
+{
+
+#line 11 "./src/main.zz"
+    ;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_tail_static_in_struct_main_main
+#define ZZ_IMPL_tail_static_in_struct_main_main
+
+#line 27 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 28 "./src/main.zz"
+  tail_static_in_struct_main_B const  b ;
+
+#line 30 "./src/main.zz"
+    tail_static_in_struct_main_foh(    (tail_static_in_struct_main_A const * )(( &    b .a1)),
+#line 15 "./src/main.zz"
+    100    );
+
+#line 31 "./src/main.zz"
+    tail_static_in_struct_main_foh(    (tail_static_in_struct_main_A const * )(( &    b .a2)),
+#line 16 "./src/main.zz"
+    200    );
+
+#line 33 "./src/main.zz"
+    tail_static_in_struct_main_bob(( &    b)    );
+
+#line 35 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
@@ -0,0 +1,67 @@
+/* type_elision_basic_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD_type_elision_basic_main_bob
+#define ZZ_FORWARD_type_elision_basic_main_bob
+#endif
+#ifndef ZZ_FORWARD_type_elision_basic_main_main
+#define ZZ_FORWARD_type_elision_basic_main_main
+#endif
+#ifndef ZZ_EXPORT_type_elision_basic_main_bob
+#define ZZ_EXPORT_type_elision_basic_main_bob
+
+#line 3 "./src/main.zz"
+static int type_elision_basic_main_bob ();
+
+#endif
+#ifndef ZZ_EXPORT_type_elision_basic_main_main
+#define ZZ_EXPORT_type_elision_basic_main_main
+
+#line 7 "./src/main.zz"
+int type_elision_basic_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_type_elision_basic_main_bob
+#define ZZ_IMPL_type_elision_basic_main_bob
+
+#line 3 "./src/main.zz"
+static int type_elision_basic_main_bob ()
+#if 0
+#else
+{
+
+#line 4 "./src/main.zz"
+  return     1;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_type_elision_basic_main_main
+#define ZZ_IMPL_type_elision_basic_main_main
+
+#line 7 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 9 "./src/main.zz"
+  int a  =     type_elision_basic_main_bob(    );
+
+#line 10 "./src/main.zz"
+    a =     0;
+
+#line 12 "./src/main.zz"
+  return     a;
+
+}
+#endif
+
+
+#endif
+#endif
+
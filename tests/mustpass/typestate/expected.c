@@ -0,0 +1,204 @@
+/* taint_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_taint_main_Socket
+#define ZZ_FORWARD_taint_main_Socket
+
+#line 5 "./src/main.zz"
+struct taint_main_Socket_t;
+typedef struct taint_main_Socket_t taint_main_Socket;
+#endif
+#ifndef ZZ_FORWARD_taint_main_isopen
+#define ZZ_FORWARD_taint_main_isopen
+#endif
+#ifndef ZZ_FORWARD_taint_main_close
+#define ZZ_FORWARD_taint_main_close
+#endif
+#ifndef ZZ_FORWARD_taint_main_isopen
+#define ZZ_FORWARD_taint_main_isopen
+#endif
+#ifndef ZZ_FORWARD_taint_main_open
+#define ZZ_FORWARD_taint_main_open
+#endif
+#ifndef ZZ_FORWARD_taint_main_read
+#define ZZ_FORWARD_taint_main_read
+#endif
+#ifndef ZZ_FORWARD_taint_main_main
+#define ZZ_FORWARD_taint_main_main
+#endif
+#ifndef ZZ_FORWARD_taint_main_open
+#define ZZ_FORWARD_taint_main_open
+#endif
+#ifndef ZZ_FORWARD_taint_main_read
+#define ZZ_FORWARD_taint_main_read
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_taint_main_Socket
+#define ZZ_EXPORT_taint_main_Socket
+
+#line 5 "./src/main.zz"
+struct taint_main_Socket_t {
+
+#line 6 "./src/main.zz"
+   int fd ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_taint_main_isopen
+#define ZZ_EXPORT_taint_main_isopen
+
+#endif
+#ifndef ZZ_EXPORT_taint_main_close
+#define ZZ_EXPORT_taint_main_close
+
+#line 24 "./src/main.zz"
+static void taint_main_close (taint_main_Socket*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_taint_main_isopen
+#define ZZ_EXPORT_taint_main_isopen
+
+#endif
+#ifndef ZZ_EXPORT_taint_main_open
+#define ZZ_EXPORT_taint_main_open
+
+#line 9 "./src/main.zz"
+static void taint_main_open (taint_main_Socket*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_taint_main_read
+#define ZZ_EXPORT_taint_main_read
+
+#line 17 "./src/main.zz"
+static void taint_main_read (taint_main_Socket*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_taint_main_main
+#define ZZ_EXPORT_taint_main_main
+
+#line 33 "./src/main.zz"
+int taint_main_main ();
+
+#endif
+#ifndef ZZ_EXPORT_taint_main_open
+#define ZZ_EXPORT_taint_main_open
+
+#line 9 "./src/main.zz"
+static void taint_main_open (taint_main_Socket*  const  self);
+
+#endif
+#ifndef ZZ_EXPORT_taint_main_read
+#define ZZ_EXPORT_taint_main_read
+
+#line 17 "./src/main.zz"
+static void taint_main_read (taint_main_Socket*  const  self);
+
+#endif
+#ifndef ZZ_IMPL_taint_main_close
+#define ZZ_IMPL_taint_main_close
+
+#line 24 "./src/main.zz"
+static void taint_main_close (taint_main_Socket*  const  self)
+#if 0
+#else
+{
+
+#line 29 "./src/main.zz"
+( *    self) =     (taint_main_Socket){
+.fd =     0,
+};
+
+#line 30 "./src/main.zz"
+;
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_taint_main_main
+#define ZZ_IMPL_taint_main_main
+
+#line 33 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 35 "./src/main.zz"
+  taint_main_Socket sock  = {    0,};
+
+#line 36 "./src/main.zz"
+    taint_main_open(( &    sock)    );
+
+#line 37 "./src/main.zz"
+    taint_main_read(( &    sock)    );
+
+#line 38 "./src/main.zz"
+    taint_main_close(( &    sock)    );
+
+#line 40 "./src/main.zz"
+    printf(    "hello taint\n"    );
+
+#line 41 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+#ifndef ZZ_IMPL_taint_main_open
+#define ZZ_IMPL_taint_main_open
+
+#line 9 "./src/main.zz"
+static void taint_main_open (taint_main_Socket*  const  self)
+#if 0
+#else
+{
+
+#line 12 "./src/main.zz"
+;
+
+#line 13 "./src/main.zz"
+    ;
+
+#line 14 "./src/main.zz"
+    self ->fd =     2;
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_taint_main_read
+#define ZZ_IMPL_taint_main_read
+
+#line 17 "./src/main.zz"
+static void taint_main_read (taint_main_Socket*  const  self)
+#if 0
+#else
+{
+
+#line 21 "./src/main.zz"
+  int const  bla  =     self ->fd;
+
+}
+#endif
+
+
+#endif
+
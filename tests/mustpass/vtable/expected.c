@@ -0,0 +1,146 @@
+/* fn_main.c */
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#ifndef ZZ_FORWARD__stdio_h_
+#define ZZ_FORWARD__stdio_h_
+#endif
+#ifndef ZZ_FORWARD_fn_main_add_t
+#define ZZ_FORWARD_fn_main_add_t
+#endif
+#ifndef ZZ_FORWARD_fn_main_Math
+#define ZZ_FORWARD_fn_main_Math
+
+#line 11 "./src/main.zz"
+struct fn_main_Math_t;
+typedef struct fn_main_Math_t fn_main_Math;
+#endif
+#ifndef ZZ_FORWARD_fn_main_add
+#define ZZ_FORWARD_fn_main_add
+#endif
+#ifndef ZZ_FORWARD_fn_main_bla
+#define ZZ_FORWARD_fn_main_bla
+#endif
+#ifndef ZZ_FORWARD_fn_main_main
+#define ZZ_FORWARD_fn_main_main
+#endif
+#ifndef ZZ_EXPORT__stdio_h_
+#define ZZ_EXPORT__stdio_h_
+
+#line 1 "./src/main.zz"
+#include <stdio.h>
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_add_t
+#define ZZ_EXPORT_fn_main_add_t
+
+#line 4 "./src/main.zz"
+#if !defined(ZZ_HAS_DEFINED_CLOSURE_fn_main_add_t)
+#define ZZ_HAS_DEFINED_CLOSURE_fn_main_add_t 1
+typedef struct {
+    void *ctx;
+    int (*fn) (int const  a, int const  b, void * _ctx);
+} fn_main_add_t;
+#endif // !defined(ZZ_HAS_DEFINED_CLOSURE_fn_main_add_t)
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_Math
+#define ZZ_EXPORT_fn_main_Math
+
+#line 11 "./src/main.zz"
+struct fn_main_Math_t {
+
+#line 12 "./src/main.zz"
+   fn_main_add_t adder ;
+}
+;
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_add
+#define ZZ_EXPORT_fn_main_add
+
+#line 7 "./src/main.zz"
+static int fn_main_add (int const  a, int const  b);
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_bla
+#define ZZ_EXPORT_fn_main_bla
+
+#line 15 "./src/main.zz"
+static int fn_main_bla (fn_main_Math const *  const  m);
+
+#endif
+#ifndef ZZ_EXPORT_fn_main_main
+#define ZZ_EXPORT_fn_main_main
+
+#line 21 "./src/main.zz"
+int fn_main_main ();
+
+#endif
+#ifndef ZZ_IMPL_fn_main_add
+#define ZZ_IMPL_fn_main_add
+
+#line 7 "./src/main.zz"
+static int fn_main_add (int const  a, int const  b)
+#if 0
+#else
+{
+
+#line 8 "./src/main.zz"
+  return (    a +    b  );
+
+}
+#endif
+
+
+#endif
+#ifndef ZZ_IMPL_fn_main_bla
+#define ZZ_IMPL_fn_main_bla
+
+#line 15 "./src/main.zz"
+static int fn_main_bla (fn_main_Math const *  const  m)
+#if 0
+#else
+{
+
+#line 18 "./src/main.zz"
+  return     m ->adder .fn(    1,    2,    m ->adder .ctx    );
+
+}
+#endif
+
+
+#endif
+#if !defined(ZZ_TEST) && !defined(ZZ_BENCH) && !defined(ZZ_FUZZ) && !defined(ZZ_PROPERTY)
+#ifndef ZZ_IMPL_fn_main_main
+#define ZZ_IMPL_fn_main_main
+
+#line 21 "./src/main.zz"
+int main ()
+#if 0
+#else
+{
+
+#line 22 "./src/main.zz"
+  fn_main_Math m ;
+
+#line 23 "./src/main.zz"
+    m .adder =     (fn_main_add_t){
+.fn =     (void const * )(    fn_main_add),
+
+.ctx =     0,
+};
+
+#line 25 "./src/main.zz"
+    printf(    "hello %d\n",    fn_main_bla(( &    m)    )    );
+
+#line 26 "./src/main.zz"
+  return     0;
+
+}
+#endif
+
+
+#endif
+#endif
+
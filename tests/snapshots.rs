@@ -0,0 +1,211 @@
+//! runs the compiler in-process on every project in tests/mustpass and tests/mustfail
+//! and compares its diagnostics and emitted c against the expected.json and expected.c
+//! checked in next to each project.
+//!
+//! run with ZZ_BLESS=1 to write the current output as the new expectation.
+//! ZZ_SNAPSHOT=<name> only runs cases with name in their path.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Diagnostic {
+    message: String,
+    file: String,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Outcome {
+    exit: i32,
+    diagnostics: Vec<Diagnostic>,
+}
+
+fn cases(kind: &str) -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join(kind);
+    let filter = std::env::var("ZZ_SNAPSHOT").ok();
+    let mut r: Vec<PathBuf> = fs::read_dir(&dir)
+        .expect(&format!("cannot read {:?}", dir))
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.join("zz.toml").exists())
+        .filter(|p| match &filter {
+            Some(f) => p.to_string_lossy().contains(f.as_str()),
+            None => true,
+        })
+        .collect();
+    r.sort();
+    r
+}
+
+/// absolute paths differ between machines
+fn normalize(s: &str, root: &Path) -> String {
+    s.replace(&root.to_string_lossy().to_string(), ".")
+        .replace(env!("CARGO_MANIFEST_DIR"), "$ZZ")
+}
+
+/// build one project up to the emitted c, the way zz check would
+fn run(root: &Path) -> Result<Outcome, String> {
+    let target = root.join("target");
+    if target.exists() {
+        fs::remove_dir_all(&target).map_err(|e| format!("cannot clean {:?}: {}", target, e))?;
+    }
+    std::env::set_current_dir(root).unwrap();
+
+    zz::parser::capture();
+    let r = std::panic::catch_unwind(|| {
+        zz::build(zz::BuildSet::Emit, "default", zz::make::Stage::test(), false)
+    });
+    let captured = zz::parser::captured();
+
+    let exit = match r {
+        Ok(()) => 0,
+        Err(e) => match e.downcast_ref::<zz::Exit>() {
+            Some(zz::Exit(code)) => *code,
+            None => {
+                let msg = e
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .or(e.downcast_ref::<&str>().map(|s| s.to_string()))
+                    .unwrap_or_default();
+                return Err(format!("compiler panicked: {}", msg));
+            }
+        },
+    };
+
+    let mut diagnostics: Vec<Diagnostic> = captured
+        .into_iter()
+        .map(|j| Diagnostic {
+            message: normalize(&j.message, root),
+            file: normalize(&j.file_name, root),
+            line: j.line_start,
+            column: j.column_start,
+        })
+        .collect();
+    // modules are checked in parallel, so the order they report in isn't stable
+    diagnostics.sort_by(|a, b| {
+        (&a.file, a.line, a.column, &a.message).cmp(&(&b.file, b.line, b.column, &b.message))
+    });
+
+    Ok(Outcome { exit, diagnostics })
+}
+
+/// the emitted c of the project's own modules, in one file
+fn emitted(root: &Path) -> String {
+    let (_, project) = zz::project::load(root);
+    let prefix = format!("{}_", project.project.name);
+    let dir = root
+        .join("target")
+        .join(zz::make::Stage::test().to_string())
+        .join("zz");
+
+    let mut files: Vec<PathBuf> = match fs::read_dir(&dir) {
+        Ok(rd) => rd.filter_map(|e| e.ok().map(|e| e.path())).collect(),
+        Err(_) => Vec::new(),
+    };
+    files.retain(|p| {
+        let name = p.file_name().unwrap().to_string_lossy();
+        name.starts_with(&prefix) && (name.ends_with(".c") || name.ends_with(".cpp"))
+    });
+    files.sort();
+
+    let mut r = String::new();
+    for file in files {
+        let c = fs::read_to_string(&file).expect(&format!("cannot read {:?}", file));
+        r.push_str(&format!(
+            "/* {} */\n{}\n",
+            file.file_name().unwrap().to_string_lossy(),
+            normalize(&c, root)
+        ));
+    }
+    r
+}
+
+/// compare against an expectation file, or overwrite it when blessing
+fn expect(path: &Path, actual: &str, bless: bool) -> Result<(), String> {
+    if bless {
+        fs::write(path, actual).map_err(|e| format!("cannot write {:?}: {}", path, e))?;
+        return Ok(());
+    }
+    let expected = match fs::read_to_string(path) {
+        Ok(v) => v,
+        Err(_) => return Err(format!("{:?} is missing. run with ZZ_BLESS=1 to create it", path)),
+    };
+    if expected == actual {
+        return Ok(());
+    }
+    let diff = similar::TextDiff::from_lines(expected.as_str(), actual);
+    Err(format!(
+        "{:?} differs:\n{}",
+        path,
+        diff.unified_diff().context_radius(3).header("expected", "actual")
+    ))
+}
+
+fn check(root: &Path, mustpass: bool, bless: bool) -> Result<(), String> {
+    let outcome = run(root)?;
+    if mustpass && outcome.exit != 0 {
+        return Err(format!("exited with {}, but it must pass", outcome.exit));
+    }
+    if !mustpass && outcome.exit == 0 {
+        return Err("passed, but it should not".to_string());
+    }
+
+    let mut errors = Vec::new();
+    let json = serde_json::to_string_pretty(&outcome).unwrap() + "\n";
+    if let Err(e) = expect(&root.join("expected.json"), &json, bless) {
+        errors.push(e);
+    }
+    // a failed build may stop anywhere, only the diagnostics are stable
+    if mustpass {
+        if let Err(e) = expect(&root.join("expected.c"), &emitted(root), bless) {
+            errors.push(e);
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+#[test]
+fn snapshots() {
+    let bless = std::env::var("ZZ_BLESS").is_ok();
+    zz::parser::ERRORS_AS_JSON.store(true, Ordering::SeqCst);
+
+    // exits unwind through the compiler while capturing, they are not failures
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if info.payload().downcast_ref::<zz::Exit>().is_none() {
+            hook(info);
+        }
+    }));
+
+    let mut failed = Vec::new();
+    let mut total = 0;
+    for (kind, mustpass) in &[("mustpass", true), ("mustfail", false)] {
+        for root in cases(kind) {
+            total += 1;
+            let name = format!("{}/{}", kind, root.file_name().unwrap().to_string_lossy());
+            match check(&root, *mustpass, bless) {
+                Ok(()) => eprintln!("snapshot {} ... ok", name),
+                Err(e) => {
+                    eprintln!("snapshot {} ... FAILED\n{}", name, e);
+                    failed.push(name);
+                }
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        panic!(
+            "{} of {} snapshots failed: {}\nrun with ZZ_BLESS=1 to accept the current output",
+            failed.len(),
+            total,
+            failed.join(", ")
+        );
+    }
+}
@@ -0,0 +1,13 @@
+const fs = require('fs');
+const assert = require('assert');
+const wasmtest = require('./target/test/wasm/wasmtest.js');
+
+wasmtest.load(fs.readFileSync('./target/test/wasm/wasmtest.wasm')).then((m) => {
+    assert.strictEqual(m.wasmtest.add(2, 3), 5);
+    assert.strictEqual(m.wasmtest.is_even(4), true);
+    assert.strictEqual(m.wasmtest.is_even(7), false);
+    assert.strictEqual(m.wasmtest.wide(21n), 42n);
+}).catch((e) => {
+    console.error(e);
+    process.exit(1);
+});